@@ -7,6 +7,7 @@ import 'package:openclaw_mobile/src/rust/api/events.dart';
 import 'package:openclaw_mobile/src/rust/api/connection.dart';
 import 'package:openclaw_mobile/src/rust/api/simple.dart';
 import 'package:openclaw_mobile/src/rust/frb_generated.dart';
+import 'package:openclaw_mobile/src/services/device_token_storage.dart';
 import 'package:openclaw_mobile/src/services/gateway_service.dart';
 import 'package:openclaw_mobile/src/ui/screens/design_system_demo.dart';
 import 'package:openclaw_mobile/src/ui/screens/terminal_screen.dart';
@@ -18,13 +19,21 @@ final gatewayServiceProvider = Provider<GatewayService>((ref) {
   throw UnimplementedError('必须在 ProviderScope 中提供 GatewayService。');
 });
 
+final deviceIdentityProvider = FutureProvider<DeviceIdentityInfo?>((ref) {
+  return ref.read(gatewayServiceProvider).deviceIdentity();
+});
+
 final chatControllerProvider =
     StateNotifierProvider<ChatController, ChatState>((ref) {
   return ChatController(ref.read(gatewayServiceProvider));
 });
 
-Future<void> bootstrap(
-    {GatewayService? gatewayService, RustLibApi? rustApi}) async {
+Future<void> bootstrap({
+  GatewayService? gatewayService,
+  RustLibApi? rustApi,
+  DeviceTokenStorage? deviceTokenStorage,
+  String? identityDir,
+}) async {
   WidgetsFlutterBinding.ensureInitialized();
 
   Object? startupError;
@@ -43,11 +52,19 @@ Future<void> bootstrap(
     }
   }
 
+  if (deviceTokenStorage != null && startupError == null) {
+    try {
+      await registerDeviceTokenStorage(deviceTokenStorage);
+    } catch (error) {
+      debugPrint('Device token storage registration failed: $error');
+    }
+  }
+
   runApp(
     ProviderScope(
       overrides: [
         gatewayServiceProvider.overrideWithValue(
-          gatewayService ?? const RustGatewayService(),
+          gatewayService ?? RustGatewayService(identityDir: identityDir),
         ),
       ],
       child: MyApp(startupError: startupError),
@@ -289,14 +306,14 @@ class ChatController extends StateNotifier<ChatState> {
       final stream = _gatewayService.connect(url: trimmed);
       _gatewaySubscription = stream.listen(
         (event) {
-          final eventText = event.toString();
+          final kind = event.kind();
 
-          if (eventText.startsWith('GatewayEvent.connected')) {
+          if (kind == GatewayEventKind.connected) {
             _setStatus(sessionId, '已连接',
                 isConnecting: false, isConnected: true);
             _addSystemMessage(sessionId, '网关已连接。', status: '已连接');
-          } else if (eventText.startsWith('GatewayEvent.disconnected')) {
-            final reason = _extractEventField(eventText) ?? '未知原因';
+          } else if (kind == GatewayEventKind.disconnected) {
+            final reason = event.text();
             _setStatus(
               sessionId,
               '已断开：$reason',
@@ -308,8 +325,8 @@ class ChatController extends StateNotifier<ChatState> {
               '网关已断开：$reason',
               status: '已断开',
             );
-          } else if (eventText.startsWith('GatewayEvent.message')) {
-            final message = _extractEventField(eventText) ?? eventText;
+          } else if (kind == GatewayEventKind.message) {
+            final message = event.text();
             final canvasScene = CanvasScene.tryParse(message);
             if (canvasScene != null) {
               _updateSession(
@@ -326,9 +343,8 @@ class ChatController extends StateNotifier<ChatState> {
                 ),
               );
             }
-          } else if (eventText.startsWith('GatewayEvent.error')) {
-            final errorMessage =
-                _extractEventField(eventText) ?? '未知错误';
+          } else if (kind == GatewayEventKind.error) {
+            final errorMessage = event.text();
             _setStatus(
               sessionId,
               '错误：$errorMessage',
@@ -341,7 +357,7 @@ class ChatController extends StateNotifier<ChatState> {
               status: '错误',
             );
           } else {
-            _addSystemMessage(sessionId, eventText, status: '网关');
+            _addSystemMessage(sessionId, event.text(), status: '网关');
           }
         },
         onError: (error) {
@@ -447,12 +463,18 @@ class ChatController extends StateNotifier<ChatState> {
       message: message,
       sessionKey: state.activeSessionId,
     );
-    await _gatewayService.sendRequest(frameJson);
-  }
-
-  String? _extractEventField(String eventText) {
-    final match = RegExp(r'field0: (.*)\)$').firstMatch(eventText);
-    return match?.group(1);
+    final sessionId = state.activeSessionId;
+    if (!(state.activeSession?.isConnected ?? false)) {
+      // The outbox holds the turn until the gateway is reachable again.
+      await _gatewayService.sendRequest(frameJson);
+      return;
+    }
+    try {
+      final response = await _gatewayService.request(frameJson);
+      _addSystemMessage(sessionId, response, status: '网关');
+    } catch (error) {
+      _addSystemMessage(sessionId, '请求失败：$error', status: '错误');
+    }
   }
 
   void _setStatus(
@@ -578,6 +600,7 @@ class _ChatScreenState extends ConsumerState<ChatScreen> {
     final chatState = ref.watch(chatControllerProvider);
     final chatController = ref.read(chatControllerProvider.notifier);
     final activeSession = chatState.activeSession ?? ChatSession.initial();
+    final deviceIdentity = ref.watch(deviceIdentityProvider).valueOrNull;
 
     return Scaffold(
       drawer: _SessionDrawer(
@@ -670,6 +693,16 @@ class _ChatScreenState extends ConsumerState<ChatScreen> {
                           labelText: '网关地址',
                         ),
                       ),
+                      if (deviceIdentity != null) ...[
+                        const SizedBox(height: AppSpacing.sm),
+                        SelectableText(
+                          '设备 ID：${deviceIdentity.deviceId}\n'
+                          '设备公钥：${deviceIdentity.publicKey}',
+                          style: AppTypography.caption.copyWith(
+                            color: AppColors.textMuted,
+                          ),
+                        ),
+                      ],
                       const SizedBox(height: AppSpacing.md),
                       Row(
                         children: [
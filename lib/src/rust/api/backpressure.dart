@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `append_data`, `capacity`, `classify`, `close_input`, `close_sink`, `coalesce_into_queued`, `count_drop`, `count_lagged`, `data_len`, `deadline`, `ends_stream`, `evict_oldest`, `flush`, `lock`, `merge_events`, `new`, `new`, `next`, `policy`, `push`, `push`, `reserve`, `set_paused`, `stats`, `stream_key`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DropCounters`, `EventClass`, `InboundLimits`, `InboundQueue`, `OutputBatcher`, `QueueState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+
+            
+
+            enum BackpressurePolicy {
+                    /// Hold events back until the app drains the queue. The session keeps reading
+/// until as many events are held again, then stops reading from the socket;
+/// disconnects and heartbeats still go through.
+block,
+/// Evict the oldest queued event of the same kind, or the oldest droppable event
+/// of any kind when none is queued, to make room for the new one.
+dropOldest,
+/// Merge into the newest queued event for the same stream, or evict like
+/// `DropOldest` when the two cannot be merged.
+coalesce,
+                    ;
+                    
+                }
+
+class BackpressureStats  {
+                final int queued;
+final bool paused;
+final BigInt droppedLogs;
+final BigInt droppedOutput;
+final BigInt droppedEvents;
+final BigInt coalesced;
+/// Events that subscribers reading the raw event stream, such as terminal
+/// streams, missed because they fell behind.
+final BigInt lagged;
+
+                const BackpressureStats({required this.queued ,required this.paused ,required this.droppedLogs ,required this.droppedOutput ,required this.droppedEvents ,required this.coalesced ,required this.lagged ,});
+
+                static Future<BackpressureStats>  default_()=>RustLib.instance.api.crateApiBackpressureBackpressureStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => queued.hashCode^paused.hashCode^droppedLogs.hashCode^droppedOutput.hashCode^droppedEvents.hashCode^coalesced.hashCode^lagged.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BackpressureStats &&
+                runtimeType == other.runtimeType
+                && queued == other.queued&& paused == other.paused&& droppedLogs == other.droppedLogs&& droppedOutput == other.droppedOutput&& droppedEvents == other.droppedEvents&& coalesced == other.coalesced&& lagged == other.lagged;
+        
+            }
+            
\ No newline at end of file
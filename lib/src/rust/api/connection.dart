@@ -4,28 +4,136 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'backpressure.dart';
+import 'deflate.dart';
 import 'events.dart';
+import 'outbox.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'proxy.dart';
+import 'tls.dart';
+part 'connection.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `answered`, `attach`, `awaits`, `backoff_delay`, `build_request_json`, `close_reason`, `close`, `connect_params`, `connect_to_gateway_with_sink_and_connector`, `connection_quality`, `connection_state`, `connections`, `deliver_events`, `dispatch_events`, `duration_to_std`, `emit_or_hold`, `emit_sequenced`, `enable_outbox`, `encode_camera_snapshot`, `encode_frame_bytes`, `ensure_elevated_permission`, `event_sink`, `expired`, `fail_all`, `flush_outbox`, `for_connection`, `forget_device_token`, `new`, `new`, `next_request_id`, `normal_close_frame`, `observe`, `observe`, `outbox_unsent`, `output_batcher`, `parse_metadata`, `parse_request_frame`, `perform_handshake`, `probe`, `recv`, `register_connection`, `register`, `release_held`, `report_expired`, `report_stopped`, `request`, `requested_role`, `response_headers`, `response_result`, `room_for`, `run_session`, `save_device_token`, `set_state`, `start_session`, `stop_requested`, `stop_signal`, `stored_device_token`, `subscribe_gateway_events`, `take`, `track_compression`, `try_emit`, `try_get_inbound_queue`, `try_get_outbound_link`, `try_get_outbox`, `unix_time_ms_after`, `update`, `upgrade_request`
+// These functions are ignored because they have generic arguments: `reserve`, `reserve`, `reserve`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConnectionEntry`, `ConnectionRegistration`, `ConnectionSink`, `DefaultConnector`, `GatewayEventSubscription`, `HandshakeOutcome`, `Handshake`, `Heartbeat`, `OutboundLinkGuard`, `OutboundLink`, `PendingRequests`, `PlannedRestart`, `SequenceCheck`, `SequenceTracker`, `SessionEnd`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `drop`, `drop`, `drop`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `add_event`, `compression`, `connect_to_gateway_with_connector`, `connect`
+// These functions are ignored (category: IgnoreBecauseNotAllowedOwner): `add_event`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `add_event`, `compression`, `connect`, `default`, `default`, `default`, `with_deflate`, `with_proxy`, `with_tls`
+
+
+            /// Agent turns and system events go through the connection's outbox, when it has
+/// one, so they survive an unreachable gateway and a session dropped mid-send.
+Future<RequestDelivery>  sendGatewayRequestFrame({required String handle , required String frameJson }) => RustLib.instance.api.crateApiConnectionSendGatewayRequestFrame(handle: handle, frameJson: frameJson);
+
+/// Closes the connection with a normal Close frame, fails its pending requests and
+/// ends the `connect_to_gateway` task, even while it is waiting to reconnect.
+Future<void>  disconnectGateway({required String handle , required String reason }) => RustLib.instance.api.crateApiConnectionDisconnectGateway(handle: handle, reason: reason);
+
+/// While paused, inbound events collect in the connection's queue and its
+/// backpressure policies decide what happens once the queue is full.
+Future<void>  setGatewayEventsPaused({required String handle , required bool paused }) => RustLib.instance.api.crateApiConnectionSetGatewayEventsPaused(handle: handle, paused: paused);
+
+Future<BackpressureStats>  gatewayBackpressureStats({required String handle }) => RustLib.instance.api.crateApiConnectionGatewayBackpressureStats(handle: handle);
+
+/// All zero when `ConnectionConfig::deflate` is unset.
+Future<CompressionStats>  gatewayCompressionStats({required String handle }) => RustLib.instance.api.crateApiConnectionGatewayCompressionStats(handle: handle);
+
+Future<GatewayResponsePayload>  gatewayRequest({required String handle , required String frameJson , BigInt? timeoutMs }) => RustLib.instance.api.crateApiConnectionGatewayRequest(handle: handle, frameJson: frameJson, timeoutMs: timeoutMs);
+
+/// Runs the connection until it ends for good. A rejected handshake, a certificate
+/// failure or running out of attempts emits its typed event and then returns the
+/// error; `disconnect_gateway` and a closed event stream return `Ok`.
+Stream<GatewayEvent>  connectToGateway({required String handle , required String url , required HandshakeOptions options , ConnectionConfig? config }) => RustLib.instance.api.crateApiConnectionConnectToGateway(handle: handle, url: url, options: options, config: config);
+
+Stream<ConnectionState>  watchConnectionState({required String handle }) => RustLib.instance.api.crateApiConnectionWatchConnectionState(handle: handle);
+
+            class ConnectionConfig  {
+                final Duration backoffBase;
+final Duration maxBackoff;
+/// Sleep a random duration in `[0, backoff]` instead of the full backoff.
+final bool jitter;
+/// Consecutive failed attempts before giving up; `None` retries forever.
+final int? maxAttempts;
+final Duration connectTimeout;
+final Duration heartbeatInterval;
+final Duration heartbeatTimeout;
+final Duration handshakeTimeout;
+final int? maxSessions;
+final Duration restartProbeInterval;
+final Duration restartProbeWindow;
+/// Longest wait honored for a shutdown notice's `restartExpectedMs`.
+final Duration maxRestartWait;
+/// Queue agent turns and system events while disconnected; `None` disables it.
+final OutboxConfig? outbox;
+/// Events buffered between the socket reader and the Dart stream.
+final int inboundCapacity;
+/// Requests buffered between senders and the socket writer.
+final int outboundCapacity;
+final BackpressurePolicy logsPolicy;
+/// Applies to `stream.data` and exec output.
+final BackpressurePolicy outputPolicy;
+/// Applies to every other gateway event and raw message.
+final BackpressurePolicy eventPolicy;
+/// How long consecutive output chunks of one stream are held to merge them
+/// into a single event; zero delivers every chunk on its own.
+final Duration outputBatchWindow;
+/// Delivers a merged batch early once its data reaches this many bytes.
+final int outputBatchBytes;
+/// Extra CA certificates, key pins and trust-on-first-use for `wss://` gateways.
+final TlsOptions? tls;
+/// HTTP `CONNECT` or SOCKS5 proxy to dial the gateway through. Terminal streams
+/// ride the gateway connection, so they use it too.
+final ProxyConfig? proxy;
+/// Extra headers on the WebSocket upgrade request, such as `Authorization`,
+/// `User-Agent` or `Origin`.
+final List<UpgradeHeader> upgradeHeaders;
+/// Offered in `Sec-WebSocket-Protocol`; the gateway must pick one of them.
+final List<String> subprotocols;
+/// Offers permessage-deflate on the gateway socket.
+final DeflateConfig? deflate;
+
+                const ConnectionConfig({required this.backoffBase ,required this.maxBackoff ,required this.jitter ,this.maxAttempts ,required this.connectTimeout ,required this.heartbeatInterval ,required this.heartbeatTimeout ,required this.handshakeTimeout ,this.maxSessions ,required this.restartProbeInterval ,required this.restartProbeWindow ,required this.maxRestartWait ,this.outbox ,required this.inboundCapacity ,required this.outboundCapacity ,required this.logsPolicy ,required this.outputPolicy ,required this.eventPolicy ,required this.outputBatchWindow ,required this.outputBatchBytes ,this.tls ,this.proxy ,required this.upgradeHeaders ,required this.subprotocols ,this.deflate ,});
+
+                static Future<ConnectionConfig>  default_()=>RustLib.instance.api.crateApiConnectionConnectionConfigDefault();
 
 
-            // These functions are ignored because they are not marked as `pub`: `build_request_json`, `connect_to_gateway_with_sink`, `duration_to_std`, `encode_camera_snapshot`, `encode_frame_bytes`, `ensure_elevated_permission`, `next_request_id`, `outbound_request_sender_slot`, `parse_metadata`, `register_outbound_request_sender`, `try_emit`, `try_get_outbound_request_sender`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConnectionConfig`, `OutboundRequestSenderGuard`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`
-// These functions are ignored (category: IgnoreBecauseNotAllowedOwner): `add_event`
-// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+                
 
+                
+        @override
+        int get hashCode => backoffBase.hashCode^maxBackoff.hashCode^jitter.hashCode^maxAttempts.hashCode^connectTimeout.hashCode^heartbeatInterval.hashCode^heartbeatTimeout.hashCode^handshakeTimeout.hashCode^maxSessions.hashCode^restartProbeInterval.hashCode^restartProbeWindow.hashCode^maxRestartWait.hashCode^outbox.hashCode^inboundCapacity.hashCode^outboundCapacity.hashCode^logsPolicy.hashCode^outputPolicy.hashCode^eventPolicy.hashCode^outputBatchWindow.hashCode^outputBatchBytes.hashCode^tls.hashCode^proxy.hashCode^upgradeHeaders.hashCode^subprotocols.hashCode^deflate.hashCode;
+        
 
-            Future<void>  sendGatewayRequestFrame({required String frameJson }) => RustLib.instance.api.crateApiConnectionSendGatewayRequestFrame(frameJson: frameJson);
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConnectionConfig &&
+                runtimeType == other.runtimeType
+                && backoffBase == other.backoffBase&& maxBackoff == other.maxBackoff&& jitter == other.jitter&& maxAttempts == other.maxAttempts&& connectTimeout == other.connectTimeout&& heartbeatInterval == other.heartbeatInterval&& heartbeatTimeout == other.heartbeatTimeout&& handshakeTimeout == other.handshakeTimeout&& maxSessions == other.maxSessions&& restartProbeInterval == other.restartProbeInterval&& restartProbeWindow == other.restartProbeWindow&& maxRestartWait == other.maxRestartWait&& outbox == other.outbox&& inboundCapacity == other.inboundCapacity&& outboundCapacity == other.outboundCapacity&& logsPolicy == other.logsPolicy&& outputPolicy == other.outputPolicy&& eventPolicy == other.eventPolicy&& outputBatchWindow == other.outputBatchWindow&& outputBatchBytes == other.outputBatchBytes&& tls == other.tls&& proxy == other.proxy&& upgradeHeaders == other.upgradeHeaders&& subprotocols == other.subprotocols&& deflate == other.deflate;
+        
+            }
 
-Stream<GatewayEvent>  connectToGateway({required String url }) => RustLib.instance.api.crateApiConnectionConnectToGateway(url: url);
+@freezed
+                sealed class ConnectionState with _$ConnectionState  {
+                    const ConnectionState._();
 
-            
-                abstract class EventSink {
-                     Future<bool>  addEvent({required GatewayEvent event });
+                     const factory ConnectionState.idle() = ConnectionState_Idle;
+ const factory ConnectionState.connecting({   required int attempt , }) = ConnectionState_Connecting;
+ const factory ConnectionState.handshaking() = ConnectionState_Handshaking;
+ const factory ConnectionState.ready({   required int protocol ,  required String role ,/// Subprotocol the gateway picked from `ConnectionConfig::subprotocols`.
+  String? subprotocol , }) = ConnectionState_Ready;
+ const factory ConnectionState.backoff({ /// Unix time in milliseconds.
+  required PlatformInt64 nextRetryAtMs ,  required String reason , }) = ConnectionState_Backoff;
+ const factory ConnectionState.restarting() = ConnectionState_Restarting;
+ const factory ConnectionState.failed({   required bool fatal ,  required String reason , }) = ConnectionState_Failed;
 
+                    
 
+                    
                 }
-                
 
 class GatewayClient  {
                 final String url;
@@ -103,4 +211,50 @@ static Future<GatewayClient>  newInstance({required String url })=>RustLib.insta
                 && url == other.url;
         
             }
+
+class HandshakeOptions  {
+                final String? clientId;
+final String? clientVersion;
+final String? platform;
+final String? displayName;
+final String? instanceId;
+final String? identityDir;
+final String? role;
+final List<String> scopes;
+final String? token;
+final String? password;
+final String? locale;
+final String? userAgent;
+
+                const HandshakeOptions({this.clientId ,this.clientVersion ,this.platform ,this.displayName ,this.instanceId ,this.identityDir ,this.role ,required this.scopes ,this.token ,this.password ,this.locale ,this.userAgent ,});
+
+                static Future<HandshakeOptions>  default_()=>RustLib.instance.api.crateApiConnectionHandshakeOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => clientId.hashCode^clientVersion.hashCode^platform.hashCode^displayName.hashCode^instanceId.hashCode^identityDir.hashCode^role.hashCode^scopes.hashCode^token.hashCode^password.hashCode^locale.hashCode^userAgent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HandshakeOptions &&
+                runtimeType == other.runtimeType
+                && clientId == other.clientId&& clientVersion == other.clientVersion&& platform == other.platform&& displayName == other.displayName&& instanceId == other.instanceId&& identityDir == other.identityDir&& role == other.role&& scopes == other.scopes&& token == other.token&& password == other.password&& locale == other.locale&& userAgent == other.userAgent;
+        
+            }
+
+enum RequestDelivery {
+                    /// Handed to the live session's writer; lost if the session drops first.
+sent,
+/// Held in the outbox until the gateway answers it; `OutboxDelivered` or
+/// `OutboxExpired` follows with its id.
+queued,
+                    ;
+                    
+                }
             
\ No newline at end of file
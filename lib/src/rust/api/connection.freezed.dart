@@ -0,0 +1,409 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'connection.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ConnectionState {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ConnectionState);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ConnectionState()';
+}
+
+
+}
+
+/// @nodoc
+class $ConnectionStateCopyWith<$Res>  {
+$ConnectionStateCopyWith(ConnectionState _, $Res Function(ConnectionState) __);
+}
+
+/// @nodoc
+
+
+class ConnectionState_Idle extends ConnectionState {
+  const ConnectionState_Idle(): super._();
+  
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ConnectionState_Idle);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ConnectionState.idle()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ConnectionState_Connecting extends ConnectionState {
+  const ConnectionState_Connecting({required this.attempt}): super._();
+  
+
+ final  int attempt;
+
+/// Create a copy of ConnectionState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ConnectionState_ConnectingCopyWith<ConnectionState_Connecting> get copyWith => _$ConnectionState_ConnectingCopyWithImpl<ConnectionState_Connecting>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ConnectionState_Connecting&&(identical(other.attempt, attempt) || other.attempt == attempt));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,attempt);
+
+@override
+String toString() {
+  return 'ConnectionState.connecting(attempt: $attempt)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ConnectionState_ConnectingCopyWith<$Res> implements $ConnectionStateCopyWith<$Res> {
+  factory $ConnectionState_ConnectingCopyWith(ConnectionState_Connecting value, $Res Function(ConnectionState_Connecting) _then) = _$ConnectionState_ConnectingCopyWithImpl;
+@useResult
+$Res call({
+ int attempt
+});
+
+
+
+
+}
+/// @nodoc
+class _$ConnectionState_ConnectingCopyWithImpl<$Res>
+    implements $ConnectionState_ConnectingCopyWith<$Res> {
+  _$ConnectionState_ConnectingCopyWithImpl(this._self, this._then);
+
+  final ConnectionState_Connecting _self;
+  final $Res Function(ConnectionState_Connecting) _then;
+
+/// Create a copy of ConnectionState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? attempt = null,}) {
+  return _then(ConnectionState_Connecting(
+attempt: null == attempt ? _self.attempt : attempt // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ConnectionState_Handshaking extends ConnectionState {
+  const ConnectionState_Handshaking(): super._();
+  
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ConnectionState_Handshaking);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ConnectionState.handshaking()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ConnectionState_Ready extends ConnectionState {
+  const ConnectionState_Ready({required this.protocol,required this.role,this.subprotocol}): super._();
+  
+
+ final  int protocol;
+ final  String role;
+/// Subprotocol the gateway picked from `ConnectionConfig::subprotocols`.
+ final  String? subprotocol;
+
+/// Create a copy of ConnectionState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ConnectionState_ReadyCopyWith<ConnectionState_Ready> get copyWith => _$ConnectionState_ReadyCopyWithImpl<ConnectionState_Ready>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ConnectionState_Ready&&(identical(other.protocol, protocol) || other.protocol == protocol)&&(identical(other.role, role) || other.role == role)&&(identical(other.subprotocol, subprotocol) || other.subprotocol == subprotocol));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,protocol,role,subprotocol);
+
+@override
+String toString() {
+  return 'ConnectionState.ready(protocol: $protocol, role: $role, subprotocol: $subprotocol)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ConnectionState_ReadyCopyWith<$Res> implements $ConnectionStateCopyWith<$Res> {
+  factory $ConnectionState_ReadyCopyWith(ConnectionState_Ready value, $Res Function(ConnectionState_Ready) _then) = _$ConnectionState_ReadyCopyWithImpl;
+@useResult
+$Res call({
+ int protocol,  String role,  String? subprotocol
+});
+
+
+
+
+}
+/// @nodoc
+class _$ConnectionState_ReadyCopyWithImpl<$Res>
+    implements $ConnectionState_ReadyCopyWith<$Res> {
+  _$ConnectionState_ReadyCopyWithImpl(this._self, this._then);
+
+  final ConnectionState_Ready _self;
+  final $Res Function(ConnectionState_Ready) _then;
+
+/// Create a copy of ConnectionState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? protocol = null,Object? role = null,Object? subprotocol = freezed,}) {
+  return _then(ConnectionState_Ready(
+protocol: null == protocol ? _self.protocol : protocol // ignore: cast_nullable_to_non_nullable
+as int,role: null == role ? _self.role : role // ignore: cast_nullable_to_non_nullable
+as String,subprotocol: freezed == subprotocol ? _self.subprotocol : subprotocol // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ConnectionState_Backoff extends ConnectionState {
+  const ConnectionState_Backoff({required this.nextRetryAtMs,required this.reason}): super._();
+  
+
+/// Unix time in milliseconds.
+ final  PlatformInt64 nextRetryAtMs;
+ final  String reason;
+
+/// Create a copy of ConnectionState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ConnectionState_BackoffCopyWith<ConnectionState_Backoff> get copyWith => _$ConnectionState_BackoffCopyWithImpl<ConnectionState_Backoff>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ConnectionState_Backoff&&(identical(other.nextRetryAtMs, nextRetryAtMs) || other.nextRetryAtMs == nextRetryAtMs)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,nextRetryAtMs,reason);
+
+@override
+String toString() {
+  return 'ConnectionState.backoff(nextRetryAtMs: $nextRetryAtMs, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ConnectionState_BackoffCopyWith<$Res> implements $ConnectionStateCopyWith<$Res> {
+  factory $ConnectionState_BackoffCopyWith(ConnectionState_Backoff value, $Res Function(ConnectionState_Backoff) _then) = _$ConnectionState_BackoffCopyWithImpl;
+@useResult
+$Res call({
+ PlatformInt64 nextRetryAtMs,  String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ConnectionState_BackoffCopyWithImpl<$Res>
+    implements $ConnectionState_BackoffCopyWith<$Res> {
+  _$ConnectionState_BackoffCopyWithImpl(this._self, this._then);
+
+  final ConnectionState_Backoff _self;
+  final $Res Function(ConnectionState_Backoff) _then;
+
+/// Create a copy of ConnectionState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? nextRetryAtMs = null,Object? reason = null,}) {
+  return _then(ConnectionState_Backoff(
+nextRetryAtMs: null == nextRetryAtMs ? _self.nextRetryAtMs : nextRetryAtMs // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ConnectionState_Restarting extends ConnectionState {
+  const ConnectionState_Restarting(): super._();
+  
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ConnectionState_Restarting);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ConnectionState.restarting()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ConnectionState_Failed extends ConnectionState {
+  const ConnectionState_Failed({required this.fatal,required this.reason}): super._();
+  
+
+ final  bool fatal;
+ final  String reason;
+
+/// Create a copy of ConnectionState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ConnectionState_FailedCopyWith<ConnectionState_Failed> get copyWith => _$ConnectionState_FailedCopyWithImpl<ConnectionState_Failed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ConnectionState_Failed&&(identical(other.fatal, fatal) || other.fatal == fatal)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,fatal,reason);
+
+@override
+String toString() {
+  return 'ConnectionState.failed(fatal: $fatal, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ConnectionState_FailedCopyWith<$Res> implements $ConnectionStateCopyWith<$Res> {
+  factory $ConnectionState_FailedCopyWith(ConnectionState_Failed value, $Res Function(ConnectionState_Failed) _then) = _$ConnectionState_FailedCopyWithImpl;
+@useResult
+$Res call({
+ bool fatal,  String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ConnectionState_FailedCopyWithImpl<$Res>
+    implements $ConnectionState_FailedCopyWith<$Res> {
+  _$ConnectionState_FailedCopyWithImpl(this._self, this._then);
+
+  final ConnectionState_Failed _self;
+  final $Res Function(ConnectionState_Failed) _then;
+
+/// Create a copy of ConnectionState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? fatal = null,Object? reason = null,}) {
+  return _then(ConnectionState_Failed(
+fatal: null == fatal ? _self.fatal : fatal // ignore: cast_nullable_to_non_nullable
+as bool,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,92 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_mask`, `client`, `collect_head`, `decode`, `deflate`, `encode`, `inflate`, `invalid_data`, `new`, `new`, `next_frame`, `offer`, `parse_extensions`, `parse_upgrade_head`, `poll_drain`, `process_inbound`, `process_outbound`, `received`, `sent`, `validate`, `window_bits`, `write_frame`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Codec`, `CompressionCounters`, `DeflateStream`, `Negotiated`, `Phase`, `Transformer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `poll_flush`, `poll_read`, `poll_shutdown`, `poll_write`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `stats`
+
+
+            
+
+            /// Message payload bytes before compression (`sent_bytes`, `received_bytes`) and
+/// as they crossed the socket (`*_wire_bytes`), summed over all reconnects.
+class CompressionStats  {
+                /// Whether the gateway accepted permessage-deflate on the current socket.
+final bool negotiated;
+/// Whether messages sent on the current socket go out uncompressed because
+/// the gateway demanded a client window smaller than 15 bits.
+final bool outgoingCompressionDisabled;
+final BigInt sentBytes;
+final BigInt sentWireBytes;
+final BigInt receivedBytes;
+final BigInt receivedWireBytes;
+
+                const CompressionStats({required this.negotiated ,required this.outgoingCompressionDisabled ,required this.sentBytes ,required this.sentWireBytes ,required this.receivedBytes ,required this.receivedWireBytes ,});
+
+                static Future<CompressionStats>  default_()=>RustLib.instance.api.crateApiDeflateCompressionStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => negotiated.hashCode^outgoingCompressionDisabled.hashCode^sentBytes.hashCode^sentWireBytes.hashCode^receivedBytes.hashCode^receivedWireBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompressionStats &&
+                runtimeType == other.runtimeType
+                && negotiated == other.negotiated&& outgoingCompressionDisabled == other.outgoingCompressionDisabled&& sentBytes == other.sentBytes&& sentWireBytes == other.sentWireBytes&& receivedBytes == other.receivedBytes&& receivedWireBytes == other.receivedWireBytes;
+        
+            }
+
+/// permessage-deflate parameters offered to the gateway.
+///
+/// Outgoing messages can only be compressed with the full 15-bit window, the
+/// only one the deflate backend implements. The offer therefore never invites
+/// the gateway to pick a smaller client window; a gateway that demands one anyway
+/// gets uncompressed outgoing messages, reported by
+/// `CompressionStats::outgoing_compression_disabled`. Incoming messages are
+/// inflated whatever window the gateway compresses with.
+class DeflateConfig  {
+                /// LZ77 window used for outgoing messages. Must be 15, see above.
+final int clientMaxWindowBits;
+/// Window the gateway is asked to compress with.
+final int serverMaxWindowBits;
+/// Resets the compressor after every outgoing message.
+final bool clientNoContextTakeover;
+/// Asks the gateway to reset its compressor after every message.
+final bool serverNoContextTakeover;
+
+                const DeflateConfig({required this.clientMaxWindowBits ,required this.serverMaxWindowBits ,required this.clientNoContextTakeover ,required this.serverNoContextTakeover ,});
+
+                static Future<DeflateConfig>  default_()=>RustLib.instance.api.crateApiDeflateDeflateConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => clientMaxWindowBits.hashCode^serverMaxWindowBits.hashCode^clientNoContextTakeover.hashCode^serverNoContextTakeover.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeflateConfig &&
+                runtimeType == other.runtimeType
+                && clientMaxWindowBits == other.clientMaxWindowBits&& serverMaxWindowBits == other.serverMaxWindowBits&& clientNoContextTakeover == other.clientNoContextTakeover&& serverNoContextTakeover == other.serverNoContextTakeover;
+        
+            }
+            
\ No newline at end of file
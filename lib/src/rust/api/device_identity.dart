@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `auth_message`, `derive_device_id`, `from_pkcs8`, `load_or_create`, `load`, `sign_challenge`, `write_private_file`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DeviceAuthPayload`, `StoredIdentity`
+
+
+            Future<DeviceIdentity>  loadDeviceIdentity({required String directory }) => RustLib.instance.api.crateApiDeviceIdentityLoadDeviceIdentity(directory: directory);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>
+                abstract class DeviceIdentity implements RustOpaqueInterface {
+                     String  deviceId();
+
+
+ String  publicKey();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -9,8 +9,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 
             // These functions are ignored because they are not marked as `pub`: `parse_error_payload`, `parse_event_payload`, `parse_payload`, `parse_request_params`, `parse_response_payload`, `scalar_field`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `GatewayResponsePayload`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `into_dart`, `into_dart`, `into_dart`, `into_dart`, `into_into_dart`, `into_into_dart`, `into_into_dart`, `into_into_dart`, `sse_decode`, `sse_encode`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `into_dart`, `into_dart`, `into_dart`, `into_dart`, `into_into_dart`, `into_into_dart`, `into_into_dart`, `into_into_dart`
 // These functions have error during generation (see debug logs or enable `stop_on_error: true` for more details): `new`
 
 
@@ -131,6 +130,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
   set token(String? token);
 
 
+static Future<ConnectAuth>  default_()=>RustLib.instance.api.crateApiEventsConnectAuthDefault();
+
+
 
                     
                 }
@@ -219,6 +221,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
   set version(String? version);
 
 
+static Future<ConnectClient>  default_()=>RustLib.instance.api.crateApiEventsConnectClientDefault();
+
+
 
                     
                 }
@@ -263,6 +268,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
   set signedAt(PlatformInt64? signedAt);
 
 
+static Future<ConnectDevice>  default_()=>RustLib.instance.api.crateApiEventsConnectDeviceDefault();
+
+
 
                     
                 }
@@ -289,6 +297,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
  BTreeMapStringValue get extra;
 
 
+ BigInt? get lastSeq;
+
+
  String? get locale;
 
 
@@ -328,6 +339,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
   set extra(BTreeMapStringValue extra);
 
 
+  set lastSeq(BigInt? lastSeq);
+
+
   set locale(String? locale);
 
 
@@ -349,6 +363,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
   set userAgent(String? userAgent);
 
 
+static Future<ConnectParams>  default_()=>RustLib.instance.api.crateApiEventsConnectParamsDefault();
+
+
 
                     
                 }
@@ -528,7 +545,14 @@ static Future<GatewayError>  default_()=>RustLib.instance.api.crateApiEventsGate
 
                 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayEvent>>
                 abstract class GatewayEvent implements RustOpaqueInterface {
-                    
+                     GatewayEventKind  kind();
+
+
+/// The reason or message of a disconnect, message or error event; any other
+/// event as its JSON form.
+ String  text();
+
+
 
                     
                 }
@@ -590,6 +614,17 @@ static Future<GatewayError>  default_()=>RustLib.instance.api.crateApiEventsGate
                     
 
                     
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>>
+                abstract class GatewayResponsePayload implements RustOpaqueInterface {
+                     Future<String>  toJson();
+
+
+
+                    
                 }
                 
 
@@ -620,6 +655,9 @@ static Future<GatewayError>  default_()=>RustLib.instance.api.crateApiEventsGate
   set scopes(List<String> scopes);
 
 
+static Future<HelloAuth>  default_()=>RustLib.instance.api.crateApiEventsHelloAuthDefault();
+
+
 
                     
                 }
@@ -672,12 +710,21 @@ static Future<GatewayError>  default_()=>RustLib.instance.api.crateApiEventsGate
  BigInt? get tickIntervalMs;
 
 
+ String? get tickMode;
+
+
   set extra(BTreeMapStringValue extra);
 
 
   set tickIntervalMs(BigInt? tickIntervalMs);
 
 
+  set tickMode(String? tickMode);
+
+
+static Future<HelloPolicy>  default_()=>RustLib.instance.api.crateApiEventsHelloPolicyDefault();
+
+
 
                     
                 }
@@ -1500,4 +1547,40 @@ static Future<SystemProbeResult>  default_()=>RustLib.instance.api.crateApiEvent
                     
                 }
                 
+
+/// Which `GatewayEvent` variant a Dart caller received; the event itself is opaque there.
+enum GatewayEventKind {
+                    connected,
+disconnected,
+message,
+error,
+other,
+                    ;
+                    
+                }
+
+/// A header on the WebSocket upgrade request or response.
+class UpgradeHeader  {
+                final String name;
+final String value;
+
+                const UpgradeHeader({required this.name ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpgradeHeader &&
+                runtimeType == other.runtimeType
+                && name == other.name&& value == other.value;
+        
+            }
             
\ No newline at end of file
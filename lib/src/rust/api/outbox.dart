@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `acknowledge`, `is_queueable`, `open`, `outbox_path`, `persist`, `push`, `start_session`, `take_expired`, `take_unsent`, `unix_time_ms`, `unsent`, `update`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `OutboxItem`, `Outbox`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            class OutboxConfig  {
+                final int maxItems;
+/// How long a queued request may wait for a connection before it expires.
+final Duration ttl;
+/// Directory to persist queued requests in so they survive app restarts.
+final String? persistDir;
+
+                const OutboxConfig({required this.maxItems ,required this.ttl ,this.persistDir ,});
+
+                static Future<OutboxConfig>  default_()=>RustLib.instance.api.crateApiOutboxOutboxConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxItems.hashCode^ttl.hashCode^persistDir.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutboxConfig &&
+                runtimeType == other.runtimeType
+                && maxItems == other.maxItems&& ttl == other.ttl&& persistDir == other.persistDir;
+        
+            }
+            
\ No newline at end of file
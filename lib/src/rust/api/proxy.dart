@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `connect_via_proxy`, `http_connect`, `socks5_connect`, `socks_len`, `socks_reply_message`, `target_address`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            class ProxyConfig  {
+                final ProxyKind kind;
+final String host;
+final int port;
+final String? username;
+final String? password;
+
+                const ProxyConfig({required this.kind ,required this.host ,required this.port ,this.username ,this.password ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^host.hashCode^port.hashCode^username.hashCode^password.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProxyConfig &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& host == other.host&& port == other.port&& username == other.username&& password == other.password;
+        
+            }
+
+enum ProxyKind {
+                    /// HTTP proxy tunnelling through `CONNECT`.
+http,
+socks5,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -5,30 +5,40 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'terminal_emulator.dart';
 
 
-            // These functions are ignored because they are not marked as `pub`: `decode_output_data`, `emit_exec_output`, `send_request_and_wait_response`
+            // These functions are ignored because they are not marked as `pub`: `add_styled`, `add`, `batch_deadline`, `batch_deadline`, `batch`, `data`, `decode_output_data`, `decode`, `error`, `exec_output`, `finish`, `flush_batch`, `flush`, `is_exec_output`, `is_stream_data`, `new`, `output_event`, `output_kind`, `pending_streams`, `stream_closed`, `stream_data`, `system`, `take_pending_stream`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChannelDecoder`, `TerminalOutput`, `Utf8ChunkDecoder`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
 
 
-            Stream<TerminalChunk>  execCommand({required String url , required String command , String? cwd , BigInt? timeoutMs , String? sessionKey }) => RustLib.instance.api.crateApiTerminalExecCommand(url: url, command: command, cwd: cwd, timeoutMs: timeoutMs, sessionKey: sessionKey);
+            Stream<TerminalChunk>  execCommand({required String handle , required String command , String? cwd , BigInt? timeoutMs , String? sessionKey , required bool rawOutput }) => RustLib.instance.api.crateApiTerminalExecCommand(handle: handle, command: command, cwd: cwd, timeoutMs: timeoutMs, sessionKey: sessionKey, rawOutput: rawOutput);
 
-Future<String>  terminalStreamOpen({required String url , int? cols , int? rows , String? term , String? sessionKey }) => RustLib.instance.api.crateApiTerminalTerminalStreamOpen(url: url, cols: cols, rows: rows, term: term, sessionKey: sessionKey);
+Future<String>  terminalStreamOpen({required String handle , int? cols , int? rows , String? term , String? sessionKey }) => RustLib.instance.api.crateApiTerminalTerminalStreamOpen(handle: handle, cols: cols, rows: rows, term: term, sessionKey: sessionKey);
 
-Future<void>  terminalStreamSend({required String url , required String streamId , required List<int> inputBytes , String? sessionKey }) => RustLib.instance.api.crateApiTerminalTerminalStreamSend(url: url, streamId: streamId, inputBytes: inputBytes, sessionKey: sessionKey);
+Future<void>  terminalStreamSend({required String handle , required String streamId , required List<int> inputBytes , String? sessionKey }) => RustLib.instance.api.crateApiTerminalTerminalStreamSend(handle: handle, streamId: streamId, inputBytes: inputBytes, sessionKey: sessionKey);
+
+/// Delivers the output of `stream_id`, starting with anything the gateway sent
+/// since `terminal_stream_open` returned it.
+Stream<TerminalChunk>  terminalStreamAttach({required String handle , required String streamId , required bool rawOutput }) => RustLib.instance.api.crateApiTerminalTerminalStreamAttach(handle: handle, streamId: streamId, rawOutput: rawOutput);
 
             class TerminalChunk  {
                 final String text;
 final String kind;
+final String? channel;
+final Uint8List? data;
+final List<TerminalRun> spans;
 
-                const TerminalChunk({required this.text ,required this.kind ,});
+                const TerminalChunk({required this.text ,required this.kind ,this.channel ,this.data ,required this.spans ,});
 
                 
                 
 
                 
         @override
-        int get hashCode => text.hashCode^kind.hashCode;
+        int get hashCode => text.hashCode^kind.hashCode^channel.hashCode^data.hashCode^spans.hashCode;
         
 
                 
@@ -37,7 +47,7 @@ final String kind;
             identical(this, other) ||
             other is TerminalChunk &&
                 runtimeType == other.runtimeType
-                && text == other.text&& kind == other.kind;
+                && text == other.text&& kind == other.kind&& channel == other.channel&& data == other.data&& spans == other.spans;
         
             }
             
\ No newline at end of file
@@ -0,0 +1,175 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'connection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'terminal_emulator.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `apply_sgr`, `blank_cell`, `blank_row`, `blank`, `clear_wide_neighbours`, `delete_chars`, `delete_lines`, `enter_alternate_screen`, `erase_cells`, `erase_in_display`, `erase_in_line`, `erase_rows`, `extended_color`, `finish`, `indexed`, `insert_blanks`, `insert_lines`, `leave_alternate_screen`, `linefeed`, `mark_rows`, `move_horizontal`, `move_to`, `move_vertical`, `new`, `new`, `param`, `parse`, `print`, `push_scrollback`, `push`, `reset`, `resize_lines`, `resize`, `resize`, `restore_cursor`, `reverse_index`, `save_cursor`, `scroll_down`, `scroll_up`, `select_graphic_rendition`, `set_mode`, `set_scroll_region`, `tab_backward`, `tab_forward`, `take_update`, `to_line`, `wrap_line`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Cell`, `Cursor`, `Row`, `SavedScreen`, `Screen`, `SpanCollector`, `StyledTextParser`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `csi_dispatch`, `csi_dispatch`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `esc_dispatch`, `execute`, `execute`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `osc_dispatch`, `print`, `print`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `default`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>>
+                abstract class TerminalEmulator implements RustOpaqueInterface {
+                     TerminalScreenUpdate  feed({required List<int> bytes });
+
+
+factory TerminalEmulator({required int cols , required int rows , int? scrollbackLimit })=>RustLib.instance.api.crateApiTerminalEmulatorTerminalEmulatorNew(cols: cols, rows: rows, scrollbackLimit: scrollbackLimit);
+
+
+ TerminalScreenUpdate  resize({required int cols , required int rows });
+
+
+ Future<String>  resizeRequest({required GatewayClient client , required String requestId , String? streamId , required int cols , required int rows , String? sessionKey });
+
+
+ List<TerminalLine>  scrollbackLines({required int start , required int count });
+
+
+ TerminalScreenUpdate  snapshot();
+
+
+
+                    
+                }
+                
+
+@freezed
+                sealed class TerminalColor with _$TerminalColor  {
+                    const TerminalColor._();
+
+                     const factory TerminalColor.default_() = TerminalColor_Default;
+ const factory TerminalColor.indexed({   required int index , }) = TerminalColor_Indexed;
+ const factory TerminalColor.rgb({   required int red ,  required int green ,  required int blue , }) = TerminalColor_Rgb;
+
+                    
+
+                    
+                }
+
+class TerminalLine  {
+                final int row;
+final List<TerminalRun> runs;
+final bool wrapped;
+
+                const TerminalLine({required this.row ,required this.runs ,required this.wrapped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => row.hashCode^runs.hashCode^wrapped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TerminalLine &&
+                runtimeType == other.runtimeType
+                && row == other.row&& runs == other.runs&& wrapped == other.wrapped;
+        
+            }
+
+class TerminalRun  {
+                final String text;
+final TerminalStyle style;
+
+                const TerminalRun({required this.text ,required this.style ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^style.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TerminalRun &&
+                runtimeType == other.runtimeType
+                && text == other.text&& style == other.style;
+        
+            }
+
+class TerminalScreenUpdate  {
+                final int cols;
+final int rows;
+final int cursorRow;
+final int cursorCol;
+final bool cursorVisible;
+final bool alternateScreen;
+final bool applicationCursorKeys;
+final bool bracketedPaste;
+final bool full;
+final List<TerminalLine> lines;
+final int scrollbackLen;
+final String? title;
+
+                const TerminalScreenUpdate({required this.cols ,required this.rows ,required this.cursorRow ,required this.cursorCol ,required this.cursorVisible ,required this.alternateScreen ,required this.applicationCursorKeys ,required this.bracketedPaste ,required this.full ,required this.lines ,required this.scrollbackLen ,this.title ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => cols.hashCode^rows.hashCode^cursorRow.hashCode^cursorCol.hashCode^cursorVisible.hashCode^alternateScreen.hashCode^applicationCursorKeys.hashCode^bracketedPaste.hashCode^full.hashCode^lines.hashCode^scrollbackLen.hashCode^title.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TerminalScreenUpdate &&
+                runtimeType == other.runtimeType
+                && cols == other.cols&& rows == other.rows&& cursorRow == other.cursorRow&& cursorCol == other.cursorCol&& cursorVisible == other.cursorVisible&& alternateScreen == other.alternateScreen&& applicationCursorKeys == other.applicationCursorKeys&& bracketedPaste == other.bracketedPaste&& full == other.full&& lines == other.lines&& scrollbackLen == other.scrollbackLen&& title == other.title;
+        
+            }
+
+class TerminalStyle  {
+                final TerminalColor foreground;
+final TerminalColor background;
+final bool bold;
+final bool dim;
+final bool italic;
+final bool underline;
+final bool inverse;
+final bool hidden;
+final bool strikethrough;
+
+                const TerminalStyle({required this.foreground ,required this.background ,required this.bold ,required this.dim ,required this.italic ,required this.underline ,required this.inverse ,required this.hidden ,required this.strikethrough ,});
+
+                static Future<TerminalStyle>  default_()=>RustLib.instance.api.crateApiTerminalEmulatorTerminalStyleDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => foreground.hashCode^background.hashCode^bold.hashCode^dim.hashCode^italic.hashCode^underline.hashCode^inverse.hashCode^hidden.hashCode^strikethrough.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TerminalStyle &&
+                runtimeType == other.runtimeType
+                && foreground == other.foreground&& background == other.background&& bold == other.bold&& dim == other.dim&& italic == other.italic&& underline == other.underline&& inverse == other.inverse&& hidden == other.hidden&& strikethrough == other.strikethrough;
+        
+            }
+            
\ No newline at end of file
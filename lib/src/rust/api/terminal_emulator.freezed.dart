@@ -0,0 +1,209 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'terminal_emulator.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$TerminalColor {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TerminalColor);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'TerminalColor()';
+}
+
+
+}
+
+/// @nodoc
+class $TerminalColorCopyWith<$Res>  {
+$TerminalColorCopyWith(TerminalColor _, $Res Function(TerminalColor) __);
+}
+
+/// @nodoc
+
+
+class TerminalColor_Default extends TerminalColor {
+  const TerminalColor_Default(): super._();
+  
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TerminalColor_Default);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'TerminalColor.default_()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class TerminalColor_Indexed extends TerminalColor {
+  const TerminalColor_Indexed({required this.index}): super._();
+  
+
+ final  int index;
+
+/// Create a copy of TerminalColor
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TerminalColor_IndexedCopyWith<TerminalColor_Indexed> get copyWith => _$TerminalColor_IndexedCopyWithImpl<TerminalColor_Indexed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TerminalColor_Indexed&&(identical(other.index, index) || other.index == index));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,index);
+
+@override
+String toString() {
+  return 'TerminalColor.indexed(index: $index)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TerminalColor_IndexedCopyWith<$Res> implements $TerminalColorCopyWith<$Res> {
+  factory $TerminalColor_IndexedCopyWith(TerminalColor_Indexed value, $Res Function(TerminalColor_Indexed) _then) = _$TerminalColor_IndexedCopyWithImpl;
+@useResult
+$Res call({
+ int index
+});
+
+
+
+
+}
+/// @nodoc
+class _$TerminalColor_IndexedCopyWithImpl<$Res>
+    implements $TerminalColor_IndexedCopyWith<$Res> {
+  _$TerminalColor_IndexedCopyWithImpl(this._self, this._then);
+
+  final TerminalColor_Indexed _self;
+  final $Res Function(TerminalColor_Indexed) _then;
+
+/// Create a copy of TerminalColor
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? index = null,}) {
+  return _then(TerminalColor_Indexed(
+index: null == index ? _self.index : index // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class TerminalColor_Rgb extends TerminalColor {
+  const TerminalColor_Rgb({required this.red,required this.green,required this.blue}): super._();
+  
+
+ final  int red;
+ final  int green;
+ final  int blue;
+
+/// Create a copy of TerminalColor
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TerminalColor_RgbCopyWith<TerminalColor_Rgb> get copyWith => _$TerminalColor_RgbCopyWithImpl<TerminalColor_Rgb>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TerminalColor_Rgb&&(identical(other.red, red) || other.red == red)&&(identical(other.green, green) || other.green == green)&&(identical(other.blue, blue) || other.blue == blue));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,red,green,blue);
+
+@override
+String toString() {
+  return 'TerminalColor.rgb(red: $red, green: $green, blue: $blue)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TerminalColor_RgbCopyWith<$Res> implements $TerminalColorCopyWith<$Res> {
+  factory $TerminalColor_RgbCopyWith(TerminalColor_Rgb value, $Res Function(TerminalColor_Rgb) _then) = _$TerminalColor_RgbCopyWithImpl;
+@useResult
+$Res call({
+ int red,  int green,  int blue
+});
+
+
+
+
+}
+/// @nodoc
+class _$TerminalColor_RgbCopyWithImpl<$Res>
+    implements $TerminalColor_RgbCopyWith<$Res> {
+  _$TerminalColor_RgbCopyWithImpl(this._self, this._then);
+
+  final TerminalColor_Rgb _self;
+  final $Res Function(TerminalColor_Rgb) _then;
+
+/// Create a copy of TerminalColor
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? red = null,Object? green = null,Object? blue = null,}) {
+  return _then(TerminalColor_Rgb(
+red: null == red ? _self.red : red // ignore: cast_nullable_to_non_nullable
+as int,green: null == green ? _self.green : green // ignore: cast_nullable_to_non_nullable
+as int,blue: null == blue ? _self.blue : blue // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
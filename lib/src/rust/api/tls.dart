@@ -0,0 +1,61 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `certificate_failure_event`, `client_certificate_alert`, `client_identity`, `config`, `der_sequence`, `explain`, `get`, `known_host_key`, `new`, `normalize_pin`, `read_known_hosts`, `reject`, `remember`, `spki_fingerprint`, `take_rejection`, `to_event`, `verify_self_signed`, `verify`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CertificateRejection`, `ClientCertificateError`, `GatewayCertVerifier`, `KnownHosts`, `PortVerifier`, `TlsClient`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `supported_verify_schemes`, `verify_server_cert`, `verify_tls12_signature`, `verify_tls13_signature`
+
+
+            
+
+            class TlsOptions  {
+                /// PEM encoded CA certificates trusted in addition to the system roots.
+final List<String> extraCaPem;
+/// SHA-256 hashes of the server's SubjectPublicKeyInfo, base64 encoded with an
+/// optional `sha256/` prefix. The server's key must match one of them on top of
+/// the usual certificate validation.
+final List<String> spkiPins;
+/// Record the server's key on first connect and reject any other key later.
+/// Requires `known_hosts_dir`.
+final bool trustOnFirstUse;
+/// Where first-use fingerprints are stored, keyed by `host:port`.
+final String? knownHostsDir;
+/// Accept a self-signed server certificate in place of a trusted CA chain once
+/// its key matches a pin or the first-use record. Expiry and hostname are still
+/// checked. Requires `spki_pins` or `trust_on_first_use`.
+final bool allowSelfSigned;
+/// PEM encoded client certificate chain, leaf first, presented for mutual TLS.
+final String? clientCertChainPem;
+/// PEM encoded private key of the client certificate (PKCS#8, PKCS#1 or SEC1).
+final String? clientKeyPem;
+/// DER encoded PKCS#8 private key, used when `client_key_pem` is not set.
+final Uint8List? clientKeyPkcs8;
+
+                const TlsOptions({required this.extraCaPem ,required this.spkiPins ,required this.trustOnFirstUse ,this.knownHostsDir ,required this.allowSelfSigned ,this.clientCertChainPem ,this.clientKeyPem ,this.clientKeyPkcs8 ,});
+
+                static Future<TlsOptions>  default_()=>RustLib.instance.api.crateApiTlsTlsOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => extraCaPem.hashCode^spkiPins.hashCode^trustOnFirstUse.hashCode^knownHostsDir.hashCode^allowSelfSigned.hashCode^clientCertChainPem.hashCode^clientKeyPem.hashCode^clientKeyPkcs8.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TlsOptions &&
+                runtimeType == other.runtimeType
+                && extraCaPem == other.extraCaPem&& spkiPins == other.spkiPins&& trustOnFirstUse == other.trustOnFirstUse&& knownHostsDir == other.knownHostsDir&& allowSelfSigned == other.allowSelfSigned&& clientCertChainPem == other.clientCertChainPem&& clientKeyPem == other.clientKeyPem&& clientKeyPkcs8 == other.clientKeyPkcs8;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,31 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `device_token_key`, `device_token_store_slot`, `in_directory`, `read_tokens`, `registered_device_token_store`, `update_tokens`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DartTokenStore`, `FileTokenStore`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `clear`, `load`, `save`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `clear`, `clear`, `load`, `load`, `save`, `save`
+
+
+            Future<void>  registerDeviceTokenStore({required CallbackTokenStore store }) => RustLib.instance.api.crateApiTokenStoreRegisterDeviceTokenStore(store: store);
+
+Future<void>  clearDeviceTokenStore() => RustLib.instance.api.crateApiTokenStoreClearDeviceTokenStore();
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>>
+                abstract class CallbackTokenStore implements RustOpaqueInterface {
+                      // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+static Future<CallbackTokenStore>  newInstance({required FutureOr<String?> Function(String) load , required FutureOr<void> Function(String, String) save , required FutureOr<void> Function(String) clear })=>RustLib.instance.api.crateApiTokenStoreCallbackTokenStoreNew(load: load, save: save, clear: clear);
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -3,10 +3,18 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/backpressure.dart';
 import 'api/connection.dart';
+import 'api/deflate.dart';
+import 'api/device_identity.dart';
 import 'api/events.dart';
+import 'api/outbox.dart';
+import 'api/proxy.dart';
 import 'api/simple.dart';
 import 'api/terminal.dart';
+import 'api/terminal_emulator.dart';
+import 'api/tls.dart';
+import 'api/token_store.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -71,7 +79,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
                   String get codegenVersion => '2.11.1';
 
                   @override
-                  int get rustContentHash => 109217522;
+                  int get rustContentHash => 92992336;
 
                   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
                     stem: 'rust_lib',
@@ -102,6 +110,8 @@ void crateApiEventsAgentTurnAutoAccessorSetThinking({required AgentTurn that , S
 
 void crateApiEventsAgentTurnAutoAccessorSetTimeoutSeconds({required AgentTurn that , BigInt? timeoutSeconds });
 
+Future<CallbackTokenStore> crateApiTokenStoreCallbackTokenStoreNew({required FutureOr<String?> Function(String) load , required FutureOr<void> Function(String, String) save , required FutureOr<void> Function(String) clear });
+
 String? crateApiEventsCameraSnapshotAutoAccessorGetCompression({required CameraSnapshot that });
 
 String crateApiEventsCameraSnapshotAutoAccessorGetData({required CameraSnapshot that });
@@ -146,6 +156,8 @@ void crateApiEventsConnectAuthAutoAccessorSetPassword({required ConnectAuth that
 
 void crateApiEventsConnectAuthAutoAccessorSetToken({required ConnectAuth that , String? token });
 
+Future<ConnectAuth> crateApiEventsConnectAuthDefault();
+
 BTreeMapStringValue crateApiEventsConnectChallengeAutoAccessorGetExtra({required ConnectChallenge that });
 
 String crateApiEventsConnectChallengeAutoAccessorGetNonce({required ConnectChallenge that });
@@ -194,6 +206,8 @@ void crateApiEventsConnectClientAutoAccessorSetPlatform({required ConnectClient
 
 void crateApiEventsConnectClientAutoAccessorSetVersion({required ConnectClient that , String? version });
 
+Future<ConnectClient> crateApiEventsConnectClientDefault();
+
 BTreeMapStringValue crateApiEventsConnectDeviceAutoAccessorGetExtra({required ConnectDevice that });
 
 String? crateApiEventsConnectDeviceAutoAccessorGetId({required ConnectDevice that });
@@ -218,6 +232,8 @@ void crateApiEventsConnectDeviceAutoAccessorSetSignature({required ConnectDevice
 
 void crateApiEventsConnectDeviceAutoAccessorSetSignedAt({required ConnectDevice that , PlatformInt64? signedAt });
 
+Future<ConnectDevice> crateApiEventsConnectDeviceDefault();
+
 ConnectAuth? crateApiEventsConnectParamsAutoAccessorGetAuth({required ConnectParams that });
 
 List<String> crateApiEventsConnectParamsAutoAccessorGetCaps({required ConnectParams that });
@@ -230,6 +246,8 @@ ConnectDevice? crateApiEventsConnectParamsAutoAccessorGetDevice({required Connec
 
 BTreeMapStringValue crateApiEventsConnectParamsAutoAccessorGetExtra({required ConnectParams that });
 
+BigInt? crateApiEventsConnectParamsAutoAccessorGetLastSeq({required ConnectParams that });
+
 String? crateApiEventsConnectParamsAutoAccessorGetLocale({required ConnectParams that });
 
 int? crateApiEventsConnectParamsAutoAccessorGetMaxProtocol({required ConnectParams that });
@@ -256,6 +274,8 @@ void crateApiEventsConnectParamsAutoAccessorSetDevice({required ConnectParams th
 
 void crateApiEventsConnectParamsAutoAccessorSetExtra({required ConnectParams that , required BTreeMapStringValue extra });
 
+void crateApiEventsConnectParamsAutoAccessorSetLastSeq({required ConnectParams that , BigInt? lastSeq });
+
 void crateApiEventsConnectParamsAutoAccessorSetLocale({required ConnectParams that , String? locale });
 
 void crateApiEventsConnectParamsAutoAccessorSetMaxProtocol({required ConnectParams that , int? maxProtocol });
@@ -270,6 +290,12 @@ void crateApiEventsConnectParamsAutoAccessorSetScopes({required ConnectParams th
 
 void crateApiEventsConnectParamsAutoAccessorSetUserAgent({required ConnectParams that , String? userAgent });
 
+Future<ConnectParams> crateApiEventsConnectParamsDefault();
+
+String crateApiDeviceIdentityDeviceIdentityDeviceId({required DeviceIdentity that });
+
+String crateApiDeviceIdentityDeviceIdentityPublicKey({required DeviceIdentity that });
+
 String? crateApiEventsExecOutputAutoAccessorGetData({required ExecOutput that });
 
 String? crateApiEventsExecOutputAutoAccessorGetEncoding({required ExecOutput that });
@@ -368,6 +394,10 @@ void crateApiEventsGatewayErrorAutoAccessorSetRetryable({required GatewayError t
 
 Future<GatewayError> crateApiEventsGatewayErrorDefault();
 
+GatewayEventKind crateApiEventsGatewayEventKind({required GatewayEvent that });
+
+String crateApiEventsGatewayEventText({required GatewayEvent that });
+
 String crateApiEventsGatewayRequestFrameAutoAccessorGetFrameType({required GatewayRequestFrame that });
 
 String crateApiEventsGatewayRequestFrameAutoAccessorGetId({required GatewayRequestFrame that });
@@ -390,6 +420,8 @@ void crateApiEventsGatewayRequestFrameAutoAccessorSetSessionKey({required Gatewa
 
 Future<String> crateApiEventsGatewayRequestFrameToJson({required GatewayRequestFrame that });
 
+Future<String> crateApiEventsGatewayResponsePayloadToJson({required GatewayResponsePayload that });
+
 String? crateApiEventsHelloAuthAutoAccessorGetDeviceToken({required HelloAuth that });
 
 BTreeMapStringValue crateApiEventsHelloAuthAutoAccessorGetExtra({required HelloAuth that });
@@ -406,6 +438,8 @@ void crateApiEventsHelloAuthAutoAccessorSetRole({required HelloAuth that , Strin
 
 void crateApiEventsHelloAuthAutoAccessorSetScopes({required HelloAuth that , required List<String> scopes });
 
+Future<HelloAuth> crateApiEventsHelloAuthDefault();
+
 HelloAuth? crateApiEventsHelloOkAutoAccessorGetAuth({required HelloOk that });
 
 BTreeMapStringValue crateApiEventsHelloOkAutoAccessorGetExtra({required HelloOk that });
@@ -430,10 +464,16 @@ BTreeMapStringValue crateApiEventsHelloPolicyAutoAccessorGetExtra({required Hell
 
 BigInt? crateApiEventsHelloPolicyAutoAccessorGetTickIntervalMs({required HelloPolicy that });
 
+String? crateApiEventsHelloPolicyAutoAccessorGetTickMode({required HelloPolicy that });
+
 void crateApiEventsHelloPolicyAutoAccessorSetExtra({required HelloPolicy that , required BTreeMapStringValue extra });
 
 void crateApiEventsHelloPolicyAutoAccessorSetTickIntervalMs({required HelloPolicy that , BigInt? tickIntervalMs });
 
+void crateApiEventsHelloPolicyAutoAccessorSetTickMode({required HelloPolicy that , String? tickMode });
+
+Future<HelloPolicy> crateApiEventsHelloPolicyDefault();
+
 BTreeMapStringValue crateApiEventsLogEntryAutoAccessorGetExtra({required LogEntry that });
 
 BTreeMapStringValue? crateApiEventsLogEntryAutoAccessorGetFields({required LogEntry that });
@@ -872,9 +912,35 @@ void crateApiEventsSystemProbeResultAutoAccessorSetNetwork({required SystemProbe
 
 Future<SystemProbeResult> crateApiEventsSystemProbeResultDefault();
 
-Stream<GatewayEvent> crateApiConnectionConnectToGateway({required String url });
+TerminalScreenUpdate crateApiTerminalEmulatorTerminalEmulatorFeed({required TerminalEmulator that , required List<int> bytes });
 
-Stream<TerminalChunk> crateApiTerminalExecCommand({required String url , required String command , String? cwd , BigInt? timeoutMs , String? sessionKey });
+TerminalEmulator crateApiTerminalEmulatorTerminalEmulatorNew({required int cols , required int rows , int? scrollbackLimit });
+
+TerminalScreenUpdate crateApiTerminalEmulatorTerminalEmulatorResize({required TerminalEmulator that , required int cols , required int rows });
+
+Future<String> crateApiTerminalEmulatorTerminalEmulatorResizeRequest({required TerminalEmulator that , required GatewayClient client , required String requestId , String? streamId , required int cols , required int rows , String? sessionKey });
+
+List<TerminalLine> crateApiTerminalEmulatorTerminalEmulatorScrollbackLines({required TerminalEmulator that , required int start , required int count });
+
+TerminalScreenUpdate crateApiTerminalEmulatorTerminalEmulatorSnapshot({required TerminalEmulator that });
+
+Future<BackpressureStats> crateApiBackpressureBackpressureStatsDefault();
+
+Future<void> crateApiTokenStoreClearDeviceTokenStore();
+
+Future<CompressionStats> crateApiDeflateCompressionStatsDefault();
+
+Stream<GatewayEvent> crateApiConnectionConnectToGateway({required String handle , required String url , required HandshakeOptions options , ConnectionConfig? config });
+
+Future<ConnectionConfig> crateApiConnectionConnectionConfigDefault();
+
+Future<DeflateConfig> crateApiDeflateDeflateConfigDefault();
+
+Future<void> crateApiConnectionDisconnectGateway({required String handle , required String reason });
+
+Stream<TerminalChunk> crateApiTerminalExecCommand({required String handle , required String command , String? cwd , BigInt? timeoutMs , String? sessionKey , required bool rawOutput });
+
+Future<BackpressureStats> crateApiConnectionGatewayBackpressureStats({required String handle });
 
 Future<String> crateApiConnectionGatewayClientAgentTurnRequest({required GatewayClient that , required String requestId , required String message , String? model , String? thinking , BigInt? timeoutSeconds , String? sessionKey });
 
@@ -912,15 +978,37 @@ Future<String> crateApiConnectionGatewayClientSystemEventRequest({required Gatew
 
 Future<String> crateApiConnectionGatewayClientSystemProbeRequest({required GatewayClient that , required String requestId , bool? network , bool? disk , bool? gateway , String? sessionKey });
 
+Future<CompressionStats> crateApiConnectionGatewayCompressionStats({required String handle });
+
+Future<GatewayResponsePayload> crateApiConnectionGatewayRequest({required String handle , required String frameJson , BigInt? timeoutMs });
+
 Future<String> crateApiSimpleGreet({required String name });
 
+Future<HandshakeOptions> crateApiConnectionHandshakeOptionsDefault();
+
+Future<DeviceIdentity> crateApiDeviceIdentityLoadDeviceIdentity({required String directory });
+
+Future<OutboxConfig> crateApiOutboxOutboxConfigDefault();
+
 Future<GatewayEvent?> crateApiEventsParseGatewayFrame({required String text });
 
-Future<void> crateApiConnectionSendGatewayRequestFrame({required String frameJson });
+Future<void> crateApiTokenStoreRegisterDeviceTokenStore({required CallbackTokenStore store });
+
+Future<RequestDelivery> crateApiConnectionSendGatewayRequestFrame({required String handle , required String frameJson });
+
+Future<void> crateApiConnectionSetGatewayEventsPaused({required String handle , required bool paused });
+
+Stream<TerminalChunk> crateApiTerminalTerminalStreamAttach({required String handle , required String streamId , required bool rawOutput });
+
+Future<String> crateApiTerminalTerminalStreamOpen({required String handle , int? cols , int? rows , String? term , String? sessionKey });
+
+Future<void> crateApiTerminalTerminalStreamSend({required String handle , required String streamId , required List<int> inputBytes , String? sessionKey });
+
+Future<TerminalStyle> crateApiTerminalEmulatorTerminalStyleDefault();
 
-Future<String> crateApiTerminalTerminalStreamOpen({required String url , int? cols , int? rows , String? term , String? sessionKey });
+Future<TlsOptions> crateApiTlsTlsOptionsDefault();
 
-Future<void> crateApiTerminalTerminalStreamSend({required String url , required String streamId , required List<int> inputBytes , String? sessionKey });
+Stream<ConnectionState> crateApiConnectionWatchConnectionState({required String handle });
 
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AgentTurn;
 
@@ -940,6 +1028,12 @@ RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BTreeMapSt
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BTreeMapStringBoolPtr;
 
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CallbackTokenStore;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CallbackTokenStore;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CallbackTokenStorePtr;
+
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CameraSnapshot;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CameraSnapshot;
@@ -976,6 +1070,12 @@ RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ConnectPar
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ConnectParamsPtr;
 
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_DeviceIdentity;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_DeviceIdentity;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DeviceIdentityPtr;
+
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ExecOutput;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ExecOutput;
@@ -1018,6 +1118,12 @@ RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_GatewayReq
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GatewayRequestParamsPtr;
 
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_GatewayResponsePayload;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_GatewayResponsePayload;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GatewayResponsePayloadPtr;
+
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_HelloAuth;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_HelloAuth;
@@ -1156,6 +1262,12 @@ RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SystemProb
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SystemProbeResultPtr;
 
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TerminalEmulator;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TerminalEmulator;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TerminalEmulatorPtr;
+
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Value;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Value;
@@ -1429,11 +1541,38 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
         );
         
 
+@override Future<CallbackTokenStore> crateApiTokenStoreCallbackTokenStoreNew({required FutureOr<String?> Function(String) load , required FutureOr<void> Function(String, String) save , required FutureOr<void> Function(String) clear })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(load, serializer);
+sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(save, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(clear, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTokenStoreCallbackTokenStoreNewConstMeta,
+            argValues: [load, save, clear],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTokenStoreCallbackTokenStoreNewConstMeta => const TaskConstMeta(
+            debugName: "CallbackTokenStore_new",
+            argNames: ["load", "save", "clear"],
+        );
+        
+
 @override String? crateApiEventsCameraSnapshotAutoAccessorGetCompression({required CameraSnapshot that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
             
             },
             codec: 
@@ -1458,7 +1597,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
             
             },
             codec: 
@@ -1483,7 +1622,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
             
             },
             codec: 
@@ -1508,7 +1647,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
             
             },
             codec: 
@@ -1533,7 +1672,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
             
             },
             codec: 
@@ -1558,7 +1697,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
             
             },
             codec: 
@@ -1583,7 +1722,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
             
             },
             codec: 
@@ -1608,7 +1747,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
             
             },
             codec: 
@@ -1634,7 +1773,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
 sse_encode_opt_String(compression, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
             
             },
             codec: 
@@ -1660,7 +1799,7 @@ sse_encode_opt_String(compression, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
 sse_encode_String(data, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
             
             },
             codec: 
@@ -1686,7 +1825,7 @@ sse_encode_String(data, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
 sse_encode_String(encoding, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
             
             },
             codec: 
@@ -1712,7 +1851,7 @@ sse_encode_String(encoding, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
             
             },
             codec: 
@@ -1738,7 +1877,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
 sse_encode_opt_String(format, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
             
             },
             codec: 
@@ -1764,7 +1903,7 @@ sse_encode_opt_String(format, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
 sse_encode_opt_box_autoadd_u_32(height, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
             
             },
             codec: 
@@ -1790,7 +1929,7 @@ sse_encode_opt_box_autoadd_u_32(height, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
 sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
             
             },
             codec: 
@@ -1816,7 +1955,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(that, serializer);
 sse_encode_opt_box_autoadd_u_32(width, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
             
             },
             codec: 
@@ -1841,7 +1980,7 @@ sse_encode_opt_box_autoadd_u_32(width, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
             
             },
             codec: 
@@ -1866,7 +2005,7 @@ sse_encode_opt_box_autoadd_u_32(width, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
             
             },
             codec: 
@@ -1891,7 +2030,7 @@ sse_encode_opt_box_autoadd_u_32(width, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
             
             },
             codec: 
@@ -1917,7 +2056,7 @@ sse_encode_opt_box_autoadd_u_32(width, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
             
             },
             codec: 
@@ -1943,7 +2082,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(that, serializer);
 sse_encode_opt_String(password, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
             
             },
             codec: 
@@ -1969,7 +2108,7 @@ sse_encode_opt_String(password, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(that, serializer);
 sse_encode_opt_String(token, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
             
             },
             codec: 
@@ -1990,11 +2129,36 @@ sse_encode_opt_String(token, serializer);
         );
         
 
+@override Future<ConnectAuth> crateApiEventsConnectAuthDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsConnectAuthDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsConnectAuthDefaultConstMeta => const TaskConstMeta(
+            debugName: "ConnectAuth_default",
+            argNames: [],
+        );
+        
+
 @override BTreeMapStringValue crateApiEventsConnectChallengeAutoAccessorGetExtra({required ConnectChallenge that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectChallenge(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
             
             },
             codec: 
@@ -2019,7 +2183,7 @@ sse_encode_opt_String(token, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectChallenge(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
             
             },
             codec: 
@@ -2044,7 +2208,7 @@ sse_encode_opt_String(token, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectChallenge(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
             
             },
             codec: 
@@ -2070,7 +2234,7 @@ sse_encode_opt_String(token, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectChallenge(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
             
             },
             codec: 
@@ -2096,7 +2260,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectChallenge(that, serializer);
 sse_encode_String(nonce, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
             
             },
             codec: 
@@ -2122,7 +2286,7 @@ sse_encode_String(nonce, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectChallenge(that, serializer);
 sse_encode_i_64(ts, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
             
             },
             codec: 
@@ -2147,7 +2311,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
             
             },
             codec: 
@@ -2172,7 +2336,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
             
             },
             codec: 
@@ -2197,7 +2361,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
             
             },
             codec: 
@@ -2222,7 +2386,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
             
             },
             codec: 
@@ -2247,7 +2411,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
             
             },
             codec: 
@@ -2272,7 +2436,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
             
             },
             codec: 
@@ -2297,7 +2461,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
             
             },
             codec: 
@@ -2322,7 +2486,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
             
             },
             codec: 
@@ -2347,7 +2511,7 @@ sse_encode_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
             
             },
             codec: 
@@ -2373,7 +2537,7 @@ sse_encode_i_64(ts, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_opt_String(deviceFamily, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
             
             },
             codec: 
@@ -2399,7 +2563,7 @@ sse_encode_opt_String(deviceFamily, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_opt_String(displayName, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
             
             },
             codec: 
@@ -2425,7 +2589,7 @@ sse_encode_opt_String(displayName, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
             
             },
             codec: 
@@ -2451,7 +2615,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_String(id, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
             
             },
             codec: 
@@ -2477,7 +2641,7 @@ sse_encode_String(id, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_opt_String(instanceId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
             
             },
             codec: 
@@ -2503,7 +2667,7 @@ sse_encode_opt_String(instanceId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_opt_String(mode, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
             
             },
             codec: 
@@ -2529,7 +2693,7 @@ sse_encode_opt_String(mode, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_opt_String(modelIdentifier, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
             
             },
             codec: 
@@ -2555,7 +2719,7 @@ sse_encode_opt_String(modelIdentifier, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_opt_String(platform, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
             
             },
             codec: 
@@ -2581,7 +2745,7 @@ sse_encode_opt_String(platform, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(that, serializer);
 sse_encode_opt_String(version, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
             
             },
             codec: 
@@ -2602,11 +2766,36 @@ sse_encode_opt_String(version, serializer);
         );
         
 
+@override Future<ConnectClient> crateApiEventsConnectClientDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsConnectClientDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsConnectClientDefaultConstMeta => const TaskConstMeta(
+            debugName: "ConnectClient_default",
+            argNames: [],
+        );
+        
+
 @override BTreeMapStringValue crateApiEventsConnectDeviceAutoAccessorGetExtra({required ConnectDevice that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
             
             },
             codec: 
@@ -2631,7 +2820,7 @@ sse_encode_opt_String(version, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
             
             },
             codec: 
@@ -2656,7 +2845,7 @@ sse_encode_opt_String(version, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
             
             },
             codec: 
@@ -2681,7 +2870,7 @@ sse_encode_opt_String(version, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
             
             },
             codec: 
@@ -2706,7 +2895,7 @@ sse_encode_opt_String(version, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
             
             },
             codec: 
@@ -2731,7 +2920,7 @@ sse_encode_opt_String(version, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
             
             },
             codec: 
@@ -2757,7 +2946,7 @@ sse_encode_opt_String(version, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
             
             },
             codec: 
@@ -2783,7 +2972,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
 sse_encode_opt_String(id, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
             
             },
             codec: 
@@ -2809,7 +2998,7 @@ sse_encode_opt_String(id, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
 sse_encode_opt_String(nonce, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
             
             },
             codec: 
@@ -2835,7 +3024,7 @@ sse_encode_opt_String(nonce, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
 sse_encode_opt_String(publicKey, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
             
             },
             codec: 
@@ -2861,7 +3050,7 @@ sse_encode_opt_String(publicKey, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
 sse_encode_opt_String(signature, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
             
             },
             codec: 
@@ -2887,7 +3076,7 @@ sse_encode_opt_String(signature, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(that, serializer);
 sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
             
             },
             codec: 
@@ -2908,11 +3097,36 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
         );
         
 
+@override Future<ConnectDevice> crateApiEventsConnectDeviceDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsConnectDeviceDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsConnectDeviceDefaultConstMeta => const TaskConstMeta(
+            debugName: "ConnectDevice_default",
+            argNames: [],
+        );
+        
+
 @override ConnectAuth? crateApiEventsConnectParamsAutoAccessorGetAuth({required ConnectParams that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
             
             },
             codec: 
@@ -2937,7 +3151,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
             
             },
             codec: 
@@ -2962,7 +3176,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
             
             },
             codec: 
@@ -2987,7 +3201,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
             
             },
             codec: 
@@ -3012,7 +3226,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
             
             },
             codec: 
@@ -3037,7 +3251,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
             
             },
             codec: 
@@ -3058,26 +3272,51 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
         );
         
 
-@override String? crateApiEventsConnectParamsAutoAccessorGetLocale({required ConnectParams that })  { return handler.executeSync(SyncTask(
+@override BigInt? crateApiEventsConnectParamsAutoAccessorGetLastSeq({required ConnectParams that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
           decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiEventsConnectParamsAutoAccessorGetLocaleConstMeta,
+            constMeta: kCrateApiEventsConnectParamsAutoAccessorGetLastSeqConstMeta,
             argValues: [that],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiEventsConnectParamsAutoAccessorGetLocaleConstMeta => const TaskConstMeta(
+        TaskConstMeta get kCrateApiEventsConnectParamsAutoAccessorGetLastSeqConstMeta => const TaskConstMeta(
+            debugName: "ConnectParams_auto_accessor_get_last_seq",
+            argNames: ["that"],
+        );
+        
+
+@override String? crateApiEventsConnectParamsAutoAccessorGetLocale({required ConnectParams that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsConnectParamsAutoAccessorGetLocaleConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsConnectParamsAutoAccessorGetLocaleConstMeta => const TaskConstMeta(
             debugName: "ConnectParams_auto_accessor_get_locale",
             argNames: ["that"],
         );
@@ -3087,7 +3326,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
             
             },
             codec: 
@@ -3112,7 +3351,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
             
             },
             codec: 
@@ -3137,7 +3376,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
             
             },
             codec: 
@@ -3162,7 +3401,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
             
             },
             codec: 
@@ -3187,7 +3426,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
             
             },
             codec: 
@@ -3212,7 +3451,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
             
             },
             codec: 
@@ -3238,7 +3477,7 @@ sse_encode_opt_box_autoadd_i_64(signedAt, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(auth, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
             
             },
             codec: 
@@ -3264,7 +3503,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_list_String(caps, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
             
             },
             codec: 
@@ -3290,7 +3529,7 @@ sse_encode_list_String(caps, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectClient(client, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
             
             },
             codec: 
@@ -3316,7 +3555,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_list_String(commands, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
             
             },
             codec: 
@@ -3342,7 +3581,7 @@ sse_encode_list_String(commands, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectDevice(device, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
             
             },
             codec: 
@@ -3368,7 +3607,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
             
             },
             codec: 
@@ -3389,12 +3628,38 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
         );
         
 
+@override void crateApiEventsConnectParamsAutoAccessorSetLastSeq({required ConnectParams that , BigInt? lastSeq })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
+sse_encode_opt_box_autoadd_u_64(lastSeq, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsConnectParamsAutoAccessorSetLastSeqConstMeta,
+            argValues: [that, lastSeq],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsConnectParamsAutoAccessorSetLastSeqConstMeta => const TaskConstMeta(
+            debugName: "ConnectParams_auto_accessor_set_last_seq",
+            argNames: ["that", "lastSeq"],
+        );
+        
+
 @override void crateApiEventsConnectParamsAutoAccessorSetLocale({required ConnectParams that , String? locale })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_opt_String(locale, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
             
             },
             codec: 
@@ -3420,7 +3685,7 @@ sse_encode_opt_String(locale, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_opt_box_autoadd_u_32(maxProtocol, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
             
             },
             codec: 
@@ -3446,7 +3711,7 @@ sse_encode_opt_box_autoadd_u_32(maxProtocol, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_opt_box_autoadd_u_32(minProtocol, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
             
             },
             codec: 
@@ -3472,7 +3737,7 @@ sse_encode_opt_box_autoadd_u_32(minProtocol, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(permissions, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
             
             },
             codec: 
@@ -3498,7 +3763,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_opt_String(role, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
             
             },
             codec: 
@@ -3524,7 +3789,7 @@ sse_encode_opt_String(role, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_list_String(scopes, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
             
             },
             codec: 
@@ -3550,7 +3815,7 @@ sse_encode_list_String(scopes, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(that, serializer);
 sse_encode_opt_String(userAgent, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
             
             },
             codec: 
@@ -3571,11 +3836,86 @@ sse_encode_opt_String(userAgent, serializer);
         );
         
 
+@override Future<ConnectParams> crateApiEventsConnectParamsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsConnectParamsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsConnectParamsDefaultConstMeta => const TaskConstMeta(
+            debugName: "ConnectParams_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiDeviceIdentityDeviceIdentityDeviceId({required DeviceIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDeviceIdentityDeviceIdentityDeviceIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeviceIdentityDeviceIdentityDeviceIdConstMeta => const TaskConstMeta(
+            debugName: "DeviceIdentity_device_id",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiDeviceIdentityDeviceIdentityPublicKey({required DeviceIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDeviceIdentityDeviceIdentityPublicKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeviceIdentityDeviceIdentityPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "DeviceIdentity_public_key",
+            argNames: ["that"],
+        );
+        
+
 @override String? crateApiEventsExecOutputAutoAccessorGetData({required ExecOutput that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
             
             },
             codec: 
@@ -3600,7 +3940,7 @@ sse_encode_opt_String(userAgent, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
             
             },
             codec: 
@@ -3625,7 +3965,7 @@ sse_encode_opt_String(userAgent, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
             
             },
             codec: 
@@ -3650,7 +3990,7 @@ sse_encode_opt_String(userAgent, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
             
             },
             codec: 
@@ -3675,7 +4015,7 @@ sse_encode_opt_String(userAgent, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
             
             },
             codec: 
@@ -3700,7 +4040,7 @@ sse_encode_opt_String(userAgent, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
             
             },
             codec: 
@@ -3725,7 +4065,7 @@ sse_encode_opt_String(userAgent, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
             
             },
             codec: 
@@ -3750,7 +4090,7 @@ sse_encode_opt_String(userAgent, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
             
             },
             codec: 
@@ -3776,7 +4116,7 @@ sse_encode_opt_String(userAgent, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
 sse_encode_opt_String(data, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
             
             },
             codec: 
@@ -3802,7 +4142,7 @@ sse_encode_opt_String(data, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
 sse_encode_opt_String(encoding, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
             
             },
             codec: 
@@ -3828,7 +4168,7 @@ sse_encode_opt_String(encoding, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
 sse_encode_opt_box_autoadd_bool(eof, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
             
             },
             codec: 
@@ -3854,7 +4194,7 @@ sse_encode_opt_box_autoadd_bool(eof, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
 sse_encode_opt_String(execId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
             
             },
             codec: 
@@ -3880,7 +4220,7 @@ sse_encode_opt_String(execId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
 sse_encode_opt_box_autoadd_i_32(exitCode, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
             
             },
             codec: 
@@ -3906,7 +4246,7 @@ sse_encode_opt_box_autoadd_i_32(exitCode, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
             
             },
             codec: 
@@ -3932,7 +4272,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
 sse_encode_opt_String(stream, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
             
             },
             codec: 
@@ -3958,7 +4298,7 @@ sse_encode_opt_String(stream, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(that, serializer);
 sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
             
             },
             codec: 
@@ -3983,7 +4323,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
             
             },
             codec: 
@@ -4008,7 +4348,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
             
             },
             codec: 
@@ -4033,7 +4373,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
             
             },
             codec: 
@@ -4058,7 +4398,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
             
             },
             codec: 
@@ -4083,7 +4423,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
             
             },
             codec: 
@@ -4108,7 +4448,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
             
             },
             codec: 
@@ -4133,7 +4473,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
             
             },
             codec: 
@@ -4158,7 +4498,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
             
             },
             codec: 
@@ -4183,7 +4523,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
             
             },
             codec: 
@@ -4208,7 +4548,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
             
             },
             codec: 
@@ -4234,7 +4574,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_list_String(args, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
             
             },
             codec: 
@@ -4260,7 +4600,7 @@ sse_encode_list_String(args, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_String(command, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
             
             },
             codec: 
@@ -4286,7 +4626,7 @@ sse_encode_String(command, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_opt_String(cwd, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
             
             },
             codec: 
@@ -4312,7 +4652,7 @@ sse_encode_opt_String(cwd, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(env, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
             
             },
             codec: 
@@ -4338,7 +4678,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_opt_String(execId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
             
             },
             codec: 
@@ -4364,7 +4704,7 @@ sse_encode_opt_String(execId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
             
             },
             codec: 
@@ -4390,7 +4730,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_opt_box_autoadd_bool(killOnDrop, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
             
             },
             codec: 
@@ -4416,7 +4756,7 @@ sse_encode_opt_box_autoadd_bool(killOnDrop, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_opt_String(stdin, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
             
             },
             codec: 
@@ -4442,7 +4782,7 @@ sse_encode_opt_String(stdin, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_opt_box_autoadd_bool(stream, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
             
             },
             codec: 
@@ -4468,7 +4808,7 @@ sse_encode_opt_box_autoadd_bool(stream, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(that, serializer);
 sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139)!;
             
             },
             codec: 
@@ -4493,7 +4833,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140)!;
             
             },
             codec: 
@@ -4518,7 +4858,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141)!;
             
             },
             codec: 
@@ -4543,7 +4883,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
             
             },
             codec: 
@@ -4568,7 +4908,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143)!;
             
             },
             codec: 
@@ -4593,7 +4933,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144)!;
             
             },
             codec: 
@@ -4618,7 +4958,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
             
             },
             codec: 
@@ -4644,7 +4984,7 @@ sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
 sse_encode_opt_String(code, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146)!;
             
             },
             codec: 
@@ -4670,7 +5010,7 @@ sse_encode_opt_String(code, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(details, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147)!;
             
             },
             codec: 
@@ -4696,7 +5036,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148)!;
             
             },
             codec: 
@@ -4722,7 +5062,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
 sse_encode_opt_String(message, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149)!;
             
             },
             codec: 
@@ -4748,7 +5088,7 @@ sse_encode_opt_String(message, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
 sse_encode_opt_box_autoadd_u_64(retryAfterMs, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
             
             },
             codec: 
@@ -4774,7 +5114,7 @@ sse_encode_opt_box_autoadd_u_64(retryAfterMs, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(that, serializer);
 sse_encode_opt_box_autoadd_bool(retryable, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151)!;
             
             },
             codec: 
@@ -4799,7 +5139,7 @@ sse_encode_opt_box_autoadd_bool(retryable, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152, port: port_);
             
             },
             codec: 
@@ -4820,36 +5160,36 @@ sse_encode_opt_box_autoadd_bool(retryable, serializer);
         );
         
 
-@override String crateApiEventsGatewayRequestFrameAutoAccessorGetFrameType({required GatewayRequestFrame that })  { return handler.executeSync(SyncTask(
+@override GatewayEventKind crateApiEventsGatewayEventKind({required GatewayEvent that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144)!;
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_gateway_event_kind,
           decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiEventsGatewayRequestFrameAutoAccessorGetFrameTypeConstMeta,
+            constMeta: kCrateApiEventsGatewayEventKindConstMeta,
             argValues: [that],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiEventsGatewayRequestFrameAutoAccessorGetFrameTypeConstMeta => const TaskConstMeta(
-            debugName: "GatewayRequestFrame_auto_accessor_get_frame_type",
+        TaskConstMeta get kCrateApiEventsGatewayEventKindConstMeta => const TaskConstMeta(
+            debugName: "GatewayEvent_kind",
             argNames: ["that"],
         );
         
 
-@override String crateApiEventsGatewayRequestFrameAutoAccessorGetId({required GatewayRequestFrame that })  { return handler.executeSync(SyncTask(
+@override String crateApiEventsGatewayEventText({required GatewayEvent that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154)!;
             
             },
             codec: 
@@ -4858,23 +5198,23 @@ sse_encode_opt_box_autoadd_bool(retryable, serializer);
           decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiEventsGatewayRequestFrameAutoAccessorGetIdConstMeta,
+            constMeta: kCrateApiEventsGatewayEventTextConstMeta,
             argValues: [that],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiEventsGatewayRequestFrameAutoAccessorGetIdConstMeta => const TaskConstMeta(
-            debugName: "GatewayRequestFrame_auto_accessor_get_id",
+        TaskConstMeta get kCrateApiEventsGatewayEventTextConstMeta => const TaskConstMeta(
+            debugName: "GatewayEvent_text",
             argNames: ["that"],
         );
         
 
-@override String crateApiEventsGatewayRequestFrameAutoAccessorGetMethod({required GatewayRequestFrame that })  { return handler.executeSync(SyncTask(
+@override String crateApiEventsGatewayRequestFrameAutoAccessorGetFrameType({required GatewayRequestFrame that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155)!;
             
             },
             codec: 
@@ -4883,14 +5223,64 @@ sse_encode_opt_box_autoadd_bool(retryable, serializer);
           decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiEventsGatewayRequestFrameAutoAccessorGetMethodConstMeta,
+            constMeta: kCrateApiEventsGatewayRequestFrameAutoAccessorGetFrameTypeConstMeta,
             argValues: [that],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiEventsGatewayRequestFrameAutoAccessorGetMethodConstMeta => const TaskConstMeta(
-            debugName: "GatewayRequestFrame_auto_accessor_get_method",
+        TaskConstMeta get kCrateApiEventsGatewayRequestFrameAutoAccessorGetFrameTypeConstMeta => const TaskConstMeta(
+            debugName: "GatewayRequestFrame_auto_accessor_get_frame_type",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiEventsGatewayRequestFrameAutoAccessorGetId({required GatewayRequestFrame that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsGatewayRequestFrameAutoAccessorGetIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsGatewayRequestFrameAutoAccessorGetIdConstMeta => const TaskConstMeta(
+            debugName: "GatewayRequestFrame_auto_accessor_get_id",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiEventsGatewayRequestFrameAutoAccessorGetMethod({required GatewayRequestFrame that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsGatewayRequestFrameAutoAccessorGetMethodConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsGatewayRequestFrameAutoAccessorGetMethodConstMeta => const TaskConstMeta(
+            debugName: "GatewayRequestFrame_auto_accessor_get_method",
             argNames: ["that"],
         );
         
@@ -4899,7 +5289,7 @@ sse_encode_opt_box_autoadd_bool(retryable, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158)!;
             
             },
             codec: 
@@ -4924,7 +5314,7 @@ sse_encode_opt_box_autoadd_bool(retryable, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159)!;
             
             },
             codec: 
@@ -4950,7 +5340,7 @@ sse_encode_opt_box_autoadd_bool(retryable, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
 sse_encode_String(frameType, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160)!;
             
             },
             codec: 
@@ -4976,7 +5366,7 @@ sse_encode_String(frameType, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
 sse_encode_String(id, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161)!;
             
             },
             codec: 
@@ -5002,7 +5392,7 @@ sse_encode_String(id, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
 sse_encode_String(method, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162)!;
             
             },
             codec: 
@@ -5028,7 +5418,7 @@ sse_encode_String(method, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(params, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163)!;
             
             },
             codec: 
@@ -5054,7 +5444,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
 sse_encode_opt_String(sessionKey, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164)!;
             
             },
             codec: 
@@ -5079,7 +5469,7 @@ sse_encode_opt_String(sessionKey, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(that, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
             
             },
             codec: 
@@ -5100,11 +5490,36 @@ sse_encode_opt_String(sessionKey, serializer);
         );
         
 
+@override Future<String> crateApiEventsGatewayResponsePayloadToJson({required GatewayResponsePayload that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEventsGatewayResponsePayloadToJsonConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsGatewayResponsePayloadToJsonConstMeta => const TaskConstMeta(
+            debugName: "GatewayResponsePayload_to_json",
+            argNames: ["that"],
+        );
+        
+
 @override String? crateApiEventsHelloAuthAutoAccessorGetDeviceToken({required HelloAuth that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167)!;
             
             },
             codec: 
@@ -5129,7 +5544,7 @@ sse_encode_opt_String(sessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168)!;
             
             },
             codec: 
@@ -5154,7 +5569,7 @@ sse_encode_opt_String(sessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169)!;
             
             },
             codec: 
@@ -5179,7 +5594,7 @@ sse_encode_opt_String(sessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170)!;
             
             },
             codec: 
@@ -5205,7 +5620,7 @@ sse_encode_opt_String(sessionKey, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(that, serializer);
 sse_encode_opt_String(deviceToken, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171)!;
             
             },
             codec: 
@@ -5231,7 +5646,7 @@ sse_encode_opt_String(deviceToken, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172)!;
             
             },
             codec: 
@@ -5257,7 +5672,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(that, serializer);
 sse_encode_opt_String(role, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173)!;
             
             },
             codec: 
@@ -5283,7 +5698,7 @@ sse_encode_opt_String(role, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(that, serializer);
 sse_encode_list_String(scopes, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174)!;
             
             },
             codec: 
@@ -5304,11 +5719,36 @@ sse_encode_list_String(scopes, serializer);
         );
         
 
+@override Future<HelloAuth> crateApiEventsHelloAuthDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsHelloAuthDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsHelloAuthDefaultConstMeta => const TaskConstMeta(
+            debugName: "HelloAuth_default",
+            argNames: [],
+        );
+        
+
 @override HelloAuth? crateApiEventsHelloOkAutoAccessorGetAuth({required HelloOk that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176)!;
             
             },
             codec: 
@@ -5333,7 +5773,7 @@ sse_encode_list_String(scopes, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177)!;
             
             },
             codec: 
@@ -5358,7 +5798,7 @@ sse_encode_list_String(scopes, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178)!;
             
             },
             codec: 
@@ -5383,7 +5823,7 @@ sse_encode_list_String(scopes, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179)!;
             
             },
             codec: 
@@ -5408,7 +5848,7 @@ sse_encode_list_String(scopes, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180)!;
             
             },
             codec: 
@@ -5434,7 +5874,7 @@ sse_encode_list_String(scopes, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(auth, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181)!;
             
             },
             codec: 
@@ -5460,7 +5900,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182)!;
             
             },
             codec: 
@@ -5486,7 +5926,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
 sse_encode_String(payloadType, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183)!;
             
             },
             codec: 
@@ -5512,7 +5952,7 @@ sse_encode_String(payloadType, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloPolicy(policy, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184)!;
             
             },
             codec: 
@@ -5538,7 +5978,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(that, serializer);
 sse_encode_opt_box_autoadd_u_32(protocol, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185)!;
             
             },
             codec: 
@@ -5563,7 +6003,7 @@ sse_encode_opt_box_autoadd_u_32(protocol, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloPolicy(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186)!;
             
             },
             codec: 
@@ -5588,7 +6028,7 @@ sse_encode_opt_box_autoadd_u_32(protocol, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloPolicy(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187)!;
             
             },
             codec: 
@@ -5609,12 +6049,37 @@ sse_encode_opt_box_autoadd_u_32(protocol, serializer);
         );
         
 
+@override String? crateApiEventsHelloPolicyAutoAccessorGetTickMode({required HelloPolicy that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloPolicy(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsHelloPolicyAutoAccessorGetTickModeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsHelloPolicyAutoAccessorGetTickModeConstMeta => const TaskConstMeta(
+            debugName: "HelloPolicy_auto_accessor_get_tick_mode",
+            argNames: ["that"],
+        );
+        
+
 @override void crateApiEventsHelloPolicyAutoAccessorSetExtra({required HelloPolicy that , required BTreeMapStringValue extra })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloPolicy(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189)!;
             
             },
             codec: 
@@ -5640,7 +6105,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloPolicy(that, serializer);
 sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190)!;
             
             },
             codec: 
@@ -5661,11 +6126,62 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
         );
         
 
+@override void crateApiEventsHelloPolicyAutoAccessorSetTickMode({required HelloPolicy that , String? tickMode })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloPolicy(that, serializer);
+sse_encode_opt_String(tickMode, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsHelloPolicyAutoAccessorSetTickModeConstMeta,
+            argValues: [that, tickMode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsHelloPolicyAutoAccessorSetTickModeConstMeta => const TaskConstMeta(
+            debugName: "HelloPolicy_auto_accessor_set_tick_mode",
+            argNames: ["that", "tickMode"],
+        );
+        
+
+@override Future<HelloPolicy> crateApiEventsHelloPolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloPolicy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEventsHelloPolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEventsHelloPolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "HelloPolicy_default",
+            argNames: [],
+        );
+        
+
 @override BTreeMapStringValue crateApiEventsLogEntryAutoAccessorGetExtra({required LogEntry that })  { return handler.executeSync(SyncTask(
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193)!;
             
             },
             codec: 
@@ -5690,7 +6206,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194)!;
             
             },
             codec: 
@@ -5715,7 +6231,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195)!;
             
             },
             codec: 
@@ -5740,7 +6256,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196)!;
             
             },
             codec: 
@@ -5765,7 +6281,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197)!;
             
             },
             codec: 
@@ -5790,7 +6306,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198)!;
             
             },
             codec: 
@@ -5815,7 +6331,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199)!;
             
             },
             codec: 
@@ -5840,7 +6356,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200)!;
             
             },
             codec: 
@@ -5865,7 +6381,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201)!;
             
             },
             codec: 
@@ -5890,7 +6406,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202)!;
             
             },
             codec: 
@@ -5915,7 +6431,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203)!;
             
             },
             codec: 
@@ -5941,7 +6457,7 @@ sse_encode_opt_box_autoadd_u_64(tickIntervalMs, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204)!;
             
             },
             codec: 
@@ -5967,7 +6483,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(fields, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205)!;
             
             },
             codec: 
@@ -5993,7 +6509,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_String(level, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206)!;
             
             },
             codec: 
@@ -6019,7 +6535,7 @@ sse_encode_opt_String(level, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_String(message, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207)!;
             
             },
             codec: 
@@ -6045,7 +6561,7 @@ sse_encode_opt_String(message, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(span, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208)!;
             
             },
             codec: 
@@ -6071,7 +6587,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(spans, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209)!;
             
             },
             codec: 
@@ -6097,7 +6613,7 @@ sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOp
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_String(target, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 210)!;
             
             },
             codec: 
@@ -6123,7 +6639,7 @@ sse_encode_opt_String(target, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_box_autoadd_u_64(threadId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 211)!;
             
             },
             codec: 
@@ -6149,7 +6665,7 @@ sse_encode_opt_box_autoadd_u_64(threadId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_String(threadName, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 212)!;
             
             },
             codec: 
@@ -6175,7 +6691,7 @@ sse_encode_opt_String(threadName, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_String(timestamp, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 213)!;
             
             },
             codec: 
@@ -6201,7 +6717,7 @@ sse_encode_opt_String(timestamp, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(that, serializer);
 sse_encode_opt_box_autoadd_i_64(ts, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 214)!;
             
             },
             codec: 
@@ -6226,7 +6742,7 @@ sse_encode_opt_box_autoadd_i_64(ts, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 215, port: port_);
             
             },
             codec: 
@@ -6251,7 +6767,7 @@ sse_encode_opt_box_autoadd_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216)!;
             
             },
             codec: 
@@ -6276,7 +6792,7 @@ sse_encode_opt_box_autoadd_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 217)!;
             
             },
             codec: 
@@ -6301,7 +6817,7 @@ sse_encode_opt_box_autoadd_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 218)!;
             
             },
             codec: 
@@ -6326,7 +6842,7 @@ sse_encode_opt_box_autoadd_i_64(ts, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 219)!;
             
             },
             codec: 
@@ -6352,7 +6868,7 @@ sse_encode_opt_box_autoadd_i_64(ts, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsEvent(that, serializer);
 sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(entries, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 220)!;
             
             },
             codec: 
@@ -6378,7 +6894,7 @@ sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOp
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsEvent(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(entry, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 221)!;
             
             },
             codec: 
@@ -6404,7 +6920,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsEvent(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 222)!;
             
             },
             codec: 
@@ -6430,7 +6946,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsEvent(that, serializer);
 sse_encode_opt_String(subscriptionId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 223)!;
             
             },
             codec: 
@@ -6455,7 +6971,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 224, port: port_);
             
             },
             codec: 
@@ -6480,7 +6996,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 225)!;
             
             },
             codec: 
@@ -6505,7 +7021,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 210)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 226)!;
             
             },
             codec: 
@@ -6530,7 +7046,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 211)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 227)!;
             
             },
             codec: 
@@ -6555,7 +7071,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 212)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 228)!;
             
             },
             codec: 
@@ -6580,7 +7096,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 213)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 229)!;
             
             },
             codec: 
@@ -6605,7 +7121,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 214)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 230)!;
             
             },
             codec: 
@@ -6631,7 +7147,7 @@ sse_encode_opt_String(subscriptionId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 215)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 231)!;
             
             },
             codec: 
@@ -6657,7 +7173,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(filters, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 232)!;
             
             },
             codec: 
@@ -6683,7 +7199,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
 sse_encode_opt_box_autoadd_bool(includeInternal, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 217)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 233)!;
             
             },
             codec: 
@@ -6709,7 +7225,7 @@ sse_encode_opt_box_autoadd_bool(includeInternal, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
 sse_encode_opt_String(level, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 218)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 234)!;
             
             },
             codec: 
@@ -6735,7 +7251,7 @@ sse_encode_opt_String(level, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
 sse_encode_opt_box_autoadd_i_64(since, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 219)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 235)!;
             
             },
             codec: 
@@ -6761,7 +7277,7 @@ sse_encode_opt_box_autoadd_i_64(since, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsSubscribeParams(that, serializer);
 sse_encode_opt_box_autoadd_u_32(tail, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 220)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 236)!;
             
             },
             codec: 
@@ -6786,7 +7302,7 @@ sse_encode_opt_box_autoadd_u_32(tail, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 221, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 237, port: port_);
             
             },
             codec: 
@@ -6811,7 +7327,7 @@ sse_encode_opt_box_autoadd_u_32(tail, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsUnsubscribeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 222)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 238)!;
             
             },
             codec: 
@@ -6836,7 +7352,7 @@ sse_encode_opt_box_autoadd_u_32(tail, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsUnsubscribeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 223)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 239)!;
             
             },
             codec: 
@@ -6862,7 +7378,7 @@ sse_encode_opt_box_autoadd_u_32(tail, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsUnsubscribeParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 224)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 240)!;
             
             },
             codec: 
@@ -6888,7 +7404,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogsUnsubscribeParams(that, serializer);
 sse_encode_opt_String(subscriptionId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 225)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 241)!;
             
             },
             codec: 
@@ -6913,7 +7429,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 226, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 242, port: port_);
             
             },
             codec: 
@@ -6938,7 +7454,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 227)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 243)!;
             
             },
             codec: 
@@ -6963,7 +7479,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 228)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 244)!;
             
             },
             codec: 
@@ -6988,7 +7504,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 229)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 245)!;
             
             },
             codec: 
@@ -7013,7 +7529,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 230)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 246)!;
             
             },
             codec: 
@@ -7038,7 +7554,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 231)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 247)!;
             
             },
             codec: 
@@ -7063,7 +7579,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 232)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 248)!;
             
             },
             codec: 
@@ -7088,7 +7604,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 233)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 249)!;
             
             },
             codec: 
@@ -7113,7 +7629,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 234)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 250)!;
             
             },
             codec: 
@@ -7138,7 +7654,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 235)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 251)!;
             
             },
             codec: 
@@ -7163,7 +7679,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 236)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 252)!;
             
             },
             codec: 
@@ -7188,7 +7704,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 237)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 253)!;
             
             },
             codec: 
@@ -7213,7 +7729,7 @@ sse_encode_opt_String(subscriptionId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 238)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 254)!;
             
             },
             codec: 
@@ -7239,7 +7755,7 @@ sse_encode_opt_String(subscriptionId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_String(deviceFamily, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 239)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 255)!;
             
             },
             codec: 
@@ -7265,7 +7781,7 @@ sse_encode_opt_String(deviceFamily, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 240)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 256)!;
             
             },
             codec: 
@@ -7291,7 +7807,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_String(host, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 241)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 257)!;
             
             },
             codec: 
@@ -7317,7 +7833,7 @@ sse_encode_opt_String(host, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_String(instanceId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 242)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 258)!;
             
             },
             codec: 
@@ -7343,7 +7859,7 @@ sse_encode_opt_String(instanceId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_String(ip, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 243)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 259)!;
             
             },
             codec: 
@@ -7369,7 +7885,7 @@ sse_encode_opt_String(ip, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_box_autoadd_u_64(lastInputSeconds, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 244)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 260)!;
             
             },
             codec: 
@@ -7395,7 +7911,7 @@ sse_encode_opt_box_autoadd_u_64(lastInputSeconds, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_String(mode, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 245)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 261)!;
             
             },
             codec: 
@@ -7421,7 +7937,7 @@ sse_encode_opt_String(mode, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_String(modelIdentifier, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 246)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 262)!;
             
             },
             codec: 
@@ -7447,7 +7963,7 @@ sse_encode_opt_String(modelIdentifier, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_String(reason, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 247)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 263)!;
             
             },
             codec: 
@@ -7473,7 +7989,7 @@ sse_encode_opt_String(reason, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_list_String(tags, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 248)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 264)!;
             
             },
             codec: 
@@ -7499,7 +8015,7 @@ sse_encode_list_String(tags, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_box_autoadd_i_64(ts, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 249)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 265)!;
             
             },
             codec: 
@@ -7525,7 +8041,7 @@ sse_encode_opt_box_autoadd_i_64(ts, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(that, serializer);
 sse_encode_opt_String(version, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 250)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 266)!;
             
             },
             codec: 
@@ -7550,7 +8066,7 @@ sse_encode_opt_String(version, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 251)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 267)!;
             
             },
             codec: 
@@ -7575,7 +8091,7 @@ sse_encode_opt_String(version, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 252)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 268)!;
             
             },
             codec: 
@@ -7601,7 +8117,7 @@ sse_encode_opt_String(version, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEvent(that, serializer);
 sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(entries, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 253)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 269)!;
             
             },
             codec: 
@@ -7627,7 +8143,7 @@ sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOp
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEvent(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 254)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 270)!;
             
             },
             codec: 
@@ -7652,7 +8168,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 255)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 271)!;
             
             },
             codec: 
@@ -7677,7 +8193,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 256)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 272)!;
             
             },
             codec: 
@@ -7702,7 +8218,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 257)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 273)!;
             
             },
             codec: 
@@ -7727,7 +8243,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 258)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 274)!;
             
             },
             codec: 
@@ -7752,7 +8268,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 259)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 275)!;
             
             },
             codec: 
@@ -7778,7 +8294,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
 sse_encode_bool(archive, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 260)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 276)!;
             
             },
             codec: 
@@ -7804,7 +8320,7 @@ sse_encode_bool(archive, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 261)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 277)!;
             
             },
             codec: 
@@ -7830,7 +8346,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(metadata, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 262)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 278)!;
             
             },
             codec: 
@@ -7856,7 +8372,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
 sse_encode_opt_String(reason, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 263)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 279)!;
             
             },
             codec: 
@@ -7882,7 +8398,7 @@ sse_encode_opt_String(reason, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsCloseParams(that, serializer);
 sse_encode_String(sessionKey, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 264)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 280)!;
             
             },
             codec: 
@@ -7907,7 +8423,7 @@ sse_encode_String(sessionKey, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 265, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 281, port: port_);
             
             },
             codec: 
@@ -7932,7 +8448,7 @@ sse_encode_String(sessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsListParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 266)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 282)!;
             
             },
             codec: 
@@ -7957,7 +8473,7 @@ sse_encode_String(sessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsListParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 267)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 283)!;
             
             },
             codec: 
@@ -7982,7 +8498,7 @@ sse_encode_String(sessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsListParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 268)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 284)!;
             
             },
             codec: 
@@ -8008,7 +8524,7 @@ sse_encode_String(sessionKey, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsListParams(that, serializer);
 sse_encode_opt_String(cursor, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 269)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 285)!;
             
             },
             codec: 
@@ -8034,7 +8550,7 @@ sse_encode_opt_String(cursor, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsListParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 270)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 286)!;
             
             },
             codec: 
@@ -8060,7 +8576,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsListParams(that, serializer);
 sse_encode_opt_box_autoadd_u_32(limit, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 271)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 287)!;
             
             },
             codec: 
@@ -8085,7 +8601,7 @@ sse_encode_opt_box_autoadd_u_32(limit, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 272, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 288, port: port_);
             
             },
             codec: 
@@ -8110,7 +8626,7 @@ sse_encode_opt_box_autoadd_u_32(limit, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsSpawnParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 273)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 289)!;
             
             },
             codec: 
@@ -8135,7 +8651,7 @@ sse_encode_opt_box_autoadd_u_32(limit, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsSpawnParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 274)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 290)!;
             
             },
             codec: 
@@ -8160,7 +8676,7 @@ sse_encode_opt_box_autoadd_u_32(limit, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsSpawnParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 275)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 291)!;
             
             },
             codec: 
@@ -8185,7 +8701,7 @@ sse_encode_opt_box_autoadd_u_32(limit, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsSpawnParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 276)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 292)!;
             
             },
             codec: 
@@ -8211,7 +8727,7 @@ sse_encode_opt_box_autoadd_u_32(limit, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsSpawnParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 277)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 293)!;
             
             },
             codec: 
@@ -8237,7 +8753,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsSpawnParams(that, serializer);
 sse_encode_opt_String(label, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 278)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 294)!;
             
             },
             codec: 
@@ -8263,7 +8779,7 @@ sse_encode_opt_String(label, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsSpawnParams(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(metadata, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 279)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 295)!;
             
             },
             codec: 
@@ -8289,7 +8805,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionsSpawnParams(that, serializer);
 sse_encode_opt_String(parentSessionKey, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 280)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 296)!;
             
             },
             codec: 
@@ -8314,7 +8830,7 @@ sse_encode_opt_String(parentSessionKey, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 281, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 297, port: port_);
             
             },
             codec: 
@@ -8339,7 +8855,7 @@ sse_encode_opt_String(parentSessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShutdownEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 282)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 298)!;
             
             },
             codec: 
@@ -8364,7 +8880,7 @@ sse_encode_opt_String(parentSessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShutdownEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 283)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 299)!;
             
             },
             codec: 
@@ -8389,7 +8905,7 @@ sse_encode_opt_String(parentSessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShutdownEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 284)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 300)!;
             
             },
             codec: 
@@ -8415,7 +8931,7 @@ sse_encode_opt_String(parentSessionKey, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShutdownEvent(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 285)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 301)!;
             
             },
             codec: 
@@ -8441,7 +8957,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShutdownEvent(that, serializer);
 sse_encode_opt_String(reason, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 286)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 302)!;
             
             },
             codec: 
@@ -8467,7 +8983,7 @@ sse_encode_opt_String(reason, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShutdownEvent(that, serializer);
 sse_encode_opt_box_autoadd_u_64(restartExpectedMs, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 287)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 303)!;
             
             },
             codec: 
@@ -8492,7 +9008,7 @@ sse_encode_opt_box_autoadd_u_64(restartExpectedMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 288)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 304)!;
             
             },
             codec: 
@@ -8517,7 +9033,7 @@ sse_encode_opt_box_autoadd_u_64(restartExpectedMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 289)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 305)!;
             
             },
             codec: 
@@ -8542,7 +9058,7 @@ sse_encode_opt_box_autoadd_u_64(restartExpectedMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 290)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 306)!;
             
             },
             codec: 
@@ -8567,7 +9083,7 @@ sse_encode_opt_box_autoadd_u_64(restartExpectedMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamCloseParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 291)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 307)!;
             
             },
             codec: 
@@ -8593,7 +9109,7 @@ sse_encode_opt_box_autoadd_u_64(restartExpectedMs, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamCloseParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 292)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 308)!;
             
             },
             codec: 
@@ -8619,7 +9135,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamCloseParams(that, serializer);
 sse_encode_opt_String(reason, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 293)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 309)!;
             
             },
             codec: 
@@ -8645,7 +9161,7 @@ sse_encode_opt_String(reason, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamCloseParams(that, serializer);
 sse_encode_String(stream, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 294)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 310)!;
             
             },
             codec: 
@@ -8671,7 +9187,7 @@ sse_encode_String(stream, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamCloseParams(that, serializer);
 sse_encode_opt_String(streamId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 295)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 311)!;
             
             },
             codec: 
@@ -8696,7 +9212,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 296, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 312, port: port_);
             
             },
             codec: 
@@ -8721,7 +9237,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 297)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 313)!;
             
             },
             codec: 
@@ -8746,7 +9262,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 298)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 314)!;
             
             },
             codec: 
@@ -8771,7 +9287,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 299)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 315)!;
             
             },
             codec: 
@@ -8796,7 +9312,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 300)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 316)!;
             
             },
             codec: 
@@ -8821,7 +9337,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 301)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 317)!;
             
             },
             codec: 
@@ -8847,7 +9363,7 @@ sse_encode_opt_String(streamId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
 sse_encode_opt_box_autoadd_i_32(exitCode, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 302)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 318)!;
             
             },
             codec: 
@@ -8873,7 +9389,7 @@ sse_encode_opt_box_autoadd_i_32(exitCode, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 303)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 319)!;
             
             },
             codec: 
@@ -8899,7 +9415,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
 sse_encode_opt_String(reason, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 304)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 320)!;
             
             },
             codec: 
@@ -8925,7 +9441,7 @@ sse_encode_opt_String(reason, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
 sse_encode_String(stream, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 305)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 321)!;
             
             },
             codec: 
@@ -8951,7 +9467,7 @@ sse_encode_String(stream, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamClosedEvent(that, serializer);
 sse_encode_opt_String(streamId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 306)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 322)!;
             
             },
             codec: 
@@ -8976,7 +9492,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 307, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 323, port: port_);
             
             },
             codec: 
@@ -9001,7 +9517,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 308)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 324)!;
             
             },
             codec: 
@@ -9026,7 +9542,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 309)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 325)!;
             
             },
             codec: 
@@ -9051,7 +9567,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 310)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 326)!;
             
             },
             codec: 
@@ -9076,7 +9592,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 311)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 327)!;
             
             },
             codec: 
@@ -9101,7 +9617,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 312)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 328)!;
             
             },
             codec: 
@@ -9126,7 +9642,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 313)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 329)!;
             
             },
             codec: 
@@ -9151,7 +9667,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 314)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 330)!;
             
             },
             codec: 
@@ -9176,7 +9692,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 315)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 331)!;
             
             },
             codec: 
@@ -9201,7 +9717,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 316)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 332)!;
             
             },
             codec: 
@@ -9227,7 +9743,7 @@ sse_encode_opt_String(streamId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_opt_String(channel, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 317)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 333)!;
             
             },
             codec: 
@@ -9253,7 +9769,7 @@ sse_encode_opt_String(channel, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_opt_box_autoadd_u_32(cols, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 318)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 334)!;
             
             },
             codec: 
@@ -9279,7 +9795,7 @@ sse_encode_opt_box_autoadd_u_32(cols, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_opt_String(data, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 319)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 335)!;
             
             },
             codec: 
@@ -9305,7 +9821,7 @@ sse_encode_opt_String(data, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_opt_String(encoding, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 320)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 336)!;
             
             },
             codec: 
@@ -9331,7 +9847,7 @@ sse_encode_opt_String(encoding, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 321)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 337)!;
             
             },
             codec: 
@@ -9357,7 +9873,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_opt_box_autoadd_u_32(rows, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 322)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 338)!;
             
             },
             codec: 
@@ -9383,7 +9899,7 @@ sse_encode_opt_box_autoadd_u_32(rows, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_String(stream, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 323)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 339)!;
             
             },
             codec: 
@@ -9409,7 +9925,7 @@ sse_encode_String(stream, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_opt_String(streamId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 324)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 340)!;
             
             },
             codec: 
@@ -9435,7 +9951,7 @@ sse_encode_opt_String(streamId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamDataEvent(that, serializer);
 sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 325)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 341)!;
             
             },
             codec: 
@@ -9460,7 +9976,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 326, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 342, port: port_);
             
             },
             codec: 
@@ -9485,7 +10001,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 327)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 343)!;
             
             },
             codec: 
@@ -9510,7 +10026,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 328)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 344)!;
             
             },
             codec: 
@@ -9535,7 +10051,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 329)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 345)!;
             
             },
             codec: 
@@ -9560,7 +10076,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 330)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 346)!;
             
             },
             codec: 
@@ -9585,7 +10101,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 331)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 347)!;
             
             },
             codec: 
@@ -9610,7 +10126,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 332)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 348)!;
             
             },
             codec: 
@@ -9636,7 +10152,7 @@ sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
 sse_encode_opt_box_autoadd_u_32(cols, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 333)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 349)!;
             
             },
             codec: 
@@ -9662,7 +10178,7 @@ sse_encode_opt_box_autoadd_u_32(cols, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 334)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 350)!;
             
             },
             codec: 
@@ -9688,7 +10204,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(metadata, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 335)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 351)!;
             
             },
             codec: 
@@ -9714,7 +10230,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
 sse_encode_opt_box_autoadd_u_32(rows, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 336)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 352)!;
             
             },
             codec: 
@@ -9740,7 +10256,7 @@ sse_encode_opt_box_autoadd_u_32(rows, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
 sse_encode_String(stream, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 337)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 353)!;
             
             },
             codec: 
@@ -9766,7 +10282,7 @@ sse_encode_String(stream, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamOpenParams(that, serializer);
 sse_encode_opt_String(term, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 338)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 354)!;
             
             },
             codec: 
@@ -9791,7 +10307,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 339, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 355, port: port_);
             
             },
             codec: 
@@ -9816,7 +10332,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 340)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 356)!;
             
             },
             codec: 
@@ -9841,7 +10357,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 341)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 357)!;
             
             },
             codec: 
@@ -9866,7 +10382,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 342)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 358)!;
             
             },
             codec: 
@@ -9891,7 +10407,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 343)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 359)!;
             
             },
             codec: 
@@ -9916,7 +10432,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 344)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 360)!;
             
             },
             codec: 
@@ -9941,7 +10457,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 345)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 361)!;
             
             },
             codec: 
@@ -9966,7 +10482,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 346)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 362)!;
             
             },
             codec: 
@@ -9991,7 +10507,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 347)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 363)!;
             
             },
             codec: 
@@ -10016,7 +10532,7 @@ sse_encode_opt_String(term, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 348)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 364)!;
             
             },
             codec: 
@@ -10042,7 +10558,7 @@ sse_encode_opt_String(term, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_opt_String(channel, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 349)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 365)!;
             
             },
             codec: 
@@ -10068,7 +10584,7 @@ sse_encode_opt_String(channel, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_opt_box_autoadd_u_32(cols, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 350)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 366)!;
             
             },
             codec: 
@@ -10094,7 +10610,7 @@ sse_encode_opt_box_autoadd_u_32(cols, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_opt_String(data, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 351)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 367)!;
             
             },
             codec: 
@@ -10120,7 +10636,7 @@ sse_encode_opt_String(data, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_opt_String(encoding, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 352)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 368)!;
             
             },
             codec: 
@@ -10146,7 +10662,7 @@ sse_encode_opt_String(encoding, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_opt_String(event, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 353)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 369)!;
             
             },
             codec: 
@@ -10172,7 +10688,7 @@ sse_encode_opt_String(event, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 354)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 370)!;
             
             },
             codec: 
@@ -10198,7 +10714,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_opt_box_autoadd_u_32(rows, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 355)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 371)!;
             
             },
             codec: 
@@ -10224,7 +10740,7 @@ sse_encode_opt_box_autoadd_u_32(rows, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_String(stream, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 356)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 372)!;
             
             },
             codec: 
@@ -10250,7 +10766,7 @@ sse_encode_String(stream, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStreamSendParams(that, serializer);
 sse_encode_opt_String(streamId, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 357)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 373)!;
             
             },
             codec: 
@@ -10275,7 +10791,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 358, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 374, port: port_);
             
             },
             codec: 
@@ -10300,7 +10816,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 359)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 375)!;
             
             },
             codec: 
@@ -10325,7 +10841,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 360)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 376)!;
             
             },
             codec: 
@@ -10350,7 +10866,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 361)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 377)!;
             
             },
             codec: 
@@ -10375,7 +10891,7 @@ sse_encode_opt_String(streamId, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemEvent(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 362)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 378)!;
             
             },
             codec: 
@@ -10401,7 +10917,7 @@ sse_encode_opt_String(streamId, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemEvent(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 363)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 379)!;
             
             },
             codec: 
@@ -10427,7 +10943,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemEvent(that, serializer);
 sse_encode_opt_String(mode, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 364)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 380)!;
             
             },
             codec: 
@@ -10453,7 +10969,7 @@ sse_encode_opt_String(mode, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemEvent(that, serializer);
 sse_encode_opt_String(sessionKey, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 365)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 381)!;
             
             },
             codec: 
@@ -10479,7 +10995,7 @@ sse_encode_opt_String(sessionKey, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemEvent(that, serializer);
 sse_encode_opt_String(text, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 366)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 382)!;
             
             },
             codec: 
@@ -10504,7 +11020,7 @@ sse_encode_opt_String(text, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 367)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 383)!;
             
             },
             codec: 
@@ -10529,7 +11045,7 @@ sse_encode_opt_String(text, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 368)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 384)!;
             
             },
             codec: 
@@ -10554,7 +11070,7 @@ sse_encode_opt_String(text, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 369)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 385)!;
             
             },
             codec: 
@@ -10579,7 +11095,7 @@ sse_encode_opt_String(text, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 370)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 386)!;
             
             },
             codec: 
@@ -10604,7 +11120,7 @@ sse_encode_opt_String(text, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 371)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 387)!;
             
             },
             codec: 
@@ -10630,7 +11146,7 @@ sse_encode_opt_String(text, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(details, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 372)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 388)!;
             
             },
             codec: 
@@ -10656,7 +11172,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 373)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 389)!;
             
             },
             codec: 
@@ -10682,7 +11198,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
 sse_encode_opt_box_autoadd_u_64(latencyMs, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 374)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 390)!;
             
             },
             codec: 
@@ -10708,7 +11224,7 @@ sse_encode_opt_box_autoadd_u_64(latencyMs, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
 sse_encode_opt_String(message, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 375)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 391)!;
             
             },
             codec: 
@@ -10734,7 +11250,7 @@ sse_encode_opt_String(message, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(that, serializer);
 sse_encode_opt_box_autoadd_bool(ok, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 376)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 392)!;
             
             },
             codec: 
@@ -10759,7 +11275,7 @@ sse_encode_opt_box_autoadd_bool(ok, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 377, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 393, port: port_);
             
             },
             codec: 
@@ -10784,7 +11300,7 @@ sse_encode_opt_box_autoadd_bool(ok, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 378)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 394)!;
             
             },
             codec: 
@@ -10809,7 +11325,7 @@ sse_encode_opt_box_autoadd_bool(ok, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 379)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 395)!;
             
             },
             codec: 
@@ -10834,7 +11350,7 @@ sse_encode_opt_box_autoadd_bool(ok, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 380)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 396)!;
             
             },
             codec: 
@@ -10859,7 +11375,7 @@ sse_encode_opt_box_autoadd_bool(ok, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeParams(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 381)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 397)!;
             
             },
             codec: 
@@ -10885,7 +11401,7 @@ sse_encode_opt_box_autoadd_bool(ok, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeParams(that, serializer);
 sse_encode_opt_box_autoadd_bool(disk, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 382)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 398)!;
             
             },
             codec: 
@@ -10911,7 +11427,7 @@ sse_encode_opt_box_autoadd_bool(disk, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeParams(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 383)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 399)!;
             
             },
             codec: 
@@ -10937,7 +11453,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeParams(that, serializer);
 sse_encode_opt_box_autoadd_bool(gateway, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 384)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 400)!;
             
             },
             codec: 
@@ -10963,7 +11479,7 @@ sse_encode_opt_box_autoadd_bool(gateway, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeParams(that, serializer);
 sse_encode_opt_box_autoadd_bool(network, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 385)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 401)!;
             
             },
             codec: 
@@ -10988,7 +11504,7 @@ sse_encode_opt_box_autoadd_bool(network, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 386, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 402, port: port_);
             
             },
             codec: 
@@ -11013,7 +11529,7 @@ sse_encode_opt_box_autoadd_bool(network, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 387)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 403)!;
             
             },
             codec: 
@@ -11038,7 +11554,7 @@ sse_encode_opt_box_autoadd_bool(network, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 388)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 404)!;
             
             },
             codec: 
@@ -11063,7 +11579,7 @@ sse_encode_opt_box_autoadd_bool(network, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 389)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 405)!;
             
             },
             codec: 
@@ -11088,7 +11604,7 @@ sse_encode_opt_box_autoadd_bool(network, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 390)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 406)!;
             
             },
             codec: 
@@ -11114,7 +11630,7 @@ sse_encode_opt_box_autoadd_bool(network, serializer);
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(disk, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 391)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 407)!;
             
             },
             codec: 
@@ -11140,7 +11656,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(that, serializer);
 sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(extra, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 392)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 408)!;
             
             },
             codec: 
@@ -11166,7 +11682,7 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(gateway, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 393)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 409)!;
             
             },
             codec: 
@@ -11192,7 +11708,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(that, serializer);
 sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeCheck(network, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 394)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 410)!;
             
             },
             codec: 
@@ -11217,7 +11733,7 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 395, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 411, port: port_);
             
             },
             codec: 
@@ -11238,114 +11754,97 @@ sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generate
         );
         
 
-@override Stream<GatewayEvent> crateApiConnectionConnectToGateway({required String url })  { 
-            final sink = RustStreamSink<GatewayEvent>();
-            unawaited(handler.executeNormal(NormalTask(
-            callFfi: (port_) {
+@override TerminalScreenUpdate crateApiTerminalEmulatorTerminalEmulatorFeed({required TerminalEmulator that , required List<int> bytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
-sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 397, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(that, serializer);
+sse_encode_list_prim_u_8_loose(bytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 412)!;
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_terminal_screen_update,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionConnectToGatewayConstMeta,
-            argValues: [url, sink],
+            constMeta: kCrateApiTerminalEmulatorTerminalEmulatorFeedConstMeta,
+            argValues: [that, bytes],
             apiImpl: this,
-        )));
-            return sink.stream;
-             }
+        )); }
 
 
-        TaskConstMeta get kCrateApiConnectionConnectToGatewayConstMeta => const TaskConstMeta(
-            debugName: "connect_to_gateway",
-            argNames: ["url", "sink"],
+        TaskConstMeta get kCrateApiTerminalEmulatorTerminalEmulatorFeedConstMeta => const TaskConstMeta(
+            debugName: "TerminalEmulator_feed",
+            argNames: ["that", "bytes"],
         );
         
 
-@override Stream<TerminalChunk> crateApiTerminalExecCommand({required String url , required String command , String? cwd , BigInt? timeoutMs , String? sessionKey })  { 
-            final sink = RustStreamSink<TerminalChunk>();
-            unawaited(handler.executeNormal(NormalTask(
-            callFfi: (port_) {
+@override TerminalEmulator crateApiTerminalEmulatorTerminalEmulatorNew({required int cols , required int rows , int? scrollbackLimit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
-sse_encode_String(command, serializer);
-sse_encode_opt_String(cwd, serializer);
-sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-sse_encode_StreamSink_terminal_chunk_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 398, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(cols, serializer);
+sse_encode_u_32(rows, serializer);
+sse_encode_opt_box_autoadd_u_32(scrollbackLimit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 413)!;
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiTerminalExecCommandConstMeta,
-            argValues: [url, command, cwd, timeoutMs, sessionKey, sink],
+            constMeta: kCrateApiTerminalEmulatorTerminalEmulatorNewConstMeta,
+            argValues: [cols, rows, scrollbackLimit],
             apiImpl: this,
-        )));
-            return sink.stream;
-             }
+        )); }
 
 
-        TaskConstMeta get kCrateApiTerminalExecCommandConstMeta => const TaskConstMeta(
-            debugName: "exec_command",
-            argNames: ["url", "command", "cwd", "timeoutMs", "sessionKey", "sink"],
+        TaskConstMeta get kCrateApiTerminalEmulatorTerminalEmulatorNewConstMeta => const TaskConstMeta(
+            debugName: "TerminalEmulator_new",
+            argNames: ["cols", "rows", "scrollbackLimit"],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientAgentTurnRequest({required GatewayClient that , required String requestId , required String message , String? model , String? thinking , BigInt? timeoutSeconds , String? sessionKey })  { return handler.executeNormal(NormalTask(
-            callFfi: (port_) {
+@override TerminalScreenUpdate crateApiTerminalEmulatorTerminalEmulatorResize({required TerminalEmulator that , required int cols , required int rows })  { return handler.executeSync(SyncTask(
+            callFfi: () {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_String(message, serializer);
-sse_encode_opt_String(model, serializer);
-sse_encode_opt_String(thinking, serializer);
-sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 399, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(that, serializer);
+sse_encode_u_32(cols, serializer);
+sse_encode_u_32(rows, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 414)!;
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_terminal_screen_update,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientAgentTurnRequestConstMeta,
-            argValues: [that, requestId, message, model, thinking, timeoutSeconds, sessionKey],
+            constMeta: kCrateApiTerminalEmulatorTerminalEmulatorResizeConstMeta,
+            argValues: [that, cols, rows],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientAgentTurnRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_agent_turn_request",
-            argNames: ["that", "requestId", "message", "model", "thinking", "timeoutSeconds", "sessionKey"],
+        TaskConstMeta get kCrateApiTerminalEmulatorTerminalEmulatorResizeConstMeta => const TaskConstMeta(
+            debugName: "TerminalEmulator_resize",
+            argNames: ["that", "cols", "rows"],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientCameraSnapRequest({required GatewayClient that , required String requestId , required List<int> frameBytes , String? format , int? width , int? height , PlatformInt64? timestampMs , required bool compress , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Future<String> crateApiTerminalEmulatorTerminalEmulatorResizeRequest({required TerminalEmulator that , required GatewayClient client , required String requestId , String? streamId , required int cols , required int rows , String? sessionKey })  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(that, serializer);
+sse_encode_box_autoadd_gateway_client(client, serializer);
 sse_encode_String(requestId, serializer);
-sse_encode_list_prim_u_8_loose(frameBytes, serializer);
-sse_encode_opt_String(format, serializer);
-sse_encode_opt_box_autoadd_u_32(width, serializer);
-sse_encode_opt_box_autoadd_u_32(height, serializer);
-sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
-sse_encode_bool(compress, serializer);
+sse_encode_opt_String(streamId, serializer);
+sse_encode_u_32(cols, serializer);
+sse_encode_u_32(rows, serializer);
 sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 400, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 415, port: port_);
             
             },
             codec: 
@@ -11354,193 +11853,553 @@ sse_encode_opt_String(sessionKey, serializer);
           decodeErrorData: sse_decode_AnyhowException,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientCameraSnapRequestConstMeta,
-            argValues: [that, requestId, frameBytes, format, width, height, timestampMs, compress, sessionKey],
+            constMeta: kCrateApiTerminalEmulatorTerminalEmulatorResizeRequestConstMeta,
+            argValues: [that, client, requestId, streamId, cols, rows, sessionKey],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientCameraSnapRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_camera_snap_request",
-            argNames: ["that", "requestId", "frameBytes", "format", "width", "height", "timestampMs", "compress", "sessionKey"],
+        TaskConstMeta get kCrateApiTerminalEmulatorTerminalEmulatorResizeRequestConstMeta => const TaskConstMeta(
+            debugName: "TerminalEmulator_resize_request",
+            argNames: ["that", "client", "requestId", "streamId", "cols", "rows", "sessionKey"],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientExecCommandRequest({required GatewayClient that , required String requestId , required String command , required List<String> args , Uint8List? stdinBytes , String? cwd , String? envJson , BigInt? timeoutMs , String? execId , bool? killOnDrop , bool? pty , int? cols , int? rows , String? term , String? permissionsJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
-            callFfi: (port_) {
+@override List<TerminalLine> crateApiTerminalEmulatorTerminalEmulatorScrollbackLines({required TerminalEmulator that , required int start , required int count })  { return handler.executeSync(SyncTask(
+            callFfi: () {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_String(command, serializer);
-sse_encode_list_String(args, serializer);
-sse_encode_opt_list_prim_u_8_strict(stdinBytes, serializer);
-sse_encode_opt_String(cwd, serializer);
-sse_encode_opt_String(envJson, serializer);
-sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
-sse_encode_opt_String(execId, serializer);
-sse_encode_opt_box_autoadd_bool(killOnDrop, serializer);
-sse_encode_opt_box_autoadd_bool(pty, serializer);
-sse_encode_opt_box_autoadd_u_32(cols, serializer);
-sse_encode_opt_box_autoadd_u_32(rows, serializer);
-sse_encode_opt_String(term, serializer);
-sse_encode_opt_String(permissionsJson, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 401, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(that, serializer);
+sse_encode_u_32(start, serializer);
+sse_encode_u_32(count, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 416)!;
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_list_terminal_line,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientExecCommandRequestConstMeta,
-            argValues: [that, requestId, command, args, stdinBytes, cwd, envJson, timeoutMs, execId, killOnDrop, pty, cols, rows, term, permissionsJson, sessionKey],
+            constMeta: kCrateApiTerminalEmulatorTerminalEmulatorScrollbackLinesConstMeta,
+            argValues: [that, start, count],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientExecCommandRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_exec_command_request",
-            argNames: ["that", "requestId", "command", "args", "stdinBytes", "cwd", "envJson", "timeoutMs", "execId", "killOnDrop", "pty", "cols", "rows", "term", "permissionsJson", "sessionKey"],
+        TaskConstMeta get kCrateApiTerminalEmulatorTerminalEmulatorScrollbackLinesConstMeta => const TaskConstMeta(
+            debugName: "TerminalEmulator_scrollback_lines",
+            argNames: ["that", "start", "count"],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientExecRequest({required GatewayClient that , required String requestId , required String command , required List<String> args , String? cwd , String? envJson , String? stdin , BigInt? timeoutMs , bool? stream , String? execId , bool? killOnDrop , String? sessionKey })  { return handler.executeNormal(NormalTask(
-            callFfi: (port_) {
+@override TerminalScreenUpdate crateApiTerminalEmulatorTerminalEmulatorSnapshot({required TerminalEmulator that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_String(command, serializer);
-sse_encode_list_String(args, serializer);
-sse_encode_opt_String(cwd, serializer);
-sse_encode_opt_String(envJson, serializer);
-sse_encode_opt_String(stdin, serializer);
-sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
-sse_encode_opt_box_autoadd_bool(stream, serializer);
-sse_encode_opt_String(execId, serializer);
-sse_encode_opt_box_autoadd_bool(killOnDrop, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 402, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 417)!;
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_terminal_screen_update,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientExecRequestConstMeta,
-            argValues: [that, requestId, command, args, cwd, envJson, stdin, timeoutMs, stream, execId, killOnDrop, sessionKey],
+            constMeta: kCrateApiTerminalEmulatorTerminalEmulatorSnapshotConstMeta,
+            argValues: [that],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientExecRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_exec_request",
-            argNames: ["that", "requestId", "command", "args", "cwd", "envJson", "stdin", "timeoutMs", "stream", "execId", "killOnDrop", "sessionKey"],
+        TaskConstMeta get kCrateApiTerminalEmulatorTerminalEmulatorSnapshotConstMeta => const TaskConstMeta(
+            debugName: "TerminalEmulator_snapshot",
+            argNames: ["that"],
         );
         
 
-@override String crateApiConnectionGatewayClientGetUrl({required GatewayClient that })  { return handler.executeSync(SyncTask(
-            callFfi: () {
+@override Future<BackpressureStats> crateApiBackpressureBackpressureStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 403)!;
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 418, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_backpressure_stats,
           decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientGetUrlConstMeta,
-            argValues: [that],
+            constMeta: kCrateApiBackpressureBackpressureStatsDefaultConstMeta,
+            argValues: [],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientGetUrlConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_get_url",
-            argNames: ["that"],
+        TaskConstMeta get kCrateApiBackpressureBackpressureStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "backpressure_stats_default",
+            argNames: [],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientInteractiveShellCloseRequest({required GatewayClient that , required String requestId , String? streamId , String? reason , String? permissionsJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Future<void> crateApiTokenStoreClearDeviceTokenStore()  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_opt_String(streamId, serializer);
-sse_encode_opt_String(reason, serializer);
-sse_encode_opt_String(permissionsJson, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 404, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 419, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientInteractiveShellCloseRequestConstMeta,
-            argValues: [that, requestId, streamId, reason, permissionsJson, sessionKey],
+            constMeta: kCrateApiTokenStoreClearDeviceTokenStoreConstMeta,
+            argValues: [],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientInteractiveShellCloseRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_interactive_shell_close_request",
-            argNames: ["that", "requestId", "streamId", "reason", "permissionsJson", "sessionKey"],
+        TaskConstMeta get kCrateApiTokenStoreClearDeviceTokenStoreConstMeta => const TaskConstMeta(
+            debugName: "clear_device_token_store",
+            argNames: [],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientInteractiveShellOpenRequest({required GatewayClient that , required String requestId , int? cols , int? rows , String? term , String? metadataJson , String? permissionsJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Future<CompressionStats> crateApiDeflateCompressionStatsDefault()  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_opt_box_autoadd_u_32(cols, serializer);
-sse_encode_opt_box_autoadd_u_32(rows, serializer);
-sse_encode_opt_String(term, serializer);
-sse_encode_opt_String(metadataJson, serializer);
-sse_encode_opt_String(permissionsJson, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 405, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 420, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_compression_stats,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientInteractiveShellOpenRequestConstMeta,
-            argValues: [that, requestId, cols, rows, term, metadataJson, permissionsJson, sessionKey],
+            constMeta: kCrateApiDeflateCompressionStatsDefaultConstMeta,
+            argValues: [],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientInteractiveShellOpenRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_interactive_shell_open_request",
-            argNames: ["that", "requestId", "cols", "rows", "term", "metadataJson", "permissionsJson", "sessionKey"],
+        TaskConstMeta get kCrateApiDeflateCompressionStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "compression_stats_default",
+            argNames: [],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientInteractiveShellResizeRequest({required GatewayClient that , required String requestId , String? streamId , required int cols , required int rows , String? permissionsJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Stream<GatewayEvent> crateApiConnectionConnectToGateway({required String handle , required String url , required HandshakeOptions options , ConnectionConfig? config })  { 
+            final sink = RustStreamSink<GatewayEvent>();
+            unawaited(handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_opt_String(streamId, serializer);
-sse_encode_u_32(cols, serializer);
-sse_encode_u_32(rows, serializer);
-sse_encode_opt_String(permissionsJson, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 406, port: port_);
-            
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_String(url, serializer);
+sse_encode_box_autoadd_handshake_options(options, serializer);
+sse_encode_opt_box_autoadd_connection_config(config, serializer);
+sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 421, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionConnectToGatewayConstMeta,
+            argValues: [handle, url, options, config, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiConnectionConnectToGatewayConstMeta => const TaskConstMeta(
+            debugName: "connect_to_gateway",
+            argNames: ["handle", "url", "options", "config", "sink"],
+        );
+        
+
+@override Future<ConnectionConfig> crateApiConnectionConnectionConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 422, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConnectionConnectionConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionConnectionConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "connection_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<DeflateConfig> crateApiDeflateDeflateConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 423, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_deflate_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDeflateDeflateConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeflateDeflateConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "deflate_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiConnectionDisconnectGateway({required String handle , required String reason })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_String(reason, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 424, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionDisconnectGatewayConstMeta,
+            argValues: [handle, reason],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionDisconnectGatewayConstMeta => const TaskConstMeta(
+            debugName: "disconnect_gateway",
+            argNames: ["handle", "reason"],
+        );
+        
+
+@override Stream<TerminalChunk> crateApiTerminalExecCommand({required String handle , required String command , String? cwd , BigInt? timeoutMs , String? sessionKey , required bool rawOutput })  { 
+            final sink = RustStreamSink<TerminalChunk>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_String(command, serializer);
+sse_encode_opt_String(cwd, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+sse_encode_bool(rawOutput, serializer);
+sse_encode_StreamSink_terminal_chunk_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 425, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTerminalExecCommandConstMeta,
+            argValues: [handle, command, cwd, timeoutMs, sessionKey, rawOutput, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiTerminalExecCommandConstMeta => const TaskConstMeta(
+            debugName: "exec_command",
+            argNames: ["handle", "command", "cwd", "timeoutMs", "sessionKey", "rawOutput", "sink"],
+        );
+        
+
+@override Future<BackpressureStats> crateApiConnectionGatewayBackpressureStats({required String handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 426, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_backpressure_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayBackpressureStatsConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayBackpressureStatsConstMeta => const TaskConstMeta(
+            debugName: "gateway_backpressure_stats",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientAgentTurnRequest({required GatewayClient that , required String requestId , required String message , String? model , String? thinking , BigInt? timeoutSeconds , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_String(message, serializer);
+sse_encode_opt_String(model, serializer);
+sse_encode_opt_String(thinking, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 427, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientAgentTurnRequestConstMeta,
+            argValues: [that, requestId, message, model, thinking, timeoutSeconds, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientAgentTurnRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_agent_turn_request",
+            argNames: ["that", "requestId", "message", "model", "thinking", "timeoutSeconds", "sessionKey"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientCameraSnapRequest({required GatewayClient that , required String requestId , required List<int> frameBytes , String? format , int? width , int? height , PlatformInt64? timestampMs , required bool compress , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_list_prim_u_8_loose(frameBytes, serializer);
+sse_encode_opt_String(format, serializer);
+sse_encode_opt_box_autoadd_u_32(width, serializer);
+sse_encode_opt_box_autoadd_u_32(height, serializer);
+sse_encode_opt_box_autoadd_i_64(timestampMs, serializer);
+sse_encode_bool(compress, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 428, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientCameraSnapRequestConstMeta,
+            argValues: [that, requestId, frameBytes, format, width, height, timestampMs, compress, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientCameraSnapRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_camera_snap_request",
+            argNames: ["that", "requestId", "frameBytes", "format", "width", "height", "timestampMs", "compress", "sessionKey"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientExecCommandRequest({required GatewayClient that , required String requestId , required String command , required List<String> args , Uint8List? stdinBytes , String? cwd , String? envJson , BigInt? timeoutMs , String? execId , bool? killOnDrop , bool? pty , int? cols , int? rows , String? term , String? permissionsJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_String(command, serializer);
+sse_encode_list_String(args, serializer);
+sse_encode_opt_list_prim_u_8_strict(stdinBytes, serializer);
+sse_encode_opt_String(cwd, serializer);
+sse_encode_opt_String(envJson, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_String(execId, serializer);
+sse_encode_opt_box_autoadd_bool(killOnDrop, serializer);
+sse_encode_opt_box_autoadd_bool(pty, serializer);
+sse_encode_opt_box_autoadd_u_32(cols, serializer);
+sse_encode_opt_box_autoadd_u_32(rows, serializer);
+sse_encode_opt_String(term, serializer);
+sse_encode_opt_String(permissionsJson, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 429, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientExecCommandRequestConstMeta,
+            argValues: [that, requestId, command, args, stdinBytes, cwd, envJson, timeoutMs, execId, killOnDrop, pty, cols, rows, term, permissionsJson, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientExecCommandRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_exec_command_request",
+            argNames: ["that", "requestId", "command", "args", "stdinBytes", "cwd", "envJson", "timeoutMs", "execId", "killOnDrop", "pty", "cols", "rows", "term", "permissionsJson", "sessionKey"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientExecRequest({required GatewayClient that , required String requestId , required String command , required List<String> args , String? cwd , String? envJson , String? stdin , BigInt? timeoutMs , bool? stream , String? execId , bool? killOnDrop , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_String(command, serializer);
+sse_encode_list_String(args, serializer);
+sse_encode_opt_String(cwd, serializer);
+sse_encode_opt_String(envJson, serializer);
+sse_encode_opt_String(stdin, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_bool(stream, serializer);
+sse_encode_opt_String(execId, serializer);
+sse_encode_opt_box_autoadd_bool(killOnDrop, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 430, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientExecRequestConstMeta,
+            argValues: [that, requestId, command, args, cwd, envJson, stdin, timeoutMs, stream, execId, killOnDrop, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientExecRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_exec_request",
+            argNames: ["that", "requestId", "command", "args", "cwd", "envJson", "stdin", "timeoutMs", "stream", "execId", "killOnDrop", "sessionKey"],
+        );
+        
+
+@override String crateApiConnectionGatewayClientGetUrl({required GatewayClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 431)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientGetUrlConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientGetUrlConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_get_url",
+            argNames: ["that"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientInteractiveShellCloseRequest({required GatewayClient that , required String requestId , String? streamId , String? reason , String? permissionsJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_opt_String(streamId, serializer);
+sse_encode_opt_String(reason, serializer);
+sse_encode_opt_String(permissionsJson, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 432, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientInteractiveShellCloseRequestConstMeta,
+            argValues: [that, requestId, streamId, reason, permissionsJson, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientInteractiveShellCloseRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_interactive_shell_close_request",
+            argNames: ["that", "requestId", "streamId", "reason", "permissionsJson", "sessionKey"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientInteractiveShellOpenRequest({required GatewayClient that , required String requestId , int? cols , int? rows , String? term , String? metadataJson , String? permissionsJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_opt_box_autoadd_u_32(cols, serializer);
+sse_encode_opt_box_autoadd_u_32(rows, serializer);
+sse_encode_opt_String(term, serializer);
+sse_encode_opt_String(metadataJson, serializer);
+sse_encode_opt_String(permissionsJson, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 433, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientInteractiveShellOpenRequestConstMeta,
+            argValues: [that, requestId, cols, rows, term, metadataJson, permissionsJson, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientInteractiveShellOpenRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_interactive_shell_open_request",
+            argNames: ["that", "requestId", "cols", "rows", "term", "metadataJson", "permissionsJson", "sessionKey"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientInteractiveShellResizeRequest({required GatewayClient that , required String requestId , String? streamId , required int cols , required int rows , String? permissionsJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_opt_String(streamId, serializer);
+sse_encode_u_32(cols, serializer);
+sse_encode_u_32(rows, serializer);
+sse_encode_opt_String(permissionsJson, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 434, port: port_);
+            
             },
             codec: 
         SseCodec(
@@ -11570,7 +12429,7 @@ sse_encode_list_prim_u_8_loose(inputBytes, serializer);
 sse_encode_opt_String(channel, serializer);
 sse_encode_opt_String(permissionsJson, serializer);
 sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 407, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 435, port: port_);
             
             },
             codec: 
@@ -11602,7 +12461,7 @@ sse_encode_opt_box_autoadd_i_64(since, serializer);
 sse_encode_opt_box_autoadd_bool(includeInternal, serializer);
 sse_encode_opt_String(filtersJson, serializer);
 sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 408, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 436, port: port_);
             
             },
             codec: 
@@ -11630,7 +12489,7 @@ sse_encode_opt_String(sessionKey, serializer);
 sse_encode_String(requestId, serializer);
 sse_encode_opt_String(subscriptionId, serializer);
 sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 409, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 437, port: port_);
             
             },
             codec: 
@@ -11655,7 +12514,7 @@ sse_encode_opt_String(sessionKey, serializer);
             callFfi: (port_) {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 410, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 438, port: port_);
             
             },
             codec: 
@@ -11680,7 +12539,7 @@ sse_encode_opt_String(sessionKey, serializer);
             callFfi: () {
               
             final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 411)!;
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 439)!;
             
             },
             codec: 
@@ -11709,257 +12568,546 @@ sse_encode_String(requestId, serializer);
 sse_encode_String(sessionKey, serializer);
 sse_encode_opt_String(reason, serializer);
 sse_encode_opt_String(metadataJson, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 412, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 440, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientSessionsCloseRequestConstMeta,
+            argValues: [that, requestId, sessionKey, reason, metadataJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientSessionsCloseRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_sessions_close_request",
+            argNames: ["that", "requestId", "sessionKey", "reason", "metadataJson"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientSessionsListRequest({required GatewayClient that , required String requestId , int? limit , String? cursor , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_opt_box_autoadd_u_32(limit, serializer);
+sse_encode_opt_String(cursor, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 441, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientSessionsListRequestConstMeta,
+            argValues: [that, requestId, limit, cursor, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientSessionsListRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_sessions_list_request",
+            argNames: ["that", "requestId", "limit", "cursor", "sessionKey"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientSessionsSpawnRequest({required GatewayClient that , required String requestId , String? parentSessionKey , String? label , String? metadataJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_opt_String(parentSessionKey, serializer);
+sse_encode_opt_String(label, serializer);
+sse_encode_opt_String(metadataJson, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 442, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientSessionsSpawnRequestConstMeta,
+            argValues: [that, requestId, parentSessionKey, label, metadataJson, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientSessionsSpawnRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_sessions_spawn_request",
+            argNames: ["that", "requestId", "parentSessionKey", "label", "metadataJson", "sessionKey"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientSystemEventRequest({required GatewayClient that , required String requestId , String? text , String? mode , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_opt_String(text, serializer);
+sse_encode_opt_String(mode, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 443, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientSystemEventRequestConstMeta,
+            argValues: [that, requestId, text, mode, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientSystemEventRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_system_event_request",
+            argNames: ["that", "requestId", "text", "mode", "sessionKey"],
+        );
+        
+
+@override Future<String> crateApiConnectionGatewayClientSystemProbeRequest({required GatewayClient that , required String requestId , bool? network , bool? disk , bool? gateway , String? sessionKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
+sse_encode_String(requestId, serializer);
+sse_encode_opt_box_autoadd_bool(network, serializer);
+sse_encode_opt_box_autoadd_bool(disk, serializer);
+sse_encode_opt_box_autoadd_bool(gateway, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 444, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayClientSystemProbeRequestConstMeta,
+            argValues: [that, requestId, network, disk, gateway, sessionKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayClientSystemProbeRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_client_system_probe_request",
+            argNames: ["that", "requestId", "network", "disk", "gateway", "sessionKey"],
+        );
+        
+
+@override Future<CompressionStats> crateApiConnectionGatewayCompressionStats({required String handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 445, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_compression_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayCompressionStatsConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayCompressionStatsConstMeta => const TaskConstMeta(
+            debugName: "gateway_compression_stats",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<GatewayResponsePayload> crateApiConnectionGatewayRequest({required String handle , required String frameJson , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_String(frameJson, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 446, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiConnectionGatewayRequestConstMeta,
+            argValues: [handle, frameJson, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionGatewayRequestConstMeta => const TaskConstMeta(
+            debugName: "gateway_request",
+            argNames: ["handle", "frameJson", "timeoutMs"],
+        );
+        
+
+@override Future<String> crateApiSimpleGreet({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 447, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<HandshakeOptions> crateApiConnectionHandshakeOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 448, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_handshake_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConnectionHandshakeOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectionHandshakeOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "handshake_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<DeviceIdentity> crateApiDeviceIdentityLoadDeviceIdentity({required String directory })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(directory, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 449, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDeviceIdentityLoadDeviceIdentityConstMeta,
+            argValues: [directory],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeviceIdentityLoadDeviceIdentityConstMeta => const TaskConstMeta(
+            debugName: "load_device_identity",
+            argNames: ["directory"],
+        );
+        
+
+@override Future<OutboxConfig> crateApiOutboxOutboxConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 450, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_outbox_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOutboxOutboxConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOutboxOutboxConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "outbox_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<GatewayEvent?> crateApiEventsParseGatewayFrame({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 451, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientSessionsCloseRequestConstMeta,
-            argValues: [that, requestId, sessionKey, reason, metadataJson],
+            constMeta: kCrateApiEventsParseGatewayFrameConstMeta,
+            argValues: [text],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientSessionsCloseRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_sessions_close_request",
-            argNames: ["that", "requestId", "sessionKey", "reason", "metadataJson"],
+        TaskConstMeta get kCrateApiEventsParseGatewayFrameConstMeta => const TaskConstMeta(
+            debugName: "parse_gateway_frame",
+            argNames: ["text"],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientSessionsListRequest({required GatewayClient that , required String requestId , int? limit , String? cursor , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Future<void> crateApiTokenStoreRegisterDeviceTokenStore({required CallbackTokenStore store })  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_opt_box_autoadd_u_32(limit, serializer);
-sse_encode_opt_String(cursor, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 413, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(store, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 452, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientSessionsListRequestConstMeta,
-            argValues: [that, requestId, limit, cursor, sessionKey],
+            constMeta: kCrateApiTokenStoreRegisterDeviceTokenStoreConstMeta,
+            argValues: [store],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientSessionsListRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_sessions_list_request",
-            argNames: ["that", "requestId", "limit", "cursor", "sessionKey"],
+        TaskConstMeta get kCrateApiTokenStoreRegisterDeviceTokenStoreConstMeta => const TaskConstMeta(
+            debugName: "register_device_token_store",
+            argNames: ["store"],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientSessionsSpawnRequest({required GatewayClient that , required String requestId , String? parentSessionKey , String? label , String? metadataJson , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Future<RequestDelivery> crateApiConnectionSendGatewayRequestFrame({required String handle , required String frameJson })  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_opt_String(parentSessionKey, serializer);
-sse_encode_opt_String(label, serializer);
-sse_encode_opt_String(metadataJson, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 414, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_String(frameJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 453, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_request_delivery,
           decodeErrorData: sse_decode_AnyhowException,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientSessionsSpawnRequestConstMeta,
-            argValues: [that, requestId, parentSessionKey, label, metadataJson, sessionKey],
+            constMeta: kCrateApiConnectionSendGatewayRequestFrameConstMeta,
+            argValues: [handle, frameJson],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientSessionsSpawnRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_sessions_spawn_request",
-            argNames: ["that", "requestId", "parentSessionKey", "label", "metadataJson", "sessionKey"],
+        TaskConstMeta get kCrateApiConnectionSendGatewayRequestFrameConstMeta => const TaskConstMeta(
+            debugName: "send_gateway_request_frame",
+            argNames: ["handle", "frameJson"],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientSystemEventRequest({required GatewayClient that , required String requestId , String? text , String? mode , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Future<void> crateApiConnectionSetGatewayEventsPaused({required String handle , required bool paused })  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_opt_String(text, serializer);
-sse_encode_opt_String(mode, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 415, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_bool(paused, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 454, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientSystemEventRequestConstMeta,
-            argValues: [that, requestId, text, mode, sessionKey],
+            constMeta: kCrateApiConnectionSetGatewayEventsPausedConstMeta,
+            argValues: [handle, paused],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientSystemEventRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_system_event_request",
-            argNames: ["that", "requestId", "text", "mode", "sessionKey"],
+        TaskConstMeta get kCrateApiConnectionSetGatewayEventsPausedConstMeta => const TaskConstMeta(
+            debugName: "set_gateway_events_paused",
+            argNames: ["handle", "paused"],
         );
         
 
-@override Future<String> crateApiConnectionGatewayClientSystemProbeRequest({required GatewayClient that , required String requestId , bool? network , bool? disk , bool? gateway , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Stream<TerminalChunk> crateApiTerminalTerminalStreamAttach({required String handle , required String streamId , required bool rawOutput })  { 
+            final sink = RustStreamSink<TerminalChunk>();
+            unawaited(handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_gateway_client(that, serializer);
-sse_encode_String(requestId, serializer);
-sse_encode_opt_box_autoadd_bool(network, serializer);
-sse_encode_opt_box_autoadd_bool(disk, serializer);
-sse_encode_opt_box_autoadd_bool(gateway, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 416, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_String(streamId, serializer);
+sse_encode_bool(rawOutput, serializer);
+sse_encode_StreamSink_terminal_chunk_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 455, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         )
         ,
-            constMeta: kCrateApiConnectionGatewayClientSystemProbeRequestConstMeta,
-            argValues: [that, requestId, network, disk, gateway, sessionKey],
+            constMeta: kCrateApiTerminalTerminalStreamAttachConstMeta,
+            argValues: [handle, streamId, rawOutput, sink],
             apiImpl: this,
-        )); }
+        )));
+            return sink.stream;
+             }
 
 
-        TaskConstMeta get kCrateApiConnectionGatewayClientSystemProbeRequestConstMeta => const TaskConstMeta(
-            debugName: "gateway_client_system_probe_request",
-            argNames: ["that", "requestId", "network", "disk", "gateway", "sessionKey"],
+        TaskConstMeta get kCrateApiTerminalTerminalStreamAttachConstMeta => const TaskConstMeta(
+            debugName: "terminal_stream_attach",
+            argNames: ["handle", "streamId", "rawOutput", "sink"],
         );
         
 
-@override Future<String> crateApiSimpleGreet({required String name })  { return handler.executeNormal(NormalTask(
+@override Future<String> crateApiTerminalTerminalStreamOpen({required String handle , int? cols , int? rows , String? term , String? sessionKey })  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 417, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_opt_box_autoadd_u_32(cols, serializer);
+sse_encode_opt_box_autoadd_u_32(rows, serializer);
+sse_encode_opt_String(term, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 456, port: port_);
             
             },
             codec: 
         SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         )
         ,
-            constMeta: kCrateApiSimpleGreetConstMeta,
-            argValues: [name],
+            constMeta: kCrateApiTerminalTerminalStreamOpenConstMeta,
+            argValues: [handle, cols, rows, term, sessionKey],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
-            debugName: "greet",
-            argNames: ["name"],
+        TaskConstMeta get kCrateApiTerminalTerminalStreamOpenConstMeta => const TaskConstMeta(
+            debugName: "terminal_stream_open",
+            argNames: ["handle", "cols", "rows", "term", "sessionKey"],
         );
         
 
-@override Future<GatewayEvent?> crateApiEventsParseGatewayFrame({required String text })  { return handler.executeNormal(NormalTask(
+@override Future<void> crateApiTerminalTerminalStreamSend({required String handle , required String streamId , required List<int> inputBytes , String? sessionKey })  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 418, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_String(streamId, serializer);
+sse_encode_list_prim_u_8_loose(inputBytes, serializer);
+sse_encode_opt_String(sessionKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 457, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         )
         ,
-            constMeta: kCrateApiEventsParseGatewayFrameConstMeta,
-            argValues: [text],
+            constMeta: kCrateApiTerminalTerminalStreamSendConstMeta,
+            argValues: [handle, streamId, inputBytes, sessionKey],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiEventsParseGatewayFrameConstMeta => const TaskConstMeta(
-            debugName: "parse_gateway_frame",
-            argNames: ["text"],
+        TaskConstMeta get kCrateApiTerminalTerminalStreamSendConstMeta => const TaskConstMeta(
+            debugName: "terminal_stream_send",
+            argNames: ["handle", "streamId", "inputBytes", "sessionKey"],
         );
         
 
-@override Future<void> crateApiConnectionSendGatewayRequestFrame({required String frameJson })  { return handler.executeNormal(NormalTask(
+@override Future<TerminalStyle> crateApiTerminalEmulatorTerminalStyleDefault()  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(frameJson, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 419, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 458, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_terminal_style,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiConnectionSendGatewayRequestFrameConstMeta,
-            argValues: [frameJson],
+            constMeta: kCrateApiTerminalEmulatorTerminalStyleDefaultConstMeta,
+            argValues: [],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiConnectionSendGatewayRequestFrameConstMeta => const TaskConstMeta(
-            debugName: "send_gateway_request_frame",
-            argNames: ["frameJson"],
+        TaskConstMeta get kCrateApiTerminalEmulatorTerminalStyleDefaultConstMeta => const TaskConstMeta(
+            debugName: "terminal_style_default",
+            argNames: [],
         );
         
 
-@override Future<String> crateApiTerminalTerminalStreamOpen({required String url , int? cols , int? rows , String? term , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Future<TlsOptions> crateApiTlsTlsOptionsDefault()  { return handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
-sse_encode_opt_box_autoadd_u_32(cols, serializer);
-sse_encode_opt_box_autoadd_u_32(rows, serializer);
-sse_encode_opt_String(term, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 420, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 459, port: port_);
             
             },
             codec: 
         SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_tls_options,
+          decodeErrorData: null,
         )
         ,
-            constMeta: kCrateApiTerminalTerminalStreamOpenConstMeta,
-            argValues: [url, cols, rows, term, sessionKey],
+            constMeta: kCrateApiTlsTlsOptionsDefaultConstMeta,
+            argValues: [],
             apiImpl: this,
         )); }
 
 
-        TaskConstMeta get kCrateApiTerminalTerminalStreamOpenConstMeta => const TaskConstMeta(
-            debugName: "terminal_stream_open",
-            argNames: ["url", "cols", "rows", "term", "sessionKey"],
+        TaskConstMeta get kCrateApiTlsTlsOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "tls_options_default",
+            argNames: [],
         );
         
 
-@override Future<void> crateApiTerminalTerminalStreamSend({required String url , required String streamId , required List<int> inputBytes , String? sessionKey })  { return handler.executeNormal(NormalTask(
+@override Stream<ConnectionState> crateApiConnectionWatchConnectionState({required String handle })  { 
+            final sink = RustStreamSink<ConnectionState>();
+            unawaited(handler.executeNormal(NormalTask(
             callFfi: (port_) {
               
-            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
-sse_encode_String(streamId, serializer);
-sse_encode_list_prim_u_8_loose(inputBytes, serializer);
-sse_encode_opt_String(sessionKey, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 421, port: port_);
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(handle, serializer);
+sse_encode_StreamSink_connection_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 460, port: port_);
             
             },
             codec: 
@@ -11968,19 +13116,112 @@ sse_encode_opt_String(sessionKey, serializer);
           decodeErrorData: sse_decode_AnyhowException,
         )
         ,
-            constMeta: kCrateApiTerminalTerminalStreamSendConstMeta,
-            argValues: [url, streamId, inputBytes, sessionKey],
+            constMeta: kCrateApiConnectionWatchConnectionStateConstMeta,
+            argValues: [handle, sink],
             apiImpl: this,
-        )); }
+        )));
+            return sink.stream;
+             }
 
 
-        TaskConstMeta get kCrateApiTerminalTerminalStreamSendConstMeta => const TaskConstMeta(
-            debugName: "terminal_stream_send",
-            argNames: ["url", "streamId", "inputBytes", "sessionKey"],
+        TaskConstMeta get kCrateApiConnectionWatchConnectionStateConstMeta => const TaskConstMeta(
+            debugName: "watch_connection_state",
+            argNames: ["handle", "sink"],
         );
         
 
-RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AgentTurn => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn;
+
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(FutureOr<String?> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<String?>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_opt_String(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_String(rawArg0);
+final arg1 = dco_decode_String(rawArg1);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AgentTurn => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_AgentTurn => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn;
 
@@ -11992,6 +13233,10 @@ RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BTreeMapSt
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BTreeMapStringBool => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool;
 
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CallbackTokenStore => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CallbackTokenStore => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore;
+
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CameraSnapshot => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CameraSnapshot => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot;
@@ -12016,6 +13261,10 @@ RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ConnectPar
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ConnectParams => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams;
 
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_DeviceIdentity => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_DeviceIdentity => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity;
+
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ExecOutput => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ExecOutput => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput;
@@ -12044,6 +13293,10 @@ RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_GatewayReq
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_GatewayRequestParams => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams;
 
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_GatewayResponsePayload => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_GatewayResponsePayload => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload;
+
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_HelloAuth => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_HelloAuth => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth;
@@ -12136,6 +13389,10 @@ RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SystemProb
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SystemProbeResult => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult;
 
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TerminalEmulator => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TerminalEmulator => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator;
+
 RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Value => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
 
 RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Value => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
@@ -12154,6 +13411,9 @@ return BTreeMapStringValueImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected BTreeMapStringBool dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return BTreeMapStringBoolImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected CallbackTokenStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallbackTokenStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected CameraSnapshot dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return CameraSnapshotImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12172,6 +13432,9 @@ return ConnectDeviceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected ConnectParams dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return ConnectParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected DeviceIdentity dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeviceIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected ExecOutput dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return ExecOutputImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12193,6 +13456,9 @@ return GatewayRequestFrameImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected GatewayRequestParams dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return GatewayRequestParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected GatewayResponsePayload dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GatewayResponsePayloadImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected HelloAuth dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return HelloAuthImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12262,6 +13528,9 @@ return SystemProbeParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected SystemProbeResult dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return SystemProbeResultImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected TerminalEmulator dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TerminalEmulatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected Value dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12364,6 +13633,9 @@ return SystemProbeParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected SystemProbeResult dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return SystemProbeResultImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected TerminalEmulator dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TerminalEmulatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected AgentTurn dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return AgentTurnImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12385,6 +13657,9 @@ return ConnectDeviceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected ConnectParams dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return ConnectParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected DeviceIdentity dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeviceIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected ExecOutput dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return ExecOutputImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12394,9 +13669,15 @@ return ExecParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected GatewayError dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return GatewayErrorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected GatewayEvent dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GatewayEventImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected GatewayRequestFrame dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return GatewayRequestFrameImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected GatewayResponsePayload dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GatewayResponsePayloadImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected HelloAuth dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return HelloAuthImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12463,6 +13744,24 @@ return SystemProbeParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected SystemProbeResult dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return SystemProbeResultImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected TerminalEmulator dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TerminalEmulatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Duration dco_decode_Chrono_Duration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeDuration(dco_decode_i_64(raw).toInt()); }
+
+@protected FutureOr<String?> Function(String) dco_decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected Object dco_decode_DartOpaque(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return decodeDartOpaque(raw, generalizedFrbRustBinding); }
+
 @protected AgentTurn dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return AgentTurnImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12472,6 +13771,9 @@ return BTreeMapStringValueImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected BTreeMapStringBool dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return BTreeMapStringBoolImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected CallbackTokenStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallbackTokenStoreImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected CameraSnapshot dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return CameraSnapshotImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12490,6 +13792,9 @@ return ConnectDeviceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected ConnectParams dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return ConnectParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected DeviceIdentity dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeviceIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected ExecOutput dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return ExecOutputImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12511,6 +13816,9 @@ return GatewayRequestFrameImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected GatewayRequestParams dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return GatewayRequestParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected GatewayResponsePayload dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GatewayResponsePayloadImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected HelloAuth dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return HelloAuthImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
@@ -12580,20 +13888,37 @@ return SystemProbeParamsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 @protected SystemProbeResult dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return SystemProbeResultImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
+@protected TerminalEmulator dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TerminalEmulatorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
 @protected Value dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>); }
 
 @protected RustStreamSink<GatewayEvent> dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 throw UnimplementedError(); }
 
+@protected RustStreamSink<ConnectionState> dco_decode_StreamSink_connection_state_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
 @protected RustStreamSink<TerminalChunk> dco_decode_StreamSink_terminal_chunk_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 throw UnimplementedError(); }
 
 @protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw as String; }
 
-@protected EventSink dco_decode_TraitDef_EventSink(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
-throw UnimplementedError(); }
+@protected BackpressurePolicy dco_decode_backpressure_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BackpressurePolicy.values[raw as int]; }
+
+@protected BackpressureStats dco_decode_backpressure_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return BackpressureStats(queued: dco_decode_u_32(arr[0]),
+paused: dco_decode_bool(arr[1]),
+droppedLogs: dco_decode_u_64(arr[2]),
+droppedOutput: dco_decode_u_64(arr[3]),
+droppedEvents: dco_decode_u_64(arr[4]),
+coalesced: dco_decode_u_64(arr[5]),
+lagged: dco_decode_u_64(arr[6]),); }
 
 @protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw as bool; }
@@ -12631,32 +13956,131 @@ return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAuto
 @protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw as bool; }
 
+@protected ConnectionConfig dco_decode_box_autoadd_connection_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_connection_config(raw); }
+
+@protected DeflateConfig dco_decode_box_autoadd_deflate_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_deflate_config(raw); }
+
 @protected GatewayClient dco_decode_box_autoadd_gateway_client(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return dco_decode_gateway_client(raw); }
 
+@protected HandshakeOptions dco_decode_box_autoadd_handshake_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_handshake_options(raw); }
+
 @protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw as int; }
 
 @protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return dco_decode_i_64(raw); }
 
+@protected OutboxConfig dco_decode_box_autoadd_outbox_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_outbox_config(raw); }
+
+@protected ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_proxy_config(raw); }
+
+@protected TlsOptions dco_decode_box_autoadd_tls_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_tls_options(raw); }
+
 @protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw as int; }
 
 @protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return dco_decode_u_64(raw); }
 
+@protected CompressionStats dco_decode_compression_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CompressionStats(negotiated: dco_decode_bool(arr[0]),
+outgoingCompressionDisabled: dco_decode_bool(arr[1]),
+sentBytes: dco_decode_u_64(arr[2]),
+sentWireBytes: dco_decode_u_64(arr[3]),
+receivedBytes: dco_decode_u_64(arr[4]),
+receivedWireBytes: dco_decode_u_64(arr[5]),); }
+
+@protected ConnectionConfig dco_decode_connection_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 25) throw Exception('unexpected arr length: expect 25 but see ${arr.length}');
+                return ConnectionConfig(backoffBase: dco_decode_Chrono_Duration(arr[0]),
+maxBackoff: dco_decode_Chrono_Duration(arr[1]),
+jitter: dco_decode_bool(arr[2]),
+maxAttempts: dco_decode_opt_box_autoadd_u_32(arr[3]),
+connectTimeout: dco_decode_Chrono_Duration(arr[4]),
+heartbeatInterval: dco_decode_Chrono_Duration(arr[5]),
+heartbeatTimeout: dco_decode_Chrono_Duration(arr[6]),
+handshakeTimeout: dco_decode_Chrono_Duration(arr[7]),
+maxSessions: dco_decode_opt_box_autoadd_u_32(arr[8]),
+restartProbeInterval: dco_decode_Chrono_Duration(arr[9]),
+restartProbeWindow: dco_decode_Chrono_Duration(arr[10]),
+maxRestartWait: dco_decode_Chrono_Duration(arr[11]),
+outbox: dco_decode_opt_box_autoadd_outbox_config(arr[12]),
+inboundCapacity: dco_decode_u_32(arr[13]),
+outboundCapacity: dco_decode_u_32(arr[14]),
+logsPolicy: dco_decode_backpressure_policy(arr[15]),
+outputPolicy: dco_decode_backpressure_policy(arr[16]),
+eventPolicy: dco_decode_backpressure_policy(arr[17]),
+outputBatchWindow: dco_decode_Chrono_Duration(arr[18]),
+outputBatchBytes: dco_decode_u_32(arr[19]),
+tls: dco_decode_opt_box_autoadd_tls_options(arr[20]),
+proxy: dco_decode_opt_box_autoadd_proxy_config(arr[21]),
+upgradeHeaders: dco_decode_list_upgrade_header(arr[22]),
+subprotocols: dco_decode_list_String(arr[23]),
+deflate: dco_decode_opt_box_autoadd_deflate_config(arr[24]),); }
+
+@protected ConnectionState dco_decode_connection_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ConnectionState_Idle();
+case 1: return ConnectionState_Connecting(attempt: dco_decode_u_32(raw[1]),);
+case 2: return ConnectionState_Handshaking();
+case 3: return ConnectionState_Ready(protocol: dco_decode_u_32(raw[1]),role: dco_decode_String(raw[2]),subprotocol: dco_decode_opt_String(raw[3]),);
+case 4: return ConnectionState_Backoff(nextRetryAtMs: dco_decode_i_64(raw[1]),reason: dco_decode_String(raw[2]),);
+case 5: return ConnectionState_Restarting();
+case 6: return ConnectionState_Failed(fatal: dco_decode_bool(raw[1]),reason: dco_decode_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected DeflateConfig dco_decode_deflate_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DeflateConfig(clientMaxWindowBits: dco_decode_u_8(arr[0]),
+serverMaxWindowBits: dco_decode_u_8(arr[1]),
+clientNoContextTakeover: dco_decode_bool(arr[2]),
+serverNoContextTakeover: dco_decode_bool(arr[3]),); }
+
 @protected GatewayClient dco_decode_gateway_client(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 final arr = raw as List<dynamic>;
                 if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
                 return GatewayClient(url: dco_decode_String(arr[0]),); }
 
+@protected GatewayEventKind dco_decode_gateway_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GatewayEventKind.values[raw as int]; }
+
+@protected HandshakeOptions dco_decode_handshake_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return HandshakeOptions(clientId: dco_decode_opt_String(arr[0]),
+clientVersion: dco_decode_opt_String(arr[1]),
+platform: dco_decode_opt_String(arr[2]),
+displayName: dco_decode_opt_String(arr[3]),
+instanceId: dco_decode_opt_String(arr[4]),
+identityDir: dco_decode_opt_String(arr[5]),
+role: dco_decode_opt_String(arr[6]),
+scopes: dco_decode_list_String(arr[7]),
+token: dco_decode_opt_String(arr[8]),
+password: dco_decode_opt_String(arr[9]),
+locale: dco_decode_opt_String(arr[10]),
+userAgent: dco_decode_opt_String(arr[11]),); }
+
 @protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw as int; }
 
 @protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return dcoDecodeI64(raw); }
 
+@protected PlatformInt64 dco_decode_isize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
 @protected List<LogEntry> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry).toList(); }
 
@@ -12675,6 +14099,15 @@ return raw as List<int>; }
 @protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw as Uint8List; }
 
+@protected List<TerminalLine> dco_decode_list_terminal_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_terminal_line).toList(); }
+
+@protected List<TerminalRun> dco_decode_list_terminal_run(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_terminal_run).toList(); }
+
+@protected List<UpgradeHeader> dco_decode_list_upgrade_header(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_upgrade_header).toList(); }
+
 @protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw == null ? null : dco_decode_String(raw); }
 
@@ -12711,12 +14144,27 @@ return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter
 @protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
 
+@protected ConnectionConfig? dco_decode_opt_box_autoadd_connection_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_connection_config(raw); }
+
+@protected DeflateConfig? dco_decode_opt_box_autoadd_deflate_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_deflate_config(raw); }
+
 @protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
 
 @protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
 
+@protected OutboxConfig? dco_decode_opt_box_autoadd_outbox_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_outbox_config(raw); }
+
+@protected ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_proxy_config(raw); }
+
+@protected TlsOptions? dco_decode_opt_box_autoadd_tls_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_tls_options(raw); }
+
 @protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
 
@@ -12726,11 +14174,101 @@ return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
 @protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
 
+@protected OutboxConfig dco_decode_outbox_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return OutboxConfig(maxItems: dco_decode_u_32(arr[0]),
+ttl: dco_decode_Chrono_Duration(arr[1]),
+persistDir: dco_decode_opt_String(arr[2]),); }
+
+@protected ProxyConfig dco_decode_proxy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ProxyConfig(kind: dco_decode_proxy_kind(arr[0]),
+host: dco_decode_String(arr[1]),
+port: dco_decode_u_16(arr[2]),
+username: dco_decode_opt_String(arr[3]),
+password: dco_decode_opt_String(arr[4]),); }
+
+@protected ProxyKind dco_decode_proxy_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProxyKind.values[raw as int]; }
+
+@protected RequestDelivery dco_decode_request_delivery(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RequestDelivery.values[raw as int]; }
+
 @protected TerminalChunk dco_decode_terminal_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 final arr = raw as List<dynamic>;
-                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
                 return TerminalChunk(text: dco_decode_String(arr[0]),
-kind: dco_decode_String(arr[1]),); }
+kind: dco_decode_String(arr[1]),
+channel: dco_decode_opt_String(arr[2]),
+data: dco_decode_opt_list_prim_u_8_strict(arr[3]),
+spans: dco_decode_list_terminal_run(arr[4]),); }
+
+@protected TerminalColor dco_decode_terminal_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return TerminalColor_Default();
+case 1: return TerminalColor_Indexed(index: dco_decode_u_8(raw[1]),);
+case 2: return TerminalColor_Rgb(red: dco_decode_u_8(raw[1]),green: dco_decode_u_8(raw[2]),blue: dco_decode_u_8(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected TerminalLine dco_decode_terminal_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TerminalLine(row: dco_decode_u_32(arr[0]),
+runs: dco_decode_list_terminal_run(arr[1]),
+wrapped: dco_decode_bool(arr[2]),); }
+
+@protected TerminalRun dco_decode_terminal_run(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TerminalRun(text: dco_decode_String(arr[0]),
+style: dco_decode_terminal_style(arr[1]),); }
+
+@protected TerminalScreenUpdate dco_decode_terminal_screen_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return TerminalScreenUpdate(cols: dco_decode_u_32(arr[0]),
+rows: dco_decode_u_32(arr[1]),
+cursorRow: dco_decode_u_32(arr[2]),
+cursorCol: dco_decode_u_32(arr[3]),
+cursorVisible: dco_decode_bool(arr[4]),
+alternateScreen: dco_decode_bool(arr[5]),
+applicationCursorKeys: dco_decode_bool(arr[6]),
+bracketedPaste: dco_decode_bool(arr[7]),
+full: dco_decode_bool(arr[8]),
+lines: dco_decode_list_terminal_line(arr[9]),
+scrollbackLen: dco_decode_u_32(arr[10]),
+title: dco_decode_opt_String(arr[11]),); }
+
+@protected TerminalStyle dco_decode_terminal_style(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return TerminalStyle(foreground: dco_decode_terminal_color(arr[0]),
+background: dco_decode_terminal_color(arr[1]),
+bold: dco_decode_bool(arr[2]),
+dim: dco_decode_bool(arr[3]),
+italic: dco_decode_bool(arr[4]),
+underline: dco_decode_bool(arr[5]),
+inverse: dco_decode_bool(arr[6]),
+hidden: dco_decode_bool(arr[7]),
+strikethrough: dco_decode_bool(arr[8]),); }
+
+@protected TlsOptions dco_decode_tls_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return TlsOptions(extraCaPem: dco_decode_list_String(arr[0]),
+spkiPins: dco_decode_list_String(arr[1]),
+trustOnFirstUse: dco_decode_bool(arr[2]),
+knownHostsDir: dco_decode_opt_String(arr[3]),
+allowSelfSigned: dco_decode_bool(arr[4]),
+clientCertChainPem: dco_decode_opt_String(arr[5]),
+clientKeyPem: dco_decode_opt_String(arr[6]),
+clientKeyPkcs8: dco_decode_opt_list_prim_u_8_strict(arr[7]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
 
 @protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return raw as int; }
@@ -12744,6 +14282,12 @@ return raw as int; }
 @protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return; }
 
+@protected UpgradeHeader dco_decode_upgrade_header(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UpgradeHeader(name: dco_decode_String(arr[0]),
+value: dco_decode_String(arr[1]),); }
+
 @protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
 return dcoDecodeU64(raw); }
 
@@ -12760,6 +14304,9 @@ return BTreeMapStringValueImpl.frbInternalSseDecode(sse_decode_usize(deserialize
 @protected BTreeMapStringBool sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return BTreeMapStringBoolImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected CallbackTokenStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CallbackTokenStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected CameraSnapshot sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return CameraSnapshotImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -12778,6 +14325,9 @@ return ConnectDeviceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), ss
 @protected ConnectParams sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return ConnectParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected DeviceIdentity sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DeviceIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected ExecOutput sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return ExecOutputImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -12799,6 +14349,9 @@ return GatewayRequestFrameImpl.frbInternalSseDecode(sse_decode_usize(deserialize
 @protected GatewayRequestParams sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return GatewayRequestParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected GatewayResponsePayload sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GatewayResponsePayloadImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected HelloAuth sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return HelloAuthImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -12868,6 +14421,9 @@ return SystemProbeParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer)
 @protected SystemProbeResult sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return SystemProbeResultImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected TerminalEmulator sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TerminalEmulatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected Value sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return ValueImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -12970,6 +14526,9 @@ return SystemProbeParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer)
 @protected SystemProbeResult sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return SystemProbeResultImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected TerminalEmulator sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TerminalEmulatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected AgentTurn sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return AgentTurnImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -12991,6 +14550,9 @@ return ConnectDeviceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), ss
 @protected ConnectParams sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return ConnectParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected DeviceIdentity sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DeviceIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected ExecOutput sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return ExecOutputImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -13000,9 +14562,15 @@ return ExecParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_d
 @protected GatewayError sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return GatewayErrorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected GatewayEvent sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GatewayEventImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected GatewayRequestFrame sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return GatewayRequestFrameImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected GatewayResponsePayload sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GatewayResponsePayloadImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected HelloAuth sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return HelloAuthImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -13069,6 +14637,17 @@ return SystemProbeParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer)
 @protected SystemProbeResult sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return SystemProbeResultImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected TerminalEmulator sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TerminalEmulatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Duration sse_decode_Chrono_Duration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_64(deserializer);
+        return Duration(microseconds: inner.toInt()); }
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_isize(deserializer);
+        return decodeDartOpaque(inner, generalizedFrbRustBinding); }
+
 @protected AgentTurn sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return AgentTurnImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -13078,6 +14657,9 @@ return BTreeMapStringValueImpl.frbInternalSseDecode(sse_decode_usize(deserialize
 @protected BTreeMapStringBool sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return BTreeMapStringBoolImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected CallbackTokenStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CallbackTokenStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected CameraSnapshot sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return CameraSnapshotImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -13096,6 +14678,9 @@ return ConnectDeviceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), ss
 @protected ConnectParams sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return ConnectParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected DeviceIdentity sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DeviceIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected ExecOutput sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return ExecOutputImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -13117,6 +14702,9 @@ return GatewayRequestFrameImpl.frbInternalSseDecode(sse_decode_usize(deserialize
 @protected GatewayRequestParams sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return GatewayRequestParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected GatewayResponsePayload sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return GatewayResponsePayloadImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected HelloAuth sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return HelloAuthImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
@@ -13186,12 +14774,18 @@ return SystemProbeParamsImpl.frbInternalSseDecode(sse_decode_usize(deserializer)
 @protected SystemProbeResult sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return SystemProbeResultImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
+@protected TerminalEmulator sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TerminalEmulatorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
 @protected Value sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return ValueImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
 
 @protected RustStreamSink<GatewayEvent> sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 throw UnimplementedError('Unreachable ()'); }
 
+@protected RustStreamSink<ConnectionState> sse_decode_StreamSink_connection_state_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
 @protected RustStreamSink<TerminalChunk> sse_decode_StreamSink_terminal_chunk_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 throw UnimplementedError('Unreachable ()'); }
 
@@ -13199,6 +14793,20 @@ throw UnimplementedError('Unreachable ()'); }
 var inner = sse_decode_list_prim_u_8_strict(deserializer);
         return utf8.decoder.convert(inner); }
 
+@protected BackpressurePolicy sse_decode_backpressure_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BackpressurePolicy.values[inner]; }
+
+@protected BackpressureStats sse_decode_backpressure_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_queued = sse_decode_u_32(deserializer);
+var var_paused = sse_decode_bool(deserializer);
+var var_droppedLogs = sse_decode_u_64(deserializer);
+var var_droppedOutput = sse_decode_u_64(deserializer);
+var var_droppedEvents = sse_decode_u_64(deserializer);
+var var_coalesced = sse_decode_u_64(deserializer);
+var var_lagged = sse_decode_u_64(deserializer);
+return BackpressureStats(queued: var_queued, paused: var_paused, droppedLogs: var_droppedLogs, droppedOutput: var_droppedOutput, droppedEvents: var_droppedEvents, coalesced: var_coalesced, lagged: var_lagged); }
+
 @protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return deserializer.buffer.getUint8() != 0; }
 
@@ -13235,31 +14843,129 @@ return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAut
 @protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return (sse_decode_bool(deserializer)); }
 
+@protected ConnectionConfig sse_decode_box_autoadd_connection_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_connection_config(deserializer)); }
+
+@protected DeflateConfig sse_decode_box_autoadd_deflate_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_deflate_config(deserializer)); }
+
 @protected GatewayClient sse_decode_box_autoadd_gateway_client(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return (sse_decode_gateway_client(deserializer)); }
 
+@protected HandshakeOptions sse_decode_box_autoadd_handshake_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_handshake_options(deserializer)); }
+
 @protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return (sse_decode_i_32(deserializer)); }
 
 @protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return (sse_decode_i_64(deserializer)); }
 
+@protected OutboxConfig sse_decode_box_autoadd_outbox_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_outbox_config(deserializer)); }
+
+@protected ProxyConfig sse_decode_box_autoadd_proxy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_proxy_config(deserializer)); }
+
+@protected TlsOptions sse_decode_box_autoadd_tls_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_tls_options(deserializer)); }
+
 @protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return (sse_decode_u_32(deserializer)); }
 
 @protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return (sse_decode_u_64(deserializer)); }
 
+@protected CompressionStats sse_decode_compression_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_negotiated = sse_decode_bool(deserializer);
+var var_outgoingCompressionDisabled = sse_decode_bool(deserializer);
+var var_sentBytes = sse_decode_u_64(deserializer);
+var var_sentWireBytes = sse_decode_u_64(deserializer);
+var var_receivedBytes = sse_decode_u_64(deserializer);
+var var_receivedWireBytes = sse_decode_u_64(deserializer);
+return CompressionStats(negotiated: var_negotiated, outgoingCompressionDisabled: var_outgoingCompressionDisabled, sentBytes: var_sentBytes, sentWireBytes: var_sentWireBytes, receivedBytes: var_receivedBytes, receivedWireBytes: var_receivedWireBytes); }
+
+@protected ConnectionConfig sse_decode_connection_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_backoffBase = sse_decode_Chrono_Duration(deserializer);
+var var_maxBackoff = sse_decode_Chrono_Duration(deserializer);
+var var_jitter = sse_decode_bool(deserializer);
+var var_maxAttempts = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_connectTimeout = sse_decode_Chrono_Duration(deserializer);
+var var_heartbeatInterval = sse_decode_Chrono_Duration(deserializer);
+var var_heartbeatTimeout = sse_decode_Chrono_Duration(deserializer);
+var var_handshakeTimeout = sse_decode_Chrono_Duration(deserializer);
+var var_maxSessions = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_restartProbeInterval = sse_decode_Chrono_Duration(deserializer);
+var var_restartProbeWindow = sse_decode_Chrono_Duration(deserializer);
+var var_maxRestartWait = sse_decode_Chrono_Duration(deserializer);
+var var_outbox = sse_decode_opt_box_autoadd_outbox_config(deserializer);
+var var_inboundCapacity = sse_decode_u_32(deserializer);
+var var_outboundCapacity = sse_decode_u_32(deserializer);
+var var_logsPolicy = sse_decode_backpressure_policy(deserializer);
+var var_outputPolicy = sse_decode_backpressure_policy(deserializer);
+var var_eventPolicy = sse_decode_backpressure_policy(deserializer);
+var var_outputBatchWindow = sse_decode_Chrono_Duration(deserializer);
+var var_outputBatchBytes = sse_decode_u_32(deserializer);
+var var_tls = sse_decode_opt_box_autoadd_tls_options(deserializer);
+var var_proxy = sse_decode_opt_box_autoadd_proxy_config(deserializer);
+var var_upgradeHeaders = sse_decode_list_upgrade_header(deserializer);
+var var_subprotocols = sse_decode_list_String(deserializer);
+var var_deflate = sse_decode_opt_box_autoadd_deflate_config(deserializer);
+return ConnectionConfig(backoffBase: var_backoffBase, maxBackoff: var_maxBackoff, jitter: var_jitter, maxAttempts: var_maxAttempts, connectTimeout: var_connectTimeout, heartbeatInterval: var_heartbeatInterval, heartbeatTimeout: var_heartbeatTimeout, handshakeTimeout: var_handshakeTimeout, maxSessions: var_maxSessions, restartProbeInterval: var_restartProbeInterval, restartProbeWindow: var_restartProbeWindow, maxRestartWait: var_maxRestartWait, outbox: var_outbox, inboundCapacity: var_inboundCapacity, outboundCapacity: var_outboundCapacity, logsPolicy: var_logsPolicy, outputPolicy: var_outputPolicy, eventPolicy: var_eventPolicy, outputBatchWindow: var_outputBatchWindow, outputBatchBytes: var_outputBatchBytes, tls: var_tls, proxy: var_proxy, upgradeHeaders: var_upgradeHeaders, subprotocols: var_subprotocols, deflate: var_deflate); }
+
+@protected ConnectionState sse_decode_connection_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ConnectionState_Idle();case 1: var var_attempt = sse_decode_u_32(deserializer);
+return ConnectionState_Connecting(attempt: var_attempt);case 2: return ConnectionState_Handshaking();case 3: var var_protocol = sse_decode_u_32(deserializer);
+var var_role = sse_decode_String(deserializer);
+var var_subprotocol = sse_decode_opt_String(deserializer);
+return ConnectionState_Ready(protocol: var_protocol, role: var_role, subprotocol: var_subprotocol);case 4: var var_nextRetryAtMs = sse_decode_i_64(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return ConnectionState_Backoff(nextRetryAtMs: var_nextRetryAtMs, reason: var_reason);case 5: return ConnectionState_Restarting();case 6: var var_fatal = sse_decode_bool(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return ConnectionState_Failed(fatal: var_fatal, reason: var_reason); default: throw UnimplementedError(''); }
+             }
+
+@protected DeflateConfig sse_decode_deflate_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_clientMaxWindowBits = sse_decode_u_8(deserializer);
+var var_serverMaxWindowBits = sse_decode_u_8(deserializer);
+var var_clientNoContextTakeover = sse_decode_bool(deserializer);
+var var_serverNoContextTakeover = sse_decode_bool(deserializer);
+return DeflateConfig(clientMaxWindowBits: var_clientMaxWindowBits, serverMaxWindowBits: var_serverMaxWindowBits, clientNoContextTakeover: var_clientNoContextTakeover, serverNoContextTakeover: var_serverNoContextTakeover); }
+
 @protected GatewayClient sse_decode_gateway_client(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 var var_url = sse_decode_String(deserializer);
 return GatewayClient(url: var_url); }
 
+@protected GatewayEventKind sse_decode_gateway_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return GatewayEventKind.values[inner]; }
+
+@protected HandshakeOptions sse_decode_handshake_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_clientId = sse_decode_opt_String(deserializer);
+var var_clientVersion = sse_decode_opt_String(deserializer);
+var var_platform = sse_decode_opt_String(deserializer);
+var var_displayName = sse_decode_opt_String(deserializer);
+var var_instanceId = sse_decode_opt_String(deserializer);
+var var_identityDir = sse_decode_opt_String(deserializer);
+var var_role = sse_decode_opt_String(deserializer);
+var var_scopes = sse_decode_list_String(deserializer);
+var var_token = sse_decode_opt_String(deserializer);
+var var_password = sse_decode_opt_String(deserializer);
+var var_locale = sse_decode_opt_String(deserializer);
+var var_userAgent = sse_decode_opt_String(deserializer);
+return HandshakeOptions(clientId: var_clientId, clientVersion: var_clientVersion, platform: var_platform, displayName: var_displayName, instanceId: var_instanceId, identityDir: var_identityDir, role: var_role, scopes: var_scopes, token: var_token, password: var_password, locale: var_locale, userAgent: var_userAgent); }
+
 @protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return deserializer.buffer.getInt32(); }
 
 @protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return deserializer.buffer.getPlatformInt64(); }
 
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
 @protected List<LogEntry> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 
         var len_ = sse_decode_i_32(deserializer);
@@ -13300,6 +15006,30 @@ var len_ = sse_decode_i_32(deserializer);
 var len_ = sse_decode_i_32(deserializer);
                 return deserializer.buffer.getUint8List(len_); }
 
+@protected List<TerminalLine> sse_decode_list_terminal_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TerminalLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_terminal_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TerminalRun> sse_decode_list_terminal_run(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TerminalRun>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_terminal_run(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UpgradeHeader> sse_decode_list_upgrade_header(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UpgradeHeader>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_upgrade_header(deserializer)); }
+        return ans_;
+         }
+
 @protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 
             if (sse_decode_bool(deserializer)) {
@@ -13408,6 +15138,24 @@ var len_ = sse_decode_i_32(deserializer);
             }
              }
 
+@protected ConnectionConfig? sse_decode_opt_box_autoadd_connection_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_connection_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DeflateConfig? sse_decode_opt_box_autoadd_deflate_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_deflate_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
 @protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 
             if (sse_decode_bool(deserializer)) {
@@ -13426,6 +15174,33 @@ var len_ = sse_decode_i_32(deserializer);
             }
              }
 
+@protected OutboxConfig? sse_decode_opt_box_autoadd_outbox_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_outbox_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_proxy_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TlsOptions? sse_decode_opt_box_autoadd_tls_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_tls_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
 @protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 
             if (sse_decode_bool(deserializer)) {
@@ -13453,10 +15228,97 @@ var len_ = sse_decode_i_32(deserializer);
             }
              }
 
+@protected OutboxConfig sse_decode_outbox_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxItems = sse_decode_u_32(deserializer);
+var var_ttl = sse_decode_Chrono_Duration(deserializer);
+var var_persistDir = sse_decode_opt_String(deserializer);
+return OutboxConfig(maxItems: var_maxItems, ttl: var_ttl, persistDir: var_persistDir); }
+
+@protected ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_proxy_kind(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_username = sse_decode_opt_String(deserializer);
+var var_password = sse_decode_opt_String(deserializer);
+return ProxyConfig(kind: var_kind, host: var_host, port: var_port, username: var_username, password: var_password); }
+
+@protected ProxyKind sse_decode_proxy_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProxyKind.values[inner]; }
+
+@protected RequestDelivery sse_decode_request_delivery(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RequestDelivery.values[inner]; }
+
 @protected TerminalChunk sse_decode_terminal_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 var var_text = sse_decode_String(deserializer);
 var var_kind = sse_decode_String(deserializer);
-return TerminalChunk(text: var_text, kind: var_kind); }
+var var_channel = sse_decode_opt_String(deserializer);
+var var_data = sse_decode_opt_list_prim_u_8_strict(deserializer);
+var var_spans = sse_decode_list_terminal_run(deserializer);
+return TerminalChunk(text: var_text, kind: var_kind, channel: var_channel, data: var_data, spans: var_spans); }
+
+@protected TerminalColor sse_decode_terminal_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return TerminalColor_Default();case 1: var var_index = sse_decode_u_8(deserializer);
+return TerminalColor_Indexed(index: var_index);case 2: var var_red = sse_decode_u_8(deserializer);
+var var_green = sse_decode_u_8(deserializer);
+var var_blue = sse_decode_u_8(deserializer);
+return TerminalColor_Rgb(red: var_red, green: var_green, blue: var_blue); default: throw UnimplementedError(''); }
+             }
+
+@protected TerminalLine sse_decode_terminal_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_row = sse_decode_u_32(deserializer);
+var var_runs = sse_decode_list_terminal_run(deserializer);
+var var_wrapped = sse_decode_bool(deserializer);
+return TerminalLine(row: var_row, runs: var_runs, wrapped: var_wrapped); }
+
+@protected TerminalRun sse_decode_terminal_run(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_style = sse_decode_terminal_style(deserializer);
+return TerminalRun(text: var_text, style: var_style); }
+
+@protected TerminalScreenUpdate sse_decode_terminal_screen_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_cols = sse_decode_u_32(deserializer);
+var var_rows = sse_decode_u_32(deserializer);
+var var_cursorRow = sse_decode_u_32(deserializer);
+var var_cursorCol = sse_decode_u_32(deserializer);
+var var_cursorVisible = sse_decode_bool(deserializer);
+var var_alternateScreen = sse_decode_bool(deserializer);
+var var_applicationCursorKeys = sse_decode_bool(deserializer);
+var var_bracketedPaste = sse_decode_bool(deserializer);
+var var_full = sse_decode_bool(deserializer);
+var var_lines = sse_decode_list_terminal_line(deserializer);
+var var_scrollbackLen = sse_decode_u_32(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+return TerminalScreenUpdate(cols: var_cols, rows: var_rows, cursorRow: var_cursorRow, cursorCol: var_cursorCol, cursorVisible: var_cursorVisible, alternateScreen: var_alternateScreen, applicationCursorKeys: var_applicationCursorKeys, bracketedPaste: var_bracketedPaste, full: var_full, lines: var_lines, scrollbackLen: var_scrollbackLen, title: var_title); }
+
+@protected TerminalStyle sse_decode_terminal_style(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_foreground = sse_decode_terminal_color(deserializer);
+var var_background = sse_decode_terminal_color(deserializer);
+var var_bold = sse_decode_bool(deserializer);
+var var_dim = sse_decode_bool(deserializer);
+var var_italic = sse_decode_bool(deserializer);
+var var_underline = sse_decode_bool(deserializer);
+var var_inverse = sse_decode_bool(deserializer);
+var var_hidden = sse_decode_bool(deserializer);
+var var_strikethrough = sse_decode_bool(deserializer);
+return TerminalStyle(foreground: var_foreground, background: var_background, bold: var_bold, dim: var_dim, italic: var_italic, underline: var_underline, inverse: var_inverse, hidden: var_hidden, strikethrough: var_strikethrough); }
+
+@protected TlsOptions sse_decode_tls_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_extraCaPem = sse_decode_list_String(deserializer);
+var var_spkiPins = sse_decode_list_String(deserializer);
+var var_trustOnFirstUse = sse_decode_bool(deserializer);
+var var_knownHostsDir = sse_decode_opt_String(deserializer);
+var var_allowSelfSigned = sse_decode_bool(deserializer);
+var var_clientCertChainPem = sse_decode_opt_String(deserializer);
+var var_clientKeyPem = sse_decode_opt_String(deserializer);
+var var_clientKeyPkcs8 = sse_decode_opt_list_prim_u_8_strict(deserializer);
+return TlsOptions(extraCaPem: var_extraCaPem, spkiPins: var_spkiPins, trustOnFirstUse: var_trustOnFirstUse, knownHostsDir: var_knownHostsDir, allowSelfSigned: var_allowSelfSigned, clientCertChainPem: var_clientCertChainPem, clientKeyPem: var_clientKeyPem, clientKeyPkcs8: var_clientKeyPkcs8); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
 
 @protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return deserializer.buffer.getUint32(); }
@@ -13470,6 +15332,11 @@ return deserializer.buffer.getUint8(); }
 @protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
  }
 
+@protected UpgradeHeader sse_decode_upgrade_header(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return UpgradeHeader(name: var_name, value: var_value); }
+
 @protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 return deserializer.buffer.getBigUint64(); }
 
@@ -13485,6 +15352,9 @@ sse_encode_usize((self as BTreeMapStringValueImpl).frbInternalSseEncode(move: tr
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(BTreeMapStringBool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as BTreeMapStringBoolImpl).frbInternalSseEncode(move: true), serializer); }
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(CallbackTokenStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CallbackTokenStoreImpl).frbInternalSseEncode(move: true), serializer); }
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(CameraSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as CameraSnapshotImpl).frbInternalSseEncode(move: true), serializer); }
 
@@ -13503,6 +15373,9 @@ sse_encode_usize((self as ConnectDeviceImpl).frbInternalSseEncode(move: true), s
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as ConnectParamsImpl).frbInternalSseEncode(move: true), serializer); }
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DeviceIdentityImpl).frbInternalSseEncode(move: true), serializer); }
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as ExecOutputImpl).frbInternalSseEncode(move: true), serializer); }
 
@@ -13524,6 +15397,9 @@ sse_encode_usize((self as GatewayRequestFrameImpl).frbInternalSseEncode(move: tr
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(GatewayRequestParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as GatewayRequestParamsImpl).frbInternalSseEncode(move: true), serializer); }
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GatewayResponsePayloadImpl).frbInternalSseEncode(move: true), serializer); }
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as HelloAuthImpl).frbInternalSseEncode(move: true), serializer); }
 
@@ -13593,6 +15469,9 @@ sse_encode_usize((self as SystemProbeParamsImpl).frbInternalSseEncode(move: true
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as SystemProbeResultImpl).frbInternalSseEncode(move: true), serializer); }
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TerminalEmulatorImpl).frbInternalSseEncode(move: true), serializer); }
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as ValueImpl).frbInternalSseEncode(move: true), serializer); }
 
@@ -13695,6 +15574,9 @@ sse_encode_usize((self as SystemProbeParamsImpl).frbInternalSseEncode(move: fals
 @protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as SystemProbeResultImpl).frbInternalSseEncode(move: false), serializer); }
 
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TerminalEmulatorImpl).frbInternalSseEncode(move: false), serializer); }
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(AgentTurn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as AgentTurnImpl).frbInternalSseEncode(move: false), serializer); }
 
@@ -13716,6 +15598,9 @@ sse_encode_usize((self as ConnectDeviceImpl).frbInternalSseEncode(move: false),
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as ConnectParamsImpl).frbInternalSseEncode(move: false), serializer); }
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DeviceIdentityImpl).frbInternalSseEncode(move: false), serializer); }
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as ExecOutputImpl).frbInternalSseEncode(move: false), serializer); }
 
@@ -13725,9 +15610,15 @@ sse_encode_usize((self as ExecParamsImpl).frbInternalSseEncode(move: false), ser
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(GatewayError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as GatewayErrorImpl).frbInternalSseEncode(move: false), serializer); }
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(GatewayEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GatewayEventImpl).frbInternalSseEncode(move: false), serializer); }
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(GatewayRequestFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as GatewayRequestFrameImpl).frbInternalSseEncode(move: false), serializer); }
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GatewayResponsePayloadImpl).frbInternalSseEncode(move: false), serializer); }
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as HelloAuthImpl).frbInternalSseEncode(move: false), serializer); }
 
@@ -13794,6 +15685,24 @@ sse_encode_usize((self as SystemProbeParamsImpl).frbInternalSseEncode(move: fals
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as SystemProbeResultImpl).frbInternalSseEncode(move: false), serializer); }
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TerminalEmulatorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(PlatformInt64Util.from(self.inMicroseconds), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(FutureOr<String?> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_isize(PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding)), serializer); }
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(AgentTurn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as AgentTurnImpl).frbInternalSseEncode(move: null), serializer); }
 
@@ -13803,6 +15712,9 @@ sse_encode_usize((self as BTreeMapStringValueImpl).frbInternalSseEncode(move: nu
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(BTreeMapStringBool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as BTreeMapStringBoolImpl).frbInternalSseEncode(move: null), serializer); }
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(CallbackTokenStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CallbackTokenStoreImpl).frbInternalSseEncode(move: null), serializer); }
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(CameraSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as CameraSnapshotImpl).frbInternalSseEncode(move: null), serializer); }
 
@@ -13821,6 +15733,9 @@ sse_encode_usize((self as ConnectDeviceImpl).frbInternalSseEncode(move: null), s
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as ConnectParamsImpl).frbInternalSseEncode(move: null), serializer); }
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DeviceIdentityImpl).frbInternalSseEncode(move: null), serializer); }
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as ExecOutputImpl).frbInternalSseEncode(move: null), serializer); }
 
@@ -13842,6 +15757,9 @@ sse_encode_usize((self as GatewayRequestFrameImpl).frbInternalSseEncode(move: nu
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(GatewayRequestParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as GatewayRequestParamsImpl).frbInternalSseEncode(move: null), serializer); }
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as GatewayResponsePayloadImpl).frbInternalSseEncode(move: null), serializer); }
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as HelloAuthImpl).frbInternalSseEncode(move: null), serializer); }
 
@@ -13911,6 +15829,9 @@ sse_encode_usize((self as SystemProbeParamsImpl).frbInternalSseEncode(move: null
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as SystemProbeResultImpl).frbInternalSseEncode(move: null), serializer); }
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TerminalEmulatorImpl).frbInternalSseEncode(move: null), serializer); }
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_usize((self as ValueImpl).frbInternalSseEncode(move: null), serializer); }
 
@@ -13920,6 +15841,12 @@ sse_encode_String(self.setupAndSerialize(codec: SseCodec(
             decodeErrorData: sse_decode_AnyhowException,
         )), serializer); }
 
+@protected void sse_encode_StreamSink_connection_state_Sse(RustStreamSink<ConnectionState> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_connection_state,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
 @protected void sse_encode_StreamSink_terminal_chunk_Sse(RustStreamSink<TerminalChunk> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_String(self.setupAndSerialize(codec: SseCodec(
             decodeSuccessData: sse_decode_terminal_chunk,
@@ -13929,6 +15856,19 @@ sse_encode_String(self.setupAndSerialize(codec: SseCodec(
 @protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
 
+@protected void sse_encode_backpressure_policy(BackpressurePolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_backpressure_stats(BackpressureStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.queued, serializer);
+sse_encode_bool(self.paused, serializer);
+sse_encode_u_64(self.droppedLogs, serializer);
+sse_encode_u_64(self.droppedOutput, serializer);
+sse_encode_u_64(self.droppedEvents, serializer);
+sse_encode_u_64(self.coalesced, serializer);
+sse_encode_u_64(self.lagged, serializer);
+ }
+
 @protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 serializer.buffer.putUint8(self ? 1 : 0); }
 
@@ -13965,31 +15905,125 @@ sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
 @protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_bool(self, serializer); }
 
+@protected void sse_encode_box_autoadd_connection_config(ConnectionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_connection_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_deflate_config(DeflateConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_deflate_config(self, serializer); }
+
 @protected void sse_encode_box_autoadd_gateway_client(GatewayClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_gateway_client(self, serializer); }
 
+@protected void sse_encode_box_autoadd_handshake_options(HandshakeOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_handshake_options(self, serializer); }
+
 @protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_i_32(self, serializer); }
 
 @protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_i_64(self, serializer); }
 
+@protected void sse_encode_box_autoadd_outbox_config(OutboxConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_outbox_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_proxy_config(ProxyConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_proxy_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_tls_options(TlsOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_tls_options(self, serializer); }
+
 @protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_u_32(self, serializer); }
 
 @protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_u_64(self, serializer); }
 
+@protected void sse_encode_compression_stats(CompressionStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.negotiated, serializer);
+sse_encode_bool(self.outgoingCompressionDisabled, serializer);
+sse_encode_u_64(self.sentBytes, serializer);
+sse_encode_u_64(self.sentWireBytes, serializer);
+sse_encode_u_64(self.receivedBytes, serializer);
+sse_encode_u_64(self.receivedWireBytes, serializer);
+ }
+
+@protected void sse_encode_connection_config(ConnectionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Chrono_Duration(self.backoffBase, serializer);
+sse_encode_Chrono_Duration(self.maxBackoff, serializer);
+sse_encode_bool(self.jitter, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxAttempts, serializer);
+sse_encode_Chrono_Duration(self.connectTimeout, serializer);
+sse_encode_Chrono_Duration(self.heartbeatInterval, serializer);
+sse_encode_Chrono_Duration(self.heartbeatTimeout, serializer);
+sse_encode_Chrono_Duration(self.handshakeTimeout, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxSessions, serializer);
+sse_encode_Chrono_Duration(self.restartProbeInterval, serializer);
+sse_encode_Chrono_Duration(self.restartProbeWindow, serializer);
+sse_encode_Chrono_Duration(self.maxRestartWait, serializer);
+sse_encode_opt_box_autoadd_outbox_config(self.outbox, serializer);
+sse_encode_u_32(self.inboundCapacity, serializer);
+sse_encode_u_32(self.outboundCapacity, serializer);
+sse_encode_backpressure_policy(self.logsPolicy, serializer);
+sse_encode_backpressure_policy(self.outputPolicy, serializer);
+sse_encode_backpressure_policy(self.eventPolicy, serializer);
+sse_encode_Chrono_Duration(self.outputBatchWindow, serializer);
+sse_encode_u_32(self.outputBatchBytes, serializer);
+sse_encode_opt_box_autoadd_tls_options(self.tls, serializer);
+sse_encode_opt_box_autoadd_proxy_config(self.proxy, serializer);
+sse_encode_list_upgrade_header(self.upgradeHeaders, serializer);
+sse_encode_list_String(self.subprotocols, serializer);
+sse_encode_opt_box_autoadd_deflate_config(self.deflate, serializer);
+ }
+
+@protected void sse_encode_connection_state(ConnectionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ConnectionState_Idle(): sse_encode_i_32(0, serializer); case ConnectionState_Connecting(attempt: final attempt): sse_encode_i_32(1, serializer); sse_encode_u_32(attempt, serializer);
+case ConnectionState_Handshaking(): sse_encode_i_32(2, serializer); case ConnectionState_Ready(protocol: final protocol,role: final role,subprotocol: final subprotocol): sse_encode_i_32(3, serializer); sse_encode_u_32(protocol, serializer);
+sse_encode_String(role, serializer);
+sse_encode_opt_String(subprotocol, serializer);
+case ConnectionState_Backoff(nextRetryAtMs: final nextRetryAtMs,reason: final reason): sse_encode_i_32(4, serializer); sse_encode_i_64(nextRetryAtMs, serializer);
+sse_encode_String(reason, serializer);
+case ConnectionState_Restarting(): sse_encode_i_32(5, serializer); case ConnectionState_Failed(fatal: final fatal,reason: final reason): sse_encode_i_32(6, serializer); sse_encode_bool(fatal, serializer);
+sse_encode_String(reason, serializer);
+  } }
+
+@protected void sse_encode_deflate_config(DeflateConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.clientMaxWindowBits, serializer);
+sse_encode_u_8(self.serverMaxWindowBits, serializer);
+sse_encode_bool(self.clientNoContextTakeover, serializer);
+sse_encode_bool(self.serverNoContextTakeover, serializer);
+ }
+
 @protected void sse_encode_gateway_client(GatewayClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_String(self.url, serializer);
  }
 
+@protected void sse_encode_gateway_event_kind(GatewayEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_handshake_options(HandshakeOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.clientId, serializer);
+sse_encode_opt_String(self.clientVersion, serializer);
+sse_encode_opt_String(self.platform, serializer);
+sse_encode_opt_String(self.displayName, serializer);
+sse_encode_opt_String(self.instanceId, serializer);
+sse_encode_opt_String(self.identityDir, serializer);
+sse_encode_opt_String(self.role, serializer);
+sse_encode_list_String(self.scopes, serializer);
+sse_encode_opt_String(self.token, serializer);
+sse_encode_opt_String(self.password, serializer);
+sse_encode_opt_String(self.locale, serializer);
+sse_encode_opt_String(self.userAgent, serializer);
+ }
+
 @protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 serializer.buffer.putInt32(self); }
 
 @protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 serializer.buffer.putPlatformInt64(self); }
 
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
 @protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(List<LogEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_i_32(self.length, serializer);
         for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(item, serializer); } }
@@ -14014,6 +16048,18 @@ sse_encode_i_32(self.length, serializer);
 sse_encode_i_32(self.length, serializer);
                     serializer.buffer.putUint8List(self); }
 
+@protected void sse_encode_list_terminal_line(List<TerminalLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_terminal_line(item, serializer); } }
+
+@protected void sse_encode_list_terminal_run(List<TerminalRun> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_terminal_run(item, serializer); } }
+
+@protected void sse_encode_list_upgrade_header(List<UpgradeHeader> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_upgrade_header(item, serializer); } }
+
 @protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 
                 sse_encode_bool(self != null, serializer);
@@ -14110,6 +16156,22 @@ sse_encode_i_32(self.length, serializer);
                 }
                  }
 
+@protected void sse_encode_opt_box_autoadd_connection_config(ConnectionConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_connection_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_deflate_config(DeflateConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_deflate_config(self, serializer);
+                }
+                 }
+
 @protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 
                 sse_encode_bool(self != null, serializer);
@@ -14126,6 +16188,30 @@ sse_encode_i_32(self.length, serializer);
                 }
                  }
 
+@protected void sse_encode_opt_box_autoadd_outbox_config(OutboxConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_outbox_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_proxy_config(ProxyConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_proxy_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_tls_options(TlsOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_tls_options(self, serializer);
+                }
+                 }
+
 @protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 
                 sse_encode_bool(self != null, serializer);
@@ -14150,11 +16236,93 @@ sse_encode_i_32(self.length, serializer);
                 }
                  }
 
+@protected void sse_encode_outbox_config(OutboxConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxItems, serializer);
+sse_encode_Chrono_Duration(self.ttl, serializer);
+sse_encode_opt_String(self.persistDir, serializer);
+ }
+
+@protected void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_proxy_kind(self.kind, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_opt_String(self.username, serializer);
+sse_encode_opt_String(self.password, serializer);
+ }
+
+@protected void sse_encode_proxy_kind(ProxyKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_request_delivery(RequestDelivery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
 @protected void sse_encode_terminal_chunk(TerminalChunk self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 sse_encode_String(self.text, serializer);
 sse_encode_String(self.kind, serializer);
+sse_encode_opt_String(self.channel, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.data, serializer);
+sse_encode_list_terminal_run(self.spans, serializer);
+ }
+
+@protected void sse_encode_terminal_color(TerminalColor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case TerminalColor_Default(): sse_encode_i_32(0, serializer); case TerminalColor_Indexed(index: final index): sse_encode_i_32(1, serializer); sse_encode_u_8(index, serializer);
+case TerminalColor_Rgb(red: final red,green: final green,blue: final blue): sse_encode_i_32(2, serializer); sse_encode_u_8(red, serializer);
+sse_encode_u_8(green, serializer);
+sse_encode_u_8(blue, serializer);
+  } }
+
+@protected void sse_encode_terminal_line(TerminalLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.row, serializer);
+sse_encode_list_terminal_run(self.runs, serializer);
+sse_encode_bool(self.wrapped, serializer);
+ }
+
+@protected void sse_encode_terminal_run(TerminalRun self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_terminal_style(self.style, serializer);
+ }
+
+@protected void sse_encode_terminal_screen_update(TerminalScreenUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.cols, serializer);
+sse_encode_u_32(self.rows, serializer);
+sse_encode_u_32(self.cursorRow, serializer);
+sse_encode_u_32(self.cursorCol, serializer);
+sse_encode_bool(self.cursorVisible, serializer);
+sse_encode_bool(self.alternateScreen, serializer);
+sse_encode_bool(self.applicationCursorKeys, serializer);
+sse_encode_bool(self.bracketedPaste, serializer);
+sse_encode_bool(self.full, serializer);
+sse_encode_list_terminal_line(self.lines, serializer);
+sse_encode_u_32(self.scrollbackLen, serializer);
+sse_encode_opt_String(self.title, serializer);
+ }
+
+@protected void sse_encode_terminal_style(TerminalStyle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_terminal_color(self.foreground, serializer);
+sse_encode_terminal_color(self.background, serializer);
+sse_encode_bool(self.bold, serializer);
+sse_encode_bool(self.dim, serializer);
+sse_encode_bool(self.italic, serializer);
+sse_encode_bool(self.underline, serializer);
+sse_encode_bool(self.inverse, serializer);
+sse_encode_bool(self.hidden, serializer);
+sse_encode_bool(self.strikethrough, serializer);
+ }
+
+@protected void sse_encode_tls_options(TlsOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.extraCaPem, serializer);
+sse_encode_list_String(self.spkiPins, serializer);
+sse_encode_bool(self.trustOnFirstUse, serializer);
+sse_encode_opt_String(self.knownHostsDir, serializer);
+sse_encode_bool(self.allowSelfSigned, serializer);
+sse_encode_opt_String(self.clientCertChainPem, serializer);
+sse_encode_opt_String(self.clientKeyPem, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.clientKeyPkcs8, serializer);
  }
 
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
 @protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 serializer.buffer.putUint32(self); }
 
@@ -14167,6 +16335,11 @@ serializer.buffer.putUint8(self); }
 @protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
  }
 
+@protected void sse_encode_upgrade_header(UpgradeHeader self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
 @protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
 serializer.buffer.putBigUint64(self); }
                 }
@@ -14251,6 +16424,23 @@ serializer.buffer.putBigUint64(self); }
                 );
 
                 
+            }
+            @sealed class CallbackTokenStoreImpl extends RustOpaque implements CallbackTokenStore {
+                // Not to be used by end users
+                CallbackTokenStoreImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CallbackTokenStoreImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CallbackTokenStore,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CallbackTokenStore,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CallbackTokenStorePtr,
+                );
+
+                
             }
             @sealed class CameraSnapshotImpl extends RustOpaque implements CameraSnapshot {
                 // Not to be used by end users
@@ -14539,6 +16729,9 @@ serializer.buffer.putBigUint64(self); }
  BTreeMapStringValue get extra=>RustLib.instance.api.crateApiEventsConnectParamsAutoAccessorGetExtra(that: this, );
 
 
+ BigInt? get lastSeq=>RustLib.instance.api.crateApiEventsConnectParamsAutoAccessorGetLastSeq(that: this, );
+
+
  String? get locale=>RustLib.instance.api.crateApiEventsConnectParamsAutoAccessorGetLocale(that: this, );
 
 
@@ -14578,6 +16771,9 @@ serializer.buffer.putBigUint64(self); }
   set extra(BTreeMapStringValue extra)=>RustLib.instance.api.crateApiEventsConnectParamsAutoAccessorSetExtra(that: this, extra: extra);
 
 
+  set lastSeq(BigInt? lastSeq)=>RustLib.instance.api.crateApiEventsConnectParamsAutoAccessorSetLastSeq(that: this, lastSeq: lastSeq);
+
+
   set locale(String? locale)=>RustLib.instance.api.crateApiEventsConnectParamsAutoAccessorSetLocale(that: this, locale: locale);
 
 
@@ -14599,6 +16795,28 @@ serializer.buffer.putBigUint64(self); }
   set userAgent(String? userAgent)=>RustLib.instance.api.crateApiEventsConnectParamsAutoAccessorSetUserAgent(that: this, userAgent: userAgent);
 
 
+            }
+            @sealed class DeviceIdentityImpl extends RustOpaque implements DeviceIdentity {
+                // Not to be used by end users
+                DeviceIdentityImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                DeviceIdentityImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_DeviceIdentity,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_DeviceIdentity,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_DeviceIdentityPtr,
+                );
+
+                 String  deviceId()=>RustLib.instance.api.crateApiDeviceIdentityDeviceIdentityDeviceId(that: this, );
+
+
+ String  publicKey()=>RustLib.instance.api.crateApiDeviceIdentityDeviceIdentityPublicKey(that: this, );
+
+
             }
             @sealed class ExecOutputImpl extends RustOpaque implements ExecOutput {
                 // Not to be used by end users
@@ -14807,7 +17025,14 @@ serializer.buffer.putBigUint64(self); }
                     rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_GatewayEventPtr,
                 );
 
-                
+                 GatewayEventKind  kind()=>RustLib.instance.api.crateApiEventsGatewayEventKind(that: this, );
+
+
+/// The reason or message of a disconnect, message or error event; any other
+/// event as its JSON form.
+ String  text()=>RustLib.instance.api.crateApiEventsGatewayEventText(that: this, );
+
+
             }
             @sealed class GatewayEventPayloadImpl extends RustOpaque implements GatewayEventPayload {
                 // Not to be used by end users
@@ -14891,6 +17116,25 @@ serializer.buffer.putBigUint64(self); }
                 );
 
                 
+            }
+            @sealed class GatewayResponsePayloadImpl extends RustOpaque implements GatewayResponsePayload {
+                // Not to be used by end users
+                GatewayResponsePayloadImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                GatewayResponsePayloadImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_GatewayResponsePayload,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_GatewayResponsePayload,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_GatewayResponsePayloadPtr,
+                );
+
+                 Future<String>  toJson()=>RustLib.instance.api.crateApiEventsGatewayResponsePayloadToJson(that: this, );
+
+
             }
             @sealed class HelloAuthImpl extends RustOpaque implements HelloAuth {
                 // Not to be used by end users
@@ -14999,12 +17243,18 @@ serializer.buffer.putBigUint64(self); }
  BigInt? get tickIntervalMs=>RustLib.instance.api.crateApiEventsHelloPolicyAutoAccessorGetTickIntervalMs(that: this, );
 
 
+ String? get tickMode=>RustLib.instance.api.crateApiEventsHelloPolicyAutoAccessorGetTickMode(that: this, );
+
+
   set extra(BTreeMapStringValue extra)=>RustLib.instance.api.crateApiEventsHelloPolicyAutoAccessorSetExtra(that: this, extra: extra);
 
 
   set tickIntervalMs(BigInt? tickIntervalMs)=>RustLib.instance.api.crateApiEventsHelloPolicyAutoAccessorSetTickIntervalMs(that: this, tickIntervalMs: tickIntervalMs);
 
 
+  set tickMode(String? tickMode)=>RustLib.instance.api.crateApiEventsHelloPolicyAutoAccessorSetTickMode(that: this, tickMode: tickMode);
+
+
             }
             @sealed class LogEntryImpl extends RustOpaque implements LogEntry {
                 // Not to be used by end users
@@ -15938,6 +18188,37 @@ serializer.buffer.putBigUint64(self); }
   set network(SystemProbeCheck? network)=>RustLib.instance.api.crateApiEventsSystemProbeResultAutoAccessorSetNetwork(that: this, network: network);
 
 
+            }
+            @sealed class TerminalEmulatorImpl extends RustOpaque implements TerminalEmulator {
+                // Not to be used by end users
+                TerminalEmulatorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                TerminalEmulatorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_TerminalEmulator,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_TerminalEmulator,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_TerminalEmulatorPtr,
+                );
+
+                 TerminalScreenUpdate  feed({required List<int> bytes })=>RustLib.instance.api.crateApiTerminalEmulatorTerminalEmulatorFeed(that: this, bytes: bytes);
+
+
+ TerminalScreenUpdate  resize({required int cols , required int rows })=>RustLib.instance.api.crateApiTerminalEmulatorTerminalEmulatorResize(that: this, cols: cols, rows: rows);
+
+
+ Future<String>  resizeRequest({required GatewayClient client , required String requestId , String? streamId , required int cols , required int rows , String? sessionKey })=>RustLib.instance.api.crateApiTerminalEmulatorTerminalEmulatorResizeRequest(that: this, client: client, requestId: requestId, streamId: streamId, cols: cols, rows: rows, sessionKey: sessionKey);
+
+
+ List<TerminalLine>  scrollbackLines({required int start , required int count })=>RustLib.instance.api.crateApiTerminalEmulatorTerminalEmulatorScrollbackLines(that: this, start: start, count: count);
+
+
+ TerminalScreenUpdate  snapshot()=>RustLib.instance.api.crateApiTerminalEmulatorTerminalEmulatorSnapshot(that: this, );
+
+
             }
             @sealed class ValueImpl extends RustOpaque implements Value {
                 // Not to be used by end users
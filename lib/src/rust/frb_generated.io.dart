@@ -3,10 +3,18 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/backpressure.dart';
 import 'api/connection.dart';
+import 'api/deflate.dart';
+import 'api/device_identity.dart';
 import 'api/events.dart';
+import 'api/outbox.dart';
+import 'api/proxy.dart';
 import 'api/simple.dart';
 import 'api/terminal.dart';
+import 'api/terminal_emulator.dart';
+import 'api/tls.dart';
+import 'api/token_store.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
@@ -31,6 +39,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BTreeMapStringValu
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BTreeMapStringBoolPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringboolPtr;
 
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CallbackTokenStorePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStorePtr;
+
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CameraSnapshotPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshotPtr;
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ConnectAuthPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuthPtr;
@@ -43,6 +53,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ConnectDevicePtr =
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ConnectParamsPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParamsPtr;
 
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DeviceIdentityPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr;
+
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ExecOutputPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutputPtr;
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ExecParamsPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParamsPtr;
@@ -57,6 +69,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GatewayRequestFram
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GatewayRequestParamsPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParamsPtr;
 
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GatewayResponsePayloadPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayloadPtr;
+
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_HelloAuthPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuthPtr;
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_HelloOkPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOkPtr;
@@ -103,6 +117,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SystemProbeParamsP
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SystemProbeResultPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResultPtr;
 
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TerminalEmulatorPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulatorPtr;
+
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValuePtr;
 
 
@@ -115,6 +131,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected BTreeMapStringBool dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(dynamic raw);
 
+@protected CallbackTokenStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(dynamic raw);
+
 @protected CameraSnapshot dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(dynamic raw);
 
 @protected ConnectAuth dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(dynamic raw);
@@ -127,6 +145,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected ConnectParams dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw);
 
+@protected DeviceIdentity dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw);
+
 @protected ExecOutput dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw);
 
 @protected ExecParams dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(dynamic raw);
@@ -141,6 +161,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected GatewayRequestParams dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(dynamic raw);
 
+@protected GatewayResponsePayload dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw);
+
 @protected HelloAuth dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw);
 
 @protected HelloOk dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(dynamic raw);
@@ -187,6 +209,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected SystemProbeResult dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw);
 
+@protected TerminalEmulator dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw);
+
 @protected Value dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw);
 
 @protected AgentTurn dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(dynamic raw);
@@ -255,6 +279,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected SystemProbeResult dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw);
 
+@protected TerminalEmulator dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw);
+
 @protected AgentTurn dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(dynamic raw);
 
 @protected CameraSnapshot dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(dynamic raw);
@@ -269,14 +295,20 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected ConnectParams dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw);
 
+@protected DeviceIdentity dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw);
+
 @protected ExecOutput dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw);
 
 @protected ExecParams dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(dynamic raw);
 
 @protected GatewayError dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(dynamic raw);
 
+@protected GatewayEvent dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(dynamic raw);
+
 @protected GatewayRequestFrame dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(dynamic raw);
 
+@protected GatewayResponsePayload dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw);
+
 @protected HelloAuth dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw);
 
 @protected HelloOk dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(dynamic raw);
@@ -321,12 +353,26 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected SystemProbeResult dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw);
 
+@protected TerminalEmulator dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw);
+
+@protected Duration dco_decode_Chrono_Duration(dynamic raw);
+
+@protected FutureOr<String?> Function(String) dco_decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected Object dco_decode_DartOpaque(dynamic raw);
+
 @protected AgentTurn dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(dynamic raw);
 
 @protected BTreeMapStringValue dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(dynamic raw);
 
 @protected BTreeMapStringBool dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(dynamic raw);
 
+@protected CallbackTokenStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(dynamic raw);
+
 @protected CameraSnapshot dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(dynamic raw);
 
 @protected ConnectAuth dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(dynamic raw);
@@ -339,6 +385,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected ConnectParams dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw);
 
+@protected DeviceIdentity dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw);
+
 @protected ExecOutput dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw);
 
 @protected ExecParams dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(dynamic raw);
@@ -353,6 +401,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected GatewayRequestParams dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(dynamic raw);
 
+@protected GatewayResponsePayload dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw);
+
 @protected HelloAuth dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw);
 
 @protected HelloOk dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(dynamic raw);
@@ -399,15 +449,21 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected SystemProbeResult dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw);
 
+@protected TerminalEmulator dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw);
+
 @protected Value dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw);
 
 @protected RustStreamSink<GatewayEvent> dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(dynamic raw);
 
+@protected RustStreamSink<ConnectionState> dco_decode_StreamSink_connection_state_Sse(dynamic raw);
+
 @protected RustStreamSink<TerminalChunk> dco_decode_StreamSink_terminal_chunk_Sse(dynamic raw);
 
 @protected String dco_decode_String(dynamic raw);
 
-@protected EventSink dco_decode_TraitDef_EventSink(dynamic raw);
+@protected BackpressurePolicy dco_decode_backpressure_policy(dynamic raw);
+
+@protected BackpressureStats dco_decode_backpressure_stats(dynamic raw);
 
 @protected bool dco_decode_bool(dynamic raw);
 
@@ -433,22 +489,48 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected bool dco_decode_box_autoadd_bool(dynamic raw);
 
+@protected ConnectionConfig dco_decode_box_autoadd_connection_config(dynamic raw);
+
+@protected DeflateConfig dco_decode_box_autoadd_deflate_config(dynamic raw);
+
 @protected GatewayClient dco_decode_box_autoadd_gateway_client(dynamic raw);
 
+@protected HandshakeOptions dco_decode_box_autoadd_handshake_options(dynamic raw);
+
 @protected int dco_decode_box_autoadd_i_32(dynamic raw);
 
 @protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
+@protected OutboxConfig dco_decode_box_autoadd_outbox_config(dynamic raw);
+
+@protected ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw);
+
+@protected TlsOptions dco_decode_box_autoadd_tls_options(dynamic raw);
+
 @protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
 @protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+@protected CompressionStats dco_decode_compression_stats(dynamic raw);
+
+@protected ConnectionConfig dco_decode_connection_config(dynamic raw);
+
+@protected ConnectionState dco_decode_connection_state(dynamic raw);
+
+@protected DeflateConfig dco_decode_deflate_config(dynamic raw);
+
 @protected GatewayClient dco_decode_gateway_client(dynamic raw);
 
+@protected GatewayEventKind dco_decode_gateway_event_kind(dynamic raw);
+
+@protected HandshakeOptions dco_decode_handshake_options(dynamic raw);
+
 @protected int dco_decode_i_32(dynamic raw);
 
 @protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
+
 @protected List<LogEntry> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(dynamic raw);
 
 @protected List<PresenceEntry> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(dynamic raw);
@@ -461,6 +543,12 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+@protected List<TerminalLine> dco_decode_list_terminal_line(dynamic raw);
+
+@protected List<TerminalRun> dco_decode_list_terminal_run(dynamic raw);
+
+@protected List<UpgradeHeader> dco_decode_list_upgrade_header(dynamic raw);
+
 @protected String? dco_decode_opt_String(dynamic raw);
 
 @protected BTreeMapStringValue? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(dynamic raw);
@@ -485,18 +573,50 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+@protected ConnectionConfig? dco_decode_opt_box_autoadd_connection_config(dynamic raw);
+
+@protected DeflateConfig? dco_decode_opt_box_autoadd_deflate_config(dynamic raw);
+
 @protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
 @protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
+@protected OutboxConfig? dco_decode_opt_box_autoadd_outbox_config(dynamic raw);
+
+@protected ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw);
+
+@protected TlsOptions? dco_decode_opt_box_autoadd_tls_options(dynamic raw);
+
 @protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
 @protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
 @protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
+@protected OutboxConfig dco_decode_outbox_config(dynamic raw);
+
+@protected ProxyConfig dco_decode_proxy_config(dynamic raw);
+
+@protected ProxyKind dco_decode_proxy_kind(dynamic raw);
+
+@protected RequestDelivery dco_decode_request_delivery(dynamic raw);
+
 @protected TerminalChunk dco_decode_terminal_chunk(dynamic raw);
 
+@protected TerminalColor dco_decode_terminal_color(dynamic raw);
+
+@protected TerminalLine dco_decode_terminal_line(dynamic raw);
+
+@protected TerminalRun dco_decode_terminal_run(dynamic raw);
+
+@protected TerminalScreenUpdate dco_decode_terminal_screen_update(dynamic raw);
+
+@protected TerminalStyle dco_decode_terminal_style(dynamic raw);
+
+@protected TlsOptions dco_decode_tls_options(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
 @protected int dco_decode_u_32(dynamic raw);
 
 @protected BigInt dco_decode_u_64(dynamic raw);
@@ -505,6 +625,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void dco_decode_unit(dynamic raw);
 
+@protected UpgradeHeader dco_decode_upgrade_header(dynamic raw);
+
 @protected BigInt dco_decode_usize(dynamic raw);
 
 @protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
@@ -515,6 +637,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected BTreeMapStringBool sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(SseDeserializer deserializer);
 
+@protected CallbackTokenStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(SseDeserializer deserializer);
+
 @protected CameraSnapshot sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(SseDeserializer deserializer);
 
 @protected ConnectAuth sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(SseDeserializer deserializer);
@@ -527,6 +651,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected ConnectParams sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer);
 
+@protected DeviceIdentity sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer);
+
 @protected ExecOutput sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer);
 
 @protected ExecParams sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(SseDeserializer deserializer);
@@ -541,6 +667,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected GatewayRequestParams sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(SseDeserializer deserializer);
 
+@protected GatewayResponsePayload sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer);
+
 @protected HelloAuth sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer);
 
 @protected HelloOk sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(SseDeserializer deserializer);
@@ -587,6 +715,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected SystemProbeResult sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer);
 
+@protected TerminalEmulator sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer);
+
 @protected Value sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer);
 
 @protected AgentTurn sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(SseDeserializer deserializer);
@@ -655,6 +785,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected SystemProbeResult sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer);
 
+@protected TerminalEmulator sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer);
+
 @protected AgentTurn sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(SseDeserializer deserializer);
 
 @protected CameraSnapshot sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(SseDeserializer deserializer);
@@ -669,14 +801,20 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected ConnectParams sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer);
 
+@protected DeviceIdentity sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer);
+
 @protected ExecOutput sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer);
 
 @protected ExecParams sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(SseDeserializer deserializer);
 
 @protected GatewayError sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(SseDeserializer deserializer);
 
+@protected GatewayEvent sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(SseDeserializer deserializer);
+
 @protected GatewayRequestFrame sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(SseDeserializer deserializer);
 
+@protected GatewayResponsePayload sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer);
+
 @protected HelloAuth sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer);
 
 @protected HelloOk sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(SseDeserializer deserializer);
@@ -721,12 +859,20 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected SystemProbeResult sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer);
 
+@protected TerminalEmulator sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer);
+
+@protected Duration sse_decode_Chrono_Duration(SseDeserializer deserializer);
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
 @protected AgentTurn sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(SseDeserializer deserializer);
 
 @protected BTreeMapStringValue sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(SseDeserializer deserializer);
 
 @protected BTreeMapStringBool sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(SseDeserializer deserializer);
 
+@protected CallbackTokenStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(SseDeserializer deserializer);
+
 @protected CameraSnapshot sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(SseDeserializer deserializer);
 
 @protected ConnectAuth sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(SseDeserializer deserializer);
@@ -739,6 +885,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected ConnectParams sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer);
 
+@protected DeviceIdentity sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer);
+
 @protected ExecOutput sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer);
 
 @protected ExecParams sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(SseDeserializer deserializer);
@@ -753,6 +901,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected GatewayRequestParams sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(SseDeserializer deserializer);
 
+@protected GatewayResponsePayload sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer);
+
 @protected HelloAuth sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer);
 
 @protected HelloOk sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(SseDeserializer deserializer);
@@ -799,14 +949,22 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected SystemProbeResult sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer);
 
+@protected TerminalEmulator sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer);
+
 @protected Value sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer);
 
 @protected RustStreamSink<GatewayEvent> sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(SseDeserializer deserializer);
 
+@protected RustStreamSink<ConnectionState> sse_decode_StreamSink_connection_state_Sse(SseDeserializer deserializer);
+
 @protected RustStreamSink<TerminalChunk> sse_decode_StreamSink_terminal_chunk_Sse(SseDeserializer deserializer);
 
 @protected String sse_decode_String(SseDeserializer deserializer);
 
+@protected BackpressurePolicy sse_decode_backpressure_policy(SseDeserializer deserializer);
+
+@protected BackpressureStats sse_decode_backpressure_stats(SseDeserializer deserializer);
+
 @protected bool sse_decode_bool(SseDeserializer deserializer);
 
 @protected BTreeMapStringValue sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(SseDeserializer deserializer);
@@ -831,22 +989,48 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+@protected ConnectionConfig sse_decode_box_autoadd_connection_config(SseDeserializer deserializer);
+
+@protected DeflateConfig sse_decode_box_autoadd_deflate_config(SseDeserializer deserializer);
+
 @protected GatewayClient sse_decode_box_autoadd_gateway_client(SseDeserializer deserializer);
 
+@protected HandshakeOptions sse_decode_box_autoadd_handshake_options(SseDeserializer deserializer);
+
 @protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
 @protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
+@protected OutboxConfig sse_decode_box_autoadd_outbox_config(SseDeserializer deserializer);
+
+@protected ProxyConfig sse_decode_box_autoadd_proxy_config(SseDeserializer deserializer);
+
+@protected TlsOptions sse_decode_box_autoadd_tls_options(SseDeserializer deserializer);
+
 @protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
 @protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+@protected CompressionStats sse_decode_compression_stats(SseDeserializer deserializer);
+
+@protected ConnectionConfig sse_decode_connection_config(SseDeserializer deserializer);
+
+@protected ConnectionState sse_decode_connection_state(SseDeserializer deserializer);
+
+@protected DeflateConfig sse_decode_deflate_config(SseDeserializer deserializer);
+
 @protected GatewayClient sse_decode_gateway_client(SseDeserializer deserializer);
 
+@protected GatewayEventKind sse_decode_gateway_event_kind(SseDeserializer deserializer);
+
+@protected HandshakeOptions sse_decode_handshake_options(SseDeserializer deserializer);
+
 @protected int sse_decode_i_32(SseDeserializer deserializer);
 
 @protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
 @protected List<LogEntry> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(SseDeserializer deserializer);
 
 @protected List<PresenceEntry> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(SseDeserializer deserializer);
@@ -859,6 +1043,12 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+@protected List<TerminalLine> sse_decode_list_terminal_line(SseDeserializer deserializer);
+
+@protected List<TerminalRun> sse_decode_list_terminal_run(SseDeserializer deserializer);
+
+@protected List<UpgradeHeader> sse_decode_list_upgrade_header(SseDeserializer deserializer);
+
 @protected String? sse_decode_opt_String(SseDeserializer deserializer);
 
 @protected BTreeMapStringValue? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(SseDeserializer deserializer);
@@ -883,18 +1073,50 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
+@protected ConnectionConfig? sse_decode_opt_box_autoadd_connection_config(SseDeserializer deserializer);
+
+@protected DeflateConfig? sse_decode_opt_box_autoadd_deflate_config(SseDeserializer deserializer);
+
 @protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
 
 @protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
+@protected OutboxConfig? sse_decode_opt_box_autoadd_outbox_config(SseDeserializer deserializer);
+
+@protected ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(SseDeserializer deserializer);
+
+@protected TlsOptions? sse_decode_opt_box_autoadd_tls_options(SseDeserializer deserializer);
+
 @protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
 @protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
 @protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
+@protected OutboxConfig sse_decode_outbox_config(SseDeserializer deserializer);
+
+@protected ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer);
+
+@protected ProxyKind sse_decode_proxy_kind(SseDeserializer deserializer);
+
+@protected RequestDelivery sse_decode_request_delivery(SseDeserializer deserializer);
+
 @protected TerminalChunk sse_decode_terminal_chunk(SseDeserializer deserializer);
 
+@protected TerminalColor sse_decode_terminal_color(SseDeserializer deserializer);
+
+@protected TerminalLine sse_decode_terminal_line(SseDeserializer deserializer);
+
+@protected TerminalRun sse_decode_terminal_run(SseDeserializer deserializer);
+
+@protected TerminalScreenUpdate sse_decode_terminal_screen_update(SseDeserializer deserializer);
+
+@protected TerminalStyle sse_decode_terminal_style(SseDeserializer deserializer);
+
+@protected TlsOptions sse_decode_tls_options(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
 @protected int sse_decode_u_32(SseDeserializer deserializer);
 
 @protected BigInt sse_decode_u_64(SseDeserializer deserializer);
@@ -903,6 +1125,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_decode_unit(SseDeserializer deserializer);
 
+@protected UpgradeHeader sse_decode_upgrade_header(SseDeserializer deserializer);
+
 @protected BigInt sse_decode_usize(SseDeserializer deserializer);
 
 @protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
@@ -913,6 +1137,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(BTreeMapStringBool self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(CallbackTokenStore self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(CameraSnapshot self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(ConnectAuth self, SseSerializer serializer);
@@ -925,6 +1151,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(ExecParams self, SseSerializer serializer);
@@ -939,6 +1167,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(GatewayRequestParams self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(HelloOk self, SseSerializer serializer);
@@ -985,6 +1215,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(AgentTurn self, SseSerializer serializer);
@@ -1053,6 +1285,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(AgentTurn self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(CameraSnapshot self, SseSerializer serializer);
@@ -1067,14 +1301,20 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(ExecParams self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(GatewayError self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(GatewayEvent self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(GatewayRequestFrame self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(HelloOk self, SseSerializer serializer);
@@ -1119,12 +1359,26 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer);
+
+@protected void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(FutureOr<String?> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(AgentTurn self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(BTreeMapStringValue self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(BTreeMapStringBool self, SseSerializer serializer);
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(CallbackTokenStore self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(CameraSnapshot self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(ConnectAuth self, SseSerializer serializer);
@@ -1137,6 +1391,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer);
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(ExecParams self, SseSerializer serializer);
@@ -1151,6 +1407,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(GatewayRequestParams self, SseSerializer serializer);
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(HelloOk self, SseSerializer serializer);
@@ -1197,14 +1455,22 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer);
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer);
 
 @protected void sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(RustStreamSink<GatewayEvent> self, SseSerializer serializer);
 
+@protected void sse_encode_StreamSink_connection_state_Sse(RustStreamSink<ConnectionState> self, SseSerializer serializer);
+
 @protected void sse_encode_StreamSink_terminal_chunk_Sse(RustStreamSink<TerminalChunk> self, SseSerializer serializer);
 
 @protected void sse_encode_String(String self, SseSerializer serializer);
 
+@protected void sse_encode_backpressure_policy(BackpressurePolicy self, SseSerializer serializer);
+
+@protected void sse_encode_backpressure_stats(BackpressureStats self, SseSerializer serializer);
+
 @protected void sse_encode_bool(bool self, SseSerializer serializer);
 
 @protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(BTreeMapStringValue self, SseSerializer serializer);
@@ -1229,22 +1495,48 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_connection_config(ConnectionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_deflate_config(DeflateConfig self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_gateway_client(GatewayClient self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_handshake_options(HandshakeOptions self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_outbox_config(OutboxConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_proxy_config(ProxyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_tls_options(TlsOptions self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+@protected void sse_encode_compression_stats(CompressionStats self, SseSerializer serializer);
+
+@protected void sse_encode_connection_config(ConnectionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_connection_state(ConnectionState self, SseSerializer serializer);
+
+@protected void sse_encode_deflate_config(DeflateConfig self, SseSerializer serializer);
+
 @protected void sse_encode_gateway_client(GatewayClient self, SseSerializer serializer);
 
+@protected void sse_encode_gateway_event_kind(GatewayEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_handshake_options(HandshakeOptions self, SseSerializer serializer);
+
 @protected void sse_encode_i_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
 @protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(List<LogEntry> self, SseSerializer serializer);
 
 @protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(List<PresenceEntry> self, SseSerializer serializer);
@@ -1257,6 +1549,12 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
+@protected void sse_encode_list_terminal_line(List<TerminalLine> self, SseSerializer serializer);
+
+@protected void sse_encode_list_terminal_run(List<TerminalRun> self, SseSerializer serializer);
+
+@protected void sse_encode_list_upgrade_header(List<UpgradeHeader> self, SseSerializer serializer);
+
 @protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
 @protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(BTreeMapStringValue? self, SseSerializer serializer);
@@ -1281,18 +1579,50 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+@protected void sse_encode_opt_box_autoadd_connection_config(ConnectionConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_deflate_config(DeflateConfig? self, SseSerializer serializer);
+
 @protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
 @protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
 
+@protected void sse_encode_opt_box_autoadd_outbox_config(OutboxConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_proxy_config(ProxyConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_tls_options(TlsOptions? self, SseSerializer serializer);
+
 @protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
 @protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
 @protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
 
+@protected void sse_encode_outbox_config(OutboxConfig self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_kind(ProxyKind self, SseSerializer serializer);
+
+@protected void sse_encode_request_delivery(RequestDelivery self, SseSerializer serializer);
+
 @protected void sse_encode_terminal_chunk(TerminalChunk self, SseSerializer serializer);
 
+@protected void sse_encode_terminal_color(TerminalColor self, SseSerializer serializer);
+
+@protected void sse_encode_terminal_line(TerminalLine self, SseSerializer serializer);
+
+@protected void sse_encode_terminal_run(TerminalRun self, SseSerializer serializer);
+
+@protected void sse_encode_terminal_screen_update(TerminalScreenUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_terminal_style(TerminalStyle self, SseSerializer serializer);
+
+@protected void sse_encode_tls_options(TlsOptions self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
 @protected void sse_encode_u_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
@@ -1301,6 +1631,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
 
 @protected void sse_encode_unit(void self, SseSerializer serializer);
 
+@protected void sse_encode_upgrade_header(UpgradeHeader self, SseSerializer serializer);
+
 @protected void sse_encode_usize(BigInt self, SseSerializer serializer);
                 }
                 
@@ -1389,6 +1721,28 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
             late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringboolPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool');
             late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringboolPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
             
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStorePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStorePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStorePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStorePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
             void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(
               ffi.Pointer<ffi.Void> ptr,
             ) {
@@ -1521,6 +1875,28 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
             late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParamsPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams');
             late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParamsPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
             
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
             void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(
               ffi.Pointer<ffi.Void> ptr,
             ) {
@@ -1675,6 +2051,28 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
             late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParamsPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams');
             late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParamsPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
             
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayloadPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayloadPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayloadPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayloadPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
             void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(
               ffi.Pointer<ffi.Void> ptr,
             ) {
@@ -2181,6 +2579,28 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire._
             late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResultPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult');
             late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResultPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
             
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulatorPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulatorPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulatorPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulatorPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
             void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
               ffi.Pointer<ffi.Void> ptr,
             ) {
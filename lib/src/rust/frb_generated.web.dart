@@ -7,10 +7,18 @@
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/backpressure.dart';
 import 'api/connection.dart';
+import 'api/deflate.dart';
+import 'api/device_identity.dart';
 import 'api/events.dart';
+import 'api/outbox.dart';
+import 'api/proxy.dart';
 import 'api/simple.dart';
 import 'api/terminal.dart';
+import 'api/terminal_emulator.dart';
+import 'api/tls.dart';
+import 'api/token_store.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -34,6 +42,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BTreeMapStringValu
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BTreeMapStringBoolPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool;
 
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CallbackTokenStorePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore;
+
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CameraSnapshotPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot;
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ConnectAuthPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth;
@@ -46,6 +56,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ConnectDevicePtr =
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ConnectParamsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams;
 
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DeviceIdentityPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity;
+
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ExecOutputPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput;
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ExecParamsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams;
@@ -60,6 +72,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GatewayRequestFram
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GatewayRequestParamsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams;
 
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GatewayResponsePayloadPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload;
+
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_HelloAuthPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth;
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_HelloOkPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk;
@@ -106,6 +120,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SystemProbeParamsP
 
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SystemProbeResultPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult;
 
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TerminalEmulatorPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator;
+
 CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
 
 
@@ -118,6 +134,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected BTreeMapStringBool dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(dynamic raw);
 
+@protected CallbackTokenStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(dynamic raw);
+
 @protected CameraSnapshot dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(dynamic raw);
 
 @protected ConnectAuth dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(dynamic raw);
@@ -130,6 +148,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected ConnectParams dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw);
 
+@protected DeviceIdentity dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw);
+
 @protected ExecOutput dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw);
 
 @protected ExecParams dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(dynamic raw);
@@ -144,6 +164,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected GatewayRequestParams dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(dynamic raw);
 
+@protected GatewayResponsePayload dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw);
+
 @protected HelloAuth dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw);
 
 @protected HelloOk dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(dynamic raw);
@@ -190,6 +212,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected SystemProbeResult dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw);
 
+@protected TerminalEmulator dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw);
+
 @protected Value dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw);
 
 @protected AgentTurn dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(dynamic raw);
@@ -258,6 +282,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected SystemProbeResult dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw);
 
+@protected TerminalEmulator dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw);
+
 @protected AgentTurn dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(dynamic raw);
 
 @protected CameraSnapshot dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(dynamic raw);
@@ -272,14 +298,20 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected ConnectParams dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw);
 
+@protected DeviceIdentity dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw);
+
 @protected ExecOutput dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw);
 
 @protected ExecParams dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(dynamic raw);
 
 @protected GatewayError dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(dynamic raw);
 
+@protected GatewayEvent dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(dynamic raw);
+
 @protected GatewayRequestFrame dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(dynamic raw);
 
+@protected GatewayResponsePayload dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw);
+
 @protected HelloAuth dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw);
 
 @protected HelloOk dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(dynamic raw);
@@ -324,12 +356,26 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected SystemProbeResult dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw);
 
+@protected TerminalEmulator dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw);
+
+@protected Duration dco_decode_Chrono_Duration(dynamic raw);
+
+@protected FutureOr<String?> Function(String) dco_decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected Object dco_decode_DartOpaque(dynamic raw);
+
 @protected AgentTurn dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(dynamic raw);
 
 @protected BTreeMapStringValue dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(dynamic raw);
 
 @protected BTreeMapStringBool dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(dynamic raw);
 
+@protected CallbackTokenStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(dynamic raw);
+
 @protected CameraSnapshot dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(dynamic raw);
 
 @protected ConnectAuth dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(dynamic raw);
@@ -342,6 +388,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected ConnectParams dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(dynamic raw);
 
+@protected DeviceIdentity dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw);
+
 @protected ExecOutput dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(dynamic raw);
 
 @protected ExecParams dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(dynamic raw);
@@ -356,6 +404,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected GatewayRequestParams dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(dynamic raw);
 
+@protected GatewayResponsePayload dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(dynamic raw);
+
 @protected HelloAuth dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(dynamic raw);
 
 @protected HelloOk dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(dynamic raw);
@@ -402,15 +452,21 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected SystemProbeResult dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(dynamic raw);
 
+@protected TerminalEmulator dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(dynamic raw);
+
 @protected Value dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw);
 
 @protected RustStreamSink<GatewayEvent> dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(dynamic raw);
 
+@protected RustStreamSink<ConnectionState> dco_decode_StreamSink_connection_state_Sse(dynamic raw);
+
 @protected RustStreamSink<TerminalChunk> dco_decode_StreamSink_terminal_chunk_Sse(dynamic raw);
 
 @protected String dco_decode_String(dynamic raw);
 
-@protected EventSink dco_decode_TraitDef_EventSink(dynamic raw);
+@protected BackpressurePolicy dco_decode_backpressure_policy(dynamic raw);
+
+@protected BackpressureStats dco_decode_backpressure_stats(dynamic raw);
 
 @protected bool dco_decode_bool(dynamic raw);
 
@@ -436,22 +492,48 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected bool dco_decode_box_autoadd_bool(dynamic raw);
 
+@protected ConnectionConfig dco_decode_box_autoadd_connection_config(dynamic raw);
+
+@protected DeflateConfig dco_decode_box_autoadd_deflate_config(dynamic raw);
+
 @protected GatewayClient dco_decode_box_autoadd_gateway_client(dynamic raw);
 
+@protected HandshakeOptions dco_decode_box_autoadd_handshake_options(dynamic raw);
+
 @protected int dco_decode_box_autoadd_i_32(dynamic raw);
 
 @protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
+@protected OutboxConfig dco_decode_box_autoadd_outbox_config(dynamic raw);
+
+@protected ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw);
+
+@protected TlsOptions dco_decode_box_autoadd_tls_options(dynamic raw);
+
 @protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
 @protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+@protected CompressionStats dco_decode_compression_stats(dynamic raw);
+
+@protected ConnectionConfig dco_decode_connection_config(dynamic raw);
+
+@protected ConnectionState dco_decode_connection_state(dynamic raw);
+
+@protected DeflateConfig dco_decode_deflate_config(dynamic raw);
+
 @protected GatewayClient dco_decode_gateway_client(dynamic raw);
 
+@protected GatewayEventKind dco_decode_gateway_event_kind(dynamic raw);
+
+@protected HandshakeOptions dco_decode_handshake_options(dynamic raw);
+
 @protected int dco_decode_i_32(dynamic raw);
 
 @protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
+
 @protected List<LogEntry> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(dynamic raw);
 
 @protected List<PresenceEntry> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(dynamic raw);
@@ -464,6 +546,12 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+@protected List<TerminalLine> dco_decode_list_terminal_line(dynamic raw);
+
+@protected List<TerminalRun> dco_decode_list_terminal_run(dynamic raw);
+
+@protected List<UpgradeHeader> dco_decode_list_upgrade_header(dynamic raw);
+
 @protected String? dco_decode_opt_String(dynamic raw);
 
 @protected BTreeMapStringValue? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(dynamic raw);
@@ -488,18 +576,50 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+@protected ConnectionConfig? dco_decode_opt_box_autoadd_connection_config(dynamic raw);
+
+@protected DeflateConfig? dco_decode_opt_box_autoadd_deflate_config(dynamic raw);
+
 @protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
 @protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
+@protected OutboxConfig? dco_decode_opt_box_autoadd_outbox_config(dynamic raw);
+
+@protected ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw);
+
+@protected TlsOptions? dco_decode_opt_box_autoadd_tls_options(dynamic raw);
+
 @protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
 @protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
 @protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
+@protected OutboxConfig dco_decode_outbox_config(dynamic raw);
+
+@protected ProxyConfig dco_decode_proxy_config(dynamic raw);
+
+@protected ProxyKind dco_decode_proxy_kind(dynamic raw);
+
+@protected RequestDelivery dco_decode_request_delivery(dynamic raw);
+
 @protected TerminalChunk dco_decode_terminal_chunk(dynamic raw);
 
+@protected TerminalColor dco_decode_terminal_color(dynamic raw);
+
+@protected TerminalLine dco_decode_terminal_line(dynamic raw);
+
+@protected TerminalRun dco_decode_terminal_run(dynamic raw);
+
+@protected TerminalScreenUpdate dco_decode_terminal_screen_update(dynamic raw);
+
+@protected TerminalStyle dco_decode_terminal_style(dynamic raw);
+
+@protected TlsOptions dco_decode_tls_options(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
 @protected int dco_decode_u_32(dynamic raw);
 
 @protected BigInt dco_decode_u_64(dynamic raw);
@@ -508,6 +628,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void dco_decode_unit(dynamic raw);
 
+@protected UpgradeHeader dco_decode_upgrade_header(dynamic raw);
+
 @protected BigInt dco_decode_usize(dynamic raw);
 
 @protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
@@ -518,6 +640,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected BTreeMapStringBool sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(SseDeserializer deserializer);
 
+@protected CallbackTokenStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(SseDeserializer deserializer);
+
 @protected CameraSnapshot sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(SseDeserializer deserializer);
 
 @protected ConnectAuth sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(SseDeserializer deserializer);
@@ -530,6 +654,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected ConnectParams sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer);
 
+@protected DeviceIdentity sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer);
+
 @protected ExecOutput sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer);
 
 @protected ExecParams sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(SseDeserializer deserializer);
@@ -544,6 +670,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected GatewayRequestParams sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(SseDeserializer deserializer);
 
+@protected GatewayResponsePayload sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer);
+
 @protected HelloAuth sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer);
 
 @protected HelloOk sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(SseDeserializer deserializer);
@@ -590,6 +718,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected SystemProbeResult sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer);
 
+@protected TerminalEmulator sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer);
+
 @protected Value sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer);
 
 @protected AgentTurn sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(SseDeserializer deserializer);
@@ -658,6 +788,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected SystemProbeResult sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer);
 
+@protected TerminalEmulator sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer);
+
 @protected AgentTurn sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(SseDeserializer deserializer);
 
 @protected CameraSnapshot sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(SseDeserializer deserializer);
@@ -672,14 +804,20 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected ConnectParams sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer);
 
+@protected DeviceIdentity sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer);
+
 @protected ExecOutput sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer);
 
 @protected ExecParams sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(SseDeserializer deserializer);
 
 @protected GatewayError sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(SseDeserializer deserializer);
 
+@protected GatewayEvent sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(SseDeserializer deserializer);
+
 @protected GatewayRequestFrame sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(SseDeserializer deserializer);
 
+@protected GatewayResponsePayload sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer);
+
 @protected HelloAuth sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer);
 
 @protected HelloOk sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(SseDeserializer deserializer);
@@ -724,12 +862,20 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected SystemProbeResult sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer);
 
+@protected TerminalEmulator sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer);
+
+@protected Duration sse_decode_Chrono_Duration(SseDeserializer deserializer);
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
 @protected AgentTurn sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(SseDeserializer deserializer);
 
 @protected BTreeMapStringValue sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(SseDeserializer deserializer);
 
 @protected BTreeMapStringBool sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(SseDeserializer deserializer);
 
+@protected CallbackTokenStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(SseDeserializer deserializer);
+
 @protected CameraSnapshot sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(SseDeserializer deserializer);
 
 @protected ConnectAuth sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(SseDeserializer deserializer);
@@ -742,6 +888,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected ConnectParams sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(SseDeserializer deserializer);
 
+@protected DeviceIdentity sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(SseDeserializer deserializer);
+
 @protected ExecOutput sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(SseDeserializer deserializer);
 
 @protected ExecParams sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(SseDeserializer deserializer);
@@ -756,6 +904,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected GatewayRequestParams sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(SseDeserializer deserializer);
 
+@protected GatewayResponsePayload sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(SseDeserializer deserializer);
+
 @protected HelloAuth sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(SseDeserializer deserializer);
 
 @protected HelloOk sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(SseDeserializer deserializer);
@@ -802,14 +952,22 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected SystemProbeResult sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SseDeserializer deserializer);
 
+@protected TerminalEmulator sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(SseDeserializer deserializer);
+
 @protected Value sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer);
 
 @protected RustStreamSink<GatewayEvent> sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(SseDeserializer deserializer);
 
+@protected RustStreamSink<ConnectionState> sse_decode_StreamSink_connection_state_Sse(SseDeserializer deserializer);
+
 @protected RustStreamSink<TerminalChunk> sse_decode_StreamSink_terminal_chunk_Sse(SseDeserializer deserializer);
 
 @protected String sse_decode_String(SseDeserializer deserializer);
 
+@protected BackpressurePolicy sse_decode_backpressure_policy(SseDeserializer deserializer);
+
+@protected BackpressureStats sse_decode_backpressure_stats(SseDeserializer deserializer);
+
 @protected bool sse_decode_bool(SseDeserializer deserializer);
 
 @protected BTreeMapStringValue sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(SseDeserializer deserializer);
@@ -834,22 +992,48 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+@protected ConnectionConfig sse_decode_box_autoadd_connection_config(SseDeserializer deserializer);
+
+@protected DeflateConfig sse_decode_box_autoadd_deflate_config(SseDeserializer deserializer);
+
 @protected GatewayClient sse_decode_box_autoadd_gateway_client(SseDeserializer deserializer);
 
+@protected HandshakeOptions sse_decode_box_autoadd_handshake_options(SseDeserializer deserializer);
+
 @protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
 @protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
+@protected OutboxConfig sse_decode_box_autoadd_outbox_config(SseDeserializer deserializer);
+
+@protected ProxyConfig sse_decode_box_autoadd_proxy_config(SseDeserializer deserializer);
+
+@protected TlsOptions sse_decode_box_autoadd_tls_options(SseDeserializer deserializer);
+
 @protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
 @protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+@protected CompressionStats sse_decode_compression_stats(SseDeserializer deserializer);
+
+@protected ConnectionConfig sse_decode_connection_config(SseDeserializer deserializer);
+
+@protected ConnectionState sse_decode_connection_state(SseDeserializer deserializer);
+
+@protected DeflateConfig sse_decode_deflate_config(SseDeserializer deserializer);
+
 @protected GatewayClient sse_decode_gateway_client(SseDeserializer deserializer);
 
+@protected GatewayEventKind sse_decode_gateway_event_kind(SseDeserializer deserializer);
+
+@protected HandshakeOptions sse_decode_handshake_options(SseDeserializer deserializer);
+
 @protected int sse_decode_i_32(SseDeserializer deserializer);
 
 @protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
 @protected List<LogEntry> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(SseDeserializer deserializer);
 
 @protected List<PresenceEntry> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(SseDeserializer deserializer);
@@ -862,6 +1046,12 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+@protected List<TerminalLine> sse_decode_list_terminal_line(SseDeserializer deserializer);
+
+@protected List<TerminalRun> sse_decode_list_terminal_run(SseDeserializer deserializer);
+
+@protected List<UpgradeHeader> sse_decode_list_upgrade_header(SseDeserializer deserializer);
+
 @protected String? sse_decode_opt_String(SseDeserializer deserializer);
 
 @protected BTreeMapStringValue? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(SseDeserializer deserializer);
@@ -886,18 +1076,50 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
+@protected ConnectionConfig? sse_decode_opt_box_autoadd_connection_config(SseDeserializer deserializer);
+
+@protected DeflateConfig? sse_decode_opt_box_autoadd_deflate_config(SseDeserializer deserializer);
+
 @protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
 
 @protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
+@protected OutboxConfig? sse_decode_opt_box_autoadd_outbox_config(SseDeserializer deserializer);
+
+@protected ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(SseDeserializer deserializer);
+
+@protected TlsOptions? sse_decode_opt_box_autoadd_tls_options(SseDeserializer deserializer);
+
 @protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
 @protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
 @protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
+@protected OutboxConfig sse_decode_outbox_config(SseDeserializer deserializer);
+
+@protected ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer);
+
+@protected ProxyKind sse_decode_proxy_kind(SseDeserializer deserializer);
+
+@protected RequestDelivery sse_decode_request_delivery(SseDeserializer deserializer);
+
 @protected TerminalChunk sse_decode_terminal_chunk(SseDeserializer deserializer);
 
+@protected TerminalColor sse_decode_terminal_color(SseDeserializer deserializer);
+
+@protected TerminalLine sse_decode_terminal_line(SseDeserializer deserializer);
+
+@protected TerminalRun sse_decode_terminal_run(SseDeserializer deserializer);
+
+@protected TerminalScreenUpdate sse_decode_terminal_screen_update(SseDeserializer deserializer);
+
+@protected TerminalStyle sse_decode_terminal_style(SseDeserializer deserializer);
+
+@protected TlsOptions sse_decode_tls_options(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
 @protected int sse_decode_u_32(SseDeserializer deserializer);
 
 @protected BigInt sse_decode_u_64(SseDeserializer deserializer);
@@ -906,6 +1128,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_decode_unit(SseDeserializer deserializer);
 
+@protected UpgradeHeader sse_decode_upgrade_header(SseDeserializer deserializer);
+
 @protected BigInt sse_decode_usize(SseDeserializer deserializer);
 
 @protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
@@ -916,6 +1140,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(BTreeMapStringBool self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(CallbackTokenStore self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(CameraSnapshot self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(ConnectAuth self, SseSerializer serializer);
@@ -928,6 +1154,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(ExecParams self, SseSerializer serializer);
@@ -942,6 +1170,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(GatewayRequestParams self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(HelloOk self, SseSerializer serializer);
@@ -988,6 +1218,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(AgentTurn self, SseSerializer serializer);
@@ -1056,6 +1288,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(AgentTurn self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(CameraSnapshot self, SseSerializer serializer);
@@ -1070,14 +1304,20 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(ExecParams self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayError(GatewayError self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent(GatewayEvent self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestFrame(GatewayRequestFrame self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer);
+
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer);
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(HelloOk self, SseSerializer serializer);
@@ -1122,12 +1362,26 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer);
 
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer);
+
+@protected void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(FutureOr<String?> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAgentTurn(AgentTurn self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(BTreeMapStringValue self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(BTreeMapStringBool self, SseSerializer serializer);
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(CallbackTokenStore self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(CameraSnapshot self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectAuth(ConnectAuth self, SseSerializer serializer);
@@ -1140,6 +1394,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ConnectParams self, SseSerializer serializer);
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(DeviceIdentity self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ExecOutput self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecParams(ExecParams self, SseSerializer serializer);
@@ -1154,6 +1410,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(GatewayRequestParams self, SseSerializer serializer);
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(GatewayResponsePayload self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(HelloAuth self, SseSerializer serializer);
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloOk(HelloOk self, SseSerializer serializer);
@@ -1200,14 +1458,22 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(SystemProbeResult self, SseSerializer serializer);
 
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(TerminalEmulator self, SseSerializer serializer);
+
 @protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer);
 
 @protected void sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayEvent_Sse(RustStreamSink<GatewayEvent> self, SseSerializer serializer);
 
+@protected void sse_encode_StreamSink_connection_state_Sse(RustStreamSink<ConnectionState> self, SseSerializer serializer);
+
 @protected void sse_encode_StreamSink_terminal_chunk_Sse(RustStreamSink<TerminalChunk> self, SseSerializer serializer);
 
 @protected void sse_encode_String(String self, SseSerializer serializer);
 
+@protected void sse_encode_backpressure_policy(BackpressurePolicy self, SseSerializer serializer);
+
+@protected void sse_encode_backpressure_stats(BackpressureStats self, SseSerializer serializer);
+
 @protected void sse_encode_bool(bool self, SseSerializer serializer);
 
 @protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(BTreeMapStringValue self, SseSerializer serializer);
@@ -1232,22 +1498,48 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_connection_config(ConnectionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_deflate_config(DeflateConfig self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_gateway_client(GatewayClient self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_handshake_options(HandshakeOptions self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_outbox_config(OutboxConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_proxy_config(ProxyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_tls_options(TlsOptions self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+@protected void sse_encode_compression_stats(CompressionStats self, SseSerializer serializer);
+
+@protected void sse_encode_connection_config(ConnectionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_connection_state(ConnectionState self, SseSerializer serializer);
+
+@protected void sse_encode_deflate_config(DeflateConfig self, SseSerializer serializer);
+
 @protected void sse_encode_gateway_client(GatewayClient self, SseSerializer serializer);
 
+@protected void sse_encode_gateway_event_kind(GatewayEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_handshake_options(HandshakeOptions self, SseSerializer serializer);
+
 @protected void sse_encode_i_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
 @protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLogEntry(List<LogEntry> self, SseSerializer serializer);
 
 @protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceEntry(List<PresenceEntry> self, SseSerializer serializer);
@@ -1260,6 +1552,12 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
+@protected void sse_encode_list_terminal_line(List<TerminalLine> self, SseSerializer serializer);
+
+@protected void sse_encode_list_terminal_run(List<TerminalRun> self, SseSerializer serializer);
+
+@protected void sse_encode_list_upgrade_header(List<UpgradeHeader> self, SseSerializer serializer);
+
 @protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
 @protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringValue(BTreeMapStringValue? self, SseSerializer serializer);
@@ -1284,18 +1582,50 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+@protected void sse_encode_opt_box_autoadd_connection_config(ConnectionConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_deflate_config(DeflateConfig? self, SseSerializer serializer);
+
 @protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
 @protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
 
+@protected void sse_encode_opt_box_autoadd_outbox_config(OutboxConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_proxy_config(ProxyConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_tls_options(TlsOptions? self, SseSerializer serializer);
+
 @protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
 @protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
 @protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
 
+@protected void sse_encode_outbox_config(OutboxConfig self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_kind(ProxyKind self, SseSerializer serializer);
+
+@protected void sse_encode_request_delivery(RequestDelivery self, SseSerializer serializer);
+
 @protected void sse_encode_terminal_chunk(TerminalChunk self, SseSerializer serializer);
 
+@protected void sse_encode_terminal_color(TerminalColor self, SseSerializer serializer);
+
+@protected void sse_encode_terminal_line(TerminalLine self, SseSerializer serializer);
+
+@protected void sse_encode_terminal_run(TerminalRun self, SseSerializer serializer);
+
+@protected void sse_encode_terminal_screen_update(TerminalScreenUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_terminal_style(TerminalStyle self, SseSerializer serializer);
+
+@protected void sse_encode_tls_options(TlsOptions self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
 @protected void sse_encode_u_32(int self, SseSerializer serializer);
 
 @protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
@@ -1304,6 +1634,8 @@ CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.r
 
 @protected void sse_encode_unit(void self, SseSerializer serializer);
 
+@protected void sse_encode_upgrade_header(UpgradeHeader self, SseSerializer serializer);
+
 @protected void sse_encode_usize(BigInt self, SseSerializer serializer);
                 }
                 
@@ -1326,6 +1658,10 @@ void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generated
 
 void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(ptr);
 
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(ptr);
+
 void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(ptr);
 
 void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(ptr);
@@ -1350,6 +1686,10 @@ void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generated
 
 void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(ptr);
 
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(ptr);
+
 void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ptr);
 
 void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(ptr);
@@ -1378,6 +1718,10 @@ void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generated
 
 void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(ptr);
 
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(ptr);
+
 void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(ptr);
 
 void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(ptr);
@@ -1470,6 +1814,10 @@ void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generated
 
 void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(ptr);
 
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(ptr);
+
 void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(ptr);
 
 void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(ptr);
@@ -1489,6 +1837,10 @@ external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_
 
 external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBTreeMapStringbool(int ptr);
 
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(int ptr);
+
 external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(int ptr);
 
 external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(int ptr);
@@ -1513,6 +1865,10 @@ external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_
 
 external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectParams(int ptr);
 
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(int ptr);
+
 external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(int ptr);
 
 external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(int ptr);
@@ -1541,6 +1897,10 @@ external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_
 
 external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayRequestParams(int ptr);
 
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(int ptr);
+
 external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(int ptr);
 
 external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(int ptr);
@@ -1633,6 +1993,10 @@ external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_
 
 external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSystemProbeResult(int ptr);
 
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(int ptr);
+
 external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(int ptr);
 
 external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(int ptr);
@@ -0,0 +1,20 @@
+import 'package:openclaw_mobile/src/rust/api/token_store.dart';
+
+/// Platform storage for the device tokens the gateway hands out, such as the
+/// keychain or keystore. Without one the Rust side keeps them in a file.
+abstract class DeviceTokenStorage {
+  Future<String?> read(String key);
+  Future<void> write(String key, String token);
+  Future<void> delete(String key);
+}
+
+/// Makes every gateway connection load and save its device token through
+/// [storage] instead of the Rust file store.
+Future<void> registerDeviceTokenStorage(DeviceTokenStorage storage) async {
+  final store = await CallbackTokenStore.newInstance(
+    load: storage.read,
+    save: storage.write,
+    clear: storage.delete,
+  );
+  await registerDeviceTokenStore(store: store);
+}
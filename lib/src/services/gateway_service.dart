@@ -1,17 +1,81 @@
 import 'package:openclaw_mobile/src/rust/api/connection.dart';
+import 'package:openclaw_mobile/src/rust/api/device_identity.dart';
 import 'package:openclaw_mobile/src/rust/api/events.dart';
+
+/// Handle the app registers its gateway connection under; terminal commands
+/// and requests address the connection through it.
+const gatewayConnectionHandle = 'main';
+
+/// The key this device authenticates to the gateway with.
+class DeviceIdentityInfo {
+  const DeviceIdentityInfo({required this.deviceId, required this.publicKey});
+
+  final String deviceId;
+  final String publicKey;
+}
+
 abstract class GatewayService {
   Stream<GatewayEvent> connect({required String url});
   Future<void> sendRequest(String frameJson);
+
+  /// Sends a request frame and completes with the gateway's response payload
+  /// as JSON. Fails on an error response, a timeout or a dropped connection.
+  Future<String> request(String frameJson, {Duration? timeout});
+
+  /// The device identity connections sign their handshake with, or `null`
+  /// when the service has no identity directory.
+  Future<DeviceIdentityInfo?> deviceIdentity();
 }
 class RustGatewayService implements GatewayService {
-  const RustGatewayService();
+  const RustGatewayService({
+    this.handle = gatewayConnectionHandle,
+    this.identityDir,
+  });
+
+  final String handle;
+
+  /// Directory holding the device keypair; created on first use.
+  final String? identityDir;
+
   @override
   Stream<GatewayEvent> connect({required String url}) {
-    return connectToGateway(url: url);
+    return connectToGateway(
+      handle: handle,
+      url: url,
+      options: HandshakeOptions(scopes: const [], identityDir: identityDir),
+    );
+  }
+  @override
+  Future<void> sendRequest(String frameJson) async {
+    await sendGatewayRequestFrame(handle: handle, frameJson: frameJson);
+  }
+  @override
+  Future<String> request(String frameJson, {Duration? timeout}) async {
+    final payload = await gatewayRequest(
+      handle: handle,
+      frameJson: frameJson,
+      timeoutMs: timeout == null ? null : BigInt.from(timeout.inMilliseconds),
+    );
+    try {
+      return await payload.toJson();
+    } finally {
+      payload.dispose();
+    }
   }
   @override
-  Future<void> sendRequest(String frameJson) {
-    return sendGatewayRequestFrame(frameJson: frameJson);
+  Future<DeviceIdentityInfo?> deviceIdentity() async {
+    final directory = identityDir;
+    if (directory == null) {
+      return null;
+    }
+    final identity = await loadDeviceIdentity(directory: directory);
+    try {
+      return DeviceIdentityInfo(
+        deviceId: identity.deviceId(),
+        publicKey: identity.publicKey(),
+      );
+    } finally {
+      identity.dispose();
+    }
   }
 }
@@ -7,6 +7,8 @@ class MockGatewayService implements GatewayService {
   StreamController<GatewayEvent>? _controller;
   String? lastUrl;
   String? lastSentFrame;
+  String response = '{}';
+  DeviceIdentityInfo? identity;
 
   @override
   Stream<GatewayEvent> connect({required String url}) {
@@ -37,6 +39,15 @@ class MockGatewayService implements GatewayService {
     lastSentFrame = frameJson;
   }
 
+  @override
+  Future<String> request(String frameJson, {Duration? timeout}) async {
+    lastSentFrame = frameJson;
+    return response;
+  }
+
+  @override
+  Future<DeviceIdentityInfo?> deviceIdentity() async => identity;
+
   void close() {
     _controller?.close();
   }
@@ -64,7 +75,10 @@ class MockGatewayEventConnected extends MockGatewayEvent {
   const MockGatewayEventConnected();
 
   @override
-  String toString() => 'GatewayEvent.connected()';
+  GatewayEventKind kind() => GatewayEventKind.connected;
+
+  @override
+  String text() => '{"type":"Connected"}';
 }
 
 class MockGatewayEventDisconnected extends MockGatewayEvent {
@@ -73,7 +87,10 @@ class MockGatewayEventDisconnected extends MockGatewayEvent {
   final String reason;
 
   @override
-  String toString() => 'GatewayEvent.disconnected(field0: $reason)';
+  GatewayEventKind kind() => GatewayEventKind.disconnected;
+
+  @override
+  String text() => reason;
 }
 
 class MockGatewayEventMessage extends MockGatewayEvent {
@@ -82,7 +99,10 @@ class MockGatewayEventMessage extends MockGatewayEvent {
   final String message;
 
   @override
-  String toString() => 'GatewayEvent.message(field0: $message)';
+  GatewayEventKind kind() => GatewayEventKind.message;
+
+  @override
+  String text() => message;
 }
 
 class MockGatewayEventError extends MockGatewayEvent {
@@ -91,5 +111,8 @@ class MockGatewayEventError extends MockGatewayEvent {
   final String message;
 
   @override
-  String toString() => 'GatewayEvent.error(field0: $message)';
+  GatewayEventKind kind() => GatewayEventKind.error;
+
+  @override
+  String text() => message;
 }
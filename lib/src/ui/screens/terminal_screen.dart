@@ -1,8 +1,12 @@
 import 'dart:async';
+import 'dart:convert';
 
 import 'package:flutter/material.dart';
 import 'package:google_fonts/google_fonts.dart';
+import 'package:openclaw_mobile/src/rust/api/connection.dart';
 import 'package:openclaw_mobile/src/rust/api/terminal.dart' as rust_terminal;
+import 'package:openclaw_mobile/src/rust/api/terminal_emulator.dart';
+import 'package:openclaw_mobile/src/services/gateway_service.dart';
 import 'package:openclaw_mobile/src/ui/theme.dart';
 
 class TerminalScreen extends StatelessWidget {
@@ -75,13 +79,17 @@ class _LiveTerminalTabState extends State<_LiveTerminalTab>
     with AutomaticKeepAliveClientMixin {
   late final TerminalCommandBridge _bridge;
   late final StreamSubscription<TerminalOutputChunk> _outputSubscription;
+  late final StreamSubscription<TerminalScreenUpdate> _screenSubscription;
   final List<TerminalOutputLine> _lines = [];
+  List<TerminalLine?> _screenLines = const [];
+  TerminalScreenUpdate? _screen;
   final ScrollController _scrollController = ScrollController();
   final TextEditingController _inputController = TextEditingController();
   final FocusNode _inputFocus = FocusNode();
   final List<String> _history = [];
   int _historyIndex = -1;
   bool _sudoMode = false;
+  bool _shellMode = false;
   bool _isExecuting = false;
 
   final List<String> _snippets = const [
@@ -102,12 +110,14 @@ class _LiveTerminalTabState extends State<_LiveTerminalTab>
     super.initState();
     _bridge = TerminalCommandBridge.rustOrFallback();
     _outputSubscription = _bridge.output.listen(_handleOutput);
+    _screenSubscription = _bridge.screen.listen(_handleScreenUpdate);
     _pushSystemLine('实时终端已连接，可执行命令。');
   }
 
   @override
   void dispose() {
     _outputSubscription.cancel();
+    _screenSubscription.cancel();
     _bridge.dispose();
     _scrollController.dispose();
     _inputController.dispose();
@@ -138,6 +148,23 @@ class _LiveTerminalTabState extends State<_LiveTerminalTab>
     }
   }
 
+  void _handleScreenUpdate(TerminalScreenUpdate update) {
+    final lines = update.full || _screenLines.length != update.rows
+        ? List<TerminalLine?>.filled(update.rows, null)
+        : List<TerminalLine?>.of(_screenLines);
+    for (final line in update.lines) {
+      if (line.row < lines.length) {
+        lines[line.row] = line;
+      }
+    }
+    if (mounted) {
+      setState(() {
+        _screen = update;
+        _screenLines = lines;
+      });
+    }
+  }
+
   void _pushSystemLine(String message) {
     _lines.add(
       TerminalOutputLine(
@@ -221,7 +248,13 @@ class _LiveTerminalTabState extends State<_LiveTerminalTab>
     });
 
     try {
-      await _bridge.sendCommand(command, sudo: _sudoMode);
+      if (_shellMode) {
+        await _bridge.sendShellInput('$command\n');
+      } else {
+        await _bridge.sendCommand(command, sudo: _sudoMode);
+      }
+    } catch (error) {
+      _pushSystemLine('发送失败：$error');
     } finally {
       if (mounted) {
         setState(() {
@@ -231,6 +264,29 @@ class _LiveTerminalTabState extends State<_LiveTerminalTab>
     }
   }
 
+  Future<void> _toggleShell(bool value) async {
+    setState(() {
+      _shellMode = value;
+    });
+    if (value) {
+      try {
+        await _bridge.openShell();
+        _pushSystemLine('交互式 Shell 已打开，输入将发送到 Shell。');
+      } catch (error) {
+        _shellMode = false;
+        _pushSystemLine('无法打开交互式 Shell：$error');
+      }
+    } else {
+      await _bridge.closeShell();
+      _screen = null;
+      _screenLines = const [];
+      _pushSystemLine('已断开交互式 Shell。');
+    }
+    if (mounted) {
+      setState(() {});
+    }
+  }
+
   void _clearOutput() {
     setState(() {
       _lines.clear();
@@ -248,7 +304,9 @@ class _LiveTerminalTabState extends State<_LiveTerminalTab>
         children: [
           _TerminalHeader(
             sudoMode: _sudoMode,
+            shellMode: _shellMode,
             isExecuting: _isExecuting,
+            onShellChanged: _toggleShell,
             onSudoChanged: (value) {
               setState(() {
                 _sudoMode = value;
@@ -259,11 +317,20 @@ class _LiveTerminalTabState extends State<_LiveTerminalTab>
             },
           ),
           const SizedBox(height: AppSpacing.lg),
-          _TerminalOutputPanel(
-            lines: _lines,
-            scrollController: _scrollController,
-            sudoMode: _sudoMode,
-          ),
+          if (_shellMode)
+            _ShellScreenPanel(
+              screen: _screen,
+              lines: _screenLines,
+              onResize: (cols, rows) => unawaited(
+                _bridge.resizeShell(cols: cols, rows: rows),
+              ),
+            )
+          else
+            _TerminalOutputPanel(
+              lines: _lines,
+              scrollController: _scrollController,
+              sudoMode: _sudoMode,
+            ),
           const SizedBox(height: AppSpacing.lg),
           _CommandComposer(
             controller: _inputController,
@@ -286,12 +353,16 @@ class _LiveTerminalTabState extends State<_LiveTerminalTab>
 class _TerminalHeader extends StatelessWidget {
   const _TerminalHeader({
     required this.sudoMode,
+    required this.shellMode,
     required this.isExecuting,
+    required this.onShellChanged,
     required this.onSudoChanged,
   });
 
   final bool sudoMode;
+  final bool shellMode;
   final bool isExecuting;
+  final ValueChanged<bool> onShellChanged;
   final ValueChanged<bool> onSudoChanged;
 
   @override
@@ -339,6 +410,14 @@ class _TerminalHeader extends StatelessWidget {
               activeColor: AppColors.danger,
               onChanged: onSudoChanged,
             ),
+            const SizedBox(height: AppSpacing.xs),
+            Text('交互 Shell', style: AppTypography.caption),
+            const SizedBox(height: AppSpacing.xs),
+            Switch.adaptive(
+              value: shellMode,
+              activeColor: AppColors.accent,
+              onChanged: onShellChanged,
+            ),
           ],
         ),
       ],
@@ -436,6 +515,216 @@ class _TerminalOutputPanel extends StatelessWidget {
   }
 }
 
+class _ShellScreenPanel extends StatefulWidget {
+  const _ShellScreenPanel({
+    required this.screen,
+    required this.lines,
+    required this.onResize,
+  });
+
+  final TerminalScreenUpdate? screen;
+  final List<TerminalLine?> lines;
+  final void Function(int cols, int rows) onResize;
+
+  @override
+  State<_ShellScreenPanel> createState() => _ShellScreenPanelState();
+}
+
+class _ShellScreenPanelState extends State<_ShellScreenPanel> {
+  static const _fontSize = 13.0;
+  static const _lineHeight = 1.3;
+
+  (int, int)? _requestedSize;
+
+  void _fitTo(BoxConstraints constraints, TextStyle style) {
+    if (widget.screen == null) {
+      return;
+    }
+    final painter = TextPainter(
+      text: TextSpan(text: 'M', style: style),
+      textDirection: TextDirection.ltr,
+    )..layout();
+    final cols = (constraints.maxWidth / painter.width).floor();
+    final rows = (constraints.maxHeight / painter.height).floor();
+    if (cols < 1 || rows < 1 || _requestedSize == (cols, rows)) {
+      return;
+    }
+    _requestedSize = (cols, rows);
+    WidgetsBinding.instance.addPostFrameCallback((_) {
+      if (mounted) {
+        widget.onResize(cols, rows);
+      }
+    });
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final baseStyle = GoogleFonts.ibmPlexMono(
+      fontSize: _fontSize,
+      height: _lineHeight,
+      color: AppColors.textPrimary,
+    );
+    final screen = widget.screen;
+
+    return Expanded(
+      child: Container(
+        padding: const EdgeInsets.all(AppSpacing.md),
+        decoration: BoxDecoration(
+          color: AppColors.surface,
+          borderRadius: BorderRadius.circular(22),
+          border: Border.all(color: AppColors.borderSubtle),
+        ),
+        child: LayoutBuilder(
+          builder: (context, constraints) {
+            _fitTo(constraints, baseStyle);
+            if (screen == null) {
+              return Text(
+                '正在打开交互式 Shell...',
+                style: baseStyle.copyWith(color: AppColors.textMuted),
+              );
+            }
+            return ClipRect(
+              child: Column(
+                crossAxisAlignment: CrossAxisAlignment.start,
+                children: [
+                  for (var row = 0; row < widget.lines.length; row++)
+                    Text.rich(
+                      TextSpan(
+                        children: _rowSpans(
+                          widget.lines[row],
+                          baseStyle,
+                          cursorCol: screen.cursorVisible &&
+                                  screen.cursorRow == row
+                              ? screen.cursorCol
+                              : null,
+                        ),
+                      ),
+                      maxLines: 1,
+                      softWrap: false,
+                    ),
+                ],
+              ),
+            );
+          },
+        ),
+      ),
+    );
+  }
+
+  List<InlineSpan> _rowSpans(
+    TerminalLine? line,
+    TextStyle baseStyle, {
+    int? cursorCol,
+  }) {
+    final spans = <InlineSpan>[];
+    var col = 0;
+    var cursorDrawn = false;
+    for (final run in line?.runs ?? const <TerminalRun>[]) {
+      final style = _TerminalPalette.style(run.style, baseStyle);
+      final length = run.text.runes.length;
+      if (cursorCol != null && cursorCol >= col && cursorCol < col + length) {
+        final chars = run.text.runes.toList();
+        final at = cursorCol - col;
+        spans
+          ..add(TextSpan(
+            text: String.fromCharCodes(chars.sublist(0, at)),
+            style: style,
+          ))
+          ..add(TextSpan(
+            text: String.fromCharCode(chars[at]),
+            style: _cursorStyle(style),
+          ))
+          ..add(TextSpan(
+            text: String.fromCharCodes(chars.sublist(at + 1)),
+            style: style,
+          ));
+        cursorDrawn = true;
+      } else {
+        spans.add(TextSpan(text: run.text, style: style));
+      }
+      col += length;
+    }
+    if (cursorCol != null && !cursorDrawn) {
+      spans.add(TextSpan(
+        text: ' ' * (cursorCol - col),
+        style: baseStyle,
+      ));
+      spans.add(TextSpan(text: ' ', style: _cursorStyle(baseStyle)));
+    }
+    if (spans.isEmpty) {
+      spans.add(TextSpan(text: ' ', style: baseStyle));
+    }
+    return spans;
+  }
+
+  TextStyle _cursorStyle(TextStyle style) {
+    return style.copyWith(
+      color: AppColors.surface,
+      backgroundColor: AppColors.textPrimary,
+    );
+  }
+}
+
+/// Maps emulator cell styles onto the terminal theme, using the same colors
+/// as [AnsiParser] for the sixteen standard ones.
+class _TerminalPalette {
+  static TextStyle style(TerminalStyle cell, TextStyle baseStyle) {
+    var foreground = color(cell.foreground) ?? baseStyle.color;
+    var background = color(cell.background);
+    if (cell.inverse) {
+      final swapped = foreground;
+      foreground = background ?? AppColors.surface;
+      background = swapped;
+    }
+    if (cell.hidden) {
+      foreground = Colors.transparent;
+    }
+    if (cell.dim) {
+      foreground = foreground?.withValues(alpha: 0.6);
+    }
+    return baseStyle.copyWith(
+      color: foreground,
+      backgroundColor: background,
+      fontWeight: cell.bold ? FontWeight.w700 : null,
+      fontStyle: cell.italic ? FontStyle.italic : null,
+      decoration: TextDecoration.combine([
+        if (cell.underline) TextDecoration.underline,
+        if (cell.strikethrough) TextDecoration.lineThrough,
+      ]),
+    );
+  }
+
+  static Color? color(TerminalColor color) {
+    return switch (color) {
+      TerminalColor_Default() => null,
+      TerminalColor_Indexed(:final index) => _indexed(index),
+      TerminalColor_Rgb(:final red, :final green, :final blue) =>
+        Color.fromARGB(0xFF, red, green, blue),
+    };
+  }
+
+  static Color? _indexed(int index) {
+    if (index < 8) {
+      return AnsiParser._ansiColor(30 + index);
+    }
+    if (index < 16) {
+      return AnsiParser._ansiColor(90 + index - 8);
+    }
+    if (index < 232) {
+      const levels = [0, 95, 135, 175, 215, 255];
+      final cube = index - 16;
+      return Color.fromARGB(
+        0xFF,
+        levels[cube ~/ 36],
+        levels[(cube ~/ 6) % 6],
+        levels[cube % 6],
+      );
+    }
+    final gray = 8 + (index - 232) * 10;
+    return Color.fromARGB(0xFF, gray, gray, gray);
+  }
+}
+
 class _TerminalLineRow extends StatelessWidget {
   const _TerminalLineRow({required this.line});
 
@@ -661,55 +950,162 @@ class _CommandComposer extends StatelessWidget {
 class TerminalCommandBridge {
   TerminalCommandBridge._(
     this._controller, {
-    required this.gatewayUrl,
+    required this.handle,
     this.sessionKey,
   });
 
-  static const _defaultGatewayUrl = 'ws://127.0.0.1:18789';
-
   factory TerminalCommandBridge.rustOrFallback({
-    String gatewayUrl = _defaultGatewayUrl,
+    String handle = gatewayConnectionHandle,
     String? sessionKey,
   }) {
     return TerminalCommandBridge._(
       StreamController<TerminalOutputChunk>.broadcast(),
-      gatewayUrl: gatewayUrl,
+      handle: handle,
       sessionKey: sessionKey,
     );
   }
 
   final StreamController<TerminalOutputChunk> _controller;
-  final String gatewayUrl;
+  final String handle;
   final String? sessionKey;
+  final StreamController<TerminalScreenUpdate> _screenController =
+      StreamController<TerminalScreenUpdate>.broadcast();
+  String? _shellStreamId;
+  StreamSubscription<rust_terminal.TerminalChunk>? _shellSubscription;
+  TerminalEmulator? _emulator;
 
   Stream<TerminalOutputChunk> get output => _controller.stream;
 
+  /// Screen changes of the interactive shell, as rendered by the Rust
+  /// terminal emulator.
+  Stream<TerminalScreenUpdate> get screen => _screenController.stream;
+
   Future<void> sendCommand(String command, {bool sudo = false}) async {
     try {
       await for (final chunk in rust_terminal.execCommand(
-        url: gatewayUrl,
+        handle: handle,
         command: command,
         sessionKey: sessionKey,
+        rawOutput: false,
       )) {
-        _controller.add(
-          TerminalOutputChunk(
-            text: chunk.text,
-            kind: _mapKind(chunk.kind),
-            timestamp: DateTime.now(),
-          ),
-        );
+        _addChunk(chunk);
       }
     } catch (error) {
-      _controller.add(
-        TerminalOutputChunk(
-          text: error.toString(),
-          kind: TerminalLineKind.error,
-          timestamp: DateTime.now(),
-        ),
-      );
+      _addError(error);
     }
   }
 
+  bool get hasShell => _shellStreamId != null;
+
+  /// Opens an interactive shell on the gateway and feeds its output through a
+  /// terminal emulator until the gateway closes the stream or [closeShell]
+  /// detaches from it.
+  Future<void> openShell({int cols = 80, int rows = 24}) async {
+    if (hasShell) {
+      return;
+    }
+    final streamId = await rust_terminal.terminalStreamOpen(
+      handle: handle,
+      cols: cols,
+      rows: rows,
+      sessionKey: sessionKey,
+    );
+    final emulator = TerminalEmulator(cols: cols, rows: rows);
+    _shellStreamId = streamId;
+    _emulator = emulator;
+    _screenController.add(emulator.snapshot());
+    _shellSubscription = rust_terminal
+        .terminalStreamAttach(
+          handle: handle,
+          streamId: streamId,
+          rawOutput: true,
+        )
+        .listen(
+          _addShellChunk,
+          onError: _addError,
+          onDone: _forgetShell,
+        );
+  }
+
+  /// Resizes the emulator and tells the gateway the shell's new size.
+  Future<void> resizeShell({required int cols, required int rows}) async {
+    final emulator = _emulator;
+    final streamId = _shellStreamId;
+    if (emulator == null || streamId == null) {
+      return;
+    }
+    final client = GatewayClient(url: handle);
+    final frameJson = await emulator.resizeRequest(
+      client: client,
+      requestId: client.nextRequestId(),
+      streamId: streamId,
+      cols: cols,
+      rows: rows,
+      sessionKey: sessionKey,
+    );
+    _screenController.add(emulator.snapshot());
+    await sendGatewayRequestFrame(handle: handle, frameJson: frameJson);
+  }
+
+  Future<void> sendShellInput(String input) async {
+    final streamId = _shellStreamId;
+    if (streamId == null) {
+      throw StateError('交互式 Shell 尚未打开。');
+    }
+    await rust_terminal.terminalStreamSend(
+      handle: handle,
+      streamId: streamId,
+      inputBytes: utf8.encode(input),
+      sessionKey: sessionKey,
+    );
+  }
+
+  Future<void> closeShell() async {
+    final subscription = _shellSubscription;
+    _forgetShell();
+    await subscription?.cancel();
+  }
+
+  void _forgetShell() {
+    _shellStreamId = null;
+    _shellSubscription = null;
+    _emulator?.dispose();
+    _emulator = null;
+  }
+
+  void _addShellChunk(rust_terminal.TerminalChunk chunk) {
+    final data = chunk.data;
+    final emulator = _emulator;
+    if (data == null || emulator == null) {
+      _addChunk(chunk);
+      return;
+    }
+    _screenController.add(emulator.feed(bytes: data));
+  }
+
+  void _addChunk(rust_terminal.TerminalChunk chunk) {
+    if (chunk.kind == 'resize') {
+      return;
+    }
+    _controller.add(
+      TerminalOutputChunk(
+        text: chunk.text,
+        kind: _mapKind(chunk.kind),
+        timestamp: DateTime.now(),
+      ),
+    );
+  }
+
+  void _addError(Object error) {
+    _controller.add(
+      TerminalOutputChunk(
+        text: error.toString(),
+        kind: TerminalLineKind.error,
+        timestamp: DateTime.now(),
+      ),
+    );
+  }
+
   TerminalLineKind _mapKind(String kind) {
     return switch (kind) {
       'error' => TerminalLineKind.error,
@@ -719,6 +1115,9 @@ class TerminalCommandBridge {
   }
 
   void dispose() {
+    _shellSubscription?.cancel();
+    _emulator?.dispose();
+    _screenController.close();
     _controller.close();
   }
 }
@@ -0,0 +1,635 @@
+use crate::api::events::{GatewayEvent, GatewayEventPayload};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use flutter_rust_bridge::frb;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration as StdDuration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackpressurePolicy {
+    /// Hold events back until the app drains the queue. The session keeps reading
+    /// until as many events are held again, then stops reading from the socket;
+    /// disconnects and heartbeats still go through.
+    Block,
+    /// Evict the oldest queued event of the same kind, or the oldest droppable event
+    /// of any kind when none is queued, to make room for the new one.
+    DropOldest,
+    /// Merge into the newest queued event for the same stream, or evict like
+    /// `DropOldest` when the two cannot be merged.
+    Coalesce,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackpressureStats {
+    pub queued: u32,
+    pub paused: bool,
+    pub dropped_logs: u64,
+    pub dropped_output: u64,
+    pub dropped_events: u64,
+    pub coalesced: u64,
+    /// Events that subscribers reading the raw event stream, such as terminal
+    /// streams, missed because they fell behind.
+    pub lagged: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct InboundLimits {
+    pub(crate) capacity: u32,
+    pub(crate) logs: BackpressurePolicy,
+    pub(crate) output: BackpressurePolicy,
+    pub(crate) events: BackpressurePolicy,
+}
+
+/// Connection lifecycle events and responses are never dropped or held back.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EventClass {
+    Control,
+    Logs,
+    Output,
+    Other,
+}
+
+fn classify(event: &GatewayEvent) -> EventClass {
+    match event {
+        GatewayEvent::ProtocolEvent { payload, .. } => match payload {
+            GatewayEventPayload::Logs(_) => EventClass::Logs,
+            GatewayEventPayload::StreamData(_) | GatewayEventPayload::ExecOutput(_) => {
+                EventClass::Output
+            }
+            _ => EventClass::Other,
+        },
+        GatewayEvent::Message { .. } | GatewayEvent::Binary { .. } => EventClass::Other,
+        _ => EventClass::Control,
+    }
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct QueueState {
+    events: VecDeque<GatewayEvent>,
+    paused: bool,
+    input_closed: bool,
+    sink_closed: bool,
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct DropCounters {
+    logs: AtomicU64,
+    output: AtomicU64,
+    events: AtomicU64,
+    coalesced: AtomicU64,
+    lagged: AtomicU64,
+}
+
+/// Bounded buffer between the socket reader and the Dart sink.
+pub(crate) struct InboundQueue {
+    state: Mutex<QueueState>,
+    queued: Notify,
+    drained: Notify,
+    limits: InboundLimits,
+    dropped: DropCounters,
+}
+
+impl InboundQueue {
+    pub(crate) fn new(limits: InboundLimits) -> Self {
+        Self {
+            state: Mutex::new(QueueState::default()),
+            queued: Notify::new(),
+            drained: Notify::new(),
+            limits,
+            dropped: DropCounters::default(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn capacity(&self) -> usize {
+        self.limits.capacity.max(1) as usize
+    }
+
+    fn policy(&self, class: EventClass) -> Option<BackpressurePolicy> {
+        match class {
+            EventClass::Control => None,
+            EventClass::Logs => Some(self.limits.logs),
+            EventClass::Output => Some(self.limits.output),
+            EventClass::Other => Some(self.limits.events),
+        }
+    }
+
+    /// Waits for room when `event` falls under a `Block` policy and the queue is full.
+    pub(crate) async fn reserve(&self, event: &GatewayEvent) {
+        if self.policy(classify(event)) != Some(BackpressurePolicy::Block) {
+            return;
+        }
+        loop {
+            let drained = self.drained.notified();
+            {
+                let state = self.lock();
+                if state.events.len() < self.capacity() || state.sink_closed {
+                    return;
+                }
+            }
+            drained.await;
+        }
+    }
+
+    /// Returns false once the sink behind the queue has gone away.
+    pub(crate) fn push(&self, event: GatewayEvent) -> bool {
+        let mut state = self.lock();
+        if state.sink_closed {
+            return false;
+        }
+        let class = classify(&event);
+        match self.policy(class) {
+            Some(BackpressurePolicy::Coalesce) | Some(BackpressurePolicy::DropOldest)
+                if state.events.len() >= self.capacity() =>
+            {
+                let coalesce = self.policy(class) == Some(BackpressurePolicy::Coalesce);
+                if coalesce && coalesce_into_queued(&mut state.events, &event) {
+                    self.dropped.coalesced.fetch_add(1, Ordering::Relaxed);
+                } else {
+                    if let Some(evicted) = evict_oldest(&mut state.events, class) {
+                        self.count_drop(classify(&evicted));
+                    }
+                    state.events.push_back(event);
+                }
+            }
+            _ => state.events.push_back(event),
+        }
+        drop(state);
+        self.queued.notify_waiters();
+        true
+    }
+
+    fn count_drop(&self, class: EventClass) {
+        let counter = match class {
+            EventClass::Logs => &self.dropped.logs,
+            EventClass::Output => &self.dropped.output,
+            _ => &self.dropped.events,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn count_lagged(&self, skipped: u64) {
+        self.dropped.lagged.fetch_add(skipped, Ordering::Relaxed);
+    }
+
+    /// The next event to hand to the sink; `None` once input is closed and drained.
+    /// Paused queues still drain after input closes so no event is stranded.
+    pub(crate) async fn next(&self) -> Option<GatewayEvent> {
+        loop {
+            let queued = self.queued.notified();
+            {
+                let mut state = self.lock();
+                if !state.paused || state.input_closed {
+                    if let Some(event) = state.events.pop_front() {
+                        drop(state);
+                        self.drained.notify_waiters();
+                        return Some(event);
+                    }
+                }
+                if state.input_closed {
+                    return None;
+                }
+            }
+            queued.await;
+        }
+    }
+
+    pub(crate) fn set_paused(&self, paused: bool) {
+        self.lock().paused = paused;
+        self.queued.notify_waiters();
+    }
+
+    pub(crate) fn close_input(&self) {
+        self.lock().input_closed = true;
+        self.queued.notify_waiters();
+    }
+
+    pub(crate) fn close_sink(&self) {
+        let mut state = self.lock();
+        state.sink_closed = true;
+        state.events.clear();
+        drop(state);
+        self.drained.notify_waiters();
+    }
+
+    pub(crate) fn stats(&self) -> BackpressureStats {
+        let state = self.lock();
+        BackpressureStats {
+            queued: state.events.len() as u32,
+            paused: state.paused,
+            dropped_logs: self.dropped.logs.load(Ordering::Relaxed),
+            dropped_output: self.dropped.output.load(Ordering::Relaxed),
+            dropped_events: self.dropped.events.load(Ordering::Relaxed),
+            coalesced: self.dropped.coalesced.load(Ordering::Relaxed),
+            lagged: self.dropped.lagged.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Removes the oldest queued event of `class`, falling back to the oldest event of
+/// any class but `Control`. Control events stay, so the queue may briefly run over
+/// capacity when it holds nothing else.
+fn evict_oldest(events: &mut VecDeque<GatewayEvent>, class: EventClass) -> Option<GatewayEvent> {
+    let oldest = events
+        .iter()
+        .position(|queued| classify(queued) == class)
+        .or_else(|| {
+            events
+                .iter()
+                .position(|queued| classify(queued) != EventClass::Control)
+        })?;
+    events.remove(oldest)
+}
+
+fn stream_key(event: &GatewayEvent) -> Option<String> {
+    let GatewayEvent::ProtocolEvent { payload, .. } = event else {
+        return None;
+    };
+    match payload {
+        GatewayEventPayload::StreamData(data) => Some(format!(
+            "stream:{}",
+            data.stream_id.as_deref().unwrap_or(&data.stream)
+        )),
+        GatewayEventPayload::ExecOutput(output) => Some(format!(
+            "exec:{}",
+            output.exec_id.as_deref().unwrap_or_default()
+        )),
+        GatewayEventPayload::Logs(logs) => Some(format!(
+            "logs:{}",
+            logs.subscription_id.as_deref().unwrap_or_default()
+        )),
+        _ => None,
+    }
+}
+
+/// Merges `next` into the newest queued event of the same stream. Events of other
+/// streams are skipped, but an unmergeable event of the same stream stops the search
+/// so per-stream ordering is preserved.
+fn coalesce_into_queued(events: &mut VecDeque<GatewayEvent>, next: &GatewayEvent) -> bool {
+    let Some(key) = stream_key(next) else {
+        return false;
+    };
+    let Some(queued) = events
+        .iter_mut()
+        .rev()
+        .find(|queued| stream_key(queued).as_deref() == Some(key.as_str()))
+    else {
+        return false;
+    };
+    merge_events(queued, next)
+}
+
+/// Appends the data of `next` to `queued`; both must belong to the same stream.
+fn merge_events(queued: &mut GatewayEvent, next: &GatewayEvent) -> bool {
+    let (
+        GatewayEvent::ProtocolEvent {
+            payload: queued, ..
+        },
+        GatewayEvent::ProtocolEvent { payload: next, .. },
+    ) = (queued, next)
+    else {
+        return false;
+    };
+    match (queued, next) {
+        (GatewayEventPayload::StreamData(queued), GatewayEventPayload::StreamData(next)) => {
+            let plain_data =
+                |cols: Option<u32>, rows: Option<u32>| cols.is_none() && rows.is_none();
+            plain_data(queued.cols, queued.rows)
+                && plain_data(next.cols, next.rows)
+                && queued.channel == next.channel
+                && queued.encoding == next.encoding
+                && append_data(&mut queued.data, &next.data, next.encoding.as_deref())
+        }
+        (GatewayEventPayload::ExecOutput(queued), GatewayEventPayload::ExecOutput(next)) => {
+            queued.eof.is_none()
+                && queued.exit_code.is_none()
+                && queued.stream == next.stream
+                && queued.encoding == next.encoding
+                && append_data(&mut queued.data, &next.data, next.encoding.as_deref())
+                && {
+                    queued.eof = next.eof;
+                    queued.exit_code = next.exit_code;
+                    true
+                }
+        }
+        (GatewayEventPayload::Logs(queued), GatewayEventPayload::Logs(next)) => {
+            if let Some(entry) = queued.entry.take() {
+                queued.entries.push(entry);
+            }
+            queued.entries.extend(next.entries.iter().cloned());
+            queued.entries.extend(next.entry.iter().cloned());
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Joins consecutive output chunks of one stream into a single delivery.
+pub(crate) struct OutputBatcher {
+    window: StdDuration,
+    max_bytes: usize,
+    held: Option<(GatewayEvent, Instant)>,
+}
+
+impl OutputBatcher {
+    pub(crate) fn new(window: StdDuration, max_bytes: u32) -> Self {
+        Self {
+            window,
+            max_bytes: max_bytes as usize,
+            held: None,
+        }
+    }
+
+    /// When the held batch must be delivered even if no further chunk arrives.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.held.as_ref().map(|(_, deadline)| *deadline)
+    }
+
+    /// Returns the events that are ready for delivery, in order.
+    pub(crate) fn push(&mut self, event: GatewayEvent) -> Vec<GatewayEvent> {
+        let mut ready = Vec::new();
+        if let Some((held, _)) = &mut self.held {
+            if stream_key(held) == stream_key(&event)
+                && classify(&event) == EventClass::Output
+                && merge_events(held, &event)
+            {
+                if ends_stream(held) || data_len(held) >= self.max_bytes {
+                    ready.extend(self.flush());
+                }
+                return ready;
+            }
+            ready.extend(self.flush());
+        }
+        if self.window.is_zero()
+            || classify(&event) != EventClass::Output
+            || ends_stream(&event)
+            || data_len(&event) >= self.max_bytes
+        {
+            ready.push(event);
+        } else {
+            self.held = Some((event, Instant::now() + self.window));
+        }
+        ready
+    }
+
+    pub(crate) fn flush(&mut self) -> Option<GatewayEvent> {
+        self.held.take().map(|(event, _)| event)
+    }
+}
+
+fn ends_stream(event: &GatewayEvent) -> bool {
+    matches!(
+        event,
+        GatewayEvent::ProtocolEvent {
+            payload: GatewayEventPayload::ExecOutput(output),
+            ..
+        } if output.eof.is_some() || output.exit_code.is_some()
+    )
+}
+
+fn data_len(event: &GatewayEvent) -> usize {
+    let GatewayEvent::ProtocolEvent { payload, .. } = event else {
+        return 0;
+    };
+    let data = match payload {
+        GatewayEventPayload::StreamData(data) => &data.data,
+        GatewayEventPayload::ExecOutput(output) => &output.data,
+        _ => return 0,
+    };
+    data.as_ref().map_or(0, String::len)
+}
+
+fn append_data(queued: &mut Option<String>, next: &Option<String>, encoding: Option<&str>) -> bool {
+    let (Some(queued), Some(next)) = (queued.as_mut(), next.as_deref()) else {
+        return false;
+    };
+    if !encoding.is_some_and(|value| value.eq_ignore_ascii_case("base64")) {
+        queued.push_str(next);
+        return true;
+    }
+    let (Ok(mut bytes), Ok(tail)) = (STANDARD.decode(queued.as_bytes()), STANDARD.decode(next))
+    else {
+        return false;
+    };
+    bytes.extend(tail);
+    *queued = STANDARD.encode(bytes);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::events::{ExecOutput, LogEntry, LogsEvent, StreamDataEvent};
+
+    fn protocol_event(payload: GatewayEventPayload) -> GatewayEvent {
+        GatewayEvent::ProtocolEvent {
+            event: "test".to_string(),
+            payload,
+            seq: String::new(),
+            state_version: String::new(),
+            session_key: String::new(),
+        }
+    }
+
+    fn stream_data(stream_id: &str, data: &str) -> GatewayEvent {
+        protocol_event(GatewayEventPayload::StreamData(StreamDataEvent {
+            stream: "stdout".to_string(),
+            stream_id: Some(stream_id.to_string()),
+            data: Some(STANDARD.encode(data)),
+            encoding: Some("base64".to_string()),
+            ..StreamDataEvent::default()
+        }))
+    }
+
+    fn log_line(message: &str) -> GatewayEvent {
+        protocol_event(GatewayEventPayload::Logs(LogsEvent {
+            entry: Some(LogEntry {
+                message: Some(message.to_string()),
+                ..LogEntry::default()
+            }),
+            ..LogsEvent::default()
+        }))
+    }
+
+    fn limits(logs: BackpressurePolicy, output: BackpressurePolicy) -> InboundLimits {
+        InboundLimits {
+            capacity: 2,
+            logs,
+            output,
+            events: BackpressurePolicy::Block,
+        }
+    }
+
+    #[tokio::test]
+    async fn coalesces_output_and_drops_oldest_logs_while_paused() {
+        let queue = InboundQueue::new(limits(
+            BackpressurePolicy::DropOldest,
+            BackpressurePolicy::Coalesce,
+        ));
+        queue.set_paused(true);
+        assert!(queue.push(stream_data("s1", "ab")));
+        assert!(queue.push(log_line("first")));
+        assert!(queue.push(stream_data("s1", "cd")));
+        assert!(queue.push(log_line("second")));
+        assert!(queue.push(GatewayEvent::Disconnected {
+            reason: "bye".to_string(),
+        }));
+
+        let stats = queue.stats();
+        assert_eq!(stats.queued, 3);
+        assert_eq!(stats.coalesced, 1);
+        assert_eq!(stats.dropped_logs, 1);
+
+        queue.set_paused(false);
+        queue.close_input();
+        let mut delivered = Vec::new();
+        while let Some(event) = queue.next().await {
+            delivered.push(event);
+        }
+        match &delivered[0] {
+            GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::StreamData(data),
+                ..
+            } => assert_eq!(data.data.as_deref(), Some(STANDARD.encode("abcd").as_str())),
+            other => panic!("expected stream data, got {other:?}"),
+        }
+        match &delivered[1] {
+            GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::Logs(logs),
+                ..
+            } => assert_eq!(
+                logs.entry
+                    .as_ref()
+                    .and_then(|entry| entry.message.as_deref()),
+                Some("second")
+            ),
+            other => panic!("expected logs, got {other:?}"),
+        }
+        assert!(matches!(delivered[2], GatewayEvent::Disconnected { .. }));
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_evicts_another_class_when_none_of_its_own_is_queued() {
+        let queue = InboundQueue::new(limits(
+            BackpressurePolicy::DropOldest,
+            BackpressurePolicy::DropOldest,
+        ));
+        queue.set_paused(true);
+        assert!(queue.push(stream_data("s1", "ab")));
+        assert!(queue.push(stream_data("s1", "cd")));
+        assert!(queue.push(log_line("newest")));
+
+        let stats = queue.stats();
+        assert_eq!(stats.queued, 2);
+        assert_eq!(stats.dropped_output, 1);
+        assert_eq!(stats.dropped_logs, 0);
+
+        queue.set_paused(false);
+        queue.close_input();
+        let mut delivered = Vec::new();
+        while let Some(event) = queue.next().await {
+            delivered.push(event);
+        }
+        match &delivered[0] {
+            GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::StreamData(data),
+                ..
+            } => assert_eq!(data.data.as_deref(), Some(STANDARD.encode("cd").as_str())),
+            other => panic!("expected stream data, got {other:?}"),
+        }
+        assert!(matches!(
+            &delivered[1],
+            GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::Logs(_),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn block_policy_waits_for_the_sink_to_drain() {
+        let queue = std::sync::Arc::new(InboundQueue::new(limits(
+            BackpressurePolicy::Block,
+            BackpressurePolicy::Block,
+        )));
+        queue.set_paused(true);
+        assert!(queue.push(log_line("one")));
+        assert!(queue.push(log_line("two")));
+
+        let third = log_line("three");
+        let reserve =
+            tokio::time::timeout(std::time::Duration::from_millis(20), queue.reserve(&third));
+        assert!(reserve.await.is_err(), "full queue should block");
+
+        let consumer = std::sync::Arc::clone(&queue);
+        queue.set_paused(false);
+        let first = consumer.next().await;
+        assert!(first.is_some());
+        queue.reserve(&third).await;
+        assert!(queue.push(third));
+        assert_eq!(queue.stats().queued, 2);
+
+        queue.close_sink();
+        assert!(!queue.push(log_line("four")));
+    }
+
+    fn exec_output(data: &str, exit_code: Option<i32>) -> GatewayEvent {
+        protocol_event(GatewayEventPayload::ExecOutput(ExecOutput {
+            exec_id: Some("exec-1".to_string()),
+            stream: Some("stdout".to_string()),
+            data: Some(data.to_string()),
+            encoding: None,
+            eof: None,
+            exit_code,
+            timestamp_ms: None,
+            extra: Default::default(),
+        }))
+    }
+
+    fn exec_data(event: &GatewayEvent) -> (Option<&str>, Option<i32>) {
+        match event {
+            GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::ExecOutput(output),
+                ..
+            } => (output.data.as_deref(), output.exit_code),
+            other => panic!("expected exec output, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn batches_output_per_stream_and_flushes_on_exit() {
+        let mut batcher = OutputBatcher::new(StdDuration::from_millis(10), 8);
+        assert!(batcher.push(exec_output("ab", None)).is_empty());
+        assert!(batcher.push(exec_output("cd", None)).is_empty());
+        assert!(batcher.deadline().is_some());
+
+        let ready = batcher.push(stream_data("s1", "x"));
+        assert_eq!(ready.len(), 1);
+        assert_eq!(exec_data(&ready[0]), (Some("abcd"), None));
+
+        let ready = batcher.push(exec_output("ef", Some(0)));
+        assert_eq!(ready.len(), 2);
+        assert!(matches!(
+            &ready[0],
+            GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::StreamData(_),
+                ..
+            }
+        ));
+        assert_eq!(exec_data(&ready[1]), (Some("ef"), Some(0)));
+        assert!(batcher.deadline().is_none());
+
+        assert!(batcher.push(exec_output("0123", None)).is_empty());
+        let ready = batcher.push(exec_output("4567", None));
+        assert_eq!(ready.len(), 1, "byte budget reached");
+        assert_eq!(exec_data(&ready[0]), (Some("01234567"), None));
+    }
+}
@@ -1,64 +1,395 @@
+use crate::api::backpressure::{
+    BackpressurePolicy, BackpressureStats, InboundLimits, InboundQueue, OutputBatcher,
+};
+use crate::api::deflate::{CompressionCounters, CompressionStats, DeflateConfig, DeflateStream};
+use crate::api::device_identity::{DeviceAuthPayload, DeviceIdentity};
 use crate::api::events::{
-    parse_gateway_frame, AgentTurn, CameraSnapshot, ExecParams, GatewayEvent, GatewayRequestFrame,
-    GatewayRequestParams, LogsSubscribeParams, LogsUnsubscribeParams, SessionsCloseParams,
-    SessionsListParams, SessionsSpawnParams, StreamCloseParams, StreamOpenParams, StreamSendParams,
-    SystemEvent, SystemProbeParams,
+    parse_gateway_frame, AgentTurn, CameraSnapshot, ConnectAuth, ConnectChallenge, ConnectClient,
+    ConnectParams, ExecParams, GatewayError, GatewayEvent, GatewayEventPayload,
+    GatewayRequestFrame, GatewayRequestParams, GatewayResponsePayload, HelloOk, HelloPolicy,
+    LogsSubscribeParams, LogsUnsubscribeParams, SessionsCloseParams, SessionsListParams,
+    SessionsSpawnParams, StreamCloseParams, StreamOpenParams, StreamSendParams, SystemEvent,
+    SystemProbeParams, UpgradeHeader,
+};
+use crate::api::outbox::{unix_time_ms, Outbox, OutboxConfig};
+use crate::api::proxy::{connect_via_proxy, target_address, ProxyConfig};
+use crate::api::tls::{certificate_failure_event, TlsClient, TlsOptions};
+use crate::api::token_store::{
+    device_token_key, registered_device_token_store, DeviceTokenStore, FileTokenStore,
 };
 use crate::frb_generated::StreamSink;
 use crate::Duration;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use flate2::{write::GzEncoder, Compression};
 use flutter_rust_bridge::frb;
-use futures_util::{SinkExt, StreamExt};
-#[cfg(test)]
 use futures_util::future::BoxFuture;
+use futures_util::{FutureExt, SinkExt, StreamExt};
+use ring::rand::{SecureRandom, SystemRandom};
+use rustls_pki_types::ServerName;
 use serde_json::Value;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::io::Write;
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{Mutex, OnceLock};
-use std::time::Duration as StdDuration;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+use tokio::net::TcpStream;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{broadcast, oneshot, watch};
 use tokio::time::{sleep, Instant, MissedTickBehavior};
-#[cfg(test)]
 use tokio::{io::AsyncRead, io::AsyncWrite};
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
-#[cfg(test)]
-use tokio_tungstenite::WebSocketStream;
+use tokio_rustls::TlsConnector;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::handshake::client::{Request, Response};
+use tokio_tungstenite::tungstenite::http::header::{
+    HeaderName, HeaderValue, SEC_WEBSOCKET_EXTENSIONS, SEC_WEBSOCKET_PROTOCOL,
+};
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::{CloseFrame, Message};
+use tokio_tungstenite::{client_async_with_config, tungstenite, MaybeTlsStream, WebSocketStream};
+
+type RequestSender = tokio::sync::mpsc::Sender<String>;
+type ResponseSender = oneshot::Sender<Result<GatewayResponsePayload>>;
+
+const DEFAULT_REQUEST_TIMEOUT: StdDuration = StdDuration::from_secs(30);
+
+#[frb(ignore)]
+#[derive(Clone, Default)]
+struct PendingRequests {
+    entries: Arc<Mutex<HashMap<String, ResponseSender>>>,
+}
+
+impl PendingRequests {
+    fn register(&self, id: &str) -> Result<oneshot::Receiver<Result<GatewayResponsePayload>>> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| anyhow!("pending request table poisoned"))?;
+        if entries.contains_key(id) {
+            return Err(anyhow!("A request with id {id} is already pending"));
+        }
+        let (tx, rx) = oneshot::channel();
+        entries.insert(id.to_string(), tx);
+        Ok(rx)
+    }
+
+    fn take(&self, id: &str) -> Option<ResponseSender> {
+        self.entries.lock().ok()?.remove(id)
+    }
+
+    fn fail_all(&self, reason: &str) {
+        let drained: Vec<(String, ResponseSender)> = match self.entries.lock() {
+            Ok(mut entries) => entries.drain().collect(),
+            Err(_) => return,
+        };
+        for (id, sender) in drained {
+            let _ = sender.send(Err(anyhow!("Request {id} failed: {reason}")));
+        }
+    }
+}
+
+#[derive(Clone)]
+struct OutboundLink {
+    sender: RequestSender,
+    pending: PendingRequests,
+}
+
+impl OutboundLink {
+    async fn request(
+        &self,
+        frame: GatewayRequestFrame,
+        timeout: StdDuration,
+    ) -> Result<GatewayResponsePayload> {
+        let payload = frame.to_json()?;
+        let response = self.pending.register(&frame.id)?;
+        if self.sender.send(payload).await.is_err() {
+            self.pending.take(&frame.id);
+            return Err(anyhow!("Failed to send request: connection is closed"));
+        }
+        match tokio::time::timeout(timeout, response).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(anyhow!(
+                "Request {} failed: gateway connection closed",
+                frame.id
+            )),
+            Err(_) => {
+                self.pending.take(&frame.id);
+                Err(anyhow!(
+                    "Request {} timed out after {}ms",
+                    frame.id,
+                    timeout.as_millis()
+                ))
+            }
+        }
+    }
+}
+
+const EVENT_BROADCAST_CAPACITY: usize = 1024;
+
+struct ConnectionEntry {
+    id: u64,
+    url: String,
+    link: Option<OutboundLink>,
+    events: broadcast::Sender<GatewayEvent>,
+    stop: watch::Sender<Option<String>>,
+    outbox: Option<Arc<Outbox>>,
+    inbound: Option<Arc<InboundQueue>>,
+    compression: Option<Arc<CompressionCounters>>,
+    output_batch_window: StdDuration,
+    output_batch_bytes: u32,
+}
+
+static CONNECTIONS: OnceLock<Mutex<HashMap<String, ConnectionEntry>>> = OnceLock::new();
+static CONNECTION_COUNTER: AtomicU64 = AtomicU64::new(1);
+static CONNECTION_STATES: OnceLock<Mutex<HashMap<String, watch::Sender<ConnectionState>>>> =
+    OnceLock::new();
+
+fn connections() -> &'static Mutex<HashMap<String, ConnectionEntry>> {
+    CONNECTIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// State channels outlive individual connections so the UI can watch a handle
+/// before `connect_to_gateway` runs and see how the last connection ended.
+fn connection_state(handle: &str) -> watch::Sender<ConnectionState> {
+    let states = CONNECTION_STATES.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut states = states.lock().unwrap_or_else(PoisonError::into_inner);
+    states
+        .entry(handle.to_string())
+        .or_insert_with(|| watch::channel(ConnectionState::Idle).0)
+        .clone()
+}
 
-type RequestSender = tokio::sync::mpsc::UnboundedSender<String>;
+fn register_connection(handle: &str, url: &str) -> Result<ConnectionRegistration> {
+    let mut connections = connections()
+        .lock()
+        .map_err(|_| anyhow!("connection registry poisoned"))?;
+    if connections.contains_key(handle) {
+        return Err(anyhow!("Gateway connection {handle} is already open"));
+    }
+    let id = CONNECTION_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let (events, _) = broadcast::channel(EVENT_BROADCAST_CAPACITY);
+    let (stop, stop_signal) = watch::channel(None);
+    connections.insert(
+        handle.to_string(),
+        ConnectionEntry {
+            id,
+            url: url.to_string(),
+            link: None,
+            events: events.clone(),
+            stop,
+            outbox: None,
+            inbound: None,
+            compression: None,
+            output_batch_window: StdDuration::ZERO,
+            output_batch_bytes: 0,
+        },
+    );
+    Ok(ConnectionRegistration {
+        handle: handle.to_string(),
+        id,
+        events,
+        state: connection_state(handle),
+        stop: stop_signal,
+        outbox: None,
+    })
+}
 
-static OUTBOUND_REQUEST_SENDER: OnceLock<Mutex<Option<RequestSender>>> = OnceLock::new();
+pub(crate) fn subscribe_gateway_events(handle: &str) -> Result<GatewayEventSubscription> {
+    let connections = connections()
+        .lock()
+        .map_err(|_| anyhow!("connection registry poisoned"))?;
+    connections
+        .get(handle)
+        .map(|entry| GatewayEventSubscription {
+            handle: handle.to_string(),
+            events: entry.events.subscribe(),
+        })
+        .ok_or_else(|| anyhow!("Gateway connection {handle} is not open"))
+}
 
-fn outbound_request_sender_slot() -> &'static Mutex<Option<RequestSender>> {
-    OUTBOUND_REQUEST_SENDER.get_or_init(|| Mutex::new(None))
+/// A broadcast subscription whose missed events show up in the connection's
+/// backpressure stats.
+pub(crate) struct GatewayEventSubscription {
+    handle: String,
+    events: broadcast::Receiver<GatewayEvent>,
 }
 
-fn register_outbound_request_sender(sender: RequestSender) -> OutboundRequestSenderGuard {
-    let slot = outbound_request_sender_slot();
-    if let Ok(mut guard) = slot.lock() {
-        *guard = Some(sender);
+impl GatewayEventSubscription {
+    pub(crate) async fn recv(&mut self) -> std::result::Result<GatewayEvent, RecvError> {
+        let event = self.events.recv().await;
+        if let Err(RecvError::Lagged(skipped)) = &event {
+            if let Ok(queue) = try_get_inbound_queue(&self.handle) {
+                queue.count_lagged(*skipped);
+            }
+        }
+        event
     }
-    OutboundRequestSenderGuard {}
 }
 
-fn try_get_outbound_request_sender() -> Option<RequestSender> {
-    outbound_request_sender_slot()
+/// A batcher with the connection's output batching settings, for subscribers that
+/// deliver output themselves.
+pub(crate) fn output_batcher(handle: &str) -> Result<OutputBatcher> {
+    let connections = connections()
         .lock()
-        .ok()
-        .and_then(|guard| guard.clone())
+        .map_err(|_| anyhow!("connection registry poisoned"))?;
+    connections
+        .get(handle)
+        .map(|entry| OutputBatcher::new(entry.output_batch_window, entry.output_batch_bytes))
+        .ok_or_else(|| anyhow!("Gateway connection {handle} is not open"))
 }
 
-struct OutboundRequestSenderGuard {}
+fn try_get_outbox(handle: &str) -> Option<Arc<Outbox>> {
+    let connections = connections().lock().ok()?;
+    connections.get(handle)?.outbox.clone()
+}
+
+fn try_get_inbound_queue(handle: &str) -> Result<Arc<InboundQueue>> {
+    let connections = connections()
+        .lock()
+        .map_err(|_| anyhow!("connection registry poisoned"))?;
+    connections
+        .get(handle)
+        .and_then(|entry| entry.inbound.clone())
+        .ok_or_else(|| anyhow!("Gateway connection {handle} is not open"))
+}
+
+fn try_get_outbound_link(handle: &str) -> Result<OutboundLink> {
+    let connections = connections()
+        .lock()
+        .map_err(|_| anyhow!("connection registry poisoned"))?;
+    let entry = connections
+        .get(handle)
+        .ok_or_else(|| anyhow!("Gateway connection {handle} is not open"))?;
+    entry
+        .link
+        .clone()
+        .ok_or_else(|| anyhow!("Gateway {handle} is not connected"))
+}
+
+struct ConnectionRegistration {
+    handle: String,
+    id: u64,
+    events: broadcast::Sender<GatewayEvent>,
+    state: watch::Sender<ConnectionState>,
+    stop: watch::Receiver<Option<String>>,
+    outbox: Option<Arc<Outbox>>,
+}
+
+impl ConnectionRegistration {
+    /// Events reach broadcast subscribers immediately and the sink through a bounded
+    /// queue drained by its own task.
+    fn event_sink<S>(&self, sink: S, config: &ConnectionConfig) -> ConnectionSink
+    where
+        S: EventSink + Send + 'static,
+    {
+        let queue = Arc::new(InboundQueue::new(InboundLimits {
+            capacity: config.inbound_capacity,
+            logs: config.logs_policy,
+            output: config.output_policy,
+            events: config.event_policy,
+        }));
+        let window = duration_to_std(config.output_batch_window);
+        self.update(|entry| {
+            entry.inbound = Some(Arc::clone(&queue));
+            entry.output_batch_window = window;
+            entry.output_batch_bytes = config.output_batch_bytes;
+        });
+        let batcher = OutputBatcher::new(window, config.output_batch_bytes);
+        tokio::spawn(dispatch_events(Arc::clone(&queue), sink, batcher));
+        ConnectionSink {
+            queue,
+            events: self.events.clone(),
+        }
+    }
+
+    fn update(&self, update: impl FnOnce(&mut ConnectionEntry)) {
+        if let Ok(mut connections) = connections().lock() {
+            if let Some(entry) = connections.get_mut(&self.handle) {
+                if entry.id == self.id {
+                    update(entry);
+                }
+            }
+        }
+    }
+
+    fn enable_outbox(&mut self, config: Option<&OutboxConfig>) -> Result<()> {
+        let Some(config) = config else {
+            return Ok(());
+        };
+        let outbox = Arc::new(Outbox::open(config, &self.handle)?);
+        self.update(|entry| entry.outbox = Some(Arc::clone(&outbox)));
+        self.outbox = Some(outbox);
+        Ok(())
+    }
+
+    fn track_compression(&self, counters: Option<Arc<CompressionCounters>>) {
+        self.update(|entry| entry.compression = counters);
+    }
+
+    fn stop_signal(&self) -> watch::Receiver<Option<String>> {
+        self.stop.clone()
+    }
+
+    fn set_state(&self, state: ConnectionState) {
+        self.state.send_if_modified(|current| {
+            if *current == state {
+                return false;
+            }
+            *current = state;
+            true
+        });
+    }
+
+    fn attach(&self, link: OutboundLink) -> OutboundLinkGuard<'_> {
+        let pending = link.pending.clone();
+        self.update(|entry| entry.link = Some(link));
+        OutboundLinkGuard {
+            registration: self,
+            pending,
+        }
+    }
+}
 
-impl Drop for OutboundRequestSenderGuard {
+impl Drop for ConnectionRegistration {
     fn drop(&mut self) {
-        if let Ok(mut guard) = outbound_request_sender_slot().lock() {
-            *guard = None;
+        self.state.send_if_modified(|current| {
+            if matches!(
+                current,
+                ConnectionState::Idle | ConnectionState::Failed { .. }
+            ) {
+                return false;
+            }
+            *current = ConnectionState::Idle;
+            true
+        });
+        if let Ok(mut connections) = connections().lock() {
+            if connections.get(&self.handle).map(|entry| entry.id) == Some(self.id) {
+                connections.remove(&self.handle);
+            }
         }
     }
 }
 
+struct OutboundLinkGuard<'a> {
+    registration: &'a ConnectionRegistration,
+    pending: PendingRequests,
+}
+
+impl OutboundLinkGuard<'_> {
+    fn close(&self, reason: &str) {
+        self.registration.update(|entry| entry.link = None);
+        self.pending.fail_all(reason);
+    }
+}
+
+impl Drop for OutboundLinkGuard<'_> {
+    fn drop(&mut self) {
+        self.close("gateway connection closed");
+    }
+}
+
 pub struct GatewayClient {
     pub url: String,
 }
@@ -70,6 +401,17 @@ impl GatewayClient {
         Self { url }
     }
 
+    /// A client for the gateway behind an open connection handle.
+    pub(crate) fn for_connection(handle: &str) -> Result<Self> {
+        let connections = connections()
+            .lock()
+            .map_err(|_| anyhow!("connection registry poisoned"))?;
+        connections
+            .get(handle)
+            .map(|entry| Self::new(entry.url.clone()))
+            .ok_or_else(|| anyhow!("Gateway connection {handle} is not open"))
+    }
+
     #[frb(sync)]
     pub fn get_url(&self) -> String {
         self.url.clone()
@@ -491,21 +833,113 @@ impl GatewayClient {
     }
 }
 
-pub fn send_gateway_request_frame(frame_json: String) -> Result<()> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestDelivery {
+    /// Handed to the live session's writer; lost if the session drops first.
+    Sent,
+    /// Held in the outbox until the gateway answers it; `OutboxDelivered` or
+    /// `OutboxExpired` follows with its id.
+    Queued,
+}
+
+/// Agent turns and system events go through the connection's outbox, when it has
+/// one, so they survive an unreachable gateway and a session dropped mid-send.
+pub fn send_gateway_request_frame(handle: String, frame_json: String) -> Result<RequestDelivery> {
+    let frame = parse_request_frame(&frame_json)?;
+    if Outbox::is_queueable(&frame) {
+        if let Some(outbox) = try_get_outbox(&handle) {
+            outbox.push(&frame, unix_time_ms())?;
+            return Ok(RequestDelivery::Queued);
+        }
+    }
+    let link = try_get_outbound_link(&handle)?;
+    let payload = frame.to_json()?;
+    link.sender
+        .try_send(payload)
+        .map(|()| RequestDelivery::Sent)
+        .map_err(|error| match error {
+            TrySendError::Full(_) => anyhow!("Outbound queue is full"),
+            TrySendError::Closed(_) => anyhow!("Failed to send request: connection is closed"),
+        })
+}
+
+/// Closes the connection with a normal Close frame, fails its pending requests and
+/// ends the `connect_to_gateway` task, even while it is waiting to reconnect.
+pub fn disconnect_gateway(handle: String, reason: String) -> Result<()> {
+    let connections = connections()
+        .lock()
+        .map_err(|_| anyhow!("connection registry poisoned"))?;
+    let entry = connections
+        .get(&handle)
+        .ok_or_else(|| anyhow!("Gateway connection {handle} is not open"))?;
+    entry.stop.send_replace(Some(reason));
+    Ok(())
+}
+
+/// While paused, inbound events collect in the connection's queue and its
+/// backpressure policies decide what happens once the queue is full.
+pub fn set_gateway_events_paused(handle: String, paused: bool) -> Result<()> {
+    try_get_inbound_queue(&handle)?.set_paused(paused);
+    Ok(())
+}
+
+pub fn gateway_backpressure_stats(handle: String) -> Result<BackpressureStats> {
+    Ok(try_get_inbound_queue(&handle)?.stats())
+}
+
+/// All zero when `ConnectionConfig::deflate` is unset.
+pub fn gateway_compression_stats(handle: String) -> Result<CompressionStats> {
+    let connections = connections()
+        .lock()
+        .map_err(|_| anyhow!("connection registry poisoned"))?;
+    let entry = connections
+        .get(&handle)
+        .ok_or_else(|| anyhow!("Gateway connection {handle} is not open"))?;
+    Ok(entry
+        .compression
+        .as_ref()
+        .map(|counters| counters.stats())
+        .unwrap_or_default())
+}
+
+pub async fn gateway_request(
+    handle: String,
+    frame_json: String,
+    timeout_ms: Option<u64>,
+) -> Result<GatewayResponsePayload> {
+    let frame = parse_request_frame(&frame_json)?;
+    let link = try_get_outbound_link(&handle)?;
+    let timeout = timeout_ms
+        .map(StdDuration::from_millis)
+        .unwrap_or(DEFAULT_REQUEST_TIMEOUT);
+    link.request(frame, timeout).await
+}
+
+fn parse_request_frame(frame_json: &str) -> Result<GatewayRequestFrame> {
     let frame: GatewayRequestFrame = serde_json::from_str(frame_json.trim())?;
     if frame.frame_type != "req" {
-        return Err(anyhow::anyhow!(
+        return Err(anyhow!(
             "Only GatewayRequestFrame payloads with type=req can be sent"
         ));
     }
+    Ok(frame)
+}
 
-    let sender = try_get_outbound_request_sender()
-        .ok_or_else(|| anyhow::anyhow!("Gateway is not connected"))?;
-    let payload = frame.to_json()?;
-    sender
-        .send(payload)
-        .map_err(|_| anyhow::anyhow!("Failed to send request: connection is closed"))?;
-    Ok(())
+fn response_result(
+    ok: bool,
+    payload: GatewayResponsePayload,
+    error: GatewayError,
+) -> Result<GatewayResponsePayload> {
+    if ok {
+        return Ok(payload);
+    }
+    let message = error
+        .message
+        .unwrap_or_else(|| "gateway request failed".to_string());
+    match error.code {
+        Some(code) => Err(anyhow!("{code}: {message}")),
+        None => Err(anyhow!(message)),
+    }
 }
 
 fn next_request_id(prefix: &str) -> String {
@@ -599,18 +1033,286 @@ fn encode_frame_bytes(frame_bytes: &[u8], compress: bool) -> Result<(String, Opt
     }
 }
 
-pub async fn connect_to_gateway(url: String, sink: StreamSink<GatewayEvent>) -> Result<()> {
-    connect_to_gateway_with_sink(url, sink, ConnectionConfig::default()).await
+/// Runs the connection until it ends for good. A rejected handshake, a certificate
+/// failure or running out of attempts emits its typed event and then returns the
+/// error; `disconnect_gateway` and a closed event stream return `Ok`.
+pub async fn connect_to_gateway(
+    handle: String,
+    url: String,
+    options: HandshakeOptions,
+    config: Option<ConnectionConfig>,
+    sink: StreamSink<GatewayEvent>,
+) -> Result<()> {
+    let mut connector = match config.as_ref().and_then(|config| config.tls.as_ref()) {
+        Some(tls) => DefaultConnector::with_tls(tls)?,
+        None => DefaultConnector::default(),
+    };
+    if let Some(proxy) = config.as_ref().and_then(|config| config.proxy.clone()) {
+        connector = connector.with_proxy(proxy);
+    }
+    if let Some(deflate) = config.as_ref().and_then(|config| config.deflate.clone()) {
+        connector = connector.with_deflate(deflate)?;
+    }
+    connect_to_gateway_with_connector(handle, url, options, config, sink, &connector).await
 }
 
-#[derive(Clone, Copy)]
+/// Same as `connect_to_gateway`, for Rust embedders that supply their own transport.
 #[frb(ignore)]
-pub(crate) struct ConnectionConfig {
-    pub(crate) backoff_base: Duration,
-    pub(crate) max_backoff: Duration,
-    pub(crate) heartbeat_interval: Duration,
-    pub(crate) heartbeat_timeout: Duration,
-    pub(crate) max_sessions: Option<u32>,
+pub async fn connect_to_gateway_with_connector<C: Connector>(
+    handle: String,
+    url: String,
+    options: HandshakeOptions,
+    config: Option<ConnectionConfig>,
+    sink: StreamSink<GatewayEvent>,
+    connector: &C,
+) -> Result<()> {
+    connect_to_gateway_with_sink_and_connector(
+        handle,
+        url,
+        sink,
+        config.unwrap_or_default(),
+        options,
+        connector,
+    )
+    .await
+}
+
+const GATEWAY_PROTOCOL_VERSION: u32 = 3;
+const DEFAULT_CLIENT_ID: &str = "openclaw-mobile";
+const DEFAULT_ROLE: &str = "operator";
+
+#[derive(Debug, Clone, Default)]
+pub struct HandshakeOptions {
+    pub client_id: Option<String>,
+    pub client_version: Option<String>,
+    pub platform: Option<String>,
+    pub display_name: Option<String>,
+    pub instance_id: Option<String>,
+    pub identity_dir: Option<String>,
+    pub role: Option<String>,
+    pub scopes: Vec<String>,
+    pub token: Option<String>,
+    pub password: Option<String>,
+    pub locale: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl HandshakeOptions {
+    fn requested_role(&self) -> String {
+        self.role
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ROLE.to_string())
+    }
+}
+
+struct Handshake {
+    options: HandshakeOptions,
+    identity: Option<DeviceIdentity>,
+    token_store: Option<Arc<dyn DeviceTokenStore>>,
+    token_key: String,
+    /// A token the gateway refused, skipped even if the store fails to clear it.
+    rejected_token: Mutex<Option<String>>,
+}
+
+impl Handshake {
+    fn new(url: &str, options: HandshakeOptions) -> Result<Self> {
+        let identity = options
+            .identity_dir
+            .as_deref()
+            .map(|dir| DeviceIdentity::load_or_create(Path::new(dir)))
+            .transpose()?;
+        let token_store = match (&identity, registered_device_token_store()) {
+            (None, _) => None,
+            (Some(_), Some(store)) => Some(store),
+            (Some(_), None) => options.identity_dir.as_deref().map(|dir| {
+                Arc::new(FileTokenStore::in_directory(Path::new(dir))) as Arc<dyn DeviceTokenStore>
+            }),
+        };
+        let token_key = device_token_key(url, &options.requested_role());
+        Ok(Self {
+            options,
+            identity,
+            token_store,
+            token_key,
+            rejected_token: Mutex::new(None),
+        })
+    }
+
+    async fn stored_device_token(&self) -> Option<String> {
+        let store = self.token_store.as_ref()?;
+        let token = store.load(self.token_key.clone()).await.ok().flatten()?;
+        let rejected = self.rejected_token.lock().ok()?;
+        (rejected.as_deref() != Some(token.as_str())).then_some(token)
+    }
+
+    async fn save_device_token(&self, token: String) -> Result<()> {
+        if let Ok(mut rejected) = self.rejected_token.lock() {
+            if rejected.as_deref() == Some(token.as_str()) {
+                *rejected = None;
+            }
+        }
+        match &self.token_store {
+            Some(store) => store.save(self.token_key.clone(), token).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn forget_device_token(&self, token: &str) {
+        if let Ok(mut rejected) = self.rejected_token.lock() {
+            *rejected = Some(token.to_string());
+        }
+        if let Some(store) = &self.token_store {
+            let _ = store.clear(self.token_key.clone()).await;
+        }
+    }
+
+    fn connect_params(
+        &self,
+        challenge: &ConnectChallenge,
+        device_token: Option<&str>,
+        last_seq: Option<u64>,
+    ) -> ConnectParams {
+        let options = &self.options;
+        let client_id = options
+            .client_id
+            .clone()
+            .unwrap_or_else(|| DEFAULT_CLIENT_ID.to_string());
+        let client_mode = "ui";
+        let role = options.requested_role();
+        let client = ConnectClient {
+            id: client_id.clone(),
+            version: options.client_version.clone(),
+            platform: options.platform.clone(),
+            mode: Some(client_mode.to_string()),
+            display_name: options.display_name.clone(),
+            instance_id: options.instance_id.clone(),
+            ..ConnectClient::default()
+        };
+        let (token, password) = match device_token {
+            Some(device_token) => (Some(device_token.to_string()), None),
+            None => (options.token.clone(), options.password.clone()),
+        };
+        let auth = if token.is_some() || password.is_some() {
+            Some(ConnectAuth {
+                token: token.clone(),
+                password,
+                extra: BTreeMap::new(),
+            })
+        } else {
+            None
+        };
+        let device = self.identity.as_ref().map(|identity| {
+            identity.sign_challenge(
+                challenge,
+                DeviceAuthPayload {
+                    client_id: &client_id,
+                    client_mode,
+                    role: &role,
+                    scopes: &options.scopes,
+                    token: token.as_deref(),
+                },
+            )
+        });
+
+        ConnectParams {
+            min_protocol: Some(GATEWAY_PROTOCOL_VERSION),
+            max_protocol: Some(GATEWAY_PROTOCOL_VERSION),
+            client: Some(client),
+            role: Some(role),
+            scopes: options.scopes.clone(),
+            auth,
+            locale: options.locale.clone(),
+            user_agent: options.user_agent.clone(),
+            device,
+            last_seq,
+            ..ConnectParams::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionState {
+    Idle,
+    Connecting {
+        attempt: u32,
+    },
+    Handshaking,
+    Ready {
+        protocol: u32,
+        role: String,
+        /// Subprotocol the gateway picked from `ConnectionConfig::subprotocols`.
+        subprotocol: Option<String>,
+    },
+    Backoff {
+        /// Unix time in milliseconds.
+        next_retry_at_ms: i64,
+        reason: String,
+    },
+    Restarting,
+    Failed {
+        fatal: bool,
+        reason: String,
+    },
+}
+
+pub async fn watch_connection_state(
+    handle: String,
+    sink: StreamSink<ConnectionState>,
+) -> Result<()> {
+    let mut states = connection_state(&handle).subscribe();
+    loop {
+        let state = states.borrow_and_update().clone();
+        if sink.add(state).is_err() || states.changed().await.is_err() {
+            return Ok(());
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectionConfig {
+    pub backoff_base: Duration,
+    pub max_backoff: Duration,
+    /// Sleep a random duration in `[0, backoff]` instead of the full backoff.
+    pub jitter: bool,
+    /// Consecutive failed attempts before giving up; `None` retries forever.
+    pub max_attempts: Option<u32>,
+    pub connect_timeout: Duration,
+    pub heartbeat_interval: Duration,
+    pub heartbeat_timeout: Duration,
+    pub handshake_timeout: Duration,
+    pub max_sessions: Option<u32>,
+    pub restart_probe_interval: Duration,
+    pub restart_probe_window: Duration,
+    /// Longest wait honored for a shutdown notice's `restartExpectedMs`.
+    pub max_restart_wait: Duration,
+    /// Queue agent turns and system events while disconnected; `None` disables it.
+    pub outbox: Option<OutboxConfig>,
+    /// Events buffered between the socket reader and the Dart stream.
+    pub inbound_capacity: u32,
+    /// Requests buffered between senders and the socket writer.
+    pub outbound_capacity: u32,
+    pub logs_policy: BackpressurePolicy,
+    /// Applies to `stream.data` and exec output.
+    pub output_policy: BackpressurePolicy,
+    /// Applies to every other gateway event and raw message.
+    pub event_policy: BackpressurePolicy,
+    /// How long consecutive output chunks of one stream are held to merge them
+    /// into a single event; zero delivers every chunk on its own.
+    pub output_batch_window: Duration,
+    /// Delivers a merged batch early once its data reaches this many bytes.
+    pub output_batch_bytes: u32,
+    /// Extra CA certificates, key pins and trust-on-first-use for `wss://` gateways.
+    pub tls: Option<TlsOptions>,
+    /// HTTP `CONNECT` or SOCKS5 proxy to dial the gateway through. Terminal streams
+    /// ride the gateway connection, so they use it too.
+    pub proxy: Option<ProxyConfig>,
+    /// Extra headers on the WebSocket upgrade request, such as `Authorization`,
+    /// `User-Agent` or `Origin`.
+    pub upgrade_headers: Vec<UpgradeHeader>,
+    /// Offered in `Sec-WebSocket-Protocol`; the gateway must pick one of them.
+    pub subprotocols: Vec<String>,
+    /// Offers permessage-deflate on the gateway socket.
+    pub deflate: Option<DeflateConfig>,
 }
 
 impl Default for ConnectionConfig {
@@ -618,15 +1320,42 @@ impl Default for ConnectionConfig {
         Self {
             backoff_base: Duration::milliseconds(500),
             max_backoff: Duration::seconds(10),
+            jitter: true,
+            max_attempts: None,
+            connect_timeout: Duration::seconds(15),
             heartbeat_interval: Duration::seconds(10),
             heartbeat_timeout: Duration::seconds(30),
+            handshake_timeout: Duration::seconds(10),
             max_sessions: None,
+            restart_probe_interval: Duration::milliseconds(250),
+            restart_probe_window: Duration::seconds(15),
+            max_restart_wait: Duration::seconds(60),
+            outbox: None,
+            inbound_capacity: 1024,
+            outbound_capacity: 256,
+            logs_policy: BackpressurePolicy::DropOldest,
+            output_policy: BackpressurePolicy::Coalesce,
+            event_policy: BackpressurePolicy::DropOldest,
+            output_batch_window: Duration::milliseconds(8),
+            output_batch_bytes: 64 * 1024,
+            tls: None,
+            proxy: None,
+            upgrade_headers: Vec::new(),
+            subprotocols: Vec::new(),
+            deflate: None,
         }
     }
 }
 
+#[frb(ignore)]
 trait EventSink {
+    #[frb(ignore)]
     fn add_event(&self, event: GatewayEvent) -> bool;
+
+    /// Waits until the sink can take `event` without dropping it.
+    fn reserve<'a>(&'a self, _event: &'a GatewayEvent) -> BoxFuture<'a, ()> {
+        Box::pin(async {})
+    }
 }
 
 impl EventSink for StreamSink<GatewayEvent> {
@@ -635,184 +1364,334 @@ impl EventSink for StreamSink<GatewayEvent> {
     }
 }
 
-#[cfg(test)]
 #[frb(ignore)]
-pub(crate) trait Connector {
+struct ConnectionSink {
+    queue: Arc<InboundQueue>,
+    events: broadcast::Sender<GatewayEvent>,
+}
+
+impl EventSink for ConnectionSink {
+    fn add_event(&self, event: GatewayEvent) -> bool {
+        let _ = self.events.send(event.clone());
+        self.queue.push(event)
+    }
+
+    fn reserve<'a>(&'a self, event: &'a GatewayEvent) -> BoxFuture<'a, ()> {
+        Box::pin(self.queue.reserve(event))
+    }
+}
+
+impl Drop for ConnectionSink {
+    fn drop(&mut self) {
+        self.queue.close_input();
+    }
+}
+
+async fn dispatch_events<S: EventSink>(
+    queue: Arc<InboundQueue>,
+    sink: S,
+    mut batcher: OutputBatcher,
+) {
+    loop {
+        let next = match batcher.deadline() {
+            Some(deadline) => match tokio::time::timeout_at(deadline, queue.next()).await {
+                Ok(next) => next,
+                Err(_) => {
+                    let ready = batcher.flush();
+                    if !deliver_events(&queue, &sink, ready) {
+                        return;
+                    }
+                    continue;
+                }
+            },
+            None => queue.next().await,
+        };
+        let ready = match next {
+            Some(event) => batcher.push(event),
+            None => {
+                deliver_events(&queue, &sink, batcher.flush());
+                return;
+            }
+        };
+        if !deliver_events(&queue, &sink, ready) {
+            return;
+        }
+    }
+}
+
+fn deliver_events<S: EventSink>(
+    queue: &InboundQueue,
+    sink: &S,
+    events: impl IntoIterator<Item = GatewayEvent>,
+) -> bool {
+    for event in events {
+        if !sink.add_event(event) {
+            queue.close_sink();
+            return false;
+        }
+    }
+    true
+}
+
+/// Opens the WebSocket for each connection attempt. The reconnect, handshake and
+/// heartbeat logic runs on top of whatever stream the connector returns.
+#[frb(ignore)]
+pub trait Connector: Send + Sync {
     type Stream: AsyncRead + AsyncWrite + Unpin + Send + 'static;
-    fn connect(&self, url: String) -> BoxFuture<'static, Result<WebSocketStream<Self::Stream>>>;
+    #[frb(ignore)]
+    fn connect(
+        &self,
+        request: Request,
+    ) -> BoxFuture<'static, Result<(WebSocketStream<Self::Stream>, Response)>>;
+
+    /// Byte counters of a connector that compresses frames, for
+    /// `gateway_compression_stats`.
+    #[frb(ignore)]
+    fn compression(&self) -> Option<Arc<CompressionCounters>> {
+        None
+    }
 }
 
-#[cfg(test)]
+/// `ws://` and `wss://` connections over TCP, optionally through a proxy, with
+/// permessage-deflate applied below tungstenite.
 #[frb(ignore)]
-pub(crate) struct DefaultConnector {}
+#[derive(Debug, Default, Clone)]
+pub struct DefaultConnector {
+    tls: Option<Arc<TlsClient>>,
+    proxy: Option<ProxyConfig>,
+    deflate: Option<DeflateConfig>,
+    counters: Arc<CompressionCounters>,
+}
+
+impl DefaultConnector {
+    /// Verifies `wss://` servers against `options` instead of only the system roots.
+    pub fn with_tls(options: &TlsOptions) -> Result<Self> {
+        Ok(Self {
+            tls: Some(Arc::new(TlsClient::new(options)?)),
+            ..Self::default()
+        })
+    }
+
+    /// Dials the gateway through an HTTP `CONNECT` or SOCKS5 proxy.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Offers permessage-deflate in every upgrade request.
+    pub fn with_deflate(mut self, deflate: DeflateConfig) -> Result<Self> {
+        deflate.validate()?;
+        self.deflate = Some(deflate);
+        Ok(self)
+    }
+}
 
-#[cfg(test)]
 impl Connector for DefaultConnector {
-    type Stream = tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>;
+    type Stream = DeflateStream<MaybeTlsStream<TcpStream>>;
 
-    fn connect(&self, url: String) -> BoxFuture<'static, Result<WebSocketStream<Self::Stream>>> {
+    fn connect(
+        &self,
+        mut request: Request,
+    ) -> BoxFuture<'static, Result<(WebSocketStream<Self::Stream>, Response)>> {
+        let tls = self.tls.clone();
+        let proxy = self.proxy.clone();
+        let deflate = self.deflate.clone();
+        let counters = self.compression();
         Box::pin(async move {
-            let (ws_stream, _) = connect_async(url).await?;
-            Ok(ws_stream)
+            if let Some(deflate) = &deflate {
+                request.headers_mut().insert(
+                    SEC_WEBSOCKET_EXTENSIONS,
+                    HeaderValue::from_str(&deflate.offer())?,
+                );
+            }
+            let (host, port) = target_address(request.uri())?;
+            let tcp = match proxy {
+                Some(proxy) => connect_via_proxy(&proxy, &host, port).await?,
+                None => TcpStream::connect((host.as_str(), port)).await?,
+            };
+            let (stream, tls) = if request.uri().scheme_str() == Some("wss") {
+                let tls = match tls {
+                    Some(tls) => tls,
+                    None => Arc::new(TlsClient::new(&TlsOptions::default())?),
+                };
+                let server_name = ServerName::try_from(host.as_str())
+                    .map_err(|_| anyhow!("Invalid TLS server name {host}"))?
+                    .to_owned();
+                match TlsConnector::from(tls.config(port))
+                    .connect(server_name, tcp)
+                    .await
+                {
+                    Ok(stream) => (MaybeTlsStream::Rustls(stream), Some(tls)),
+                    Err(error) => return Err(tls.explain(tungstenite::Error::Io(error))),
+                }
+            } else {
+                (MaybeTlsStream::Plain(tcp), None)
+            };
+            let stream = DeflateStream::client(stream, deflate, counters);
+            match (client_async_with_config(request, stream, None).await, tls) {
+                (Ok(upgraded), _) => Ok(upgraded),
+                (Err(error), Some(tls)) => Err(tls.explain(error)),
+                (Err(error), None) => Err(error.into()),
+            }
         })
     }
+
+    fn compression(&self) -> Option<Arc<CompressionCounters>> {
+        self.deflate.as_ref().map(|_| Arc::clone(&self.counters))
+    }
+}
+
+fn upgrade_request(url: &str, config: &ConnectionConfig) -> Result<Request> {
+    let mut request = url.into_client_request()?;
+    let headers = request.headers_mut();
+    for header in &config.upgrade_headers {
+        let name = HeaderName::from_bytes(header.name.as_bytes())
+            .map_err(|e| anyhow!("Invalid upgrade header name {:?}: {e}", header.name))?;
+        let value = HeaderValue::from_str(&header.value)
+            .map_err(|e| anyhow!("Invalid value for upgrade header {}: {e}", header.name))?;
+        headers.append(name, value);
+    }
+    if !config.subprotocols.is_empty() {
+        let offered = HeaderValue::from_str(&config.subprotocols.join(", "))
+            .map_err(|e| anyhow!("Invalid subprotocol list: {e}"))?;
+        headers.insert(SEC_WEBSOCKET_PROTOCOL, offered);
+    }
+    Ok(request)
+}
+
+fn response_headers(response: &Response) -> Vec<UpgradeHeader> {
+    response
+        .headers()
+        .iter()
+        .map(|(name, value)| UpgradeHeader {
+            name: name.to_string(),
+            value: String::from_utf8_lossy(value.as_bytes()).into_owned(),
+        })
+        .collect()
 }
 
-async fn connect_to_gateway_with_sink<S: EventSink>(
+async fn connect_to_gateway_with_sink_and_connector<S: EventSink + Send + 'static, C: Connector>(
+    handle: String,
     url: String,
     sink: S,
     config: ConnectionConfig,
+    options: HandshakeOptions,
+    connector: &C,
 ) -> Result<()> {
     let backoff_base = duration_to_std(config.backoff_base);
     let max_backoff = duration_to_std(config.max_backoff);
-    let heartbeat_interval = duration_to_std(config.heartbeat_interval);
-    let heartbeat_timeout = duration_to_std(config.heartbeat_timeout);
+    let mut registration = register_connection(&handle, &url)?;
+    registration.track_compression(connector.compression());
+    let sink = registration.event_sink(sink, &config);
+    registration.enable_outbox(config.outbox.as_ref())?;
+    if let Some(outbox) = &registration.outbox {
+        if !report_expired(&sink, outbox) {
+            return Ok(());
+        }
+    }
+    let handshake = Handshake::new(&url, options)?;
+    let request = upgrade_request(&url, &config)?;
+    let mut sequence = SequenceTracker::default();
     let mut backoff = backoff_base;
+    let restart_probe_interval = duration_to_std(config.restart_probe_interval);
+    let restart_probe_window = duration_to_std(config.restart_probe_window);
+    let connect_timeout = duration_to_std(config.connect_timeout);
     let mut attempt: u32 = 0;
+    let mut failures: u32 = 0;
     let mut sessions_completed: u32 = 0;
+    let mut restart_probe_until: Option<Instant> = None;
+    let mut stop = registration.stop_signal();
 
     loop {
-        match connect_async(url.clone()).await {
-            Ok((ws_stream, _)) => {
-                attempt = 0;
-                backoff = backoff_base;
-                if !try_emit(&sink, GatewayEvent::Connected) {
-                    return Ok(());
-                }
-
-                let (mut write, mut read) = ws_stream.split();
-                let (request_tx, mut request_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
-                let _request_sender_guard = register_outbound_request_sender(request_tx);
-                let mut heartbeat = tokio::time::interval(heartbeat_interval);
-                heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
-                let mut last_received = Instant::now();
-                let mut disconnect_reason = "Connection closed".to_string();
-
-                loop {
-                    tokio::select! {
-                        msg = read.next() => {
-                            match msg {
-                                Some(Ok(Message::Text(text))) => {
-                                    last_received = Instant::now();
-                                    let event = parse_gateway_frame(&text)
-                                        .unwrap_or_else(|| GatewayEvent::Message {
-                                            message: text.to_string(),
-                                        });
-                                    if !try_emit(&sink, event) {
-                                        return Ok(());
-                                    }
-                                }
-                                Some(Ok(Message::Ping(payload))) => {
-                                    last_received = Instant::now();
-                                    if let Err(e) = write.send(Message::Pong(payload)).await {
-                                        disconnect_reason = format!("WebSocket pong error: {e}");
-                                        if !try_emit(
-                                            &sink,
-                                            GatewayEvent::Error {
-                                                message: e.to_string(),
-                                            },
-                                        ) {
-                                            return Ok(());
-                                        }
-                                        break;
-                                    }
-                                }
-                                Some(Ok(Message::Pong(_))) => {
-                                    last_received = Instant::now();
-                                }
-                                Some(Ok(Message::Binary(data))) => {
-                                    last_received = Instant::now();
-                                    if !try_emit(
-                                        &sink,
-                                        GatewayEvent::Binary {
-                                            data: data.to_vec(),
-                                        },
-                                    ) {
-                                        return Ok(());
-                                    }
-                                }
-                                Some(Ok(Message::Close(frame))) => {
-                                    disconnect_reason = frame
-                                        .map(|f| {
-                                            if f.reason.is_empty() {
-                                                "Server closed connection".to_string()
-                                            } else {
-                                                f.reason.to_string()
-                                            }
-                                        })
-                                        .unwrap_or_else(|| "Server closed connection".to_string());
-                                    break;
-                                }
-                                Some(Err(e)) => {
-                                    disconnect_reason = format!("WebSocket error: {e}");
-                                    if !try_emit(
-                                        &sink,
-                                        GatewayEvent::Error {
-                                            message: e.to_string(),
-                                        },
-                                    ) {
-                                        return Ok(());
-                                    }
-                                    break;
-                                }
-                                None => {
-                                    disconnect_reason = "Connection closed".to_string();
-                                    break;
-                                }
-                                _ => {}
-                            }
-                        }
-                        outbound = request_rx.recv() => {
-                            match outbound {
-                                Some(payload) => {
-                                    if let Err(e) = write.send(Message::Text(payload.into())).await {
-                                        disconnect_reason = format!("Request send error: {e}");
-                                        if !try_emit(
-                                            &sink,
-                                            GatewayEvent::Error {
-                                                message: e.to_string(),
-                                            },
-                                        ) {
-                                            return Ok(());
-                                        }
-                                        break;
-                                    }
-                                }
-                                None => {
-                                    disconnect_reason = "Request channel closed".to_string();
-                                    break;
-                                }
-                            }
-                        }
-                        _ = heartbeat.tick() => {
-                            if last_received.elapsed() >= heartbeat_timeout {
-                                disconnect_reason = "Heartbeat timeout".to_string();
-                                break;
-                            }
-                            if let Err(e) = write.send(Message::Ping(Vec::new().into())).await {
-                                disconnect_reason = format!("Heartbeat send error: {e}");
-                                if !try_emit(
-                                    &sink,
-                                    GatewayEvent::Error {
-                                        message: e.to_string(),
-                                    },
-                                ) {
-                                    return Ok(());
-                                }
-                                break;
-                            }
-                        }
-                    }
+        let mut planned_restart = None;
+        let mut retry_reason = String::new();
+        // Falling back from a refused device token is not a failed attempt.
+        let mut token_fallback = false;
+        if restart_probe_until.is_none_or(|until| Instant::now() >= until) {
+            registration.set_state(ConnectionState::Connecting {
+                attempt: attempt + 1,
+            });
+        }
+        let connected = tokio::select! {
+            connected = tokio::time::timeout(connect_timeout, connector.connect(request.clone())) => {
+                match connected {
+                    Ok(result) => result,
+                    Err(_) => Err(anyhow!(
+                        "Connect timed out after {}ms",
+                        connect_timeout.as_millis()
+                    )),
+                }
+            }
+            reason = stop_requested(&mut stop) => {
+                report_stopped(&sink, &registration, Some(reason));
+                return Ok(());
+            }
+        };
+        match connected {
+            Ok((ws_stream, response)) => {
+                let (reason, established) = match run_session(
+                    ws_stream,
+                    &response,
+                    &sink,
+                    &config,
+                    &handshake,
+                    &registration,
+                    &mut sequence,
+                )
+                .await
+                {
+                    SessionEnd::Disconnected {
+                        reason,
+                        established,
+                        restart,
+                    } => {
+                        planned_restart = restart;
+                        (reason, established)
+                    }
+                    SessionEnd::Stopped { reason } => {
+                        report_stopped(&sink, &registration, Some(reason));
+                        return Ok(());
+                    }
+                    SessionEnd::DeviceTokenRejected { reason } => {
+                        token_fallback = true;
+                        (reason, false)
+                    }
+                    SessionEnd::Rejected { reason } => return Err(anyhow!(reason)),
+                    SessionEnd::SinkClosed => return Ok(()),
+                };
+                if established {
+                    attempt = 0;
+                    failures = 0;
+                    backoff = backoff_base;
+                    restart_probe_until = None;
+                } else if !token_fallback {
+                    failures = failures.saturating_add(1);
+                    registration.set_state(ConnectionState::Failed {
+                        fatal: false,
+                        reason: reason.clone(),
+                    });
                 }
 
                 if !try_emit(
                     &sink,
                     GatewayEvent::Disconnected {
-                        reason: disconnect_reason,
+                        reason: reason.clone(),
                     },
                 ) {
                     return Ok(());
                 }
+                retry_reason = reason;
+                if let Some(restart) = &planned_restart {
+                    if !try_emit(
+                        &sink,
+                        GatewayEvent::Restarting {
+                            reason: restart.reason.clone(),
+                            expected_ms: restart.window.as_millis() as u64,
+                        },
+                    ) {
+                        return Ok(());
+                    }
+                }
 
                 sessions_completed = sessions_completed.saturating_add(1);
                 if let Some(max_sessions) = config.max_sessions {
@@ -821,8 +1700,24 @@ async fn connect_to_gateway_with_sink<S: EventSink>(
                     }
                 }
             }
+            Err(e) if certificate_failure_event(&e).is_some() => {
+                registration.set_state(ConnectionState::Failed {
+                    fatal: true,
+                    reason: e.to_string(),
+                });
+                if let Some(event) = certificate_failure_event(&e) {
+                    try_emit(&sink, event);
+                }
+                return Err(e);
+            }
+            Err(_) if restart_probe_until.is_some_and(|until| Instant::now() < until) => {}
             Err(e) => {
+                failures = failures.saturating_add(1);
                 let error_message = format!("Connect failed (attempt {}): {e}", attempt + 1);
+                registration.set_state(ConnectionState::Failed {
+                    fatal: false,
+                    reason: error_message.clone(),
+                });
                 if !try_emit(
                     &sink,
                     GatewayEvent::Error {
@@ -834,210 +1729,843 @@ async fn connect_to_gateway_with_sink<S: EventSink>(
                 if !try_emit(
                     &sink,
                     GatewayEvent::Disconnected {
-                        reason: error_message,
+                        reason: error_message.clone(),
                     },
                 ) {
                     return Ok(());
                 }
+                retry_reason = error_message;
+            }
+        }
+
+        if config.max_attempts.is_some_and(|max| failures >= max) {
+            let message = format!("Gave up after {failures} failed connection attempts");
+            registration.set_state(ConnectionState::Failed {
+                fatal: true,
+                reason: message.clone(),
+            });
+            try_emit(
+                &sink,
+                GatewayEvent::Error {
+                    message: message.clone(),
+                },
+            );
+            return Err(anyhow!(message));
+        }
+
+        if let Some(outbox) = &registration.outbox {
+            if !report_expired(&sink, outbox) {
+                return Ok(());
             }
         }
 
         attempt = attempt.saturating_add(1);
-        sleep(backoff).await;
-        backoff = std::cmp::min(backoff.checked_mul(2).unwrap_or(max_backoff), max_backoff);
+        let delay = match planned_restart {
+            Some(restart) => {
+                restart_probe_until = Some(Instant::now() + restart.window + restart_probe_window);
+                registration.set_state(ConnectionState::Restarting);
+                restart.window
+            }
+            None if restart_probe_until.is_some_and(|until| Instant::now() < until) => {
+                restart_probe_interval
+            }
+            None => {
+                let delay = backoff_delay(backoff, config.jitter);
+                backoff = std::cmp::min(backoff.checked_mul(2).unwrap_or(max_backoff), max_backoff);
+                registration.set_state(ConnectionState::Backoff {
+                    next_retry_at_ms: unix_time_ms_after(delay),
+                    reason: retry_reason,
+                });
+                delay
+            }
+        };
+        tokio::select! {
+            _ = sleep(delay) => {}
+            _ = stop_requested(&mut stop) => {
+                report_stopped(&sink, &registration, None);
+                return Ok(());
+            }
+        }
     }
 }
 
-#[cfg(test)]
-async fn connect_to_gateway_with_sink_and_connector<S: EventSink, C: Connector>(
-    url: String,
-    sink: S,
-    config: ConnectionConfig,
-    connector: &C,
-) -> Result<()> {
-    let backoff_base = duration_to_std(config.backoff_base);
-    let max_backoff = duration_to_std(config.max_backoff);
-    let heartbeat_interval = duration_to_std(config.heartbeat_interval);
-    let heartbeat_timeout = duration_to_std(config.heartbeat_timeout);
-    let mut backoff = backoff_base;
-    let mut attempt: u32 = 0;
-    let mut sessions_completed: u32 = 0;
+/// Reports the end of a connection stopped by `disconnect_gateway`, along with any
+/// queued requests that expired while it waited. `None` means the session had
+/// already reported its disconnect.
+fn report_stopped<S: EventSink>(
+    sink: &S,
+    registration: &ConnectionRegistration,
+    reason: Option<String>,
+) {
+    if let Some(reason) = reason {
+        if !try_emit(sink, GatewayEvent::Disconnected { reason }) {
+            return;
+        }
+    }
+    if let Some(outbox) = &registration.outbox {
+        report_expired(sink, outbox);
+    }
+}
 
-    loop {
-        match connector.connect(url.clone()).await {
-            Ok(ws_stream) => {
-                attempt = 0;
-                backoff = backoff_base;
-                if !try_emit(&sink, GatewayEvent::Connected) {
-                    return Ok(());
-                }
+fn unix_time_ms_after(delay: StdDuration) -> i64 {
+    (SystemTime::now() + delay)
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_millis() as i64)
+        .unwrap_or_default()
+}
 
-                let (mut write, mut read) = ws_stream.split();
-                let (request_tx, mut request_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
-                let _request_sender_guard = register_outbound_request_sender(request_tx);
-                let mut heartbeat = tokio::time::interval(heartbeat_interval);
-                heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
-                let mut last_received = Instant::now();
-                let mut disconnect_reason = "Connection closed".to_string();
-
-                loop {
-                    tokio::select! {
-                        msg = read.next() => {
-                            match msg {
-                                Some(Ok(Message::Text(text))) => {
-                                    last_received = Instant::now();
-                                    let event = parse_gateway_frame(&text)
-                                        .unwrap_or_else(|| GatewayEvent::Message {
-                                            message: text.to_string(),
-                                        });
-                                    if !try_emit(&sink, event) {
-                                        return Ok(());
-                                    }
-                                }
-                                Some(Ok(Message::Ping(payload))) => {
-                                    last_received = Instant::now();
-                                    if let Err(e) = write.send(Message::Pong(payload)).await {
-                                        disconnect_reason = format!("WebSocket pong error: {e}");
-                                        if !try_emit(
-                                            &sink,
-                                            GatewayEvent::Error {
-                                                message: e.to_string(),
-                                            },
-                                        ) {
-                                            return Ok(());
-                                        }
-                                        break;
-                                    }
-                                }
-                                Some(Ok(Message::Pong(_))) => {
-                                    last_received = Instant::now();
-                                }
-                                Some(Ok(Message::Binary(data))) => {
-                                    last_received = Instant::now();
-                                    if !try_emit(
-                                        &sink,
-                                        GatewayEvent::Binary {
-                                            data: data.to_vec(),
-                                        },
-                                    ) {
-                                        return Ok(());
-                                    }
-                                }
-                                Some(Ok(Message::Close(frame))) => {
-                                    disconnect_reason = frame
-                                        .map(|f| {
-                                            if f.reason.is_empty() {
-                                                "Server closed connection".to_string()
-                                            } else {
-                                                f.reason.to_string()
-                                            }
-                                        })
-                                        .unwrap_or_else(|| "Server closed connection".to_string());
-                                    break;
+fn backoff_delay(backoff: StdDuration, jitter: bool) -> StdDuration {
+    if !jitter {
+        return backoff;
+    }
+    let mut bytes = [0u8; 8];
+    if SystemRandom::new().fill(&mut bytes).is_err() {
+        return backoff;
+    }
+    backoff.mul_f64(u64::from_le_bytes(bytes) as f64 / u64::MAX as f64)
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct SequenceTracker {
+    last_seq: Option<u64>,
+    state_version: Option<u64>,
+    new_session: bool,
+}
+
+enum SequenceCheck {
+    InOrder,
+    Duplicate,
+    Gap { expected: u64, received: u64 },
+}
+
+impl SequenceTracker {
+    fn start_session(&mut self) {
+        self.new_session = true;
+    }
+
+    fn observe(&mut self, seq: &str, state_version: &str) -> SequenceCheck {
+        let Ok(seq) = seq.parse::<u64>() else {
+            return SequenceCheck::InOrder;
+        };
+        let state_version = state_version.parse::<u64>().ok();
+        // A gateway that honors `lastSeq` replays from it, so a first event below it,
+        // or from an older state version, means the gateway restarted and numbers
+        // its events from scratch.
+        if std::mem::take(&mut self.new_session)
+            && (self.last_seq.is_some_and(|last| seq < last)
+                || state_version
+                    .is_some_and(|version| self.state_version.is_some_and(|last| version < last)))
+        {
+            self.last_seq = None;
+        }
+        if state_version.is_some() {
+            self.state_version = state_version;
+        }
+        let check = match self.last_seq {
+            Some(last) if seq <= last => return SequenceCheck::Duplicate,
+            Some(last) if seq > last + 1 => SequenceCheck::Gap {
+                expected: last + 1,
+                received: seq,
+            },
+            _ => SequenceCheck::InOrder,
+        };
+        self.last_seq = Some(seq);
+        check
+    }
+}
+
+struct PlannedRestart {
+    reason: String,
+    window: StdDuration,
+}
+
+const APP_TICK_MODE: &str = "app";
+const TICK_EVENT: &str = "tick";
+const CLOSE_REPLY_TIMEOUT: StdDuration = StdDuration::from_secs(1);
+
+/// Keepalive for an established session. Probes are WebSocket pings sent at the
+/// interval from the gateway's hello policy and time the round trip. In app tick
+/// mode the gateway's own `tick` events decide whether the session is alive.
+struct Heartbeat {
+    app_ticks: bool,
+    interval: StdDuration,
+    timeout: StdDuration,
+    in_flight: Option<(String, Instant)>,
+    last_tick: Instant,
+}
+
+impl Heartbeat {
+    fn new(config: &ConnectionConfig, policy: Option<&HelloPolicy>) -> Self {
+        let interval = policy
+            .and_then(|policy| policy.tick_interval_ms)
+            .filter(|ms| *ms > 0)
+            .map(StdDuration::from_millis)
+            .unwrap_or_else(|| duration_to_std(config.heartbeat_interval));
+        let timeout = std::cmp::max(
+            duration_to_std(config.heartbeat_timeout),
+            interval.saturating_mul(2),
+        );
+        Self {
+            app_ticks: policy.and_then(|policy| policy.tick_mode.as_deref()) == Some(APP_TICK_MODE),
+            interval,
+            timeout,
+            in_flight: None,
+            last_tick: Instant::now(),
+        }
+    }
+
+    fn observe(&mut self, event: &GatewayEvent) {
+        if matches!(event, GatewayEvent::ProtocolEvent { event, .. } if event == TICK_EVENT) {
+            self.last_tick = Instant::now();
+        }
+    }
+
+    /// Why the session counts as dead, judged by gateway ticks in app tick mode
+    /// and by any inbound traffic otherwise.
+    fn expired(&self, last_received: Instant) -> Option<&'static str> {
+        if self.app_ticks {
+            (self.last_tick.elapsed() >= self.timeout).then_some("Tick timeout")
+        } else {
+            (last_received.elapsed() >= self.timeout).then_some("Heartbeat timeout")
+        }
+    }
+
+    fn probe(&mut self) -> Message {
+        let id = next_request_id("tick");
+        let message = Message::Ping(id.clone().into_bytes().into());
+        self.in_flight = Some((id, Instant::now()));
+        message
+    }
+
+    fn awaits(&self, key: &[u8]) -> bool {
+        self.in_flight
+            .as_ref()
+            .is_some_and(|(id, _)| id.as_bytes() == key)
+    }
+
+    fn answered(&mut self) -> Option<StdDuration> {
+        self.in_flight.take().map(|(_, sent)| sent.elapsed())
+    }
+}
+
+fn connection_quality(latency: StdDuration) -> GatewayEvent {
+    GatewayEvent::ConnectionQuality {
+        latency_ms: latency.as_millis() as u64,
+    }
+}
+
+enum SessionEnd {
+    Disconnected {
+        reason: String,
+        established: bool,
+        restart: Option<PlannedRestart>,
+    },
+    Stopped {
+        reason: String,
+    },
+    /// The stored device token was refused and forgotten; retry after the usual
+    /// backoff with the configured credentials.
+    DeviceTokenRejected {
+        reason: String,
+    },
+    Rejected {
+        reason: String,
+    },
+    SinkClosed,
+}
+
+enum HandshakeOutcome {
+    Accepted(HelloOk),
+    Rejected(GatewayError),
+    Failed(String),
+    Stopped(String),
+    SinkClosed,
+}
+
+async fn run_session<S, St>(
+    mut ws_stream: WebSocketStream<St>,
+    response: &Response,
+    sink: &S,
+    config: &ConnectionConfig,
+    handshake: &Handshake,
+    registration: &ConnectionRegistration,
+    sequence: &mut SequenceTracker,
+) -> SessionEnd
+where
+    S: EventSink,
+    St: AsyncRead + AsyncWrite + Unpin,
+{
+    let handshake_timeout = duration_to_std(config.handshake_timeout);
+    registration.set_state(ConnectionState::Handshaking);
+    let mut stop = registration.stop_signal();
+    let device_token = handshake.stored_device_token().await;
+    sequence.start_session();
+    // Events a `Block` policy holds back wait here so the reader keeps answering
+    // pings and resolving responses; reads stop only once this fills too.
+    let mut held: VecDeque<GatewayEvent> = VecDeque::new();
+    let outcome = tokio::select! {
+        outcome = perform_handshake(
+            &mut ws_stream,
+            sink,
+            handshake,
+            device_token.as_deref(),
+            sequence,
+            &mut held,
+            handshake_timeout,
+        ) => outcome,
+        reason = stop_requested(&mut stop) => HandshakeOutcome::Stopped(reason),
+    };
+    if !matches!(outcome, HandshakeOutcome::Accepted(_)) && !release_held(sink, &mut held) {
+        return SessionEnd::SinkClosed;
+    }
+    let hello = match outcome {
+        HandshakeOutcome::Accepted(hello) => hello,
+        HandshakeOutcome::Rejected(error) if device_token.is_some() => {
+            if let Some(token) = &device_token {
+                handshake.forget_device_token(token).await;
+            }
+            let _ = ws_stream.close(None).await;
+            let message = error
+                .message
+                .unwrap_or_else(|| "Gateway rejected the connection".to_string());
+            return SessionEnd::DeviceTokenRejected {
+                reason: format!("Stored device token rejected: {message}"),
+            };
+        }
+        HandshakeOutcome::Rejected(error) => {
+            let _ = ws_stream.close(None).await;
+            let message = error
+                .message
+                .unwrap_or_else(|| "Gateway rejected the connection".to_string());
+            registration.set_state(ConnectionState::Failed {
+                fatal: true,
+                reason: message.clone(),
+            });
+            try_emit(
+                sink,
+                GatewayEvent::HandshakeRejected {
+                    code: error.code.unwrap_or_default(),
+                    message: message.clone(),
+                },
+            );
+            return SessionEnd::Rejected { reason: message };
+        }
+        HandshakeOutcome::Failed(reason) => {
+            return SessionEnd::Disconnected {
+                reason,
+                established: false,
+                restart: None,
+            };
+        }
+        HandshakeOutcome::Stopped(reason) => {
+            let _ = ws_stream.close(Some(normal_close_frame(&reason))).await;
+            return SessionEnd::Stopped { reason };
+        }
+        HandshakeOutcome::SinkClosed => return SessionEnd::SinkClosed,
+    };
+
+    let auth = hello.auth.unwrap_or_default();
+    let role = auth
+        .role
+        .unwrap_or_else(|| handshake.options.requested_role());
+    let scopes = if auth.scopes.is_empty() {
+        handshake.options.scopes.clone()
+    } else {
+        auth.scopes
+    };
+    let protocol = hello.protocol.unwrap_or(GATEWAY_PROTOCOL_VERSION);
+    let subprotocol = response
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    registration.set_state(ConnectionState::Ready {
+        protocol,
+        role: role.clone(),
+        subprotocol: subprotocol.clone(),
+    });
+    if !try_emit(
+        sink,
+        GatewayEvent::Connected {
+            protocol,
+            role: role.clone(),
+            scopes,
+            subprotocol,
+            response_headers: response_headers(response),
+        },
+    ) {
+        return SessionEnd::SinkClosed;
+    }
+
+    if let Some(issued_token) = auth.device_token {
+        if device_token.as_deref() != Some(issued_token.as_str()) {
+            let event = match handshake.save_device_token(issued_token).await {
+                Ok(()) => GatewayEvent::DeviceTokenRotated { role },
+                Err(e) => GatewayEvent::Error {
+                    message: format!("Failed to store device token: {e}"),
+                },
+            };
+            if !try_emit(sink, event) {
+                return SessionEnd::SinkClosed;
+            }
+        }
+    }
+
+    let mut heartbeat = Heartbeat::new(config, hello.policy.as_ref());
+    let (mut write, mut read) = ws_stream.split();
+    let (request_tx, mut request_rx) =
+        tokio::sync::mpsc::channel::<String>(config.outbound_capacity.max(1) as usize);
+    let pending = PendingRequests::default();
+    let link_guard = registration.attach(OutboundLink {
+        sender: request_tx,
+        pending: pending.clone(),
+    });
+    let mut heartbeat_timer = tokio::time::interval(heartbeat.interval);
+    heartbeat_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut last_received = Instant::now();
+    let mut planned_restart: Option<PlannedRestart> = None;
+    let held_capacity = config.inbound_capacity.max(1) as usize;
+    if let Some(outbox) = &registration.outbox {
+        outbox.start_session();
+        if !report_expired(sink, outbox) {
+            return SessionEnd::SinkClosed;
+        }
+    }
+    let mut flush_error = match &registration.outbox {
+        Some(outbox) => flush_outbox(outbox, &mut write).await.err(),
+        None => None,
+    };
+
+    let disconnect_reason = loop {
+        if let Some(reason) = flush_error.take() {
+            break reason;
+        }
+        tokio::select! {
+            msg = read.next(), if held.len() < held_capacity => {
+                match msg {
+                    Some(Ok(Message::Text(text))) => {
+                        last_received = Instant::now();
+                        let event = match parse_gateway_frame(&text) {
+                            Some(GatewayEvent::ProtocolResponse {
+                                id,
+                                ok,
+                                payload,
+                                error,
+                                session_key,
+                            }) => match pending.take(&id) {
+                                Some(waiter) => {
+                                    let _ = waiter.send(response_result(ok, payload, error));
+                                    continue;
                                 }
-                                Some(Err(e)) => {
-                                    disconnect_reason = format!("WebSocket error: {e}");
-                                    if !try_emit(
-                                        &sink,
-                                        GatewayEvent::Error {
-                                            message: e.to_string(),
-                                        },
-                                    ) {
-                                        return Ok(());
+                                None => {
+                                    let delivered = registration
+                                        .outbox
+                                        .as_ref()
+                                        .is_some_and(|outbox| outbox.acknowledge(&id));
+                                    if delivered
+                                        && !emit_or_hold(
+                                            sink,
+                                            &mut held,
+                                            GatewayEvent::OutboxDelivered { id: id.clone() },
+                                        )
+                                    {
+                                        return SessionEnd::SinkClosed;
                                     }
-                                    break;
+                                    GatewayEvent::ProtocolResponse {
+                                    id,
+                                    ok,
+                                    payload,
+                                    error,
+                                    session_key,
                                 }
-                                None => {
-                                    disconnect_reason = "Connection closed".to_string();
-                                    break;
                                 }
-                                _ => {}
+                            },
+                            Some(event) => event,
+                            None => GatewayEvent::Message {
+                                message: text.to_string(),
+                            },
+                        };
+                        heartbeat.observe(&event);
+                        if let GatewayEvent::ProtocolEvent {
+                            payload: GatewayEventPayload::Shutdown(shutdown),
+                            ..
+                        } = &event
+                        {
+                            planned_restart = shutdown.restart_expected_ms.map(|ms| PlannedRestart {
+                                reason: shutdown
+                                    .reason
+                                    .clone()
+                                    .unwrap_or_else(|| "Gateway restarting".to_string()),
+                                window: std::cmp::min(
+                                    StdDuration::from_millis(ms),
+                                    duration_to_std(config.max_restart_wait),
+                                ),
+                            });
+                        }
+                        if !emit_sequenced(sink, sequence, &mut held, event) {
+                            return SessionEnd::SinkClosed;
+                        }
+                    }
+                    Some(Ok(Message::Ping(payload))) => {
+                        last_received = Instant::now();
+                        if let Err(e) = write.send(Message::Pong(payload)).await {
+                            if !try_emit(
+                                sink,
+                                GatewayEvent::Error {
+                                    message: e.to_string(),
+                                },
+                            ) {
+                                return SessionEnd::SinkClosed;
                             }
+                            break format!("WebSocket pong error: {e}");
                         }
-                        outbound = request_rx.recv() => {
-                            match outbound {
-                                Some(payload) => {
-                                    if let Err(e) = write.send(Message::Text(payload.into())).await {
-                                        disconnect_reason = format!("Request send error: {e}");
-                                        if !try_emit(
-                                            &sink,
-                                            GatewayEvent::Error {
-                                                message: e.to_string(),
-                                            },
-                                        ) {
-                                            return Ok(());
-                                        }
-                                        break;
-                                    }
-                                }
-                                None => {
-                                    disconnect_reason = "Request channel closed".to_string();
-                                    break;
+                    }
+                    Some(Ok(Message::Pong(payload))) => {
+                        last_received = Instant::now();
+                        if heartbeat.awaits(&payload) {
+                            if let Some(latency) = heartbeat.answered() {
+                                if !try_emit(sink, connection_quality(latency)) {
+                                    return SessionEnd::SinkClosed;
                                 }
                             }
                         }
-                        _ = heartbeat.tick() => {
-                            if last_received.elapsed() >= heartbeat_timeout {
-                                disconnect_reason = "Heartbeat timeout".to_string();
-                                break;
-                            }
-                            if let Err(e) = write.send(Message::Ping(Vec::new().into())).await {
-                                disconnect_reason = format!("Heartbeat send error: {e}");
-                                if !try_emit(
-                                    &sink,
-                                    GatewayEvent::Error {
-                                        message: e.to_string(),
-                                    },
-                                ) {
-                                    return Ok(());
-                                }
-                                break;
+                    }
+                    Some(Ok(Message::Binary(data))) => {
+                        last_received = Instant::now();
+                        let event = GatewayEvent::Binary {
+                            data: data.to_vec(),
+                        };
+                        if !emit_or_hold(sink, &mut held, event) {
+                            return SessionEnd::SinkClosed;
+                        }
+                    }
+                    Some(Ok(Message::Close(frame))) => {
+                        break close_reason(frame);
+                    }
+                    Some(Err(e)) => {
+                        if !try_emit(
+                            sink,
+                            GatewayEvent::Error {
+                                message: e.to_string(),
+                            },
+                        ) {
+                            return SessionEnd::SinkClosed;
+                        }
+                        break format!("WebSocket error: {e}");
+                    }
+                    None => {
+                        break "Connection closed".to_string();
+                    }
+                    _ => {}
+                }
+            }
+            _ = room_for(sink, held.front()) => {
+                if let Some(event) = held.pop_front() {
+                    if !try_emit(sink, event) {
+                        return SessionEnd::SinkClosed;
+                    }
+                }
+            }
+            _ = outbox_unsent(registration.outbox.as_deref()) => {
+                if let Some(outbox) = &registration.outbox {
+                    if let Err(reason) = flush_outbox(outbox, &mut write).await {
+                        break reason;
+                    }
+                }
+            }
+            outbound = request_rx.recv() => {
+                match outbound {
+                    Some(payload) => {
+                        if let Err(e) = write.send(Message::Text(payload.into())).await {
+                            if !try_emit(
+                                sink,
+                                GatewayEvent::Error {
+                                    message: e.to_string(),
+                                },
+                            ) {
+                                return SessionEnd::SinkClosed;
                             }
+                            break format!("Request send error: {e}");
+                        }
+                    }
+                    None => {
+                        break "Request channel closed".to_string();
+                    }
+                }
+            }
+            reason = stop_requested(&mut stop) => {
+                let _ = write.send(Message::Close(Some(normal_close_frame(&reason)))).await;
+                link_guard.close(&reason);
+                // Keep reading until the gateway answers the Close frame so it can
+                // flush what it already queued instead of writing into a dropped socket.
+                let _ = tokio::time::timeout(CLOSE_REPLY_TIMEOUT, async {
+                    while let Some(Ok(message)) = read.next().await {
+                        if message.is_close() {
+                            break;
                         }
                     }
+                })
+                .await;
+                if !release_held(sink, &mut held) {
+                    return SessionEnd::SinkClosed;
+                }
+                return SessionEnd::Stopped { reason };
+            }
+            _ = heartbeat_timer.tick() => {
+                if held.len() >= held_capacity {
+                    // Reads are paused on our side, so gateway silence proves nothing.
+                    last_received = Instant::now();
+                    heartbeat.last_tick = last_received;
+                } else if let Some(reason) = heartbeat.expired(last_received) {
+                    break reason.to_string();
+                }
+                if let Err(e) = write.send(heartbeat.probe()).await {
+                    if !try_emit(
+                        sink,
+                        GatewayEvent::Error {
+                            message: e.to_string(),
+                        },
+                    ) {
+                        return SessionEnd::SinkClosed;
+                    }
+                    break format!("Heartbeat send error: {e}");
                 }
+            }
+        }
+    };
 
-                if !try_emit(
-                    &sink,
-                    GatewayEvent::Disconnected {
-                        reason: disconnect_reason,
-                    },
-                ) {
-                    return Ok(());
+    link_guard.close(&disconnect_reason);
+    if !release_held(sink, &mut held) {
+        return SessionEnd::SinkClosed;
+    }
+    SessionEnd::Disconnected {
+        reason: disconnect_reason,
+        established: true,
+        restart: planned_restart,
+    }
+}
+
+/// Drops duplicate protocol events and reports gaps ahead of the event itself.
+fn emit_sequenced<S: EventSink>(
+    sink: &S,
+    sequence: &mut SequenceTracker,
+    held: &mut VecDeque<GatewayEvent>,
+    event: GatewayEvent,
+) -> bool {
+    if let GatewayEvent::ProtocolEvent {
+        seq, state_version, ..
+    } = &event
+    {
+        match sequence.observe(seq, state_version) {
+            SequenceCheck::InOrder => {}
+            SequenceCheck::Duplicate => return true,
+            SequenceCheck::Gap { expected, received } => {
+                let gap = GatewayEvent::EventGap { expected, received };
+                if !emit_or_hold(sink, held, gap) {
+                    return false;
                 }
+            }
+        }
+    }
+    emit_or_hold(sink, held, event)
+}
 
-                sessions_completed = sessions_completed.saturating_add(1);
-                if let Some(max_sessions) = config.max_sessions {
-                    if sessions_completed >= max_sessions {
-                        return Ok(());
+/// Hands `event` to the sink unless its policy blocks on a full queue, in which case
+/// it waits in `held` behind any event already waiting there.
+fn emit_or_hold<S: EventSink>(
+    sink: &S,
+    held: &mut VecDeque<GatewayEvent>,
+    event: GatewayEvent,
+) -> bool {
+    if held.is_empty() && sink.reserve(&event).now_or_never().is_some() {
+        return try_emit(sink, event);
+    }
+    held.push_back(event);
+    true
+}
+
+async fn room_for<S: EventSink>(sink: &S, event: Option<&GatewayEvent>) {
+    match event {
+        Some(event) => sink.reserve(event).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Delivers held events when the session ends; the queue takes them over capacity.
+fn release_held<S: EventSink>(sink: &S, held: &mut VecDeque<GatewayEvent>) -> bool {
+    held.drain(..).all(|event| try_emit(sink, event))
+}
+
+async fn perform_handshake<S, St>(
+    ws_stream: &mut WebSocketStream<St>,
+    sink: &S,
+    handshake: &Handshake,
+    device_token: Option<&str>,
+    sequence: &mut SequenceTracker,
+    held: &mut VecDeque<GatewayEvent>,
+    handshake_timeout: StdDuration,
+) -> HandshakeOutcome
+where
+    S: EventSink,
+    St: AsyncRead + AsyncWrite + Unpin,
+{
+    let deadline = Instant::now() + handshake_timeout;
+    let mut connect_request_id: Option<String> = None;
+
+    loop {
+        let Ok(msg) = tokio::time::timeout_at(deadline, ws_stream.next()).await else {
+            return HandshakeOutcome::Failed("Handshake timed out".to_string());
+        };
+        match msg {
+            Some(Ok(Message::Text(text))) => {
+                let Some(frame) = parse_gateway_frame(&text) else {
+                    let message = text.to_string();
+                    if !try_emit(sink, GatewayEvent::Message { message }) {
+                        return HandshakeOutcome::SinkClosed;
+                    }
+                    continue;
+                };
+                match frame {
+                    GatewayEvent::ProtocolEvent {
+                        payload: GatewayEventPayload::ConnectChallenge(challenge),
+                        ..
+                    } if connect_request_id.is_none() => {
+                        let request_id = next_request_id("connect");
+                        let frame = GatewayRequestFrame::new(
+                            request_id.clone(),
+                            "connect",
+                            GatewayRequestParams::Connect(handshake.connect_params(
+                                &challenge,
+                                device_token,
+                                sequence.last_seq,
+                            )),
+                            None,
+                        );
+                        let payload = match frame.to_json() {
+                            Ok(payload) => payload,
+                            Err(e) => {
+                                return HandshakeOutcome::Failed(format!(
+                                    "Failed to encode connect request: {e}"
+                                ));
+                            }
+                        };
+                        if let Err(e) = ws_stream.send(Message::Text(payload.into())).await {
+                            return HandshakeOutcome::Failed(format!("Handshake send error: {e}"));
+                        }
+                        connect_request_id = Some(request_id);
+                    }
+                    GatewayEvent::ProtocolResponse {
+                        id,
+                        ok,
+                        payload,
+                        error,
+                        ..
+                    } if connect_request_id.as_deref() == Some(id.as_str()) => {
+                        if !ok {
+                            if error.retryable == Some(true) {
+                                let message = error.message.unwrap_or_default();
+                                return HandshakeOutcome::Failed(format!(
+                                    "Handshake failed: {message}"
+                                ));
+                            }
+                            return HandshakeOutcome::Rejected(error);
+                        }
+                        return match payload {
+                            GatewayResponsePayload::HelloOk(hello) => {
+                                HandshakeOutcome::Accepted(hello)
+                            }
+                            _ => HandshakeOutcome::Failed(
+                                "Handshake response was not hello-ok".to_string(),
+                            ),
+                        };
+                    }
+                    event => {
+                        if !emit_sequenced(sink, sequence, held, event) {
+                            return HandshakeOutcome::SinkClosed;
+                        }
                     }
                 }
             }
-            Err(e) => {
-                let error_message = format!("Connect failed (attempt {}): {e}", attempt + 1);
-                if !try_emit(
-                    &sink,
-                    GatewayEvent::Error {
-                        message: error_message.clone(),
-                    },
-                ) {
-                    return Ok(());
-                }
-                if !try_emit(
-                    &sink,
-                    GatewayEvent::Disconnected {
-                        reason: error_message,
-                    },
-                ) {
-                    return Ok(());
+            Some(Ok(Message::Ping(payload))) => {
+                if let Err(e) = ws_stream.send(Message::Pong(payload)).await {
+                    return HandshakeOutcome::Failed(format!("WebSocket pong error: {e}"));
                 }
             }
+            Some(Ok(Message::Close(frame))) => {
+                return HandshakeOutcome::Failed(close_reason(frame));
+            }
+            Some(Ok(_)) => {}
+            Some(Err(e)) => return HandshakeOutcome::Failed(format!("WebSocket error: {e}")),
+            None => {
+                return HandshakeOutcome::Failed("Connection closed during handshake".to_string());
+            }
         }
+    }
+}
 
-        attempt = attempt.saturating_add(1);
-        sleep(backoff).await;
-        backoff = std::cmp::min(backoff.checked_mul(2).unwrap_or(max_backoff), max_backoff);
+fn report_expired<S: EventSink>(sink: &S, outbox: &Outbox) -> bool {
+    outbox
+        .take_expired(unix_time_ms())
+        .into_iter()
+        .all(|item| try_emit(sink, GatewayEvent::OutboxExpired { id: item.id }))
+}
+
+/// Writes the queued requests this session has not sent yet, in order. They stay
+/// queued until their responses arrive, so a failed write loses nothing.
+async fn flush_outbox<W>(outbox: &Outbox, write: &mut W) -> std::result::Result<(), String>
+where
+    W: futures_util::Sink<Message> + Unpin,
+    W::Error: std::fmt::Display,
+{
+    for item in outbox.take_unsent() {
+        if let Err(e) = write.send(Message::Text(item.frame_json.into())).await {
+            return Err(format!("Outbox send error: {e}"));
+        }
     }
+    Ok(())
+}
+
+async fn outbox_unsent(outbox: Option<&Outbox>) {
+    match outbox {
+        Some(outbox) => outbox.unsent().await,
+        None => std::future::pending().await,
+    }
+}
+
+async fn stop_requested(stop: &mut watch::Receiver<Option<String>>) -> String {
+    let reason = stop
+        .wait_for(Option::is_some)
+        .await
+        .map(|reason| reason.clone().unwrap_or_default());
+    match reason {
+        Ok(reason) => reason,
+        Err(_) => std::future::pending().await,
+    }
+}
+
+/// Close frame reasons are limited to 123 bytes of UTF-8.
+fn normal_close_frame(reason: &str) -> CloseFrame {
+    let mut end = reason.len().min(123);
+    while !reason.is_char_boundary(end) {
+        end -= 1;
+    }
+    CloseFrame {
+        code: CloseCode::Normal,
+        reason: reason[..end].to_string().into(),
+    }
+}
+
+fn close_reason(frame: Option<CloseFrame>) -> String {
+    frame
+        .map(|f| {
+            if f.reason.is_empty() {
+                "Server closed connection".to_string()
+            } else {
+                f.reason.to_string()
+            }
+        })
+        .unwrap_or_else(|| "Server closed connection".to_string())
 }
 
 fn duration_to_std(duration: Duration) -> StdDuration {
@@ -1070,7 +2598,9 @@ impl EventSink for TestSink {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::tls::ClientCertificateError;
     use futures_util::SinkExt;
+    use serde_json::json;
     use std::collections::VecDeque;
     use std::sync::Arc;
     use tokio::io::DuplexStream;
@@ -1097,20 +2627,82 @@ mod tests {
         }
     }
 
-    impl Connector for TestConnector {
+    async fn read_request<St: AsyncRead + AsyncWrite + Unpin>(
+        ws_stream: &mut WebSocketStream<St>,
+    ) -> Value {
+        loop {
+            match ws_stream.next().await.expect("frame").expect("message") {
+                Message::Text(text) => return serde_json::from_str(&text).expect("json"),
+                Message::Close(_) => panic!("client closed before sending a request"),
+                _ => {}
+            }
+        }
+    }
+
+    async fn accept_handshake<St: AsyncRead + AsyncWrite + Unpin>(
+        ws_stream: &mut WebSocketStream<St>,
+    ) -> Value {
+        let challenge = json!({
+            "type": "event",
+            "event": "connect.challenge",
+            "payload": { "nonce": "nonce-1", "ts": 1700000000000i64 }
+        });
+        ws_stream
+            .send(Message::Text(challenge.to_string().into()))
+            .await
+            .expect("challenge");
+        let request = read_request(ws_stream).await;
+        let hello = json!({
+            "type": "res",
+            "id": request["id"],
+            "ok": true,
+            "payload": {
+                "type": "hello-ok",
+                "protocol": 3,
+                "policy": { "tickIntervalMs": 50 },
+                "auth": { "role": "operator", "scopes": ["operator.read"] }
+            }
+        });
+        ws_stream
+            .send(Message::Text(hello.to_string().into()))
+            .await
+            .expect("hello");
+        request
+    }
+
+    fn test_config(max_sessions: u32) -> ConnectionConfig {
+        ConnectionConfig {
+            backoff_base: Duration::milliseconds(10),
+            max_backoff: Duration::milliseconds(40),
+            jitter: false,
+            max_attempts: None,
+            connect_timeout: Duration::milliseconds(500),
+            heartbeat_interval: Duration::milliseconds(50),
+            heartbeat_timeout: Duration::milliseconds(200),
+            handshake_timeout: Duration::milliseconds(500),
+            max_sessions: Some(max_sessions),
+            restart_probe_interval: Duration::milliseconds(10),
+            restart_probe_window: Duration::milliseconds(200),
+            outbox: None,
+            ..ConnectionConfig::default()
+        }
+    }
+
+    impl Connector for TestConnector {
         type Stream = DuplexStream;
 
         fn connect(
             &self,
-            _url: String,
-        ) -> BoxFuture<'static, Result<WebSocketStream<Self::Stream>>> {
+            _request: Request,
+        ) -> BoxFuture<'static, Result<(WebSocketStream<Self::Stream>, Response)>> {
             let streams = Arc::clone(&self.streams);
             Box::pin(async move {
                 let mut guard = streams.lock().await;
                 let stream = guard
                     .pop_front()
                     .ok_or_else(|| anyhow::anyhow!("no more streams"))?;
-                Ok(WebSocketStream::from_raw_socket(stream, Role::Client, None).await)
+                let ws_stream = WebSocketStream::from_raw_socket(stream, Role::Client, None).await;
+                Ok((ws_stream, Response::new(None)))
             })
         }
     }
@@ -1125,6 +2717,7 @@ mod tests {
             for server_stream in [server1, server2] {
                 let mut ws_stream =
                     WebSocketStream::from_raw_socket(server_stream, Role::Server, None).await;
+                accept_handshake(&mut ws_stream).await;
                 ws_stream.send(Message::Close(None)).await.expect("close");
             }
         });
@@ -1133,17 +2726,12 @@ mod tests {
         let sink = TestSink::new(tx);
 
         let client_task = tokio::spawn(async move {
-            let config = ConnectionConfig {
-                backoff_base: Duration::milliseconds(10),
-                max_backoff: Duration::milliseconds(40),
-                heartbeat_interval: Duration::milliseconds(50),
-                heartbeat_timeout: Duration::milliseconds(200),
-                max_sessions: Some(2),
-            };
             connect_to_gateway_with_sink_and_connector(
+                "reconnect".to_string(),
                 "ws://test".to_string(),
                 sink,
-                config,
+                test_config(2),
+                HandshakeOptions::default(),
                 &connector,
             )
             .await
@@ -1158,7 +2746,7 @@ mod tests {
                 .await
                 .expect("event");
             match event {
-                GatewayEvent::Connected => connected_count += 1,
+                GatewayEvent::Connected { .. } => connected_count += 1,
                 GatewayEvent::Disconnected { .. } => disconnected_count += 1,
                 _ => {}
             }
@@ -1176,6 +2764,7 @@ mod tests {
 
         let server_task = tokio::spawn(async move {
             let mut ws_stream = WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
             tokio::time::sleep(WaitDuration::from_millis(300)).await;
             let _ = ws_stream.send(Message::Close(None)).await;
         });
@@ -1185,16 +2774,15 @@ mod tests {
 
         let client_task = tokio::spawn(async move {
             let config = ConnectionConfig {
-                backoff_base: Duration::milliseconds(10),
-                max_backoff: Duration::milliseconds(40),
-                heartbeat_interval: Duration::milliseconds(50),
                 heartbeat_timeout: Duration::milliseconds(120),
-                max_sessions: Some(1),
+                ..test_config(1)
             };
             connect_to_gateway_with_sink_and_connector(
+                "heartbeat".to_string(),
                 "ws://test".to_string(),
                 sink,
                 config,
+                HandshakeOptions::default(),
                 &connector,
             )
             .await
@@ -1214,4 +2802,1985 @@ mod tests {
         let _ = client_task.await;
         let _ = server_task.await;
     }
+
+    #[tokio::test]
+    async fn handshake_sends_connect_and_reports_session() {
+        let (client, server) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client]);
+
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+            let request = accept_handshake(&mut ws_stream).await;
+            let _ = ws_stream.send(Message::Close(None)).await;
+            request
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let sink = TestSink::new(tx);
+
+        let client_task = tokio::spawn(async move {
+            let options = HandshakeOptions {
+                client_version: Some("1.2.3".to_string()),
+                token: Some("secret".to_string()),
+                ..HandshakeOptions::default()
+            };
+            connect_to_gateway_with_sink_and_connector(
+                "handshake".to_string(),
+                "ws://test".to_string(),
+                sink,
+                test_config(1),
+                options,
+                &connector,
+            )
+            .await
+            .expect("client");
+        });
+
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        match event {
+            GatewayEvent::Connected {
+                protocol,
+                role,
+                scopes,
+                subprotocol,
+                ..
+            } => {
+                assert_eq!(subprotocol, None);
+                assert_eq!(protocol, 3);
+                assert_eq!(role, "operator");
+                assert_eq!(scopes, vec!["operator.read".to_string()]);
+            }
+            other => panic!("expected connected, got {other:?}"),
+        }
+
+        let request = server_task.await.expect("server");
+        assert_eq!(request["method"], "connect");
+        assert_eq!(request["params"]["minProtocol"], 3);
+        assert_eq!(request["params"]["client"]["id"], DEFAULT_CLIENT_ID);
+        assert_eq!(request["params"]["client"]["version"], "1.2.3");
+        assert_eq!(request["params"]["auth"]["token"], "secret");
+        let _ = client_task.await;
+    }
+
+    #[tokio::test]
+    async fn pings_follow_hello_tick_interval_and_report_latency() {
+        let (client, server) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client]);
+
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+            let challenge = json!({
+                "type": "event",
+                "event": "connect.challenge",
+                "payload": { "nonce": "nonce-1", "ts": 1700000000000i64 }
+            });
+            ws_stream
+                .send(Message::Text(challenge.to_string().into()))
+                .await
+                .expect("challenge");
+            let connect = read_request(&mut ws_stream).await;
+            let hello = json!({
+                "type": "res",
+                "id": connect["id"],
+                "ok": true,
+                "payload": {
+                    "type": "hello-ok",
+                    "protocol": 3,
+                    "policy": { "tickIntervalMs": 20 }
+                }
+            });
+            ws_stream
+                .send(Message::Text(hello.to_string().into()))
+                .await
+                .expect("hello");
+            // The second ping only comes this soon if the hello interval applies.
+            for _ in 0..2 {
+                let ping = loop {
+                    match ws_stream.next().await.expect("frame").expect("message") {
+                        Message::Ping(payload) => break payload,
+                        Message::Close(_) => panic!("client closed before pinging"),
+                        _ => {}
+                    }
+                };
+                ws_stream.send(Message::Pong(ping)).await.expect("pong");
+            }
+            let _ = ws_stream.send(Message::Close(None)).await;
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            heartbeat_interval: Duration::seconds(10),
+            ..test_config(1)
+        };
+        let client = connect_to_gateway_with_sink_and_connector(
+            "ticks".to_string(),
+            "ws://test".to_string(),
+            TestSink::new(tx),
+            config,
+            HandshakeOptions::default(),
+            &connector,
+        );
+        timeout(WaitDuration::from_secs(2), client)
+            .await
+            .expect("pinged at the hello interval")
+            .expect("client");
+        server_task.await.expect("server");
+
+        let mut events = Vec::new();
+        while let Some(event) = collect_event(&mut rx, WaitDuration::from_millis(50)).await {
+            events.push(event);
+        }
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, GatewayEvent::ConnectionQuality { .. })),
+            "{events:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn app_tick_mode_disconnects_when_gateway_ticks_stop() {
+        let (client, server) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client]);
+
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+            let challenge = json!({
+                "type": "event",
+                "event": "connect.challenge",
+                "payload": { "nonce": "nonce-1", "ts": 1700000000000i64 }
+            });
+            ws_stream
+                .send(Message::Text(challenge.to_string().into()))
+                .await
+                .expect("challenge");
+            let connect = read_request(&mut ws_stream).await;
+            let hello = json!({
+                "type": "res",
+                "id": connect["id"],
+                "ok": true,
+                "payload": {
+                    "type": "hello-ok",
+                    "protocol": 3,
+                    "policy": { "tickIntervalMs": 20, "tickMode": "app" }
+                }
+            });
+            ws_stream
+                .send(Message::Text(hello.to_string().into()))
+                .await
+                .expect("hello");
+            for _ in 0..3 {
+                let tick = json!({ "type": "event", "event": "tick", "payload": {} });
+                ws_stream
+                    .send(Message::Text(tick.to_string().into()))
+                    .await
+                    .expect("tick");
+                sleep(StdDuration::from_millis(20)).await;
+            }
+            // Keep answering pings so only the missing ticks can end the session.
+            while let Some(Ok(message)) = ws_stream.next().await {
+                if message.is_close() {
+                    break;
+                }
+            }
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let client = connect_to_gateway_with_sink_and_connector(
+            "app-ticks".to_string(),
+            "ws://test".to_string(),
+            TestSink::new(tx),
+            test_config(1),
+            HandshakeOptions::default(),
+            &connector,
+        );
+        timeout(WaitDuration::from_secs(2), client)
+            .await
+            .expect("ended by the tick timeout")
+            .expect("client");
+        let _ = server_task.await;
+
+        let mut reason = None;
+        while let Some(event) = collect_event(&mut rx, WaitDuration::from_millis(50)).await {
+            if let GatewayEvent::Disconnected { reason: why } = event {
+                reason = Some(why);
+            }
+        }
+        assert_eq!(reason.as_deref(), Some("Tick timeout"));
+    }
+
+    fn record_states(handle: &str) -> tokio::task::JoinHandle<Vec<ConnectionState>> {
+        let mut states = connection_state(handle).subscribe();
+        let mut seen = vec![states.borrow_and_update().clone()];
+        tokio::spawn(async move {
+            while let Ok(Ok(())) =
+                tokio::time::timeout(WaitDuration::from_millis(100), states.changed()).await
+            {
+                seen.push(states.borrow_and_update().clone());
+            }
+            seen
+        })
+    }
+
+    #[tokio::test]
+    async fn connection_state_follows_session_lifecycle() {
+        let (client, server) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client]);
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            tokio::time::sleep(WaitDuration::from_millis(50)).await;
+            let _ = ws_stream.send(Message::Close(None)).await;
+        });
+
+        let recorder = record_states("lifecycle");
+        let (tx, _rx) = mpsc::unbounded_channel();
+        connect_to_gateway_with_sink_and_connector(
+            "lifecycle".to_string(),
+            "ws://test".to_string(),
+            TestSink::new(tx),
+            test_config(1),
+            HandshakeOptions::default(),
+            &connector,
+        )
+        .await
+        .expect("client");
+        server_task.await.expect("server");
+
+        let states = recorder.await.expect("recorder");
+        assert_eq!(states.first(), Some(&ConnectionState::Idle));
+        assert!(
+            states.contains(&ConnectionState::Ready {
+                protocol: 3,
+                role: "operator".to_string(),
+                subprotocol: None,
+            }),
+            "{states:?}"
+        );
+        assert_eq!(states.last(), Some(&ConnectionState::Idle), "{states:?}");
+    }
+
+    #[tokio::test]
+    async fn connection_state_reports_backoff_and_fatal_failure() {
+        let connector = TestConnector::new(Vec::new());
+        let recorder = record_states("failing");
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            max_attempts: Some(2),
+            max_sessions: None,
+            ..test_config(1)
+        };
+        connect_to_gateway_with_sink_and_connector(
+            "failing".to_string(),
+            "ws://test".to_string(),
+            TestSink::new(tx),
+            config,
+            HandshakeOptions::default(),
+            &connector,
+        )
+        .await
+        .expect_err("gave up");
+
+        let states = recorder.await.expect("recorder");
+        assert!(
+            states.iter().any(|state| matches!(
+                state,
+                ConnectionState::Backoff { reason, .. } if reason.contains("no more streams")
+            )),
+            "{states:?}"
+        );
+        assert!(
+            matches!(
+                states.last(),
+                Some(ConnectionState::Failed { fatal: true, .. })
+            ),
+            "{states:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejected_handshake_is_terminal() {
+        let (client, server) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client]);
+
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+            let challenge = json!({
+                "type": "event",
+                "event": "connect.challenge",
+                "payload": { "nonce": "nonce-1", "ts": 1700000000000i64 }
+            });
+            ws_stream
+                .send(Message::Text(challenge.to_string().into()))
+                .await
+                .expect("challenge");
+            let request = read_request(&mut ws_stream).await;
+            let rejection = json!({
+                "type": "res",
+                "id": request["id"],
+                "ok": false,
+                "error": { "code": "AUTH_FAILED", "message": "bad token" }
+            });
+            ws_stream
+                .send(Message::Text(rejection.to_string().into()))
+                .await
+                .expect("rejection");
+            while let Some(Ok(_)) = ws_stream.next().await {}
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let sink = TestSink::new(tx);
+
+        let error = timeout(
+            WaitDuration::from_secs(1),
+            connect_to_gateway_with_sink_and_connector(
+                "rejected".to_string(),
+                "ws://test".to_string(),
+                sink,
+                test_config(5),
+                HandshakeOptions::default(),
+                &connector,
+            ),
+        )
+        .await
+        .expect("client should stop after rejection")
+        .expect_err("rejection is fatal");
+        assert_eq!(error.to_string(), "bad token");
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        assert!(
+            matches!(
+                events.as_slice(),
+                [GatewayEvent::HandshakeRejected { code, message }]
+                    if code == "AUTH_FAILED" && message == "bad token"
+            ),
+            "events: {events:?}"
+        );
+        let _ = server_task.await;
+    }
+
+    #[tokio::test]
+    async fn rejected_device_token_falls_back_and_stores_rotation() {
+        let dir = std::env::temp_dir().join(format!("openclaw-conn-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        DeviceIdentity::load_or_create(&dir).expect("identity");
+        let store = FileTokenStore::in_directory(&dir);
+        let key = device_token_key("ws://test", DEFAULT_ROLE);
+        store
+            .save(key.clone(), "stale-token".to_string())
+            .await
+            .expect("seed token");
+
+        let (client1, server1) = tokio::io::duplex(4096);
+        let (client2, server2) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client1, client2]);
+
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server1, Role::Server, None).await;
+            let challenge = json!({
+                "type": "event",
+                "event": "connect.challenge",
+                "payload": { "nonce": "nonce-1", "ts": 1700000000000i64 }
+            });
+            ws_stream
+                .send(Message::Text(challenge.to_string().into()))
+                .await
+                .expect("challenge");
+            let first = read_request(&mut ws_stream).await;
+            let rejection = json!({
+                "type": "res",
+                "id": first["id"],
+                "ok": false,
+                "error": { "code": "DEVICE_TOKEN_INVALID", "message": "token revoked" }
+            });
+            ws_stream
+                .send(Message::Text(rejection.to_string().into()))
+                .await
+                .expect("rejection");
+
+            let mut ws_stream = WebSocketStream::from_raw_socket(server2, Role::Server, None).await;
+            ws_stream
+                .send(Message::Text(challenge.to_string().into()))
+                .await
+                .expect("challenge");
+            let second = read_request(&mut ws_stream).await;
+            let hello = json!({
+                "type": "res",
+                "id": second["id"],
+                "ok": true,
+                "payload": {
+                    "type": "hello-ok",
+                    "protocol": 3,
+                    "auth": { "deviceToken": "fresh-token", "role": "operator" }
+                }
+            });
+            ws_stream
+                .send(Message::Text(hello.to_string().into()))
+                .await
+                .expect("hello");
+            let _ = ws_stream.send(Message::Close(None)).await;
+            (first, second)
+        });
+
+        let recorder = record_states("device-token");
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let sink = TestSink::new(tx);
+        let options = HandshakeOptions {
+            identity_dir: Some(dir.to_string_lossy().to_string()),
+            password: Some("hunter2".to_string()),
+            ..HandshakeOptions::default()
+        };
+        // A single allowed failure proves the fallback does not count as one.
+        let config = ConnectionConfig {
+            max_attempts: Some(1),
+            ..test_config(2)
+        };
+        connect_to_gateway_with_sink_and_connector(
+            "device-token".to_string(),
+            "ws://test".to_string(),
+            sink,
+            config,
+            options,
+            &connector,
+        )
+        .await
+        .expect("client");
+
+        let (first, second) = server_task.await.expect("server");
+        assert_eq!(first["params"]["auth"]["token"], "stale-token");
+        assert!(first["params"]["device"]["signature"].is_string());
+        assert_eq!(second["params"]["auth"]["password"], "hunter2");
+        assert!(second["params"]["auth"]["token"].is_null());
+
+        let mut rotated = false;
+        while let Some(event) = rx.recv().await {
+            if let GatewayEvent::DeviceTokenRotated { role } = event {
+                assert_eq!(role, "operator");
+                rotated = true;
+            }
+        }
+        assert!(rotated, "expected token rotation event");
+        let states = recorder.await.expect("recorder");
+        assert!(
+            states
+                .iter()
+                .all(|state| !matches!(state, ConnectionState::Failed { .. })),
+            "{states:?}"
+        );
+        assert!(
+            states.iter().any(|state| matches!(
+                state,
+                ConnectionState::Backoff { reason, .. }
+                    if reason.starts_with("Stored device token rejected")
+            )),
+            "{states:?}"
+        );
+        assert_eq!(
+            store.load(key).await.expect("load"),
+            Some("fresh-token".to_string())
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn resumes_from_last_seq_and_suppresses_duplicates() {
+        let (client1, server1) = tokio::io::duplex(4096);
+        let (client2, server2) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client1, client2]);
+
+        let server_task = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for (server_stream, seqs) in [(server1, vec![1, 2, 2, 4]), (server2, vec![4, 5])] {
+                let mut ws_stream =
+                    WebSocketStream::from_raw_socket(server_stream, Role::Server, None).await;
+                requests.push(accept_handshake(&mut ws_stream).await);
+                for seq in seqs {
+                    let event = json!({
+                        "type": "event",
+                        "event": "tick",
+                        "seq": seq,
+                        "payload": {}
+                    });
+                    ws_stream
+                        .send(Message::Text(event.to_string().into()))
+                        .await
+                        .expect("event");
+                }
+                let _ = ws_stream.send(Message::Close(None)).await;
+            }
+            requests
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        connect_to_gateway_with_sink_and_connector(
+            "resume".to_string(),
+            "ws://test".to_string(),
+            TestSink::new(tx),
+            test_config(2),
+            HandshakeOptions::default(),
+            &connector,
+        )
+        .await
+        .expect("client");
+
+        let requests = server_task.await.expect("server");
+        assert!(requests[0]["params"]["lastSeq"].is_null());
+        assert_eq!(requests[1]["params"]["lastSeq"], 4);
+
+        let mut seen = Vec::new();
+        while let Some(event) = rx.recv().await {
+            match event {
+                GatewayEvent::ProtocolEvent { seq, .. } => seen.push(seq),
+                GatewayEvent::EventGap { expected, received } => {
+                    seen.push(format!("gap {expected}-{received}"))
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(seen, vec!["1", "2", "gap 3-4", "4", "5"]);
+    }
+
+    #[tokio::test]
+    async fn restarted_gateway_resets_sequence_tracking() {
+        let (client1, server1) = tokio::io::duplex(4096);
+        let (client2, server2) = tokio::io::duplex(4096);
+        let (client3, server3) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client1, client2, client3]);
+
+        let server_task = tokio::spawn(async move {
+            let sessions = [
+                (server1, vec![(5, 7), (6, 7)], false),
+                (server2, vec![(1, 1), (2, 1)], true),
+                (server3, vec![(9, 0), (10, 0)], false),
+            ];
+            for (server_stream, events, early) in sessions {
+                let mut ws_stream =
+                    WebSocketStream::from_raw_socket(server_stream, Role::Server, None).await;
+                let mut frames = Vec::new();
+                for (seq, state_version) in events {
+                    frames.push(json!({
+                        "type": "event",
+                        "event": "tick",
+                        "seq": seq,
+                        "stateVersion": state_version,
+                        "payload": {}
+                    }));
+                }
+                if early {
+                    // Delivered before hello-ok, so they must be sequenced too.
+                    let first = frames.remove(0);
+                    ws_stream
+                        .send(Message::Text(first.to_string().into()))
+                        .await
+                        .expect("early event");
+                }
+                accept_handshake(&mut ws_stream).await;
+                for frame in frames {
+                    ws_stream
+                        .send(Message::Text(frame.to_string().into()))
+                        .await
+                        .expect("event");
+                }
+                let _ = ws_stream.send(Message::Close(None)).await;
+            }
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        connect_to_gateway_with_sink_and_connector(
+            "seq-reset".to_string(),
+            "ws://test".to_string(),
+            TestSink::new(tx),
+            test_config(3),
+            HandshakeOptions::default(),
+            &connector,
+        )
+        .await
+        .expect("client");
+        server_task.await.expect("server");
+
+        let mut seen = Vec::new();
+        while let Some(event) = rx.recv().await {
+            match event {
+                GatewayEvent::ProtocolEvent { seq, .. } => seen.push(seq),
+                GatewayEvent::EventGap { expected, received } => {
+                    seen.push(format!("gap {expected}-{received}"))
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(seen, vec!["5", "6", "1", "2", "9", "10"]);
+    }
+
+    #[tokio::test]
+    async fn planned_restart_wait_is_capped() {
+        let (client1, server1) = tokio::io::duplex(4096);
+        let (client2, server2) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client1, client2]);
+
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server1, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            let shutdown = json!({
+                "type": "event",
+                "event": "shutdown",
+                "payload": { "reason": "upgrade", "restartExpectedMs": 3_600_000 }
+            });
+            ws_stream
+                .send(Message::Text(shutdown.to_string().into()))
+                .await
+                .expect("shutdown");
+            let _ = ws_stream.send(Message::Close(None)).await;
+
+            let mut ws_stream = WebSocketStream::from_raw_socket(server2, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            let _ = ws_stream.send(Message::Close(None)).await;
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            max_restart_wait: Duration::milliseconds(100),
+            ..test_config(2)
+        };
+        let client = connect_to_gateway_with_sink_and_connector(
+            "restart-capped".to_string(),
+            "ws://test".to_string(),
+            TestSink::new(tx),
+            config,
+            HandshakeOptions::default(),
+            &connector,
+        );
+        timeout(WaitDuration::from_secs(2), client)
+            .await
+            .expect("reconnects within the cap")
+            .expect("client");
+        server_task.await.expect("server");
+
+        let mut announced = None;
+        while let Some(event) = rx.recv().await {
+            if let GatewayEvent::Restarting { expected_ms, .. } = event {
+                announced = Some(expected_ms);
+            }
+        }
+        assert_eq!(announced, Some(100));
+    }
+
+    #[tokio::test]
+    async fn planned_restart_waits_for_announced_window() {
+        let (client1, server1) = tokio::io::duplex(4096);
+        let (client2, server2) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client1, client2]);
+
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server1, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            let shutdown = json!({
+                "type": "event",
+                "event": "shutdown",
+                "payload": { "reason": "upgrade", "restartExpectedMs": 150 }
+            });
+            ws_stream
+                .send(Message::Text(shutdown.to_string().into()))
+                .await
+                .expect("shutdown");
+            let _ = ws_stream.send(Message::Close(None)).await;
+
+            let mut ws_stream = WebSocketStream::from_raw_socket(server2, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            let _ = ws_stream.send(Message::Close(None)).await;
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "restart".to_string(),
+                "ws://test".to_string(),
+                TestSink::new(tx),
+                test_config(2),
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+
+        let mut restarting_at = None;
+        loop {
+            let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+                .await
+                .expect("event");
+            match event {
+                GatewayEvent::Restarting {
+                    reason,
+                    expected_ms,
+                } => {
+                    assert_eq!(reason, "upgrade");
+                    assert_eq!(expected_ms, 150);
+                    restarting_at = Some(Instant::now());
+                }
+                GatewayEvent::Connected { .. } if restarting_at.is_some() => break,
+                _ => {}
+            }
+        }
+        let waited = restarting_at.expect("restarting event").elapsed();
+        assert!(waited >= WaitDuration::from_millis(140), "{waited:?}");
+
+        client_task.await.expect("join").expect("client");
+        let _ = server_task.await;
+    }
+
+    fn test_frame(id: &str) -> GatewayRequestFrame {
+        GatewayRequestFrame::new(
+            id.to_string(),
+            "system.probe",
+            GatewayRequestParams::SystemProbe(SystemProbeParams::default()),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn request_resolves_matching_response() {
+        let (sender, mut outbound) = mpsc::channel(8);
+        let link = OutboundLink {
+            sender,
+            pending: PendingRequests::default(),
+        };
+        let pending = link.pending.clone();
+        let responder = tokio::spawn(async move {
+            let payload = outbound.recv().await.expect("outbound frame");
+            let frame: Value = serde_json::from_str(&payload).expect("json");
+            let id = frame["id"].as_str().expect("id").to_string();
+            let waiter = pending.take(&id).expect("pending entry");
+            let _ = waiter.send(response_result(
+                true,
+                GatewayResponsePayload::Unknown(json!({ "ok": 1 })),
+                GatewayError::default(),
+            ));
+        });
+
+        let response = link
+            .request(test_frame("req-a"), WaitDuration::from_secs(1))
+            .await
+            .expect("response");
+        assert_eq!(
+            response,
+            GatewayResponsePayload::Unknown(json!({ "ok": 1 }))
+        );
+        let _ = responder.await;
+    }
+
+    #[tokio::test]
+    async fn request_times_out_and_fails_on_disconnect() {
+        let (sender, _outbound) = mpsc::channel(8);
+        let link = OutboundLink {
+            sender,
+            pending: PendingRequests::default(),
+        };
+
+        let error = link
+            .request(test_frame("req-timeout"), WaitDuration::from_millis(20))
+            .await
+            .expect_err("timeout");
+        assert!(error.to_string().contains("timed out"), "{error}");
+        assert!(link.pending.take("req-timeout").is_none());
+
+        let pending = link.pending.clone();
+        let waiting = tokio::spawn(async move {
+            link.request(test_frame("req-drop"), WaitDuration::from_secs(5))
+                .await
+        });
+        while pending.entries.lock().expect("lock").is_empty() {
+            tokio::task::yield_now().await;
+        }
+        pending.fail_all("Heartbeat timeout");
+        let error = waiting.await.expect("join").expect_err("dropped");
+        assert_eq!(
+            error.to_string(),
+            "Request req-drop failed: Heartbeat timeout"
+        );
+    }
+
+    #[tokio::test]
+    async fn connections_are_isolated_by_handle() {
+        let mut clients = Vec::new();
+        let mut servers = Vec::new();
+        for name in ["home", "office"] {
+            let (client, server) = tokio::io::duplex(4096);
+            let connector = TestConnector::new(vec![client]);
+            let (tx, rx) = mpsc::unbounded_channel();
+            clients.push((name, connector, TestSink::new(tx), rx));
+            servers.push(tokio::spawn(async move {
+                let mut ws_stream =
+                    WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+                accept_handshake(&mut ws_stream).await;
+                let request = read_request(&mut ws_stream).await;
+                let response = json!({
+                    "type": "res",
+                    "id": request["id"],
+                    "ok": true,
+                    "payload": { "gateway": name }
+                });
+                ws_stream
+                    .send(Message::Text(response.to_string().into()))
+                    .await
+                    .expect("response");
+                let _ = ws_stream.send(Message::Close(None)).await;
+            }));
+        }
+
+        let mut tasks = Vec::new();
+        let mut receivers = Vec::new();
+        for (name, connector, sink, mut rx) in clients {
+            tasks.push(tokio::spawn(async move {
+                connect_to_gateway_with_sink_and_connector(
+                    name.to_string(),
+                    "ws://test".to_string(),
+                    sink,
+                    test_config(1),
+                    HandshakeOptions::default(),
+                    &connector,
+                )
+                .await
+            }));
+            let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+                .await
+                .expect("event");
+            assert!(matches!(event, GatewayEvent::Connected { .. }), "{event:?}");
+            receivers.push(rx);
+        }
+
+        assert!(register_connection("home", "ws://test").is_err());
+        let client = GatewayClient::for_connection("home").expect("client");
+        assert_eq!(client.get_url(), "ws://test");
+        assert!(GatewayClient::for_connection("nowhere").is_err());
+        let mut home_events = subscribe_gateway_events("home").expect("subscribe");
+        for (name, id) in [("office", "iso-office"), ("home", "iso-home")] {
+            let frame_json = test_frame(id).to_json().expect("frame");
+            let response = gateway_request(name.to_string(), frame_json, Some(1000))
+                .await
+                .expect("response");
+            let value = serde_json::to_value(&response).expect("json");
+            assert_eq!(value["gateway"], name);
+        }
+
+        for task in tasks {
+            task.await.expect("join").expect("client");
+        }
+        loop {
+            match home_events.recv().await {
+                Ok(GatewayEvent::ConnectionQuality { .. }) => {}
+                other => {
+                    assert!(
+                        matches!(other, Ok(GatewayEvent::Disconnected { .. })),
+                        "{other:?}"
+                    );
+                    break;
+                }
+            }
+        }
+        for server in servers {
+            let _ = server.await;
+        }
+        let error = send_gateway_request_frame(
+            "home".to_string(),
+            test_frame("iso-closed").to_json().expect("frame"),
+        )
+        .expect_err("closed");
+        assert!(error.to_string().contains("not open"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let connector = TestConnector::new(Vec::new());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            max_attempts: Some(3),
+            max_sessions: None,
+            ..test_config(1)
+        };
+
+        let error = connect_to_gateway_with_sink_and_connector(
+            "give-up".to_string(),
+            "ws://test".to_string(),
+            TestSink::new(tx),
+            config,
+            HandshakeOptions::default(),
+            &connector,
+        )
+        .await
+        .expect_err("gave up");
+        assert_eq!(
+            error.to_string(),
+            "Gave up after 3 failed connection attempts"
+        );
+
+        let mut connect_failures = 0;
+        let mut last_error = None;
+        while let Some(event) = collect_event(&mut rx, WaitDuration::from_millis(50)).await {
+            if let GatewayEvent::Error { message } = event {
+                if message.starts_with("Connect failed") {
+                    connect_failures += 1;
+                }
+                last_error = Some(message);
+            }
+        }
+        assert_eq!(connect_failures, 3);
+        assert_eq!(
+            last_error.as_deref(),
+            Some("Gave up after 3 failed connection attempts")
+        );
+        assert!(register_connection("give-up", "ws://test").is_ok());
+    }
+
+    #[tokio::test]
+    async fn lagging_subscribers_are_counted_in_backpressure_stats() {
+        let registration = register_connection("lagging", "ws://test").expect("register");
+        let config = ConnectionConfig::default();
+        let queue = Arc::new(InboundQueue::new(InboundLimits {
+            capacity: config.inbound_capacity,
+            logs: config.logs_policy,
+            output: config.output_policy,
+            events: config.event_policy,
+        }));
+        registration.update(|entry| entry.inbound = Some(Arc::clone(&queue)));
+        let mut events = subscribe_gateway_events("lagging").expect("subscribe");
+        let overflow = 3;
+        for _ in 0..EVENT_BROADCAST_CAPACITY + overflow {
+            let _ = registration.events.send(GatewayEvent::Message {
+                message: "tick".to_string(),
+            });
+        }
+
+        assert!(matches!(
+            events.recv().await,
+            Err(RecvError::Lagged(skipped)) if skipped == overflow as u64
+        ));
+        assert!(events.recv().await.is_ok());
+        let stats = gateway_backpressure_stats("lagging".to_string()).expect("stats");
+        assert_eq!(stats.lagged, overflow as u64);
+    }
+
+    #[tokio::test]
+    async fn stream_output_sent_right_after_open_waits_for_attach() {
+        let (client, server) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client]);
+        let (opened_tx, opened_rx) = oneshot::channel::<()>();
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            let request = read_request(&mut ws_stream).await;
+            assert_eq!(request["method"], "streams.open");
+            let response = json!({
+                "type": "res",
+                "id": request["id"],
+                "ok": true,
+                "payload": { "streamId": "shell-1" }
+            });
+            let prompt = json!({
+                "type": "event",
+                "event": "stream.data",
+                "payload": { "streamId": "shell-1", "stream": "shell", "data": "$ " }
+            });
+            for frame in [response, prompt] {
+                ws_stream
+                    .send(Message::Text(frame.to_string().into()))
+                    .await
+                    .expect("frame");
+            }
+            let _ = opened_rx.await;
+            let _ = ws_stream.send(Message::Close(None)).await;
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "open-attach".to_string(),
+                "ws://test".to_string(),
+                TestSink::new(tx),
+                test_config(1),
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Connected { .. }), "{event:?}");
+
+        let stream_id = crate::api::terminal::terminal_stream_open(
+            "open-attach".to_string(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("open");
+        assert_eq!(stream_id, "shell-1");
+        // Let the prompt reach the broadcast before anything attaches.
+        sleep(StdDuration::from_millis(50)).await;
+        let mut events =
+            crate::api::terminal::take_pending_stream("open-attach", &stream_id).expect("pending");
+        let data = loop {
+            if let GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::StreamData(data),
+                ..
+            } = events.recv().await.expect("event")
+            {
+                break data;
+            }
+        };
+        assert_eq!(data.data.as_deref(), Some("$ "));
+
+        let _ = opened_tx.send(());
+        client_task.await.expect("join").expect("client");
+        let _ = server_task.await;
+    }
+
+    #[test]
+    fn jittered_backoff_stays_within_bounds() {
+        let backoff = WaitDuration::from_millis(400);
+        assert_eq!(backoff_delay(backoff, false), backoff);
+        for _ in 0..32 {
+            assert!(backoff_delay(backoff, true) <= backoff);
+        }
+    }
+
+    #[tokio::test]
+    async fn disconnect_closes_socket_and_fails_pending_requests() {
+        let (client, server) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client]);
+        let (request_seen_tx, request_seen) = oneshot::channel();
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream = WebSocketStream::from_raw_socket(server, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            read_request(&mut ws_stream).await;
+            let _ = request_seen_tx.send(());
+            loop {
+                match ws_stream.next().await {
+                    Some(Ok(Message::Close(frame))) => return frame,
+                    Some(Ok(_)) => {}
+                    _ => return None,
+                }
+            }
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "disconnect".to_string(),
+                "ws://test".to_string(),
+                TestSink::new(tx),
+                test_config(5),
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Connected { .. }), "{event:?}");
+
+        let frame_json = test_frame("req-pending").to_json().expect("frame");
+        let request = tokio::spawn(gateway_request(
+            "disconnect".to_string(),
+            frame_json,
+            Some(5000),
+        ));
+        request_seen.await.expect("request seen");
+        disconnect_gateway("disconnect".to_string(), "Signed out".to_string()).expect("disconnect");
+
+        let error = request.await.expect("join").expect_err("failed");
+        assert_eq!(error.to_string(), "Request req-pending failed: Signed out");
+        let frame = server_task.await.expect("server").expect("close frame");
+        assert_eq!(frame.code, CloseCode::Normal);
+        assert_eq!(frame.reason.as_str(), "Signed out");
+        tokio::time::timeout(WaitDuration::from_secs(1), client_task)
+            .await
+            .expect("client returns")
+            .expect("join")
+            .expect("client");
+        let mut last = None;
+        while let Some(event) = collect_event(&mut rx, WaitDuration::from_millis(50)).await {
+            last = Some(event);
+        }
+        assert!(
+            matches!(&last, Some(GatewayEvent::Disconnected { reason }) if reason == "Signed out"),
+            "{last:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn disconnect_interrupts_backoff() {
+        let connector = TestConnector::new(Vec::new());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            backoff_base: Duration::seconds(30),
+            max_backoff: Duration::seconds(30),
+            max_sessions: None,
+            ..test_config(1)
+        };
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "backoff-stop".to_string(),
+                "ws://test".to_string(),
+                TestSink::new(tx),
+                config,
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Error { .. }), "{event:?}");
+
+        disconnect_gateway("backoff-stop".to_string(), "Cancelled".to_string())
+            .expect("disconnect");
+        tokio::time::timeout(WaitDuration::from_secs(1), client_task)
+            .await
+            .expect("client returns")
+            .expect("join")
+            .expect("client");
+        assert!(disconnect_gateway("backoff-stop".to_string(), String::new()).is_err());
+    }
+
+    #[tokio::test]
+    async fn outbox_holds_requests_until_the_next_session() {
+        let connector = TestConnector::new(Vec::new());
+        let streams = Arc::clone(&connector.streams);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            outbox: Some(OutboxConfig::default()),
+            ..test_config(1)
+        };
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "outbox".to_string(),
+                "ws://test".to_string(),
+                TestSink::new(tx),
+                config,
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Error { .. }), "{event:?}");
+
+        let client = GatewayClient::new("ws://test".to_string());
+        let queued = client
+            .agent_turn_request(
+                "agent-1".to_string(),
+                "hello".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("frame");
+        let delivery = send_gateway_request_frame("outbox".to_string(), queued).expect("queued");
+        assert_eq!(delivery, RequestDelivery::Queued);
+        let probe = test_frame("probe-1").to_json().expect("frame");
+        let error = send_gateway_request_frame("outbox".to_string(), probe).expect_err("rejected");
+        assert!(error.to_string().contains("not connected"), "{error}");
+
+        let (client_stream, server_stream) = tokio::io::duplex(4096);
+        let (answer_tx, answer_rx) = oneshot::channel::<()>();
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream =
+                WebSocketStream::from_raw_socket(server_stream, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            let mut requests = BTreeMap::new();
+            for _ in 0..3 {
+                let request = read_request(&mut ws_stream).await;
+                let id = request["id"].as_str().expect("id").to_string();
+                requests.insert(id, request);
+            }
+            // Only the first agent turn is answered; the second stays queued.
+            let _ = answer_rx.await;
+            let response = json!({
+                "type": "res",
+                "id": "agent-1",
+                "ok": true,
+                "payload": { "status": "accepted" }
+            });
+            ws_stream
+                .send(Message::Text(response.to_string().into()))
+                .await
+                .expect("response");
+            let _ = ws_stream.send(Message::Close(None)).await;
+            requests
+        });
+        streams.lock().await.push_back(client_stream);
+        loop {
+            let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+                .await
+                .expect("event");
+            if matches!(event, GatewayEvent::Connected { .. }) {
+                break;
+            }
+        }
+        let second = client
+            .agent_turn_request(
+                "agent-2".to_string(),
+                "again".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("frame");
+        let delivery = send_gateway_request_frame("outbox".to_string(), second).expect("queued");
+        assert_eq!(delivery, RequestDelivery::Queued);
+        let live = test_frame("live-1").to_json().expect("frame");
+        let delivery = send_gateway_request_frame("outbox".to_string(), live).expect("sent");
+        assert_eq!(delivery, RequestDelivery::Sent);
+
+        while let Some(event) = collect_event(&mut rx, WaitDuration::from_millis(50)).await {
+            assert!(
+                !matches!(event, GatewayEvent::OutboxDelivered { .. }),
+                "delivered before the gateway answered"
+            );
+        }
+        let _ = answer_tx.send(());
+        let requests = server_task.await.expect("server");
+        assert_eq!(requests["agent-1"]["method"], "agent");
+        assert_eq!(requests["agent-2"]["method"], "agent");
+        assert!(requests.contains_key("live-1"));
+        client_task.await.expect("join").expect("client");
+        let mut delivered = Vec::new();
+        while let Some(event) = collect_event(&mut rx, WaitDuration::from_millis(50)).await {
+            if let GatewayEvent::OutboxDelivered { id } = event {
+                delivered.push(id);
+            }
+        }
+        assert_eq!(delivered, ["agent-1"]);
+    }
+
+    #[tokio::test]
+    async fn disconnect_reports_expired_outbox_items() {
+        let connector = TestConnector::new(Vec::new());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            backoff_base: Duration::seconds(30),
+            max_backoff: Duration::seconds(30),
+            max_sessions: None,
+            outbox: Some(OutboxConfig {
+                ttl: Duration::milliseconds(20),
+                ..OutboxConfig::default()
+            }),
+            ..test_config(1)
+        };
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "outbox-expiry".to_string(),
+                "ws://test".to_string(),
+                TestSink::new(tx),
+                config,
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Error { .. }), "{event:?}");
+
+        let queued = GatewayClient::new("ws://test".to_string())
+            .system_event_request("evt-1".to_string(), None, None, None)
+            .expect("frame");
+        let delivery =
+            send_gateway_request_frame("outbox-expiry".to_string(), queued).expect("queued");
+        assert_eq!(delivery, RequestDelivery::Queued);
+        sleep(StdDuration::from_millis(40)).await;
+
+        disconnect_gateway("outbox-expiry".to_string(), "done".to_string()).expect("disconnect");
+        timeout(WaitDuration::from_secs(1), client_task)
+            .await
+            .expect("client returns")
+            .expect("join")
+            .expect("client");
+        let mut expired = false;
+        while let Some(event) = collect_event(&mut rx, WaitDuration::from_millis(50)).await {
+            expired |= matches!(&event, GatewayEvent::OutboxExpired { id } if id == "evt-1");
+        }
+        assert!(expired);
+    }
+
+    #[tokio::test]
+    async fn paused_output_is_coalesced_and_counted() {
+        let (client_stream, server_stream) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client_stream]);
+        let (resume_tx, resume_rx) = oneshot::channel::<()>();
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream =
+                WebSocketStream::from_raw_socket(server_stream, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            let _ = resume_rx.await;
+            for (seq, chunk) in [(1, "ab"), (2, "cd"), (3, "ef")] {
+                let event = json!({
+                    "type": "event",
+                    "event": "stream.data",
+                    "seq": seq,
+                    "payload": {
+                        "stream": "stdout",
+                        "streamId": "s1",
+                        "data": STANDARD.encode(chunk),
+                        "encoding": "base64"
+                    }
+                });
+                ws_stream
+                    .send(Message::Text(event.to_string().into()))
+                    .await
+                    .expect("event");
+            }
+            while let Some(Ok(_)) = ws_stream.next().await {}
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            inbound_capacity: 1,
+            ..test_config(1)
+        };
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "backpressure".to_string(),
+                "ws://test".to_string(),
+                TestSink::new(tx),
+                config,
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Connected { .. }), "{event:?}");
+
+        set_gateway_events_paused("backpressure".to_string(), true).expect("pause");
+        resume_tx.send(()).expect("resume");
+        let deadline = Instant::now() + StdDuration::from_secs(1);
+        let stats = loop {
+            let stats = gateway_backpressure_stats("backpressure".to_string()).expect("stats");
+            if stats.coalesced == 2 || Instant::now() >= deadline {
+                break stats;
+            }
+            sleep(StdDuration::from_millis(5)).await;
+        };
+        assert_eq!(stats.coalesced, 2, "{stats:?}");
+        assert!(stats.queued >= 1, "{stats:?}");
+        assert!(stats.paused);
+
+        set_gateway_events_paused("backpressure".to_string(), false).expect("resume");
+        let event = loop {
+            let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+                .await
+                .expect("event");
+            if !matches!(event, GatewayEvent::ConnectionQuality { .. }) {
+                break event;
+            }
+        };
+        match event {
+            GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::StreamData(data),
+                ..
+            } => assert_eq!(data.data, Some(STANDARD.encode("abcdef"))),
+            other => panic!("expected merged stream data, got {other:?}"),
+        }
+
+        disconnect_gateway("backpressure".to_string(), "done".to_string()).expect("disconnect");
+        client_task.await.expect("join").expect("client");
+        server_task.await.expect("server");
+    }
+
+    /// Pauses events, lets the gateway fill the queue, then checks that requests
+    /// and disconnects are still served.
+    async fn full_paused_queue_session(handle: &str, event_policy: BackpressurePolicy) {
+        let (client_stream, server_stream) = tokio::io::duplex(4096);
+        let connector = TestConnector::new(vec![client_stream]);
+        let (resume_tx, resume_rx) = oneshot::channel::<()>();
+        let server_task = tokio::spawn(async move {
+            let mut ws_stream =
+                WebSocketStream::from_raw_socket(server_stream, Role::Server, None).await;
+            accept_handshake(&mut ws_stream).await;
+            let _ = resume_rx.await;
+            for seq in 1..=3 {
+                let event = json!({
+                    "type": "event",
+                    "event": "custom.note",
+                    "seq": seq,
+                    "payload": { "n": seq }
+                });
+                ws_stream
+                    .send(Message::Text(event.to_string().into()))
+                    .await
+                    .expect("event");
+            }
+            loop {
+                match ws_stream.next().await {
+                    Some(Ok(Message::Text(text))) => {
+                        let request: Value = serde_json::from_str(&text).expect("json");
+                        let response = json!({
+                            "type": "res",
+                            "id": request["id"],
+                            "ok": true,
+                            "payload": { "ok": 1 }
+                        });
+                        ws_stream
+                            .send(Message::Text(response.to_string().into()))
+                            .await
+                            .expect("response");
+                    }
+                    Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                    Some(Ok(_)) => {}
+                }
+            }
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            inbound_capacity: 2,
+            event_policy,
+            ..test_config(1)
+        };
+        let name = handle.to_string();
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                name,
+                "ws://test".to_string(),
+                TestSink::new(tx),
+                config,
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Connected { .. }), "{event:?}");
+
+        set_gateway_events_paused(handle.to_string(), true).expect("pause");
+        resume_tx.send(()).expect("resume");
+        let deadline = Instant::now() + StdDuration::from_secs(1);
+        while gateway_backpressure_stats(handle.to_string())
+            .expect("stats")
+            .queued
+            < 2
+        {
+            assert!(Instant::now() < deadline, "queue never filled");
+            sleep(StdDuration::from_millis(5)).await;
+        }
+        // Let the third event reach the session before the request goes out.
+        sleep(StdDuration::from_millis(100)).await;
+
+        let frame_json = test_frame("req-full").to_json().expect("frame");
+        let response = gateway_request(handle.to_string(), frame_json, Some(1000)).await;
+        assert!(response.is_ok(), "{response:?}");
+
+        disconnect_gateway(handle.to_string(), "done".to_string()).expect("disconnect");
+        tokio::time::timeout(WaitDuration::from_secs(1), client_task)
+            .await
+            .expect("client returns")
+            .expect("join")
+            .expect("client");
+        server_task.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn full_paused_queue_still_serves_requests_and_disconnect() {
+        let config = ConnectionConfig::default();
+        assert_ne!(config.event_policy, BackpressurePolicy::Block);
+        full_paused_queue_session("full-default", config.event_policy).await;
+    }
+
+    #[tokio::test]
+    async fn blocked_events_do_not_stall_requests_or_disconnect() {
+        full_paused_queue_session("full-block", BackpressurePolicy::Block).await;
+    }
+
+    fn tls_acceptor(
+        cert_pem: &str,
+        key_pem: &str,
+        client_ca_pem: Option<&str>,
+    ) -> tokio_rustls::TlsAcceptor {
+        use rustls_pki_types::pem::PemObject;
+        use rustls_pki_types::{CertificateDer, PrivateKeyDer};
+
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let cert = CertificateDer::from_pem_slice(cert_pem.as_bytes()).expect("cert");
+        let key = PrivateKeyDer::from_pem_slice(key_pem.as_bytes()).expect("key");
+        let builder = rustls::ServerConfig::builder_with_provider(Arc::clone(&provider))
+            .with_safe_default_protocol_versions()
+            .expect("protocols");
+        let builder = match client_ca_pem {
+            Some(ca_pem) => {
+                let mut roots = rustls::RootCertStore::empty();
+                roots
+                    .add(CertificateDer::from_pem_slice(ca_pem.as_bytes()).expect("ca"))
+                    .expect("root");
+                let verifier = rustls::server::WebPkiClientVerifier::builder_with_provider(
+                    Arc::new(roots),
+                    provider,
+                )
+                .build()
+                .expect("client verifier");
+                builder.with_client_cert_verifier(verifier)
+            }
+            None => builder.with_no_client_auth(),
+        };
+        let config = builder
+            .with_single_cert(vec![cert], key)
+            .expect("server config");
+        tokio_rustls::TlsAcceptor::from(Arc::new(config))
+    }
+
+    #[tokio::test]
+    async fn changed_certificate_key_is_fatal_under_trust_on_first_use() {
+        use crate::api::tls::tests::{CA_PEM, LEAF_KEY_PEM, LEAF_PEM, OTHER_KEY_PEM, OTHER_PEM};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server_task = tokio::spawn(async move {
+            for (cert, key) in [(OTHER_PEM, OTHER_KEY_PEM), (LEAF_PEM, LEAF_KEY_PEM)] {
+                let (tcp, _) = listener.accept().await.expect("accept");
+                let Ok(tls) = tls_acceptor(cert, key, None).accept(tcp).await else {
+                    continue;
+                };
+                let mut ws_stream = tokio_tungstenite::accept_async(tls)
+                    .await
+                    .expect("websocket");
+                accept_handshake(&mut ws_stream).await;
+                let _ = ws_stream.send(Message::Close(None)).await;
+                while let Some(Ok(_)) = ws_stream.next().await {}
+            }
+        });
+
+        let known_hosts_dir =
+            std::env::temp_dir().join(format!("openclaw-tofu-connect-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&known_hosts_dir);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = ConnectionConfig {
+            tls: Some(TlsOptions {
+                extra_ca_pem: vec![CA_PEM.to_string()],
+                trust_on_first_use: true,
+                known_hosts_dir: Some(known_hosts_dir.to_string_lossy().into_owned()),
+                allow_self_signed: true,
+                ..TlsOptions::default()
+            }),
+            ..test_config(5)
+        };
+        let connector = DefaultConnector::with_tls(config.tls.as_ref().expect("tls")).expect("tls");
+        let states = connection_state("tofu");
+        let result = timeout(
+            WaitDuration::from_secs(5),
+            connect_to_gateway_with_sink_and_connector(
+                "tofu".to_string(),
+                format!("wss://localhost:{port}"),
+                TestSink::new(tx),
+                config,
+                HandshakeOptions::default(),
+                &connector,
+            ),
+        )
+        .await
+        .expect("client should stop after the key change");
+        server_task.await.expect("server");
+        let _ = std::fs::remove_dir_all(&known_hosts_dir);
+
+        let error = result.expect_err("fatal");
+        assert!(error.to_string().contains("changed"), "{error}");
+        assert!(matches!(
+            &*states.borrow(),
+            ConnectionState::Failed { fatal: true, .. }
+        ));
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, GatewayEvent::Connected { .. })));
+        assert!(
+            matches!(
+                events.last(),
+                Some(GatewayEvent::CertificateChanged { host, .. }) if host == "localhost"
+            ),
+            "{events:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn client_certificate_is_presented_and_rejection_is_typed() {
+        use crate::api::tls::tests::{CA_PEM, CLIENT_KEY_PEM, CLIENT_PEM, LEAF_KEY_PEM, LEAF_PEM};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server_task = tokio::spawn(async move {
+            let acceptor = tls_acceptor(LEAF_PEM, LEAF_KEY_PEM, Some(CA_PEM));
+            let mut accepted = 0;
+            while let Ok((tcp, _)) = listener.accept().await {
+                let Ok(tls) = acceptor.accept(tcp).await else {
+                    continue;
+                };
+                let mut ws_stream = tokio_tungstenite::accept_async(tls)
+                    .await
+                    .expect("websocket");
+                accept_handshake(&mut ws_stream).await;
+                let _ = ws_stream.send(Message::Close(None)).await;
+                while let Some(Ok(_)) = ws_stream.next().await {}
+                accepted += 1;
+            }
+            accepted
+        });
+
+        let trusted = TlsOptions {
+            extra_ca_pem: vec![CA_PEM.to_string()],
+            ..TlsOptions::default()
+        };
+        let with_identity = TlsOptions {
+            client_cert_chain_pem: Some(CLIENT_PEM.to_string()),
+            client_key_pem: Some(CLIENT_KEY_PEM.to_string()),
+            ..trusted.clone()
+        };
+        let url = format!("wss://localhost:{port}");
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        connect_to_gateway_with_sink_and_connector(
+            "mtls-ok".to_string(),
+            url.clone(),
+            TestSink::new(tx),
+            test_config(1),
+            HandshakeOptions::default(),
+            &DefaultConnector::with_tls(&with_identity).expect("tls"),
+        )
+        .await
+        .expect("client");
+        let event = collect_event(&mut rx, WaitDuration::from_secs(1))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Connected { .. }), "{event:?}");
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let error = timeout(
+            WaitDuration::from_secs(5),
+            connect_to_gateway_with_sink_and_connector(
+                "mtls-missing".to_string(),
+                url,
+                TestSink::new(tx),
+                test_config(5),
+                HandshakeOptions::default(),
+                &DefaultConnector::with_tls(&trusted).expect("tls"),
+            ),
+        )
+        .await
+        .expect("client should stop")
+        .expect_err("rejected");
+        assert!(
+            matches!(
+                error.downcast_ref::<ClientCertificateError>(),
+                Some(ClientCertificateError::Rejected { .. })
+            ),
+            "{error}"
+        );
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        assert!(
+            matches!(
+                events.as_slice(),
+                [GatewayEvent::ClientCertificateRejected { .. }]
+            ),
+            "{events:?}"
+        );
+        server_task.abort();
+    }
+
+    #[tokio::test]
+    async fn gateway_session_runs_through_socks5_proxy() {
+        use crate::api::proxy::tests::spawn_socks5_proxy;
+        use crate::api::proxy::ProxyKind;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server_task = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.expect("accept");
+            let mut ws_stream = tokio_tungstenite::accept_async(tcp)
+                .await
+                .expect("websocket");
+            accept_handshake(&mut ws_stream).await;
+            while let Some(Ok(message)) = ws_stream.next().await {
+                if matches!(message, Message::Close(_)) {
+                    break;
+                }
+            }
+        });
+
+        let proxy = ProxyConfig {
+            kind: ProxyKind::Socks5,
+            host: "127.0.0.1".to_string(),
+            port: spawn_socks5_proxy().await,
+            username: Some("user".to_string()),
+            password: Some("pass".to_string()),
+        };
+        let connector = DefaultConnector::default().with_proxy(proxy);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "proxied".to_string(),
+                format!("ws://localhost:{port}"),
+                TestSink::new(tx),
+                test_config(5),
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(2))
+            .await
+            .expect("event");
+        assert!(matches!(event, GatewayEvent::Connected { .. }), "{event:?}");
+
+        disconnect_gateway("proxied".to_string(), "done".to_string()).expect("disconnect");
+        timeout(WaitDuration::from_secs(2), client_task)
+            .await
+            .expect("client returns")
+            .expect("join")
+            .expect("client");
+        server_task.await.expect("server");
+    }
+
+    #[tokio::test]
+    async fn upgrade_carries_headers_and_reports_negotiated_subprotocol() {
+        use tokio_tungstenite::tungstenite::handshake::server::{
+            ErrorResponse, Request, Response as UpgradeResponse,
+        };
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server_task = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.expect("accept");
+            let mut seen = None;
+            #[allow(clippy::result_large_err)]
+            let callback = |request: &Request, mut response: UpgradeResponse| {
+                seen = Some(request.headers().clone());
+                let headers = response.headers_mut();
+                headers.insert(
+                    SEC_WEBSOCKET_PROTOCOL,
+                    HeaderValue::from_static("gateway.v3"),
+                );
+                headers.insert("x-gateway-node", HeaderValue::from_static("node-7"));
+                Ok::<_, ErrorResponse>(response)
+            };
+            let mut ws_stream = tokio_tungstenite::accept_hdr_async(tcp, callback)
+                .await
+                .expect("websocket");
+            accept_handshake(&mut ws_stream).await;
+            while let Some(Ok(message)) = ws_stream.next().await {
+                if matches!(message, Message::Close(_)) {
+                    break;
+                }
+            }
+            seen.expect("upgrade request")
+        });
+
+        let config = ConnectionConfig {
+            upgrade_headers: vec![
+                UpgradeHeader {
+                    name: "Authorization".to_string(),
+                    value: "Bearer secret".to_string(),
+                },
+                UpgradeHeader {
+                    name: "User-Agent".to_string(),
+                    value: "gateway-app/1.0".to_string(),
+                },
+            ],
+            subprotocols: vec!["gateway.v4".to_string(), "gateway.v3".to_string()],
+            ..test_config(5)
+        };
+        let states = connection_state("upgrade");
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                "upgrade".to_string(),
+                format!("ws://127.0.0.1:{port}"),
+                TestSink::new(tx),
+                config,
+                HandshakeOptions::default(),
+                &DefaultConnector::default(),
+            )
+            .await
+        });
+        let event = collect_event(&mut rx, WaitDuration::from_secs(2))
+            .await
+            .expect("event");
+        match event {
+            GatewayEvent::Connected {
+                subprotocol,
+                response_headers,
+                ..
+            } => {
+                assert_eq!(subprotocol.as_deref(), Some("gateway.v3"));
+                assert!(response_headers.contains(&UpgradeHeader {
+                    name: "x-gateway-node".to_string(),
+                    value: "node-7".to_string(),
+                }));
+            }
+            other => panic!("expected connected, got {other:?}"),
+        }
+        assert!(matches!(
+            &*states.borrow(),
+            ConnectionState::Ready { subprotocol: Some(subprotocol), .. } if subprotocol == "gateway.v3"
+        ));
+
+        disconnect_gateway("upgrade".to_string(), "done".to_string()).expect("disconnect");
+        timeout(WaitDuration::from_secs(2), client_task)
+            .await
+            .expect("client returns")
+            .expect("join")
+            .expect("client");
+        let headers = server_task.await.expect("server");
+        assert_eq!(headers["authorization"], "Bearer secret");
+        assert_eq!(headers["user-agent"], "gateway-app/1.0");
+        assert_eq!(headers["sec-websocket-protocol"], "gateway.v4, gateway.v3");
+    }
+
+    async fn deflate_session(handle: &str, answer: &'static str) -> CompressionStats {
+        use crate::api::deflate::DeflateStream;
+        use tokio_tungstenite::tungstenite::handshake::server::{
+            ErrorResponse, Request, Response as UpgradeResponse,
+        };
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server_task = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.expect("accept");
+            let mut offer = None;
+            #[allow(clippy::result_large_err)]
+            let callback = |request: &Request, mut response: UpgradeResponse| {
+                offer = request.headers().get(SEC_WEBSOCKET_EXTENSIONS).cloned();
+                response
+                    .headers_mut()
+                    .insert(SEC_WEBSOCKET_EXTENSIONS, HeaderValue::from_static(answer));
+                Ok::<_, ErrorResponse>(response)
+            };
+            let mut ws_stream =
+                tokio_tungstenite::accept_hdr_async(DeflateStream::server(tcp), callback)
+                    .await
+                    .expect("websocket");
+            accept_handshake(&mut ws_stream).await;
+            let lines = vec!["GET /health 200 0.4ms"; 200];
+            let logs = json!({
+                "type": "event",
+                "event": "system.note",
+                "payload": { "lines": lines }
+            });
+            ws_stream
+                .send(Message::Text(logs.to_string().into()))
+                .await
+                .expect("logs");
+            while let Some(Ok(message)) = ws_stream.next().await {
+                if matches!(message, Message::Close(_)) {
+                    break;
+                }
+            }
+            offer.expect("deflate offer")
+        });
+
+        let config = ConnectionConfig {
+            deflate: Some(DeflateConfig::default()),
+            ..test_config(5)
+        };
+        let mut connector = DefaultConnector::default();
+        if let Some(deflate) = config.deflate.clone() {
+            connector = connector.with_deflate(deflate).expect("deflate");
+        }
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let task_handle = handle.to_string();
+        let client_task = tokio::spawn(async move {
+            connect_to_gateway_with_sink_and_connector(
+                task_handle,
+                format!("ws://127.0.0.1:{port}"),
+                TestSink::new(tx),
+                config,
+                HandshakeOptions::default(),
+                &connector,
+            )
+            .await
+        });
+        loop {
+            let event = collect_event(&mut rx, WaitDuration::from_secs(2))
+                .await
+                .expect("event");
+            if let GatewayEvent::ProtocolEvent { event, payload, .. } = event {
+                assert_eq!(event, "system.note");
+                let GatewayEventPayload::Unknown(payload) = payload else {
+                    panic!("unexpected payload {payload:?}");
+                };
+                assert_eq!(payload["lines"].as_array().map(Vec::len), Some(200));
+                break;
+            }
+        }
+        let stats = gateway_compression_stats(handle.to_string()).expect("stats");
+
+        disconnect_gateway(handle.to_string(), "done".to_string()).expect("disconnect");
+        timeout(WaitDuration::from_secs(2), client_task)
+            .await
+            .expect("client returns")
+            .expect("join")
+            .expect("client");
+        let offer = server_task.await.expect("server");
+        assert_eq!(offer, "permessage-deflate");
+        stats
+    }
+
+    #[tokio::test]
+    async fn negotiated_deflate_compresses_both_directions() {
+        let stats = deflate_session("deflate", "permessage-deflate").await;
+        assert!(stats.negotiated);
+        assert!(!stats.outgoing_compression_disabled);
+        assert!(
+            stats.received_wire_bytes * 10 < stats.received_bytes,
+            "{stats:?}"
+        );
+        assert!(stats.sent_wire_bytes < stats.sent_bytes, "{stats:?}");
+    }
+
+    #[tokio::test]
+    async fn demanded_narrow_client_window_is_reported_as_uncompressed_sending() {
+        let stats = deflate_session(
+            "deflate-narrow",
+            "permessage-deflate; client_max_window_bits=10; server_no_context_takeover",
+        )
+        .await;
+        assert!(stats.negotiated);
+        assert!(stats.outgoing_compression_disabled, "{stats:?}");
+        assert!(
+            stats.received_wire_bytes * 10 < stats.received_bytes,
+            "{stats:?}"
+        );
+        assert!(stats.sent_bytes > 0);
+        assert_eq!(stats.sent_wire_bytes, stats.sent_bytes, "{stats:?}");
+    }
 }
@@ -0,0 +1,755 @@
+use anyhow::{anyhow, Result};
+use flate2::{Compress, Compression, Decompress, FlushCompress, FlushDecompress};
+use flutter_rust_bridge::frb;
+use std::io::{self, Cursor};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio_tungstenite::tungstenite::protocol::frame::coding::{Data, OpCode};
+use tokio_tungstenite::tungstenite::protocol::frame::FrameHeader;
+use tokio_tungstenite::tungstenite::protocol::Role;
+
+const EXTENSION_NAME: &str = "permessage-deflate";
+const EXTENSIONS_HEADER: &str = "sec-websocket-extensions";
+const MAX_WINDOW_BITS: u8 = 15;
+const DEFLATE_TAIL: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+const MAX_HANDSHAKE_BYTES: usize = 16 * 1024;
+const MAX_INFLATED_BYTES: usize = 64 << 20;
+const WRITE_HIGH_WATER: usize = 256 * 1024;
+const READ_CHUNK_BYTES: usize = 16 * 1024;
+
+/// permessage-deflate parameters offered to the gateway.
+///
+/// Outgoing messages can only be compressed with the full 15-bit window, the
+/// only one the deflate backend implements. The offer therefore never invites
+/// the gateway to pick a smaller client window; a gateway that demands one anyway
+/// gets uncompressed outgoing messages, reported by
+/// `CompressionStats::outgoing_compression_disabled`. Incoming messages are
+/// inflated whatever window the gateway compresses with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeflateConfig {
+    /// LZ77 window used for outgoing messages. Must be 15, see above.
+    pub client_max_window_bits: u8,
+    /// Window the gateway is asked to compress with.
+    pub server_max_window_bits: u8,
+    /// Resets the compressor after every outgoing message.
+    pub client_no_context_takeover: bool,
+    /// Asks the gateway to reset its compressor after every message.
+    pub server_no_context_takeover: bool,
+}
+
+impl Default for DeflateConfig {
+    fn default() -> Self {
+        Self {
+            client_max_window_bits: MAX_WINDOW_BITS,
+            server_max_window_bits: MAX_WINDOW_BITS,
+            client_no_context_takeover: false,
+            server_no_context_takeover: false,
+        }
+    }
+}
+
+impl DeflateConfig {
+    pub(crate) fn validate(&self) -> Result<()> {
+        for bits in [self.client_max_window_bits, self.server_max_window_bits] {
+            if !(8..=MAX_WINDOW_BITS).contains(&bits) {
+                return Err(anyhow!("Window bits must be between 8 and 15, got {bits}"));
+            }
+        }
+        if self.client_max_window_bits != MAX_WINDOW_BITS {
+            return Err(anyhow!(
+                "Client window bits must be 15, the only window outgoing messages can be compressed with, got {}",
+                self.client_max_window_bits
+            ));
+        }
+        Ok(())
+    }
+
+    /// `Sec-WebSocket-Extensions` value offered in the upgrade request.
+    pub(crate) fn offer(&self) -> String {
+        let mut offer = EXTENSION_NAME.to_string();
+        if self.server_max_window_bits < MAX_WINDOW_BITS {
+            offer.push_str(&format!(
+                "; server_max_window_bits={}",
+                self.server_max_window_bits
+            ));
+        }
+        if self.client_no_context_takeover {
+            offer.push_str("; client_no_context_takeover");
+        }
+        if self.server_no_context_takeover {
+            offer.push_str("; server_no_context_takeover");
+        }
+        offer
+    }
+}
+
+/// Message payload bytes before compression (`sent_bytes`, `received_bytes`) and
+/// as they crossed the socket (`*_wire_bytes`), summed over all reconnects.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompressionStats {
+    /// Whether the gateway accepted permessage-deflate on the current socket.
+    pub negotiated: bool,
+    /// Whether messages sent on the current socket go out uncompressed because
+    /// the gateway demanded a client window smaller than 15 bits.
+    pub outgoing_compression_disabled: bool,
+    pub sent_bytes: u64,
+    pub sent_wire_bytes: u64,
+    pub received_bytes: u64,
+    pub received_wire_bytes: u64,
+}
+
+#[frb(ignore)]
+#[derive(Debug, Default)]
+pub struct CompressionCounters {
+    negotiated: AtomicBool,
+    outgoing_compression_disabled: AtomicBool,
+    sent_bytes: AtomicU64,
+    sent_wire_bytes: AtomicU64,
+    received_bytes: AtomicU64,
+    received_wire_bytes: AtomicU64,
+}
+
+impl CompressionCounters {
+    pub fn stats(&self) -> CompressionStats {
+        CompressionStats {
+            negotiated: self.negotiated.load(Ordering::Relaxed),
+            outgoing_compression_disabled: self
+                .outgoing_compression_disabled
+                .load(Ordering::Relaxed),
+            sent_bytes: self.sent_bytes.load(Ordering::Relaxed),
+            sent_wire_bytes: self.sent_wire_bytes.load(Ordering::Relaxed),
+            received_bytes: self.received_bytes.load(Ordering::Relaxed),
+            received_wire_bytes: self.received_wire_bytes.load(Ordering::Relaxed),
+        }
+    }
+
+    fn sent(&self, raw: usize, wire: usize) {
+        self.sent_bytes.fetch_add(raw as u64, Ordering::Relaxed);
+        self.sent_wire_bytes
+            .fetch_add(wire as u64, Ordering::Relaxed);
+    }
+
+    fn received(&self, raw: usize, wire: usize) {
+        self.received_bytes.fetch_add(raw as u64, Ordering::Relaxed);
+        self.received_wire_bytes
+            .fetch_add(wire as u64, Ordering::Relaxed);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Negotiated {
+    client_max_window_bits: u8,
+    server_max_window_bits: u8,
+    client_no_context_takeover: bool,
+    server_no_context_takeover: bool,
+}
+
+/// Parses the accepted extensions of an upgrade response; `None` when the
+/// gateway declined permessage-deflate.
+fn parse_extensions(value: &str) -> io::Result<Option<Negotiated>> {
+    for extension in value.split(',') {
+        let mut params = extension.split(';').map(str::trim);
+        if params.next() != Some(EXTENSION_NAME) {
+            continue;
+        }
+        let mut negotiated = Negotiated {
+            client_max_window_bits: MAX_WINDOW_BITS,
+            server_max_window_bits: MAX_WINDOW_BITS,
+            client_no_context_takeover: false,
+            server_no_context_takeover: false,
+        };
+        for param in params {
+            let (name, value) = match param.split_once('=') {
+                Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+                None => (param, None),
+            };
+            match (name, value) {
+                ("client_no_context_takeover", None) => {
+                    negotiated.client_no_context_takeover = true
+                }
+                ("server_no_context_takeover", None) => {
+                    negotiated.server_no_context_takeover = true
+                }
+                ("client_max_window_bits", Some(bits)) => {
+                    negotiated.client_max_window_bits = window_bits(bits)?
+                }
+                ("server_max_window_bits", Some(bits)) => {
+                    negotiated.server_max_window_bits = window_bits(bits)?
+                }
+                _ => {
+                    return Err(invalid_data(format!(
+                        "Unsupported {EXTENSION_NAME} parameter: {param}"
+                    )))
+                }
+            }
+        }
+        return Ok(Some(negotiated));
+    }
+    Ok(None)
+}
+
+fn window_bits(value: &str) -> io::Result<u8> {
+    value
+        .parse()
+        .ok()
+        .filter(|bits| (8..=MAX_WINDOW_BITS).contains(bits))
+        .ok_or_else(|| invalid_data(format!("Invalid {EXTENSION_NAME} window bits: {value}")))
+}
+
+/// Reads the extension answer out of a raw `101 Switching Protocols` head.
+fn parse_upgrade_head(head: &[u8]) -> io::Result<Option<Negotiated>> {
+    let head = String::from_utf8_lossy(head);
+    let mut lines = head.split("\r\n");
+    let status = lines.next().unwrap_or_default();
+    if status.split_whitespace().nth(1) != Some("101") {
+        return Ok(None);
+    }
+    let extensions = lines
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(name, _)| name.trim().eq_ignore_ascii_case(EXTENSIONS_HEADER))
+        .map(|(_, value)| value)
+        .collect::<Vec<_>>()
+        .join(",");
+    parse_extensions(&extensions)
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn apply_mask(payload: &mut [u8], mask: [u8; 4]) {
+    for (index, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[index & 3];
+    }
+}
+
+/// Header and payload bounds of the first complete frame in `buf`.
+fn next_frame(buf: &[u8]) -> io::Result<Option<(FrameHeader, usize, usize)>> {
+    let mut cursor = Cursor::new(buf);
+    let Some((header, length)) =
+        FrameHeader::parse(&mut cursor).map_err(|e| invalid_data(e.to_string()))?
+    else {
+        return Ok(None);
+    };
+    let start = cursor.position() as usize;
+    let end = usize::try_from(length)
+        .ok()
+        .and_then(|length| start.checked_add(length))
+        .ok_or_else(|| invalid_data("Frame too large".to_string()))?;
+    Ok((end <= buf.len()).then_some((header, start, end)))
+}
+
+fn write_frame(header: &FrameHeader, mut payload: Vec<u8>, out: &mut Vec<u8>) -> io::Result<()> {
+    header
+        .format(payload.len() as u64, out)
+        .map_err(|e| invalid_data(e.to_string()))?;
+    if let Some(mask) = header.mask {
+        apply_mask(&mut payload, mask);
+    }
+    out.extend_from_slice(&payload);
+    Ok(())
+}
+
+struct Codec {
+    /// `None` when the negotiated window is smaller than the backend supports.
+    compressor: Option<Compress>,
+    reset_compressor: bool,
+    decompressor: Decompress,
+    reset_decompressor: bool,
+}
+
+impl Codec {
+    fn new(role: Role, config: Option<&DeflateConfig>, negotiated: Negotiated) -> Self {
+        let (window_bits, reset_compressor, reset_decompressor) = match role {
+            Role::Client => (
+                negotiated.client_max_window_bits,
+                negotiated.client_no_context_takeover
+                    || config.is_some_and(|config| config.client_no_context_takeover),
+                negotiated.server_no_context_takeover,
+            ),
+            Role::Server => (
+                negotiated.server_max_window_bits,
+                negotiated.server_no_context_takeover,
+                negotiated.client_no_context_takeover,
+            ),
+        };
+        Self {
+            compressor: (window_bits == MAX_WINDOW_BITS)
+                .then(|| Compress::new(Compression::default(), false)),
+            reset_compressor,
+            decompressor: Decompress::new(false),
+            reset_decompressor,
+        }
+    }
+
+    fn deflate(&mut self, input: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        let Some(compressor) = self.compressor.as_mut() else {
+            return Ok(None);
+        };
+        let start = compressor.total_in();
+        let mut output = Vec::with_capacity(input.len() / 2 + 64);
+        loop {
+            let consumed = (compressor.total_in() - start) as usize;
+            compressor
+                .compress_vec(&input[consumed..], &mut output, FlushCompress::Sync)
+                .map_err(|e| invalid_data(e.to_string()))?;
+            let consumed = (compressor.total_in() - start) as usize;
+            if consumed == input.len() && output.len() < output.capacity() {
+                break;
+            }
+            output.reserve(output.capacity().max(64));
+        }
+        if output.ends_with(&DEFLATE_TAIL) {
+            output.truncate(output.len() - DEFLATE_TAIL.len());
+        }
+        if self.reset_compressor {
+            compressor.reset();
+        }
+        Ok(Some(output))
+    }
+
+    fn inflate(&mut self, payload: &[u8]) -> io::Result<Vec<u8>> {
+        let mut input = Vec::with_capacity(payload.len() + DEFLATE_TAIL.len());
+        input.extend_from_slice(payload);
+        input.extend_from_slice(&DEFLATE_TAIL);
+        let decompressor = &mut self.decompressor;
+        let start = decompressor.total_in();
+        let mut output = Vec::with_capacity(payload.len() * 4 + 64);
+        loop {
+            let consumed = (decompressor.total_in() - start) as usize;
+            let produced = output.len();
+            decompressor
+                .decompress_vec(&input[consumed..], &mut output, FlushDecompress::Sync)
+                .map_err(|e| invalid_data(e.to_string()))?;
+            let now_consumed = (decompressor.total_in() - start) as usize;
+            if now_consumed == input.len() && output.len() < output.capacity() {
+                break;
+            }
+            if output.len() > MAX_INFLATED_BYTES {
+                return Err(invalid_data("Inflated message too large".to_string()));
+            }
+            if now_consumed == consumed
+                && output.len() == produced
+                && output.len() < output.capacity()
+            {
+                return Err(invalid_data("Truncated deflate stream".to_string()));
+            }
+            output.reserve(output.capacity());
+        }
+        if self.reset_decompressor {
+            decompressor.reset(false);
+        }
+        Ok(output)
+    }
+}
+
+/// Frame-level permessage-deflate on an upgraded socket.
+struct Transformer {
+    codec: Codec,
+    counters: Option<Arc<CompressionCounters>>,
+    /// First header and collected payload of a compressed message being received.
+    receiving: Option<(FrameHeader, Vec<u8>)>,
+    /// An uncompressed fragmented message is being sent.
+    sending_fragments: bool,
+}
+
+impl Transformer {
+    /// Inflates compressed messages from the peer, passing everything else through.
+    fn decode(&mut self, wire: &mut Vec<u8>, out: &mut Vec<u8>) -> io::Result<()> {
+        let mut pos = 0;
+        while let Some((header, start, end)) = next_frame(&wire[pos..])? {
+            let (start, end) = (pos + start, pos + end);
+            let compressed_start =
+                header.rsv1 && matches!(header.opcode, OpCode::Data(Data::Text | Data::Binary));
+            let continues =
+                self.receiving.is_some() && header.opcode == OpCode::Data(Data::Continue);
+            if compressed_start || continues {
+                let mut payload = wire[start..end].to_vec();
+                if let Some(mask) = header.mask {
+                    apply_mask(&mut payload, mask);
+                }
+                if compressed_start && self.receiving.is_some() {
+                    return Err(invalid_data("Compressed message interrupted".to_string()));
+                }
+                let is_final = header.is_final;
+                match self.receiving.as_mut() {
+                    Some((_, collected)) => collected.extend_from_slice(&payload),
+                    None => self.receiving = Some((header, payload)),
+                }
+                if is_final {
+                    let (first, compressed) = self.receiving.take().expect("message");
+                    let inflated = self.codec.inflate(&compressed)?;
+                    if let Some(counters) = &self.counters {
+                        counters.received(inflated.len(), compressed.len());
+                    }
+                    let header = FrameHeader {
+                        is_final: true,
+                        rsv1: false,
+                        ..first
+                    };
+                    write_frame(&header, inflated, out)?;
+                }
+            } else {
+                if let (OpCode::Data(_), Some(counters)) = (header.opcode, &self.counters) {
+                    counters.received(end - start, end - start);
+                }
+                out.extend_from_slice(&wire[pos..end]);
+            }
+            pos = end;
+        }
+        wire.drain(..pos);
+        Ok(())
+    }
+
+    /// Compresses complete outgoing messages; fragmented ones go out as they are.
+    fn encode(&mut self, frames: &mut Vec<u8>, out: &mut Vec<u8>) -> io::Result<()> {
+        let mut pos = 0;
+        while let Some((header, start, end)) = next_frame(&frames[pos..])? {
+            let (start, end) = (pos + start, pos + end);
+            let whole_message = header.is_final
+                && !self.sending_fragments
+                && matches!(header.opcode, OpCode::Data(Data::Text | Data::Binary));
+            let mut compressed = None;
+            if whole_message {
+                let mut payload = frames[start..end].to_vec();
+                if let Some(mask) = header.mask {
+                    apply_mask(&mut payload, mask);
+                }
+                compressed = self.codec.deflate(&payload)?;
+            }
+            match compressed {
+                Some(compressed) => {
+                    if let Some(counters) = &self.counters {
+                        counters.sent(end - start, compressed.len());
+                    }
+                    let header = FrameHeader {
+                        rsv1: true,
+                        ..header
+                    };
+                    write_frame(&header, compressed, out)?;
+                }
+                None => {
+                    if let OpCode::Data(_) = header.opcode {
+                        self.sending_fragments = !header.is_final;
+                        if let Some(counters) = &self.counters {
+                            counters.sent(end - start, end - start);
+                        }
+                    }
+                    out.extend_from_slice(&frames[pos..end]);
+                }
+            }
+            pos = end;
+        }
+        frames.drain(..pos);
+        Ok(())
+    }
+}
+
+enum Phase {
+    /// Waiting for the end of the upgrade response to learn what was agreed.
+    Handshake,
+    Plain,
+    Framed(Box<Transformer>),
+}
+
+/// Byte stream under tungstenite that applies permessage-deflate to the frames
+/// crossing it, since tungstenite itself rejects compressed (RSV1) frames.
+#[frb(ignore)]
+pub struct DeflateStream<S> {
+    inner: S,
+    role: Role,
+    config: Option<DeflateConfig>,
+    counters: Option<Arc<CompressionCounters>>,
+    phase: Phase,
+    head: Vec<u8>,
+    inbound: Vec<u8>,
+    readable: Vec<u8>,
+    read_pos: usize,
+    outbound: Vec<u8>,
+    writable: Vec<u8>,
+}
+
+impl<S> DeflateStream<S> {
+    /// Without a config the stream passes bytes through untouched.
+    pub(crate) fn client(
+        inner: S,
+        config: Option<DeflateConfig>,
+        counters: Option<Arc<CompressionCounters>>,
+    ) -> Self {
+        let phase = match config {
+            Some(_) => Phase::Handshake,
+            None => Phase::Plain,
+        };
+        Self::new(inner, Role::Client, config, counters, phase)
+    }
+
+    /// Gateway side, for tests: compresses once its own upgrade response accepts.
+    #[cfg(test)]
+    pub(crate) fn server(inner: S) -> Self {
+        Self::new(inner, Role::Server, None, None, Phase::Handshake)
+    }
+
+    fn new(
+        inner: S,
+        role: Role,
+        config: Option<DeflateConfig>,
+        counters: Option<Arc<CompressionCounters>>,
+        phase: Phase,
+    ) -> Self {
+        Self {
+            inner,
+            role,
+            config,
+            counters,
+            phase,
+            head: Vec::new(),
+            inbound: Vec::new(),
+            readable: Vec::new(),
+            read_pos: 0,
+            outbound: Vec::new(),
+            writable: Vec::new(),
+        }
+    }
+
+    /// Moves `bytes` of the upgrade response into `head` and returns whatever
+    /// follows the response once it is complete.
+    fn collect_head(&mut self, bytes: &mut Vec<u8>) -> io::Result<Option<(Vec<u8>, Vec<u8>)>> {
+        self.head.append(bytes);
+        let Some(end) = self
+            .head
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+        else {
+            if self.head.len() > MAX_HANDSHAKE_BYTES {
+                return Err(invalid_data("Upgrade response too large".to_string()));
+            }
+            return Ok(None);
+        };
+        let rest = self.head.split_off(end + 4);
+        let head = std::mem::take(&mut self.head);
+        let negotiated = parse_upgrade_head(&head)?;
+        let codec =
+            negotiated.map(|negotiated| Codec::new(self.role, self.config.as_ref(), negotiated));
+        if let Some(counters) = &self.counters {
+            counters
+                .negotiated
+                .store(codec.is_some(), Ordering::Relaxed);
+            counters.outgoing_compression_disabled.store(
+                codec
+                    .as_ref()
+                    .is_some_and(|codec| codec.compressor.is_none()),
+                Ordering::Relaxed,
+            );
+        }
+        self.phase = match codec {
+            Some(codec) => Phase::Framed(Box::new(Transformer {
+                codec,
+                counters: self.counters.clone(),
+                receiving: None,
+                sending_fragments: false,
+            })),
+            None => Phase::Plain,
+        };
+        Ok(Some((head, rest)))
+    }
+
+    fn process_inbound(&mut self) -> io::Result<()> {
+        match &mut self.phase {
+            Phase::Handshake if self.role == Role::Client => {
+                let mut bytes = std::mem::take(&mut self.inbound);
+                if let Some((head, rest)) = self.collect_head(&mut bytes)? {
+                    self.readable.extend_from_slice(&head);
+                    self.inbound = rest;
+                    self.process_inbound()?;
+                }
+            }
+            Phase::Handshake | Phase::Plain => self.readable.append(&mut self.inbound),
+            Phase::Framed(transformer) => {
+                transformer.decode(&mut self.inbound, &mut self.readable)?
+            }
+        }
+        Ok(())
+    }
+
+    fn process_outbound(&mut self) -> io::Result<()> {
+        match &mut self.phase {
+            Phase::Handshake if self.role == Role::Server => {
+                let mut bytes = std::mem::take(&mut self.outbound);
+                if let Some((head, rest)) = self.collect_head(&mut bytes)? {
+                    self.writable.extend_from_slice(&head);
+                    self.outbound = rest;
+                    self.process_outbound()?;
+                }
+            }
+            Phase::Handshake | Phase::Plain => self.writable.append(&mut self.outbound),
+            Phase::Framed(transformer) => {
+                transformer.encode(&mut self.outbound, &mut self.writable)?
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: AsyncWrite + Unpin> DeflateStream<S> {
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while !self.writable.is_empty() {
+            let written = ready!(Pin::new(&mut self.inner).poll_write(cx, &self.writable))?;
+            if written == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.writable.drain(..written);
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for DeflateStream<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        loop {
+            if this.read_pos < this.readable.len() {
+                let available = &this.readable[this.read_pos..];
+                let count = available.len().min(buf.remaining());
+                buf.put_slice(&available[..count]);
+                this.read_pos += count;
+                if this.read_pos == this.readable.len() {
+                    this.readable.clear();
+                    this.read_pos = 0;
+                }
+                return Poll::Ready(Ok(()));
+            }
+            if matches!(this.phase, Phase::Plain) && this.inbound.is_empty() {
+                return Pin::new(&mut this.inner).poll_read(cx, buf);
+            }
+            let mut chunk = [0u8; READ_CHUNK_BYTES];
+            let mut chunk = ReadBuf::new(&mut chunk);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut chunk))?;
+            if chunk.filled().is_empty() {
+                return Poll::Ready(Ok(()));
+            }
+            this.inbound.extend_from_slice(chunk.filled());
+            this.process_inbound()?;
+        }
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for DeflateStream<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        data: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        if matches!(this.phase, Phase::Plain) && this.writable.is_empty() {
+            return Pin::new(&mut this.inner).poll_write(cx, data);
+        }
+        if this.writable.len() >= WRITE_HIGH_WATER {
+            ready!(this.poll_drain(cx))?;
+        }
+        this.outbound.extend_from_slice(data);
+        this.process_outbound()?;
+        if let Poll::Ready(Err(error)) = this.poll_drain(cx) {
+            return Poll::Ready(Err(error));
+        }
+        Poll::Ready(Ok(data.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.poll_drain(cx))?;
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.poll_drain(cx))?;
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn negotiated(answer: &str) -> Negotiated {
+        parse_extensions(answer)
+            .expect("valid answer")
+            .expect("accepted")
+    }
+
+    #[test]
+    fn offer_and_answer_parameters_round_trip() {
+        let config = DeflateConfig {
+            server_max_window_bits: 10,
+            server_no_context_takeover: true,
+            ..DeflateConfig::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.offer(),
+            "permessage-deflate; server_max_window_bits=10; server_no_context_takeover"
+        );
+        assert_eq!(DeflateConfig::default().offer(), "permessage-deflate");
+        for config in [
+            DeflateConfig {
+                server_max_window_bits: 7,
+                ..DeflateConfig::default()
+            },
+            DeflateConfig {
+                client_max_window_bits: 12,
+                ..DeflateConfig::default()
+            },
+        ] {
+            assert!(config.validate().is_err(), "{config:?}");
+        }
+
+        let answer = negotiated("x-other, permessage-deflate; server_max_window_bits=\"10\"; client_no_context_takeover");
+        assert_eq!(answer.server_max_window_bits, 10);
+        assert_eq!(answer.client_max_window_bits, 15);
+        assert!(answer.client_no_context_takeover);
+        assert_eq!(parse_extensions("x-other").expect("valid"), None);
+        assert!(parse_extensions("permessage-deflate; client_max_window_bits=16").is_err());
+        assert!(parse_extensions("permessage-deflate; unknown").is_err());
+    }
+
+    #[test]
+    fn codec_round_trips_with_and_without_context_takeover() {
+        let line = r#"{"type":"event","event":"logs","payload":{"line":"GET /health 200"}}"#;
+        let message = line.repeat(4).into_bytes();
+        let message = message.as_slice();
+        for answer in [
+            "permessage-deflate",
+            "permessage-deflate; client_no_context_takeover; server_no_context_takeover",
+        ] {
+            let mut client = Codec::new(Role::Client, None, negotiated(answer));
+            let mut server = Codec::new(Role::Server, None, negotiated(answer));
+            let mut sizes = Vec::new();
+            for _ in 0..3 {
+                let compressed = client
+                    .deflate(message)
+                    .expect("deflate")
+                    .expect("compressed");
+                sizes.push(compressed.len());
+                assert_eq!(server.inflate(&compressed).expect("inflate"), message);
+            }
+            assert!(sizes[0] < message.len());
+            if answer == "permessage-deflate" {
+                assert!(sizes[1] < sizes[0], "{sizes:?}");
+            } else {
+                assert_eq!(sizes[1], sizes[0]);
+            }
+        }
+
+        let mut narrow = Codec::new(
+            Role::Client,
+            Some(&DeflateConfig::default()),
+            negotiated("permessage-deflate; client_max_window_bits=10"),
+        );
+        assert_eq!(narrow.deflate(message).expect("deflate"), None);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::api::events::{ConnectChallenge, ConnectDevice};
+use anyhow::{anyhow, Result};
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine as _,
+};
+use flutter_rust_bridge::frb;
+use ring::digest::{digest, SHA256};
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const IDENTITY_FILE_NAME: &str = "device-identity.json";
+const IDENTITY_FILE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StoredIdentity {
+    version: u32,
+    device_id: String,
+    public_key: String,
+    private_key: String,
+}
+
+pub struct DeviceIdentity {
+    device_id: String,
+    public_key: String,
+    key_pair: Ed25519KeyPair,
+}
+
+pub(crate) struct DeviceAuthPayload<'a> {
+    pub(crate) client_id: &'a str,
+    pub(crate) client_mode: &'a str,
+    pub(crate) role: &'a str,
+    pub(crate) scopes: &'a [String],
+    pub(crate) token: Option<&'a str>,
+}
+
+impl DeviceIdentity {
+    #[frb(sync)]
+    pub fn device_id(&self) -> String {
+        self.device_id.clone()
+    }
+
+    #[frb(sync)]
+    pub fn public_key(&self) -> String {
+        self.public_key.clone()
+    }
+
+    pub(crate) fn load_or_create(directory: &Path) -> Result<Self> {
+        let path = directory.join(IDENTITY_FILE_NAME);
+        if path.exists() {
+            return Self::load(&path);
+        }
+
+        fs::create_dir_all(directory)?;
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+            .map_err(|_| anyhow!("Failed to generate device key pair"))?;
+        let identity = Self::from_pkcs8(pkcs8.as_ref())?;
+        let stored = StoredIdentity {
+            version: IDENTITY_FILE_VERSION,
+            device_id: identity.device_id.clone(),
+            public_key: identity.public_key.clone(),
+            private_key: STANDARD.encode(pkcs8.as_ref()),
+        };
+        write_private_file(&path, serde_json::to_string_pretty(&stored)?.as_bytes())?;
+        Ok(identity)
+    }
+
+    fn load(path: &Path) -> Result<Self> {
+        let stored: StoredIdentity = serde_json::from_slice(&fs::read(path)?)?;
+        if stored.version != IDENTITY_FILE_VERSION {
+            return Err(anyhow!(
+                "Unsupported device identity version {}",
+                stored.version
+            ));
+        }
+        let pkcs8 = STANDARD.decode(stored.private_key.as_bytes())?;
+        Self::from_pkcs8(&pkcs8)
+    }
+
+    fn from_pkcs8(pkcs8: &[u8]) -> Result<Self> {
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8)
+            .map_err(|_| anyhow!("Stored device key is not a valid Ed25519 key"))?;
+        let public_key_bytes = key_pair.public_key().as_ref();
+        Ok(Self {
+            device_id: derive_device_id(public_key_bytes),
+            public_key: URL_SAFE_NO_PAD.encode(public_key_bytes),
+            key_pair,
+        })
+    }
+
+    pub(crate) fn sign_challenge(
+        &self,
+        challenge: &ConnectChallenge,
+        payload: DeviceAuthPayload<'_>,
+    ) -> ConnectDevice {
+        let message = self.auth_message(challenge, &payload);
+        let signature = self.key_pair.sign(message.as_bytes());
+        ConnectDevice {
+            id: Some(self.device_id.clone()),
+            public_key: Some(self.public_key.clone()),
+            signature: Some(URL_SAFE_NO_PAD.encode(signature.as_ref())),
+            signed_at: Some(challenge.ts),
+            nonce: Some(challenge.nonce.clone()),
+            extra: BTreeMap::new(),
+        }
+    }
+
+    fn auth_message(
+        &self,
+        challenge: &ConnectChallenge,
+        payload: &DeviceAuthPayload<'_>,
+    ) -> String {
+        [
+            "v2",
+            &self.device_id,
+            payload.client_id,
+            payload.client_mode,
+            payload.role,
+            &payload.scopes.join(","),
+            &challenge.ts.to_string(),
+            payload.token.unwrap_or_default(),
+            &challenge.nonce,
+        ]
+        .join("|")
+    }
+}
+
+pub fn load_device_identity(directory: String) -> Result<DeviceIdentity> {
+    DeviceIdentity::load_or_create(&PathBuf::from(directory))
+}
+
+fn derive_device_id(public_key: &[u8]) -> String {
+    digest(&SHA256, public_key)
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub(crate) fn write_private_file(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::OpenOptionsExt;
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(&tmp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+    }
+    #[cfg(not(unix))]
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::signature::{UnparsedPublicKey, ED25519};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("openclaw-identity-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn persists_identity_across_loads() {
+        let dir = temp_dir("persist");
+        let first = DeviceIdentity::load_or_create(&dir).expect("create");
+        let second = DeviceIdentity::load_or_create(&dir).expect("load");
+
+        assert_eq!(first.device_id(), second.device_id());
+        assert_eq!(first.public_key(), second.public_key());
+        assert_eq!(first.device_id().len(), 64);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn signs_challenge_with_device_key() {
+        let dir = temp_dir("sign");
+        let identity = DeviceIdentity::load_or_create(&dir).expect("create");
+        let challenge = ConnectChallenge {
+            nonce: "nonce-1".to_string(),
+            ts: 1_700_000_000_000,
+            extra: BTreeMap::new(),
+        };
+        let scopes = vec!["operator.read".to_string(), "operator.write".to_string()];
+        let device = identity.sign_challenge(
+            &challenge,
+            DeviceAuthPayload {
+                client_id: "openclaw-mobile",
+                client_mode: "ui",
+                role: "operator",
+                scopes: &scopes,
+                token: None,
+            },
+        );
+
+        assert_eq!(device.nonce.as_deref(), Some("nonce-1"));
+        assert_eq!(device.signed_at, Some(1_700_000_000_000));
+        let expected = format!(
+            "v2|{}|openclaw-mobile|ui|operator|operator.read,operator.write|1700000000000||nonce-1",
+            identity.device_id()
+        );
+        let public_key = URL_SAFE_NO_PAD
+            .decode(device.public_key.expect("public key"))
+            .expect("decode key");
+        let signature = URL_SAFE_NO_PAD
+            .decode(device.signature.expect("signature"))
+            .expect("decode signature");
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(expected.as_bytes(), &signature)
+            .expect("signature should verify");
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
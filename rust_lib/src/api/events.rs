@@ -1,4 +1,3 @@
-use crate::frb_generated::{SseDecode, SseEncode};
 use flutter_rust_bridge::frb;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -165,7 +164,7 @@ impl GatewayRequestFrame {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -192,11 +191,13 @@ pub struct ConnectParams {
     pub user_agent: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub device: Option<ConnectDevice>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seq: Option<u64>,
     #[serde(flatten, default)]
     pub extra: BTreeMap<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectClient {
     pub id: String,
@@ -218,7 +219,7 @@ pub struct ConnectClient {
     pub extra: BTreeMap<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectAuth {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -229,7 +230,7 @@ pub struct ConnectAuth {
     pub extra: BTreeMap<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectDevice {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -271,16 +272,20 @@ pub struct HelloOk {
     pub extra: BTreeMap<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct HelloPolicy {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tick_interval_ms: Option<u64>,
+    /// `"app"` means the gateway sends `tick` events at the tick interval and
+    /// clients treat missing ticks as a dead connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick_mode: Option<String>,
     #[serde(flatten, default)]
     pub extra: BTreeMap<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct HelloAuth {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -612,6 +617,12 @@ pub enum GatewayResponsePayload {
     Unknown(Value),
 }
 
+impl GatewayResponsePayload {
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(untagged)]
 #[frb(unignore)]
@@ -627,11 +638,37 @@ pub enum GatewayEventPayload {
     Unknown(Value),
 }
 
+/// A header on the WebSocket upgrade request or response.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct UpgradeHeader {
+    pub name: String,
+    pub value: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type", content = "data")]
 #[frb(unignore)]
 pub enum GatewayEvent {
-    Connected,
+    Connected {
+        protocol: u32,
+        role: String,
+        scopes: Vec<String>,
+        subprotocol: Option<String>,
+        response_headers: Vec<UpgradeHeader>,
+    },
+    HandshakeRejected { code: String, message: String },
+    DeviceTokenRotated { role: String },
+    EventGap { expected: u64, received: u64 },
+    Restarting { reason: String, expected_ms: u64 },
+    ConnectionQuality { latency_ms: u64 },
+    OutboxDelivered { id: String },
+    OutboxExpired { id: String },
+    CertificateChanged {
+        host: String,
+        expected: String,
+        received: String,
+    },
+    ClientCertificateRejected { alert: String },
     Disconnected { reason: String },
     Message { message: String },
     Error { message: String },
@@ -658,6 +695,40 @@ pub enum GatewayEvent {
     },
 }
 
+/// Which `GatewayEvent` variant a Dart caller received; the event itself is opaque there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayEventKind {
+    Connected,
+    Disconnected,
+    Message,
+    Error,
+    Other,
+}
+
+impl GatewayEvent {
+    #[frb(sync)]
+    pub fn kind(&self) -> GatewayEventKind {
+        match self {
+            GatewayEvent::Connected { .. } => GatewayEventKind::Connected,
+            GatewayEvent::Disconnected { .. } => GatewayEventKind::Disconnected,
+            GatewayEvent::Message { .. } => GatewayEventKind::Message,
+            GatewayEvent::Error { .. } => GatewayEventKind::Error,
+            _ => GatewayEventKind::Other,
+        }
+    }
+
+    /// The reason or message of a disconnect, message or error event; any other
+    /// event as its JSON form.
+    #[frb(sync)]
+    pub fn text(&self) -> String {
+        match self {
+            GatewayEvent::Disconnected { reason } => reason.clone(),
+            GatewayEvent::Message { message } | GatewayEvent::Error { message } => message.clone(),
+            other => serde_json::to_string(other).unwrap_or_else(|err| err.to_string()),
+        }
+    }
+}
+
 pub fn parse_gateway_frame(text: &str) -> Option<GatewayEvent> {
     let value: Value = serde_json::from_str(text).ok()?;
     let obj = value.as_object()?;
@@ -866,21 +937,6 @@ fn scalar_field(obj: &serde_json::Map<String, Value>, key: &str) -> String {
 }
 
 
-impl SseEncode for GatewayResponsePayload {
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let json = serde_json::to_string(&self).unwrap_or_default();
-        <String as SseEncode>::sse_encode(json, serializer);
-    }
-}
-
-impl SseDecode for GatewayResponsePayload {
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let json = <String as SseDecode>::sse_decode(deserializer);
-        serde_json::from_str(&json).unwrap_or(GatewayResponsePayload::Unknown(Value::Null))
-    }
-}
-
-
 impl flutter_rust_bridge::IntoDart for GatewayRequestParams {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         let json = serde_json::to_string(&self).unwrap_or_default();
@@ -975,6 +1031,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn classifies_events_for_dart() {
+        let disconnected = GatewayEvent::Disconnected {
+            reason: "closed".to_string(),
+        };
+        assert_eq!(disconnected.kind(), GatewayEventKind::Disconnected);
+        assert_eq!(disconnected.text(), "closed");
+
+        let gap = GatewayEvent::EventGap {
+            expected: 2,
+            received: 4,
+        };
+        assert_eq!(gap.kind(), GatewayEventKind::Other);
+        assert_eq!(
+            gap.text(),
+            r#"{"type":"EventGap","data":{"expected":2,"received":4}}"#
+        );
+    }
+
     #[test]
     fn parses_camera_snap_request() {
         let text = r#"{
@@ -1,4 +1,12 @@
+pub mod backpressure;
 pub mod connection;
+pub mod deflate;
+pub mod device_identity;
 pub mod events;
+pub mod outbox;
+pub mod proxy;
 pub mod simple;
 pub mod terminal;
+pub mod terminal_emulator;
+pub mod tls;
+pub mod token_store;
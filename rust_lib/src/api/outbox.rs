@@ -0,0 +1,242 @@
+use crate::api::events::GatewayRequestFrame;
+use crate::Duration;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::Notify;
+
+const QUEUEABLE_METHODS: [&str; 2] = ["agent", "system-event"];
+
+#[derive(Debug, Clone)]
+pub struct OutboxConfig {
+    pub max_items: u32,
+    /// How long a queued request may wait for a connection before it expires.
+    pub ttl: Duration,
+    /// Directory to persist queued requests in so they survive app restarts.
+    pub persist_dir: Option<String>,
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            max_items: 100,
+            ttl: Duration::seconds(600),
+            persist_dir: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OutboxItem {
+    pub(crate) id: String,
+    pub(crate) frame_json: String,
+    pub(crate) expires_at_ms: i64,
+    /// Written on the current session and waiting for the gateway's response.
+    #[serde(skip)]
+    pub(crate) sent: bool,
+}
+
+/// Requests stay queued until the gateway answers them, so one that was written
+/// but lost with its session is sent again on the next.
+pub(crate) struct Outbox {
+    items: Mutex<VecDeque<OutboxItem>>,
+    unsent: Notify,
+    max_items: usize,
+    ttl_ms: i64,
+    path: Option<PathBuf>,
+}
+
+impl Outbox {
+    pub(crate) fn open(config: &OutboxConfig, handle: &str) -> Result<Self> {
+        let path = config
+            .persist_dir
+            .as_deref()
+            .map(|dir| outbox_path(Path::new(dir), handle));
+        let items = match &path {
+            Some(path) if path.exists() => serde_json::from_slice(&fs::read(path)?)?,
+            _ => VecDeque::new(),
+        };
+        Ok(Self {
+            items: Mutex::new(items),
+            unsent: Notify::new(),
+            max_items: config.max_items as usize,
+            ttl_ms: config.ttl.num_milliseconds(),
+            path,
+        })
+    }
+
+    pub(crate) fn is_queueable(frame: &GatewayRequestFrame) -> bool {
+        QUEUEABLE_METHODS.contains(&frame.method.as_str())
+    }
+
+    pub(crate) fn push(&self, frame: &GatewayRequestFrame, now_ms: i64) -> Result<()> {
+        let frame_json = frame.to_json()?;
+        self.update(|items| {
+            if items.len() >= self.max_items {
+                return Err(anyhow!(
+                    "Outbox is full ({} queued requests)",
+                    self.max_items
+                ));
+            }
+            items.push_back(OutboxItem {
+                id: frame.id.clone(),
+                frame_json,
+                expires_at_ms: now_ms.saturating_add(self.ttl_ms),
+                sent: false,
+            });
+            Ok(())
+        })?;
+        self.unsent.notify_one();
+        Ok(())
+    }
+
+    /// Marks every item unsent again; call when a new session starts.
+    pub(crate) fn start_session(&self) {
+        if let Ok(mut items) = self.items.lock() {
+            items.iter_mut().for_each(|item| item.sent = false);
+        }
+    }
+
+    /// Waits until `push` has queued something since the last `take_unsent`.
+    pub(crate) async fn unsent(&self) {
+        self.unsent.notified().await;
+    }
+
+    /// The items not yet written on this session, in order, marked as sent.
+    pub(crate) fn take_unsent(&self) -> Vec<OutboxItem> {
+        let Ok(mut items) = self.items.lock() else {
+            return Vec::new();
+        };
+        items
+            .iter_mut()
+            .filter(|item| !item.sent)
+            .map(|item| {
+                item.sent = true;
+                item.clone()
+            })
+            .collect()
+    }
+
+    /// Removes the item the gateway answered; false if `id` is not queued.
+    pub(crate) fn acknowledge(&self, id: &str) -> bool {
+        self.update(|items| {
+            let position = items.iter().position(|item| item.id == id);
+            Ok(position
+                .and_then(|position| items.remove(position))
+                .is_some())
+        })
+        .unwrap_or_default()
+    }
+
+    /// Items still waiting to be sent once their time to live ran out.
+    pub(crate) fn take_expired(&self, now_ms: i64) -> Vec<OutboxItem> {
+        self.update(|items| {
+            let (expired, kept): (VecDeque<_>, VecDeque<_>) = items
+                .drain(..)
+                .partition(|item| !item.sent && item.expires_at_ms <= now_ms);
+            *items = kept;
+            Ok(expired.into())
+        })
+        .unwrap_or_default()
+    }
+
+    fn update<T>(&self, update: impl FnOnce(&mut VecDeque<OutboxItem>) -> Result<T>) -> Result<T> {
+        let mut items = self.items.lock().map_err(|_| anyhow!("outbox poisoned"))?;
+        let before = items.len();
+        let result = update(&mut items)?;
+        if items.len() != before {
+            if let Some(path) = &self.path {
+                persist(path, &items)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+pub(crate) fn unix_time_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+fn outbox_path(directory: &Path, handle: &str) -> PathBuf {
+    let name: String = handle
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    directory.join(format!("outbox-{name}.json"))
+}
+
+fn persist(path: &Path, items: &VecDeque<OutboxItem>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, serde_json::to_vec_pretty(items)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::events::{GatewayRequestParams, SystemEvent};
+
+    fn system_event(id: &str) -> GatewayRequestFrame {
+        GatewayRequestFrame::new(
+            id.to_string(),
+            "system-event",
+            GatewayRequestParams::SystemEvent(SystemEvent {
+                text: Some("queued".to_string()),
+                session_key: None,
+                mode: None,
+                extra: Default::default(),
+            }),
+            None,
+        )
+    }
+
+    #[test]
+    fn persisted_items_survive_reopen_and_expire_in_order() {
+        let dir = std::env::temp_dir().join(format!("openclaw-outbox-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let config = OutboxConfig {
+            max_items: 2,
+            ttl: Duration::milliseconds(100),
+            persist_dir: Some(dir.to_string_lossy().into_owned()),
+        };
+
+        let outbox = Outbox::open(&config, "home/gw").expect("open");
+        outbox.push(&system_event("evt-1"), 1_000).expect("push");
+        outbox.push(&system_event("evt-2"), 1_050).expect("push");
+        assert!(outbox.push(&system_event("evt-3"), 1_060).is_err());
+
+        let reopened = Outbox::open(&config, "home/gw").expect("reopen");
+        let expired = reopened.take_expired(1_120);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, "evt-1");
+        let unsent = reopened.take_unsent();
+        assert_eq!(unsent.len(), 1);
+        assert_eq!(unsent[0].id, "evt-2");
+        assert!(unsent[0].frame_json.contains("\"system-event\""));
+        assert!(reopened.take_unsent().is_empty());
+        assert!(reopened.take_expired(10_000).is_empty());
+
+        let restarted = Outbox::open(&config, "home/gw").expect("reopen");
+        assert_eq!(restarted.take_unsent().len(), 1);
+        assert!(restarted.acknowledge("evt-2"));
+        assert!(!restarted.acknowledge("evt-2"));
+        assert!(Outbox::open(&config, "home/gw")
+            .expect("reopen")
+            .take_unsent()
+            .is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,404 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use std::net::IpAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::http::Uri;
+
+const MAX_CONNECT_RESPONSE_BYTES: usize = 8 * 1024;
+const SOCKS_VERSION: u8 = 5;
+const SOCKS_NO_AUTH: u8 = 0x00;
+const SOCKS_USER_PASS: u8 = 0x02;
+const SOCKS_NO_ACCEPTABLE_METHOD: u8 = 0xff;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyKind {
+    /// HTTP proxy tunnelling through `CONNECT`.
+    Http,
+    Socks5,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    pub kind: ProxyKind,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+/// Opens a TCP tunnel to `target_host:target_port` through `proxy`. The target name
+/// is resolved by the proxy.
+pub(crate) async fn connect_via_proxy(
+    proxy: &ProxyConfig,
+    target_host: &str,
+    target_port: u16,
+) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect((proxy.host.as_str(), proxy.port))
+        .await
+        .map_err(|e| anyhow!("Proxy {}:{} unreachable: {e}", proxy.host, proxy.port))?;
+    match proxy.kind {
+        ProxyKind::Http => http_connect(&mut stream, proxy, target_host, target_port).await?,
+        ProxyKind::Socks5 => socks5_connect(&mut stream, proxy, target_host, target_port).await?,
+    }
+    Ok(stream)
+}
+
+/// Host and port a `ws://`/`wss://` URL dials, for the proxy to connect to.
+pub(crate) fn target_address(uri: &Uri) -> Result<(String, u16)> {
+    let host = uri
+        .host()
+        .ok_or_else(|| anyhow!("Gateway URL {uri} has no host"))?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    let port = match (uri.port_u16(), uri.scheme_str()) {
+        (Some(port), _) => port,
+        (None, Some("wss")) => 443,
+        (None, Some("ws")) => 80,
+        _ => return Err(anyhow!("Unsupported gateway URL scheme in {uri}")),
+    };
+    Ok((host, port))
+}
+
+async fn http_connect(
+    stream: &mut TcpStream,
+    proxy: &ProxyConfig,
+    target_host: &str,
+    target_port: u16,
+) -> Result<()> {
+    let authority = match target_host.parse::<IpAddr>() {
+        Ok(IpAddr::V6(ip)) => format!("[{ip}]:{target_port}"),
+        _ => format!("{target_host}:{target_port}"),
+    };
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if let Some(username) = &proxy.username {
+        let credentials = format!("{username}:{}", proxy.password.as_deref().unwrap_or(""));
+        request.push_str(&format!(
+            "Proxy-Authorization: Basic {}\r\n",
+            STANDARD.encode(credentials)
+        ));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // Read byte by byte so nothing after the header is consumed from the tunnel.
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_CONNECT_RESPONSE_BYTES {
+            return Err(anyhow!("Proxy sent an oversized CONNECT response"));
+        }
+        let byte = stream
+            .read_u8()
+            .await
+            .map_err(|e| anyhow!("Proxy closed the connection during CONNECT: {e}"))?;
+        response.push(byte);
+    }
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| anyhow!("Invalid proxy response: {status_line}"))?;
+    match status {
+        200..=299 => Ok(()),
+        407 => Err(anyhow!("Proxy authentication failed: {status_line}")),
+        _ => Err(anyhow!("Proxy refused CONNECT: {status_line}")),
+    }
+}
+
+async fn socks5_connect(
+    stream: &mut TcpStream,
+    proxy: &ProxyConfig,
+    target_host: &str,
+    target_port: u16,
+) -> Result<()> {
+    let method = if proxy.username.is_some() {
+        SOCKS_USER_PASS
+    } else {
+        SOCKS_NO_AUTH
+    };
+    stream.write_all(&[SOCKS_VERSION, 1, method]).await?;
+    let mut choice = [0u8; 2];
+    stream.read_exact(&mut choice).await?;
+    if choice[0] != SOCKS_VERSION {
+        return Err(anyhow!("Proxy is not a SOCKS5 server"));
+    }
+    match choice[1] {
+        SOCKS_NO_AUTH => {}
+        SOCKS_USER_PASS if method == SOCKS_USER_PASS => {
+            let username = proxy.username.as_deref().unwrap_or_default().as_bytes();
+            let password = proxy.password.as_deref().unwrap_or_default().as_bytes();
+            let mut auth = vec![1, socks_len(username, "username")?];
+            auth.extend_from_slice(username);
+            auth.push(socks_len(password, "password")?);
+            auth.extend_from_slice(password);
+            stream.write_all(&auth).await?;
+            let mut status = [0u8; 2];
+            stream.read_exact(&mut status).await?;
+            if status[1] != 0 {
+                return Err(anyhow!("Proxy authentication failed"));
+            }
+        }
+        SOCKS_NO_ACCEPTABLE_METHOD => {
+            return Err(anyhow!(
+                "Proxy accepts none of the offered authentication methods"
+            ));
+        }
+        other => {
+            return Err(anyhow!(
+                "Proxy chose unsupported authentication method {other}"
+            ))
+        }
+    }
+
+    let mut request = vec![SOCKS_VERSION, 1, 0];
+    match target_host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(1);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            request.push(3);
+            request.push(socks_len(target_host.as_bytes(), "host name")?);
+            request.extend_from_slice(target_host.as_bytes());
+        }
+    }
+    request.extend_from_slice(&target_port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[1] != 0 {
+        return Err(anyhow!(
+            "Proxy refused CONNECT: {}",
+            socks_reply_message(reply[1])
+        ));
+    }
+    let bound_address_len = match reply[3] {
+        1 => 4,
+        4 => 16,
+        3 => stream.read_u8().await? as usize,
+        other => return Err(anyhow!("Proxy sent unknown address type {other}")),
+    };
+    let mut bound = vec![0u8; bound_address_len + 2];
+    stream.read_exact(&mut bound).await?;
+    Ok(())
+}
+
+fn socks_len(value: &[u8], what: &str) -> Result<u8> {
+    u8::try_from(value.len()).map_err(|_| anyhow!("SOCKS5 {what} is longer than 255 bytes"))
+}
+
+fn socks_reply_message(code: u8) -> &'static str {
+    match code {
+        1 => "general failure",
+        2 => "connection not allowed by ruleset",
+        3 => "network unreachable",
+        4 => "host unreachable",
+        5 => "connection refused",
+        6 => "TTL expired",
+        7 => "command not supported",
+        8 => "address type not supported",
+        _ => "unknown error",
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Minimal HTTP CONNECT proxy that checks Basic credentials when `auth` is set.
+    pub(crate) async fn spawn_http_proxy(auth: Option<&'static str>) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        tokio::spawn(async move {
+            while let Ok((mut client, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut head = Vec::new();
+                    while !head.ends_with(b"\r\n\r\n") {
+                        head.push(client.read_u8().await.expect("read"));
+                    }
+                    let head = String::from_utf8(head).expect("utf8");
+                    let target = head.split_whitespace().nth(1).expect("target").to_string();
+                    let authorized = auth.is_none_or(|credentials| {
+                        head.contains(&format!(
+                            "Proxy-Authorization: Basic {}",
+                            STANDARD.encode(credentials)
+                        ))
+                    });
+                    if !authorized {
+                        let _ = client
+                            .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                            .await;
+                        return;
+                    }
+                    let mut upstream = TcpStream::connect(target).await.expect("upstream");
+                    client
+                        .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                        .await
+                        .expect("reply");
+                    let _ = tokio::io::copy_bidirectional(&mut client, &mut upstream).await;
+                });
+            }
+        });
+        port
+    }
+
+    /// Minimal SOCKS5 proxy requiring `user`/`pass` and resolving names itself.
+    pub(crate) async fn spawn_socks5_proxy() -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        tokio::spawn(async move {
+            while let Ok((mut client, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut greeting = [0u8; 2];
+                    client.read_exact(&mut greeting).await.expect("greeting");
+                    let mut methods = vec![0u8; greeting[1] as usize];
+                    client.read_exact(&mut methods).await.expect("methods");
+                    if !methods.contains(&SOCKS_USER_PASS) {
+                        let _ = client
+                            .write_all(&[SOCKS_VERSION, SOCKS_NO_ACCEPTABLE_METHOD])
+                            .await;
+                        return;
+                    }
+                    client
+                        .write_all(&[SOCKS_VERSION, SOCKS_USER_PASS])
+                        .await
+                        .expect("method");
+                    let mut fields = Vec::new();
+                    let _auth_version = client.read_u8().await.expect("auth");
+                    for _ in 0..2 {
+                        let mut field = vec![0u8; client.read_u8().await.expect("len") as usize];
+                        client.read_exact(&mut field).await.expect("auth");
+                        fields.push(String::from_utf8(field).expect("utf8"));
+                    }
+                    let ok = fields == ["user", "pass"];
+                    client
+                        .write_all(&[1, if ok { 0 } else { 1 }])
+                        .await
+                        .expect("auth status");
+                    if !ok {
+                        return;
+                    }
+                    let mut request = [0u8; 4];
+                    client.read_exact(&mut request).await.expect("request");
+                    let host = match request[3] {
+                        1 => {
+                            let mut ip = [0u8; 4];
+                            client.read_exact(&mut ip).await.expect("ip");
+                            std::net::Ipv4Addr::from(ip).to_string()
+                        }
+                        3 => {
+                            let mut name = vec![0u8; client.read_u8().await.expect("len") as usize];
+                            client.read_exact(&mut name).await.expect("name");
+                            String::from_utf8(name).expect("utf8")
+                        }
+                        other => panic!("unexpected address type {other}"),
+                    };
+                    let port = client.read_u16().await.expect("port");
+                    let mut upstream = TcpStream::connect((host.as_str(), port))
+                        .await
+                        .expect("upstream");
+                    client
+                        .write_all(&[SOCKS_VERSION, 0, 0, 1, 127, 0, 0, 1, 0, 0])
+                        .await
+                        .expect("reply");
+                    let _ = tokio::io::copy_bidirectional(&mut client, &mut upstream).await;
+                });
+            }
+        });
+        port
+    }
+
+    async fn spawn_echo_server() -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let (mut read, mut write) = stream.split();
+                    let _ = tokio::io::copy(&mut read, &mut write).await;
+                });
+            }
+        });
+        port
+    }
+
+    fn proxy(kind: ProxyKind, port: u16, credentials: Option<(&str, &str)>) -> ProxyConfig {
+        ProxyConfig {
+            kind,
+            host: "127.0.0.1".to_string(),
+            port,
+            username: credentials.map(|(user, _)| user.to_string()),
+            password: credentials.map(|(_, pass)| pass.to_string()),
+        }
+    }
+
+    async fn assert_echoes(mut stream: TcpStream) {
+        stream.write_all(b"ping").await.expect("write");
+        let mut echoed = [0u8; 4];
+        stream.read_exact(&mut echoed).await.expect("read");
+        assert_eq!(&echoed, b"ping");
+    }
+
+    #[tokio::test]
+    async fn http_connect_tunnels_with_basic_auth() {
+        let target = spawn_echo_server().await;
+        let proxy_port = spawn_http_proxy(Some("user:pass")).await;
+
+        let stream = connect_via_proxy(
+            &proxy(ProxyKind::Http, proxy_port, Some(("user", "pass"))),
+            "127.0.0.1",
+            target,
+        )
+        .await
+        .expect("tunnel");
+        assert_echoes(stream).await;
+
+        let error = connect_via_proxy(
+            &proxy(ProxyKind::Http, proxy_port, None),
+            "127.0.0.1",
+            target,
+        )
+        .await
+        .expect_err("unauthorized");
+        assert!(
+            error.to_string().contains("authentication failed"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn socks5_tunnels_by_host_name_with_credentials() {
+        let target = spawn_echo_server().await;
+        let proxy_port = spawn_socks5_proxy().await;
+
+        let stream = connect_via_proxy(
+            &proxy(ProxyKind::Socks5, proxy_port, Some(("user", "pass"))),
+            "localhost",
+            target,
+        )
+        .await
+        .expect("tunnel");
+        assert_echoes(stream).await;
+
+        let error = connect_via_proxy(
+            &proxy(ProxyKind::Socks5, proxy_port, Some(("user", "wrong"))),
+            "localhost",
+            target,
+        )
+        .await
+        .expect_err("unauthorized");
+        assert!(
+            error.to_string().contains("authentication failed"),
+            "{error}"
+        );
+    }
+}
@@ -1,28 +1,62 @@
-use crate::api::connection::GatewayClient;
+use crate::api::backpressure::OutputBatcher;
+use crate::api::connection::{
+    gateway_request, output_batcher, subscribe_gateway_events, GatewayClient,
+    GatewayEventSubscription,
+};
 use crate::api::events::{
-    parse_gateway_frame, ExecOutput, GatewayEvent, GatewayEventPayload, GatewayResponsePayload,
+    ExecOutput, GatewayEvent, GatewayEventPayload, GatewayResponsePayload, StreamClosedEvent,
+    StreamDataEvent,
 };
+use crate::api::terminal_emulator::{StyledTextParser, TerminalRun};
 use crate::frb_generated::StreamSink;
 use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use futures_util::{SinkExt, StreamExt};
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use flutter_rust_bridge::frb;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::Instant;
 
 #[derive(Debug, Clone)]
 pub struct TerminalChunk {
     pub text: String,
     pub kind: String,
+    pub channel: Option<String>,
+    pub data: Option<Vec<u8>>,
+    pub spans: Vec<TerminalRun>,
+}
+
+const EXEC_RESPONSE_GRACE_MS: u64 = 30_000;
+
+/// Subscriptions taken out before `streams.open` was sent, keyed by handle and
+/// stream id, so output the gateway sends before the app attaches is not lost.
+static PENDING_STREAMS: OnceLock<Mutex<HashMap<(String, String), GatewayEventSubscription>>> =
+    OnceLock::new();
+
+fn pending_streams() -> &'static Mutex<HashMap<(String, String), GatewayEventSubscription>> {
+    PENDING_STREAMS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub(crate) fn take_pending_stream(
+    handle: &str,
+    stream_id: &str,
+) -> Option<GatewayEventSubscription> {
+    pending_streams()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&(handle.to_string(), stream_id.to_string()))
 }
 
 pub async fn exec_command(
-    url: String,
+    handle: String,
     command: String,
     cwd: Option<String>,
     timeout_ms: Option<u64>,
     session_key: Option<String>,
+    raw_output: bool,
     sink: StreamSink<TerminalChunk>,
 ) -> Result<()> {
-    let client = GatewayClient::new(url.clone());
+    let client = GatewayClient::for_connection(&handle)?;
     let request_id = client.next_request_id();
 
     let request_json = client.exec_request(
@@ -34,88 +68,105 @@ pub async fn exec_command(
         None,
         timeout_ms,
         Some(true),
-        None,
+        Some(request_id.clone()),
         Some(true),
         session_key,
     )?;
 
-    let (ws_stream, _) = connect_async(&url).await?;
-    let (mut write, mut read) = ws_stream.split();
-    write.send(Message::Text(request_json.into())).await?;
-
-    while let Some(message) = read.next().await {
-        match message {
-            Ok(Message::Text(text)) => {
-                let Some(frame) = parse_gateway_frame(&text) else {
-                    continue;
-                };
-
-                match frame {
-                    GatewayEvent::ProtocolResponse {
-                        id,
-                        ok,
-                        payload,
-                        error,
-                        ..
-                    } => {
-                        if id != request_id {
-                            continue;
-                        }
-                        if !ok {
-                            let message = error
-                                .message
-                                .unwrap_or_else(|| "exec request failed".to_string());
-                            let _ = sink.add(TerminalChunk {
-                                text: message.clone(),
-                                kind: "error".to_string(),
-                            });
-                            return Err(anyhow!(message));
-                        }
+    let mut events = subscribe_gateway_events(&handle)?;
+    let response = gateway_request(
+        handle.clone(),
+        request_json,
+        timeout_ms.map(|ms| ms.saturating_add(EXEC_RESPONSE_GRACE_MS)),
+    );
+    tokio::pin!(response);
+    // The gateway kills the command at `timeout_ms`; give up on the exit event
+    // once the grace period after that has passed too.
+    let deadline_ms = timeout_ms.map(|ms| ms.saturating_add(EXEC_RESPONSE_GRACE_MS));
+    let deadline = async {
+        match deadline_ms {
+            Some(ms) => tokio::time::sleep(std::time::Duration::from_millis(ms)).await,
+            None => std::future::pending().await,
+        }
+    };
+    tokio::pin!(deadline);
+    let mut awaiting_response = true;
+    let batcher = output_batcher(&handle)?;
+    let mut terminal = TerminalOutput::new(&sink, raw_output, true, batcher);
 
-                        if let GatewayResponsePayload::ExecResult(output) = payload {
-                            emit_exec_output(&sink, &output);
-                            if output.exit_code.is_some() {
-                                return Ok(());
-                            }
+    loop {
+        tokio::select! {
+            result = &mut response, if awaiting_response => {
+                awaiting_response = false;
+                match result {
+                    Ok(GatewayResponsePayload::ExecResult(output)) => {
+                        terminal.flush_batch();
+                        terminal.exec_output(&output);
+                        if output.exit_code.is_some() {
+                            return Ok(());
                         }
                     }
-                    GatewayEvent::ProtocolEvent { payload, .. } => {
-                        if let GatewayEventPayload::ExecOutput(output) = payload {
-                            emit_exec_output(&sink, &output);
-                            if output.exit_code.is_some() {
-                                return Ok(());
-                            }
-                        }
+                    Ok(_) => {
+                        let message = "exec returned a response that is not an exec result";
+                        terminal.error(message.to_string());
+                        return Err(anyhow!(message));
+                    }
+                    Err(error) => {
+                        terminal.error(error.to_string());
+                        return Err(error);
                     }
-                    _ => {}
                 }
             }
-            Ok(Message::Close(_)) => break,
-            Ok(_) => {}
-            Err(error) => {
-                let _ = sink.add(TerminalChunk {
-                    text: error.to_string(),
-                    kind: "error".to_string(),
-                });
-                return Err(error.into());
+            _ = &mut deadline => {
+                terminal.flush();
+                let message = format!(
+                    "Command did not exit within {}ms",
+                    deadline_ms.unwrap_or_default()
+                );
+                terminal.error(message.clone());
+                return Err(anyhow!(message));
+            }
+            _ = batch_deadline(terminal.batch_deadline()) => {
+                if terminal.flush_batch() {
+                    return Ok(());
+                }
+            }
+            event = events.recv() => {
+                match event {
+                    Ok(event) if is_exec_output(&event, &request_id) => {
+                        if terminal.batch(event) {
+                            return Ok(());
+                        }
+                    }
+                    Ok(GatewayEvent::Disconnected { reason }) => {
+                        let message = format!("Gateway disconnected: {reason}");
+                        terminal.error(message.clone());
+                        return Err(anyhow!(message));
+                    }
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(skipped)) => {
+                        terminal.error(format!("Dropped {skipped} gateway events"));
+                    }
+                    Err(RecvError::Closed) => {
+                        return Err(anyhow!("Gateway connection closed"));
+                    }
+                }
             }
         }
     }
-
-    Ok(())
 }
 
 pub async fn terminal_stream_open(
-    url: String,
+    handle: String,
     cols: Option<u32>,
     rows: Option<u32>,
     term: Option<String>,
     session_key: Option<String>,
 ) -> Result<String> {
-    let client = GatewayClient::new(url.clone());
+    let client = GatewayClient::for_connection(&handle)?;
     let request_id = client.next_request_id();
     let request_json = client.interactive_shell_open_request(
-        request_id.clone(),
+        request_id,
         cols,
         rows,
         term,
@@ -124,28 +175,30 @@ pub async fn terminal_stream_open(
         session_key,
     )?;
 
-    let response = send_request_and_wait_response(url, request_id, request_json).await?;
-    let GatewayResponsePayload::Unknown(payload) = response else {
-        return Err(anyhow!("streams.open response payload was not recognized"));
-    };
-
-    payload
+    let events = subscribe_gateway_events(&handle)?;
+    let response = gateway_request(handle.clone(), request_json, None).await?;
+    let stream_id = serde_json::to_value(&response)?
         .get("streamId")
         .and_then(|value| value.as_str())
         .map(|value| value.to_string())
-        .ok_or_else(|| anyhow!("streams.open response missing streamId"))
+        .ok_or_else(|| anyhow!("streams.open response missing streamId"))?;
+    pending_streams()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert((handle, stream_id.clone()), events);
+    Ok(stream_id)
 }
 
 pub async fn terminal_stream_send(
-    url: String,
+    handle: String,
     stream_id: String,
     input_bytes: Vec<u8>,
     session_key: Option<String>,
 ) -> Result<()> {
-    let client = GatewayClient::new(url.clone());
+    let client = GatewayClient::for_connection(&handle)?;
     let request_id = client.next_request_id();
     let request_json = client.interactive_shell_send_request(
-        request_id.clone(),
+        request_id,
         Some(stream_id),
         input_bytes,
         Some("stdin".to_string()),
@@ -153,86 +206,344 @@ pub async fn terminal_stream_send(
         session_key,
     )?;
 
-    let _ = send_request_and_wait_response(url, request_id, request_json).await?;
+    gateway_request(handle, request_json, None).await?;
     Ok(())
 }
 
-async fn send_request_and_wait_response(
-    url: String,
-    request_id: String,
-    request_json: String,
-) -> Result<GatewayResponsePayload> {
-    let (ws_stream, _) = connect_async(&url).await?;
-    let (mut write, mut read) = ws_stream.split();
-    write.send(Message::Text(request_json.into())).await?;
-
-    while let Some(message) = read.next().await {
-        match message {
-            Ok(Message::Text(text)) => {
-                let Some(frame) = parse_gateway_frame(&text) else {
-                    continue;
-                };
-
-                if let GatewayEvent::ProtocolResponse {
-                    id,
-                    ok,
-                    payload,
-                    error,
-                    ..
-                } = frame
-                {
-                    if id != request_id {
-                        continue;
-                    }
-
-                    if ok {
-                        return Ok(payload);
-                    }
+/// Delivers the output of `stream_id`, starting with anything the gateway sent
+/// since `terminal_stream_open` returned it.
+pub async fn terminal_stream_attach(
+    handle: String,
+    stream_id: String,
+    raw_output: bool,
+    sink: StreamSink<TerminalChunk>,
+) -> Result<()> {
+    let mut events = match take_pending_stream(&handle, &stream_id) {
+        Some(events) => events,
+        None => subscribe_gateway_events(&handle)?,
+    };
+    let batcher = output_batcher(&handle)?;
+    let mut terminal = TerminalOutput::new(&sink, raw_output, false, batcher);
 
-                    let message = error
-                        .message
-                        .unwrap_or_else(|| "gateway request failed".to_string());
-                    return Err(anyhow!(message));
-                }
+    loop {
+        let event = tokio::select! {
+            event = events.recv() => event,
+            _ = batch_deadline(terminal.batch_deadline()) => {
+                terminal.flush_batch();
+                continue;
             }
-            Ok(Message::Close(_)) => {
-                return Err(anyhow!("connection closed before response"));
+        };
+        match event {
+            Ok(event) if is_stream_data(&event, &stream_id) => {
+                terminal.batch(event);
+            }
+            Ok(GatewayEvent::ProtocolEvent {
+                payload: GatewayEventPayload::StreamClosed(closed),
+                ..
+            }) if closed.stream_id.as_deref() == Some(stream_id.as_str()) => {
+                terminal.stream_closed(&closed);
+                return Ok(());
+            }
+            Ok(GatewayEvent::Disconnected { reason }) => {
+                let message = format!("Gateway disconnected: {reason}");
+                terminal.error(message.clone());
+                return Err(anyhow!(message));
             }
             Ok(_) => {}
-            Err(error) => return Err(error.into()),
+            Err(RecvError::Lagged(skipped)) => {
+                terminal.error(format!("Dropped {skipped} gateway events"));
+            }
+            Err(RecvError::Closed) => {
+                return Err(anyhow!("Gateway connection closed"));
+            }
         }
     }
+}
+
+fn is_exec_output(event: &GatewayEvent, exec_id: &str) -> bool {
+    matches!(
+        event,
+        GatewayEvent::ProtocolEvent {
+            payload: GatewayEventPayload::ExecOutput(output),
+            ..
+        } if output.exec_id.as_deref() == Some(exec_id)
+    )
+}
+
+fn is_stream_data(event: &GatewayEvent, stream_id: &str) -> bool {
+    matches!(
+        event,
+        GatewayEvent::ProtocolEvent {
+            payload: GatewayEventPayload::StreamData(data),
+            ..
+        } if data.stream_id.as_deref() == Some(stream_id)
+    )
+}
+
+async fn batch_deadline(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+struct TerminalOutput<'a> {
+    sink: &'a StreamSink<TerminalChunk>,
+    raw_output: bool,
+    styled: bool,
+    decoders: BTreeMap<String, ChannelDecoder>,
+    batcher: OutputBatcher,
+}
 
-    Err(anyhow!("connection closed before response"))
+#[frb(ignore)]
+#[derive(Default)]
+struct ChannelDecoder {
+    text: Utf8ChunkDecoder,
+    styled: StyledTextParser,
 }
 
-fn emit_exec_output(sink: &StreamSink<TerminalChunk>, output: &ExecOutput) {
-    if let Some(data) = output.data.clone() {
-        let decoded = decode_output_data(data, output.encoding.as_deref());
-        if !decoded.is_empty() {
+impl<'a> TerminalOutput<'a> {
+    fn new(
+        sink: &'a StreamSink<TerminalChunk>,
+        raw_output: bool,
+        styled: bool,
+        batcher: OutputBatcher,
+    ) -> Self {
+        Self {
+            sink,
+            raw_output,
+            styled,
+            decoders: BTreeMap::new(),
+            batcher,
+        }
+    }
+
+    /// Joins output events through the connection's batcher; true once the exec
+    /// has exited.
+    fn batch(&mut self, event: GatewayEvent) -> bool {
+        let mut exited = false;
+        for ready in self.batcher.push(event) {
+            exited |= self.output_event(ready);
+        }
+        exited
+    }
+
+    fn batch_deadline(&self) -> Option<Instant> {
+        self.batcher.deadline()
+    }
+
+    fn flush_batch(&mut self) -> bool {
+        match self.batcher.flush() {
+            Some(held) => self.output_event(held),
+            None => false,
+        }
+    }
+
+    fn output_event(&mut self, event: GatewayEvent) -> bool {
+        let GatewayEvent::ProtocolEvent { payload, .. } = event else {
+            return false;
+        };
+        match payload {
+            GatewayEventPayload::ExecOutput(output) => {
+                self.exec_output(&output);
+                output.exit_code.is_some()
+            }
+            GatewayEventPayload::StreamData(data) => {
+                self.stream_data(data);
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn exec_output(&mut self, output: &ExecOutput) {
+        if let Some(data) = output.data.clone() {
             let stream = output.stream.as_deref().unwrap_or("stdout");
-            let kind = if stream == "stderr" { "error" } else { "output" };
-            let _ = sink.add(TerminalChunk {
-                text: decoded,
-                kind: kind.to_string(),
+            self.data(stream, decode_output_data(data, output.encoding.as_deref()));
+        }
+
+        if let Some(exit_code) = output.exit_code {
+            self.flush();
+            self.system(format!("Process exited with code {exit_code}."));
+        }
+    }
+
+    fn stream_data(&mut self, event: StreamDataEvent) {
+        if let (Some(cols), Some(rows)) = (event.cols, event.rows) {
+            self.add(format!("{cols}x{rows}"), "resize", None, None);
+        }
+
+        if let Some(data) = event.data {
+            let channel = event.channel.as_deref().unwrap_or("stdout");
+            self.data(channel, decode_output_data(data, event.encoding.as_deref()));
+        }
+    }
+
+    fn stream_closed(&mut self, event: &StreamClosedEvent) {
+        self.flush();
+        let text = match (event.exit_code, event.reason.as_deref()) {
+            (Some(exit_code), _) => format!("Process exited with code {exit_code}."),
+            (None, Some(reason)) if !reason.is_empty() => format!("Stream closed: {reason}"),
+            _ => "Stream closed.".to_string(),
+        };
+        self.system(text);
+    }
+
+    fn data(&mut self, channel: &str, bytes: Vec<u8>) {
+        if bytes.is_empty() {
+            return;
+        }
+        if self.raw_output {
+            self.add(
+                String::new(),
+                output_kind(channel),
+                Some(channel),
+                Some(bytes),
+            );
+            return;
+        }
+        let decoder = self.decoders.entry(channel.to_string()).or_default();
+        if self.styled {
+            let spans = decoder.styled.parse(&bytes);
+            self.add_styled(channel, spans);
+            return;
+        }
+        let text = decoder.text.decode(&bytes);
+        if !text.is_empty() {
+            self.add(text, output_kind(channel), Some(channel), None);
+        }
+    }
+
+    fn flush(&mut self) {
+        self.flush_batch();
+        for (channel, mut decoder) in std::mem::take(&mut self.decoders) {
+            if self.styled {
+                let spans = decoder.styled.finish();
+                self.add_styled(&channel, spans);
+                continue;
+            }
+            let text = decoder.text.finish();
+            if !text.is_empty() {
+                self.add(text, output_kind(&channel), Some(&channel), None);
+            }
+        }
+    }
+
+    fn add_styled(&self, channel: &str, spans: Vec<TerminalRun>) {
+        let text: String = spans.iter().map(|span| span.text.as_str()).collect();
+        if !text.is_empty() {
+            let _ = self.sink.add(TerminalChunk {
+                text,
+                kind: output_kind(channel).to_string(),
+                channel: Some(channel.to_string()),
+                data: None,
+                spans,
             });
         }
     }
 
-    if let Some(exit_code) = output.exit_code {
-        let _ = sink.add(TerminalChunk {
-            text: format!("Process exited with code {exit_code}."),
-            kind: "system".to_string(),
+    fn system(&self, text: String) {
+        self.add(text, "system", None, None);
+    }
+
+    fn error(&mut self, text: String) {
+        self.flush_batch();
+        self.add(text, "error", None, None);
+    }
+
+    fn add(&self, text: String, kind: &str, channel: Option<&str>, data: Option<Vec<u8>>) {
+        let _ = self.sink.add(TerminalChunk {
+            text,
+            kind: kind.to_string(),
+            channel: channel.map(|channel| channel.to_string()),
+            data,
+            spans: Vec::new(),
         });
     }
 }
 
-fn decode_output_data(data: String, encoding: Option<&str>) -> String {
+fn output_kind(channel: &str) -> &'static str {
+    if channel == "stderr" {
+        "error"
+    } else {
+        "output"
+    }
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct Utf8ChunkDecoder {
+    pending: Vec<u8>,
+}
+
+impl Utf8ChunkDecoder {
+    fn decode(&mut self, bytes: &[u8]) -> String {
+        self.pending.extend_from_slice(bytes);
+        let buffer = std::mem::take(&mut self.pending);
+        let mut output = String::with_capacity(buffer.len());
+        let mut rest = buffer.as_slice();
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    output.push_str(valid);
+                    break;
+                }
+                Err(error) => {
+                    let (valid, invalid) = rest.split_at(error.valid_up_to());
+                    output.push_str(&String::from_utf8_lossy(valid));
+                    match error.error_len() {
+                        Some(len) => {
+                            output.push(char::REPLACEMENT_CHARACTER);
+                            rest = &invalid[len..];
+                        }
+                        None => {
+                            self.pending = invalid.to_vec();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        output
+    }
+
+    fn finish(&mut self) -> String {
+        let pending = std::mem::take(&mut self.pending);
+        String::from_utf8_lossy(&pending).to_string()
+    }
+}
+
+fn decode_output_data(data: String, encoding: Option<&str>) -> Vec<u8> {
     match encoding {
         Some(value) if value.eq_ignore_ascii_case("base64") => STANDARD
             .decode(data.as_bytes())
-            .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
-            .unwrap_or(data),
-        _ => data,
+            .unwrap_or_else(|_| data.into_bytes()),
+        _ => data.into_bytes(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carries_split_characters_across_chunks() {
+        let bytes = "终端 ─ ok".as_bytes();
+        let mut decoder = Utf8ChunkDecoder::default();
+        let mut output = String::new();
+        for chunk in bytes.chunks(1) {
+            output.push_str(&decoder.decode(chunk));
+        }
+        output.push_str(&decoder.finish());
+        assert_eq!(output, "终端 ─ ok");
+    }
+
+    #[test]
+    fn replaces_invalid_sequences_without_stalling() {
+        let mut decoder = Utf8ChunkDecoder::default();
+        assert_eq!(decoder.decode(b"a\xffb\xe7"), "a\u{FFFD}b");
+        assert_eq!(decoder.decode(b"\xbb\x88"), "\u{7ec8}");
+        assert_eq!(decoder.decode(b"\xf0\x9f"), "");
+        assert_eq!(decoder.finish(), "\u{FFFD}");
     }
 }
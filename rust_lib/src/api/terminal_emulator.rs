@@ -0,0 +1,1197 @@
+use crate::api::connection::GatewayClient;
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use std::collections::{BTreeSet, VecDeque};
+use unicode_width::UnicodeWidthChar;
+use vte::{Params, Parser, Perform};
+
+const DEFAULT_SCROLLBACK_LIMIT: usize = 1000;
+const TAB_WIDTH: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalColor {
+    Default,
+    Indexed {
+        index: u8,
+    },
+    Rgb {
+        red: u8,
+        green: u8,
+        blue: u8,
+    },
+}
+
+// Written out so the bridge does not add a static `default_` that clashes with
+// the `TerminalColor.default_` constructor in Dart.
+#[frb(ignore)]
+impl Default for TerminalColor {
+    fn default() -> Self {
+        TerminalColor::Default
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TerminalStyle {
+    pub foreground: TerminalColor,
+    pub background: TerminalColor,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub inverse: bool,
+    pub hidden: bool,
+    pub strikethrough: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerminalRun {
+    pub text: String,
+    pub style: TerminalStyle,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerminalLine {
+    pub row: u32,
+    pub runs: Vec<TerminalRun>,
+    pub wrapped: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerminalScreenUpdate {
+    pub cols: u32,
+    pub rows: u32,
+    pub cursor_row: u32,
+    pub cursor_col: u32,
+    pub cursor_visible: bool,
+    pub alternate_screen: bool,
+    pub application_cursor_keys: bool,
+    pub bracketed_paste: bool,
+    pub full: bool,
+    pub lines: Vec<TerminalLine>,
+    pub scrollback_len: u32,
+    pub title: Option<String>,
+}
+
+pub struct TerminalEmulator {
+    parser: Parser,
+    screen: Screen,
+}
+
+impl TerminalEmulator {
+    #[frb(sync)]
+    pub fn new(cols: u32, rows: u32, scrollback_limit: Option<u32>) -> Self {
+        let scrollback_limit = scrollback_limit
+            .map(|limit| limit as usize)
+            .unwrap_or(DEFAULT_SCROLLBACK_LIMIT);
+        Self {
+            parser: Parser::new(),
+            screen: Screen::new(cols as usize, rows as usize, scrollback_limit),
+        }
+    }
+
+    #[frb(sync)]
+    pub fn feed(&mut self, bytes: Vec<u8>) -> TerminalScreenUpdate {
+        self.parser.advance(&mut self.screen, &bytes);
+        self.screen.take_update()
+    }
+
+    #[frb(sync)]
+    pub fn snapshot(&mut self) -> TerminalScreenUpdate {
+        self.screen.full_redraw = true;
+        self.screen.take_update()
+    }
+
+    #[frb(sync)]
+    pub fn resize(&mut self, cols: u32, rows: u32) -> TerminalScreenUpdate {
+        self.screen.resize(cols as usize, rows as usize);
+        self.screen.take_update()
+    }
+
+    pub fn resize_request(
+        &mut self,
+        client: &GatewayClient,
+        request_id: String,
+        stream_id: Option<String>,
+        cols: u32,
+        rows: u32,
+        session_key: Option<String>,
+    ) -> Result<String> {
+        let request = client.interactive_shell_resize_request(
+            request_id,
+            stream_id,
+            cols,
+            rows,
+            Some(r#"{"elevated":true}"#.to_string()),
+            session_key,
+        )?;
+        self.screen.resize(cols as usize, rows as usize);
+        Ok(request)
+    }
+
+    #[frb(sync)]
+    pub fn scrollback_lines(&self, start: u32, count: u32) -> Vec<TerminalLine> {
+        self.screen
+            .scrollback
+            .iter()
+            .enumerate()
+            .skip(start as usize)
+            .take(count as usize)
+            .map(|(index, row)| row.to_line(index))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Cell {
+    ch: char,
+    width: u8,
+    style: TerminalStyle,
+}
+
+impl Cell {
+    fn blank(style: TerminalStyle) -> Self {
+        Self {
+            ch: ' ',
+            width: 1,
+            style,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Row {
+    cells: Vec<Cell>,
+    wrapped: bool,
+}
+
+impl Row {
+    fn new(cols: usize, blank: Cell) -> Self {
+        Self {
+            cells: vec![blank; cols],
+            wrapped: false,
+        }
+    }
+
+    fn resize(&mut self, cols: usize) {
+        self.cells
+            .resize(cols, Cell::blank(TerminalStyle::default()));
+        if let Some(last) = self.cells.last_mut() {
+            if last.width == 2 {
+                *last = Cell::blank(last.style);
+            }
+        }
+    }
+
+    fn to_line(&self, row: usize) -> TerminalLine {
+        let default_blank = Cell::blank(TerminalStyle::default());
+        let end = self
+            .cells
+            .iter()
+            .rposition(|cell| *cell != default_blank)
+            .map_or(0, |index| index + 1);
+        let mut runs: Vec<TerminalRun> = Vec::new();
+        for cell in &self.cells[..end] {
+            if cell.width == 0 {
+                continue;
+            }
+            match runs.last_mut() {
+                Some(run) if run.style == cell.style => run.text.push(cell.ch),
+                _ => runs.push(TerminalRun {
+                    text: cell.ch.to_string(),
+                    style: cell.style,
+                }),
+            }
+        }
+        TerminalLine {
+            row: row as u32,
+            runs,
+            wrapped: self.wrapped,
+        }
+    }
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone, Copy, Default)]
+struct Cursor {
+    row: usize,
+    col: usize,
+    style: TerminalStyle,
+    pending_wrap: bool,
+}
+
+struct SavedScreen {
+    lines: Vec<Row>,
+    cursor: Cursor,
+    saved_cursor: Option<Cursor>,
+}
+
+struct Screen {
+    cols: usize,
+    rows: usize,
+    lines: Vec<Row>,
+    scrollback: VecDeque<Row>,
+    scrollback_limit: usize,
+    primary: Option<SavedScreen>,
+    cursor: Cursor,
+    saved_cursor: Option<Cursor>,
+    scroll_top: usize,
+    scroll_bottom: usize,
+    autowrap: bool,
+    origin_mode: bool,
+    insert_mode: bool,
+    cursor_visible: bool,
+    application_cursor_keys: bool,
+    bracketed_paste: bool,
+    last_printed: Option<char>,
+    title: Option<String>,
+    dirty: BTreeSet<usize>,
+    full_redraw: bool,
+}
+
+impl Screen {
+    fn new(cols: usize, rows: usize, scrollback_limit: usize) -> Self {
+        let cols = cols.max(1);
+        let rows = rows.max(1);
+        Self {
+            cols,
+            rows,
+            lines: vec![Row::new(cols, Cell::blank(TerminalStyle::default())); rows],
+            scrollback: VecDeque::new(),
+            scrollback_limit,
+            primary: None,
+            cursor: Cursor::default(),
+            saved_cursor: None,
+            scroll_top: 0,
+            scroll_bottom: rows - 1,
+            autowrap: true,
+            origin_mode: false,
+            insert_mode: false,
+            cursor_visible: true,
+            application_cursor_keys: false,
+            bracketed_paste: false,
+            last_printed: None,
+            title: None,
+            dirty: BTreeSet::new(),
+            full_redraw: true,
+        }
+    }
+
+    fn take_update(&mut self) -> TerminalScreenUpdate {
+        let rows: Vec<usize> = if self.full_redraw {
+            (0..self.rows).collect()
+        } else {
+            self.dirty
+                .iter()
+                .copied()
+                .filter(|row| *row < self.rows)
+                .collect()
+        };
+        let update = TerminalScreenUpdate {
+            cols: self.cols as u32,
+            rows: self.rows as u32,
+            cursor_row: self.cursor.row as u32,
+            cursor_col: self.cursor.col as u32,
+            cursor_visible: self.cursor_visible,
+            alternate_screen: self.primary.is_some(),
+            application_cursor_keys: self.application_cursor_keys,
+            bracketed_paste: self.bracketed_paste,
+            full: self.full_redraw,
+            lines: rows
+                .into_iter()
+                .map(|row| self.lines[row].to_line(row))
+                .collect(),
+            scrollback_len: self.scrollback.len() as u32,
+            title: self.title.clone(),
+        };
+        self.dirty.clear();
+        self.full_redraw = false;
+        update
+    }
+
+    fn blank_cell(&self) -> Cell {
+        Cell::blank(TerminalStyle {
+            background: self.cursor.style.background,
+            ..TerminalStyle::default()
+        })
+    }
+
+    fn blank_row(&self) -> Row {
+        Row::new(self.cols, self.blank_cell())
+    }
+
+    fn mark_rows(&mut self, start: usize, end: usize) {
+        self.dirty.extend(start..=end.min(self.rows - 1));
+    }
+
+    fn push_scrollback(&mut self, row: Row) {
+        if self.scrollback_limit == 0 {
+            return;
+        }
+        if self.scrollback.len() >= self.scrollback_limit {
+            self.scrollback.pop_front();
+        }
+        self.scrollback.push_back(row);
+    }
+
+    fn print(&mut self, ch: char) {
+        let width = match ch.width() {
+            Some(width) if width > 0 => width.min(2),
+            _ => return,
+        };
+        if width > self.cols {
+            return;
+        }
+        if self.cursor.pending_wrap && self.autowrap {
+            self.wrap_line();
+        }
+        if self.cursor.col + width > self.cols {
+            if self.autowrap {
+                let blank = self.blank_cell();
+                let row = self.cursor.row;
+                for col in self.cursor.col..self.cols {
+                    self.lines[row].cells[col] = blank;
+                }
+                self.wrap_line();
+            } else {
+                self.cursor.col = self.cols - width;
+            }
+        }
+
+        let row = self.cursor.row;
+        let col = self.cursor.col;
+        if self.insert_mode {
+            let blank = self.blank_cell();
+            let cells = &mut self.lines[row].cells;
+            for _ in 0..width {
+                cells.insert(col, blank);
+            }
+            cells.truncate(self.cols);
+            self.lines[row].resize(self.cols);
+        }
+        self.clear_wide_neighbours(row, col, width);
+        let style = self.cursor.style;
+        self.lines[row].cells[col] = Cell {
+            ch,
+            width: width as u8,
+            style,
+        };
+        if width == 2 {
+            self.lines[row].cells[col + 1] = Cell {
+                ch: ' ',
+                width: 0,
+                style,
+            };
+        }
+        self.dirty.insert(row);
+        self.last_printed = Some(ch);
+
+        if col + width >= self.cols {
+            self.cursor.col = self.cols - 1;
+            self.cursor.pending_wrap = self.autowrap;
+        } else {
+            self.cursor.col = col + width;
+        }
+    }
+
+    fn clear_wide_neighbours(&mut self, row: usize, col: usize, width: usize) {
+        let blank = self.blank_cell();
+        let cells = &mut self.lines[row].cells;
+        if cells[col].width == 0 && col > 0 {
+            cells[col - 1] = blank;
+        }
+        let last = col + width - 1;
+        if cells[last].width == 2 && last + 1 < cells.len() {
+            cells[last + 1] = blank;
+        }
+    }
+
+    fn wrap_line(&mut self) {
+        self.lines[self.cursor.row].wrapped = true;
+        self.cursor.col = 0;
+        self.cursor.pending_wrap = false;
+        self.linefeed();
+    }
+
+    fn linefeed(&mut self) {
+        self.cursor.pending_wrap = false;
+        if self.cursor.row == self.scroll_bottom {
+            self.scroll_up(1);
+        } else if self.cursor.row + 1 < self.rows {
+            self.cursor.row += 1;
+        }
+    }
+
+    fn reverse_index(&mut self) {
+        self.cursor.pending_wrap = false;
+        if self.cursor.row == self.scroll_top {
+            self.scroll_down(1);
+        } else if self.cursor.row > 0 {
+            self.cursor.row -= 1;
+        }
+    }
+
+    fn scroll_up(&mut self, count: usize) {
+        let (top, bottom) = (self.scroll_top, self.scroll_bottom);
+        let count = count.min(bottom - top + 1);
+        for _ in 0..count {
+            let row = self.lines.remove(top);
+            if top == 0 && self.primary.is_none() {
+                self.push_scrollback(row);
+            }
+            let blank = self.blank_row();
+            self.lines.insert(bottom, blank);
+        }
+        self.mark_rows(top, bottom);
+    }
+
+    fn scroll_down(&mut self, count: usize) {
+        let (top, bottom) = (self.scroll_top, self.scroll_bottom);
+        let count = count.min(bottom - top + 1);
+        for _ in 0..count {
+            self.lines.remove(bottom);
+            let blank = self.blank_row();
+            self.lines.insert(top, blank);
+        }
+        self.mark_rows(top, bottom);
+    }
+
+    fn insert_lines(&mut self, count: usize) {
+        let row = self.cursor.row;
+        if row < self.scroll_top || row > self.scroll_bottom {
+            return;
+        }
+        let count = count.min(self.scroll_bottom - row + 1);
+        for _ in 0..count {
+            self.lines.remove(self.scroll_bottom);
+            let blank = self.blank_row();
+            self.lines.insert(row, blank);
+        }
+        self.cursor.col = 0;
+        self.cursor.pending_wrap = false;
+        self.mark_rows(row, self.scroll_bottom);
+    }
+
+    fn delete_lines(&mut self, count: usize) {
+        let row = self.cursor.row;
+        if row < self.scroll_top || row > self.scroll_bottom {
+            return;
+        }
+        let count = count.min(self.scroll_bottom - row + 1);
+        for _ in 0..count {
+            self.lines.remove(row);
+            let blank = self.blank_row();
+            self.lines.insert(self.scroll_bottom, blank);
+        }
+        self.cursor.col = 0;
+        self.cursor.pending_wrap = false;
+        self.mark_rows(row, self.scroll_bottom);
+    }
+
+    fn erase_cells(&mut self, row: usize, start: usize, end: usize) {
+        let blank = self.blank_cell();
+        let end = end.min(self.cols);
+        if start >= end {
+            return;
+        }
+        let cells = &mut self.lines[row].cells;
+        if cells[start].width == 0 && start > 0 {
+            cells[start - 1] = blank;
+        }
+        if end < cells.len() && cells[end].width == 0 {
+            cells[end] = blank;
+        }
+        for cell in &mut cells[start..end] {
+            *cell = blank;
+        }
+        self.dirty.insert(row);
+    }
+
+    fn erase_rows(&mut self, start: usize, end: usize) {
+        for row in start..end.min(self.rows) {
+            self.lines[row] = self.blank_row();
+        }
+        if start < end {
+            self.mark_rows(start, end - 1);
+        }
+    }
+
+    fn erase_in_display(&mut self, mode: u16) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        match mode {
+            0 => {
+                self.erase_cells(row, col, self.cols);
+                self.lines[row].wrapped = false;
+                self.erase_rows(row + 1, self.rows);
+            }
+            1 => {
+                self.erase_rows(0, row);
+                self.erase_cells(row, 0, col + 1);
+            }
+            2 => self.erase_rows(0, self.rows),
+            3 => {
+                self.scrollback.clear();
+                self.full_redraw = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn erase_in_line(&mut self, mode: u16) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        match mode {
+            0 => {
+                self.erase_cells(row, col, self.cols);
+                self.lines[row].wrapped = false;
+            }
+            1 => self.erase_cells(row, 0, col + 1),
+            2 => {
+                self.erase_cells(row, 0, self.cols);
+                self.lines[row].wrapped = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn delete_chars(&mut self, count: usize) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        let blank = self.blank_cell();
+        let count = count.min(self.cols - col);
+        self.clear_wide_neighbours(row, col, 1);
+        let cells = &mut self.lines[row].cells;
+        cells.drain(col..col + count);
+        cells.extend(std::iter::repeat_n(blank, count));
+        self.lines[row].resize(self.cols);
+        self.cursor.pending_wrap = false;
+        self.dirty.insert(row);
+    }
+
+    fn insert_blanks(&mut self, count: usize) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        let blank = self.blank_cell();
+        let count = count.min(self.cols - col);
+        self.clear_wide_neighbours(row, col, 1);
+        let cells = &mut self.lines[row].cells;
+        for _ in 0..count {
+            cells.insert(col, blank);
+        }
+        cells.truncate(self.cols);
+        self.lines[row].resize(self.cols);
+        self.cursor.pending_wrap = false;
+        self.dirty.insert(row);
+    }
+
+    fn move_to(&mut self, row: usize, col: usize) {
+        let (min_row, max_row) = if self.origin_mode {
+            (self.scroll_top, self.scroll_bottom)
+        } else {
+            (0, self.rows - 1)
+        };
+        let row = if self.origin_mode {
+            row + self.scroll_top
+        } else {
+            row
+        };
+        self.cursor.row = row.clamp(min_row, max_row);
+        self.cursor.col = col.min(self.cols - 1);
+        self.cursor.pending_wrap = false;
+    }
+
+    fn move_vertical(&mut self, delta: isize) {
+        let row = self.cursor.row;
+        let (top, bottom) = if (self.scroll_top..=self.scroll_bottom).contains(&row) {
+            (self.scroll_top, self.scroll_bottom)
+        } else {
+            (0, self.rows - 1)
+        };
+        self.cursor.row = row.saturating_add_signed(delta).clamp(top, bottom);
+        self.cursor.pending_wrap = false;
+    }
+
+    fn move_horizontal(&mut self, delta: isize) {
+        self.cursor.col = self
+            .cursor
+            .col
+            .saturating_add_signed(delta)
+            .min(self.cols - 1);
+        self.cursor.pending_wrap = false;
+    }
+
+    fn tab_forward(&mut self, count: usize) {
+        for _ in 0..count {
+            let next = (self.cursor.col / TAB_WIDTH + 1) * TAB_WIDTH;
+            self.cursor.col = next.min(self.cols - 1);
+        }
+        self.cursor.pending_wrap = false;
+    }
+
+    fn tab_backward(&mut self, count: usize) {
+        for _ in 0..count {
+            let col = self.cursor.col;
+            self.cursor.col = if col == 0 {
+                0
+            } else {
+                (col - 1) / TAB_WIDTH * TAB_WIDTH
+            };
+        }
+        self.cursor.pending_wrap = false;
+    }
+
+    fn set_scroll_region(&mut self, top: usize, bottom: usize) {
+        let top = top.saturating_sub(1);
+        let bottom = if bottom == 0 {
+            self.rows - 1
+        } else {
+            (bottom - 1).min(self.rows - 1)
+        };
+        if top < bottom {
+            self.scroll_top = top;
+            self.scroll_bottom = bottom;
+            self.move_to(0, 0);
+        }
+    }
+
+    fn save_cursor(&mut self) {
+        self.saved_cursor = Some(self.cursor);
+    }
+
+    fn restore_cursor(&mut self) {
+        let saved = self.saved_cursor.unwrap_or_default();
+        self.cursor = Cursor {
+            row: saved.row.min(self.rows - 1),
+            col: saved.col.min(self.cols - 1),
+            ..saved
+        };
+    }
+
+    fn enter_alternate_screen(&mut self, save_cursor: bool) {
+        if self.primary.is_some() {
+            return;
+        }
+        if save_cursor {
+            self.save_cursor();
+        }
+        let blank = Row::new(self.cols, Cell::blank(TerminalStyle::default()));
+        let lines = std::mem::replace(&mut self.lines, vec![blank; self.rows]);
+        self.primary = Some(SavedScreen {
+            lines,
+            cursor: self.cursor,
+            saved_cursor: self.saved_cursor,
+        });
+        self.full_redraw = true;
+    }
+
+    fn leave_alternate_screen(&mut self, restore_cursor: bool) {
+        let Some(primary) = self.primary.take() else {
+            return;
+        };
+        self.lines = primary.lines;
+        self.cursor = primary.cursor;
+        self.saved_cursor = primary.saved_cursor;
+        if restore_cursor {
+            self.restore_cursor();
+        }
+        self.full_redraw = true;
+    }
+
+    fn set_mode(&mut self, private: bool, mode: u16, enabled: bool) {
+        match (private, mode) {
+            (false, 4) => self.insert_mode = enabled,
+            (true, 1) => self.application_cursor_keys = enabled,
+            (true, 6) => {
+                self.origin_mode = enabled;
+                self.move_to(0, 0);
+            }
+            (true, 7) => self.autowrap = enabled,
+            (true, 25) => self.cursor_visible = enabled,
+            (true, 47) | (true, 1047) => {
+                if enabled {
+                    self.enter_alternate_screen(false);
+                } else {
+                    self.leave_alternate_screen(false);
+                }
+            }
+            (true, 1049) => {
+                if enabled {
+                    self.enter_alternate_screen(true);
+                } else {
+                    self.leave_alternate_screen(true);
+                }
+            }
+            (true, 2004) => self.bracketed_paste = enabled,
+            _ => {}
+        }
+    }
+
+    fn resize(&mut self, cols: usize, rows: usize) {
+        let cols = cols.max(1);
+        let rows = rows.max(1);
+        if cols == self.cols && rows == self.rows {
+            return;
+        }
+
+        let alternate = self.primary.is_some();
+        let overflow = (self.cursor.row + 1).saturating_sub(rows);
+        let scrolled: Vec<Row> = self.lines.drain(..overflow).collect();
+        if !alternate {
+            for row in scrolled {
+                self.push_scrollback(row);
+            }
+        }
+        self.cursor.row -= overflow;
+        resize_lines(&mut self.lines, cols, rows);
+        if let Some(primary) = self.primary.as_mut() {
+            let overflow = (primary.cursor.row + 1).saturating_sub(rows);
+            let scrolled: Vec<Row> = primary.lines.drain(..overflow).collect();
+            primary.cursor.row -= overflow;
+            primary.cursor.row = primary.cursor.row.min(rows - 1);
+            primary.cursor.col = primary.cursor.col.min(cols - 1);
+            resize_lines(&mut primary.lines, cols, rows);
+            for row in scrolled {
+                self.push_scrollback(row);
+            }
+        }
+        for row in self.scrollback.iter_mut() {
+            row.resize(cols);
+        }
+
+        self.cols = cols;
+        self.rows = rows;
+        self.scroll_top = 0;
+        self.scroll_bottom = rows - 1;
+        self.cursor.row = self.cursor.row.min(rows - 1);
+        self.cursor.col = self.cursor.col.min(cols - 1);
+        self.cursor.pending_wrap = false;
+        self.full_redraw = true;
+    }
+
+    fn reset(&mut self) {
+        let title = self.title.take();
+        *self = Self::new(self.cols, self.rows, self.scrollback_limit);
+        self.title = title;
+    }
+
+    fn select_graphic_rendition(&mut self, params: &Params) {
+        apply_sgr(&mut self.cursor.style, params);
+    }
+}
+
+fn apply_sgr(style: &mut TerminalStyle, params: &Params) {
+    let groups: Vec<&[u16]> = params.iter().collect();
+    if groups.is_empty() {
+        *style = TerminalStyle::default();
+        return;
+    }
+    let mut index = 0;
+    while index < groups.len() {
+        let group = groups[index];
+        index += 1;
+        match group[0] {
+            0 => *style = TerminalStyle::default(),
+            1 => style.bold = true,
+            2 => style.dim = true,
+            3 => style.italic = true,
+            4 | 21 => style.underline = group.get(1) != Some(&0),
+            7 => style.inverse = true,
+            8 => style.hidden = true,
+            9 => style.strikethrough = true,
+            22 => {
+                style.bold = false;
+                style.dim = false;
+            }
+            23 => style.italic = false,
+            24 => style.underline = false,
+            27 => style.inverse = false,
+            28 => style.hidden = false,
+            29 => style.strikethrough = false,
+            code @ 30..=37 => style.foreground = indexed(code - 30),
+            38 => style.foreground = extended_color(group, &groups, &mut index),
+            39 => style.foreground = TerminalColor::Default,
+            code @ 40..=47 => style.background = indexed(code - 40),
+            48 => style.background = extended_color(group, &groups, &mut index),
+            49 => style.background = TerminalColor::Default,
+            code @ 90..=97 => style.foreground = indexed(code - 90 + 8),
+            code @ 100..=107 => style.background = indexed(code - 100 + 8),
+            _ => {}
+        }
+    }
+}
+
+fn resize_lines(lines: &mut Vec<Row>, cols: usize, rows: usize) {
+    lines.truncate(rows);
+    for row in lines.iter_mut() {
+        row.resize(cols);
+    }
+    while lines.len() < rows {
+        lines.push(Row::new(cols, Cell::blank(TerminalStyle::default())));
+    }
+}
+
+fn indexed(index: u16) -> TerminalColor {
+    TerminalColor::Indexed { index: index as u8 }
+}
+
+fn extended_color(group: &[u16], groups: &[&[u16]], index: &mut usize) -> TerminalColor {
+    let values: Vec<u16> = if group.len() > 1 {
+        group[1..].to_vec()
+    } else {
+        let mut values = Vec::new();
+        if let Some(kind) = groups.get(*index) {
+            values.push(kind[0]);
+            let needed = if kind[0] == 5 { 1 } else { 3 };
+            for next in groups.iter().skip(*index + 1).take(needed) {
+                values.push(next[0]);
+            }
+            *index += values.len();
+        }
+        values
+    };
+    match values.as_slice() {
+        [5, color, ..] => indexed(*color),
+        [2, _, red, green, blue] | [2, red, green, blue] => TerminalColor::Rgb {
+            red: *red as u8,
+            green: *green as u8,
+            blue: *blue as u8,
+        },
+        _ => TerminalColor::Default,
+    }
+}
+
+fn param(params: &Params, index: usize, default: usize) -> usize {
+    match params.iter().nth(index).map(|group| group[0]) {
+        Some(0) | None => default,
+        Some(value) => value as usize,
+    }
+}
+
+impl Perform for Screen {
+    fn print(&mut self, ch: char) {
+        Screen::print(self, ch);
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            0x08 => {
+                if self.cursor.pending_wrap {
+                    self.cursor.pending_wrap = false;
+                } else {
+                    self.move_horizontal(-1);
+                }
+            }
+            0x09 => self.tab_forward(1),
+            0x0A..=0x0C => self.linefeed(),
+            0x0D => {
+                self.cursor.col = 0;
+                self.cursor.pending_wrap = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn csi_dispatch(&mut self, params: &Params, intermediates: &[u8], ignore: bool, action: char) {
+        if ignore {
+            return;
+        }
+        let private = intermediates.first() == Some(&b'?');
+        if private && !matches!(action, 'h' | 'l') {
+            return;
+        }
+        let count = param(params, 0, 1);
+        let delta = count as isize;
+        match action {
+            'A' => self.move_vertical(-delta),
+            'B' | 'e' => self.move_vertical(delta),
+            'C' | 'a' => self.move_horizontal(delta),
+            'D' => self.move_horizontal(-delta),
+            'E' => {
+                self.move_vertical(delta);
+                self.cursor.col = 0;
+            }
+            'F' => {
+                self.move_vertical(-delta);
+                self.cursor.col = 0;
+            }
+            'G' | '`' => {
+                self.cursor.col = (count - 1).min(self.cols - 1);
+                self.cursor.pending_wrap = false;
+            }
+            'H' | 'f' => {
+                let row = param(params, 0, 1) - 1;
+                let col = param(params, 1, 1) - 1;
+                self.move_to(row, col);
+            }
+            'd' => {
+                let col = self.cursor.col;
+                self.move_to(count - 1, col);
+            }
+            'J' => self.erase_in_display(param(params, 0, 0) as u16),
+            'K' => self.erase_in_line(param(params, 0, 0) as u16),
+            'L' => self.insert_lines(count),
+            'M' => self.delete_lines(count),
+            'P' => self.delete_chars(count),
+            '@' => self.insert_blanks(count),
+            'X' => {
+                let (row, col) = (self.cursor.row, self.cursor.col);
+                self.erase_cells(row, col, col + count);
+            }
+            'S' => self.scroll_up(count),
+            'T' => self.scroll_down(count),
+            'I' => self.tab_forward(count),
+            'Z' => self.tab_backward(count),
+            'b' => {
+                if let Some(ch) = self.last_printed {
+                    for _ in 0..count.min(self.cols * self.rows) {
+                        Screen::print(self, ch);
+                    }
+                }
+            }
+            'r' => self.set_scroll_region(param(params, 0, 1), param(params, 1, 0)),
+            's' => self.save_cursor(),
+            'u' => self.restore_cursor(),
+            'm' => self.select_graphic_rendition(params),
+            'h' | 'l' => {
+                for group in params.iter() {
+                    self.set_mode(private, group[0], action == 'h');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn esc_dispatch(&mut self, intermediates: &[u8], ignore: bool, byte: u8) {
+        if ignore || !intermediates.is_empty() {
+            return;
+        }
+        match byte {
+            b'7' => self.save_cursor(),
+            b'8' => self.restore_cursor(),
+            b'D' => self.linefeed(),
+            b'E' => {
+                self.linefeed();
+                self.cursor.col = 0;
+            }
+            b'M' => self.reverse_index(),
+            b'c' => self.reset(),
+            _ => {}
+        }
+    }
+
+    fn osc_dispatch(&mut self, params: &[&[u8]], _bell_terminated: bool) {
+        if let [b"0" | b"2", title @ ..] = params {
+            let title = title
+                .iter()
+                .map(|part| String::from_utf8_lossy(part))
+                .collect::<Vec<_>>()
+                .join(";");
+            self.title = Some(title);
+        }
+    }
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+pub(crate) struct StyledTextParser {
+    parser: Parser,
+    spans: SpanCollector,
+}
+
+impl StyledTextParser {
+    pub(crate) fn parse(&mut self, bytes: &[u8]) -> Vec<TerminalRun> {
+        self.parser.advance(&mut self.spans, bytes);
+        std::mem::take(&mut self.spans.runs)
+    }
+
+    /// Ends the stream: an unfinished escape sequence is dropped and a truncated
+    /// UTF-8 character comes back as U+FFFD, leaving the parser ready for new input.
+    pub(crate) fn finish(&mut self) -> Vec<TerminalRun> {
+        // CAN aborts any sequence in progress and prints nothing itself.
+        self.parse(b"\x18")
+    }
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct SpanCollector {
+    style: TerminalStyle,
+    runs: Vec<TerminalRun>,
+}
+
+impl SpanCollector {
+    fn push(&mut self, ch: char) {
+        match self.runs.last_mut() {
+            Some(run) if run.style == self.style => run.text.push(ch),
+            _ => self.runs.push(TerminalRun {
+                text: ch.to_string(),
+                style: self.style,
+            }),
+        }
+    }
+}
+
+impl Perform for SpanCollector {
+    fn print(&mut self, ch: char) {
+        self.push(ch);
+    }
+
+    fn execute(&mut self, byte: u8) {
+        if matches!(byte, b'\t' | b'\n' | b'\r') {
+            self.push(byte as char);
+        }
+    }
+
+    fn csi_dispatch(&mut self, params: &Params, intermediates: &[u8], ignore: bool, action: char) {
+        if !ignore && intermediates.is_empty() && action == 'm' {
+            apply_sgr(&mut self.style, params);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &TerminalLine) -> String {
+        line.runs.iter().map(|run| run.text.as_str()).collect()
+    }
+
+    fn screen_text(update: &TerminalScreenUpdate) -> Vec<String> {
+        update.lines.iter().map(line_text).collect()
+    }
+
+    #[test]
+    fn wraps_long_lines_into_scrollback() {
+        let mut emulator = TerminalEmulator::new(4, 2, None);
+        let update = emulator.feed(b"abcdefghij".to_vec());
+
+        assert_eq!(screen_text(&update), vec!["efgh", "ij"]);
+        assert!(update.lines[0].wrapped);
+        assert_eq!(update.scrollback_len, 1);
+        assert_eq!(
+            line_text(&emulator.scrollback_lines(0, 1)[0]),
+            "abcd".to_string()
+        );
+        assert_eq!((update.cursor_row, update.cursor_col), (1, 2));
+    }
+
+    #[test]
+    fn moves_cursor_and_erases() {
+        let mut emulator = TerminalEmulator::new(10, 3, None);
+        emulator.feed(b"hello\r\nworld".to_vec());
+        let update = emulator.feed(b"\x1b[1;3H\x1b[K\x1b[2;1H\x1b[2P".to_vec());
+
+        assert_eq!(update.lines.len(), 2);
+        assert_eq!(line_text(&update.lines[0]), "he");
+        assert_eq!(line_text(&update.lines[1]), "rld");
+        assert_eq!((update.cursor_row, update.cursor_col), (1, 0));
+    }
+
+    #[test]
+    fn alternate_screen_restores_primary_contents() {
+        let mut emulator = TerminalEmulator::new(10, 2, None);
+        emulator.feed(b"primary".to_vec());
+        let update = emulator.feed(b"\x1b[?1049h\x1b[Hvim".to_vec());
+        assert!(update.alternate_screen);
+        assert_eq!(screen_text(&update), vec!["vim", ""]);
+
+        let update = emulator.feed(b"\x1b[?1049l".to_vec());
+        assert!(!update.alternate_screen);
+        assert_eq!(screen_text(&update), vec!["primary", ""]);
+        assert_eq!((update.cursor_row, update.cursor_col), (0, 7));
+    }
+
+    #[test]
+    fn applies_graphic_rendition() {
+        let mut emulator = TerminalEmulator::new(20, 1, None);
+        let update =
+            emulator.feed(b"\x1b[1;31mred\x1b[0m \x1b[38;2;1;2;3mrgb\x1b[48;5;200mx".to_vec());
+        let runs = &update.lines[0].runs;
+
+        assert_eq!(runs.len(), 4);
+        assert_eq!(runs[0].text, "red");
+        assert!(runs[0].style.bold);
+        assert_eq!(
+            runs[0].style.foreground,
+            TerminalColor::Indexed { index: 1 }
+        );
+        assert_eq!(runs[1].style, TerminalStyle::default());
+        assert_eq!(
+            runs[2].style.foreground,
+            TerminalColor::Rgb {
+                red: 1,
+                green: 2,
+                blue: 3
+            }
+        );
+        assert_eq!(
+            runs[3].style.background,
+            TerminalColor::Indexed { index: 200 }
+        );
+    }
+
+    #[test]
+    fn wide_characters_take_two_cells() {
+        let mut emulator = TerminalEmulator::new(5, 2, None);
+        let update = emulator.feed("中文字".as_bytes().to_vec());
+
+        assert_eq!(screen_text(&update), vec!["中文", "字"]);
+        assert!(update.lines[0].wrapped);
+        assert_eq!((update.cursor_row, update.cursor_col), (1, 2));
+    }
+
+    #[test]
+    fn resize_keeps_cursor_line_visible() {
+        let mut emulator = TerminalEmulator::new(10, 3, None);
+        emulator.feed(b"1\r\n2\r\n3".to_vec());
+        let update = emulator.resize(4, 2);
+
+        assert!(update.full);
+        assert_eq!(screen_text(&update), vec!["2", "3"]);
+        assert_eq!(update.scrollback_len, 1);
+        assert_eq!((update.cursor_row, update.cursor_col), (1, 1));
+    }
+
+    #[test]
+    fn scroll_region_keeps_outer_lines() {
+        let mut emulator = TerminalEmulator::new(10, 4, None);
+        emulator.feed(b"top\r\na\r\nb\r\nbottom".to_vec());
+        let update = emulator.feed(b"\x1b[2;3r\x1b[3;1H\nc".to_vec());
+
+        assert_eq!(emulator.snapshot().scrollback_len, 0);
+        let text = screen_text(&emulator.snapshot());
+        assert_eq!(text, vec!["top", "b", "c", "bottom"]);
+        assert!(!update.full);
+    }
+
+    #[test]
+    fn styled_parser_keeps_state_across_chunks() {
+        let mut parser = StyledTextParser::default();
+        assert!(parser.parse(b"\x1b[1;3").is_empty());
+        let runs = parser.parse(b"2mok\x1b[");
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].text, "ok");
+        assert!(runs[0].style.bold);
+        assert_eq!(
+            runs[0].style.foreground,
+            TerminalColor::Indexed { index: 2 }
+        );
+
+        let runs = parser.parse(b"0m done\n\x1b[2K");
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].text, " done\n");
+        assert_eq!(runs[0].style, TerminalStyle::default());
+    }
+
+    #[test]
+    fn styled_parser_finish_drops_an_unfinished_escape() {
+        let mut parser = StyledTextParser::default();
+        assert_eq!(parser.parse(b"ok\x1b[31")[0].text, "ok");
+        assert!(parser.finish().is_empty());
+        let runs = parser.parse(b"m next");
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].text, "m next");
+
+        assert!(parser.parse(b"\xe7\xbb").is_empty());
+        let runs = parser.finish();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].text, "\u{FFFD}");
+    }
+}
@@ -0,0 +1,751 @@
+use crate::api::events::GatewayEvent;
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use flutter_rust_bridge::frb;
+use ring::digest::{digest, SHA256};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::{
+    AlertDescription, CertificateError, ClientConfig, DigitallySignedStruct, RootCertStore,
+    SignatureScheme,
+};
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer, ServerName, UnixTime};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio_tungstenite::tungstenite;
+
+const KNOWN_HOSTS_FILE_NAME: &str = "known-gateways.json";
+const PIN_PREFIX: &str = "sha256/";
+const DEFAULT_WSS_PORT: u16 = 443;
+
+#[derive(Debug, Clone, Default)]
+pub struct TlsOptions {
+    /// PEM encoded CA certificates trusted in addition to the system roots.
+    pub extra_ca_pem: Vec<String>,
+    /// SHA-256 hashes of the server's SubjectPublicKeyInfo, base64 encoded with an
+    /// optional `sha256/` prefix. The server's key must match one of them on top of
+    /// the usual certificate validation.
+    pub spki_pins: Vec<String>,
+    /// Record the server's key on first connect and reject any other key later.
+    /// Requires `known_hosts_dir`.
+    pub trust_on_first_use: bool,
+    /// Where first-use fingerprints are stored, keyed by `host:port`.
+    pub known_hosts_dir: Option<String>,
+    /// Accept a self-signed server certificate in place of a trusted CA chain once
+    /// its key matches a pin or the first-use record. Expiry and hostname are still
+    /// checked. Requires `spki_pins` or `trust_on_first_use`.
+    pub allow_self_signed: bool,
+    /// PEM encoded client certificate chain, leaf first, presented for mutual TLS.
+    pub client_cert_chain_pem: Option<String>,
+    /// PEM encoded private key of the client certificate (PKCS#8, PKCS#1 or SEC1).
+    pub client_key_pem: Option<String>,
+    /// DER encoded PKCS#8 private key, used when `client_key_pem` is not set.
+    pub client_key_pkcs8: Option<Vec<u8>>,
+}
+
+/// Why a server certificate was refused by pinning or trust-on-first-use.
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CertificateRejection {
+    Changed {
+        host: String,
+        expected: String,
+        received: String,
+    },
+    NotPinned {
+        host: String,
+        received: String,
+    },
+}
+
+impl fmt::Display for CertificateRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Changed {
+                host,
+                expected,
+                received,
+            } => write!(
+                f,
+                "Certificate key for {host} changed (expected {expected}, received {received})"
+            ),
+            Self::NotPinned { host, received } => write!(
+                f,
+                "Certificate key for {host} matches no pinned key (received {received})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CertificateRejection {}
+
+impl CertificateRejection {
+    fn to_event(&self) -> GatewayEvent {
+        match self {
+            Self::Changed {
+                host,
+                expected,
+                received,
+            } => GatewayEvent::CertificateChanged {
+                host: host.clone(),
+                expected: expected.clone(),
+                received: received.clone(),
+            },
+            Self::NotPinned { .. } => GatewayEvent::Error {
+                message: self.to_string(),
+            },
+        }
+    }
+}
+
+/// Mutual TLS failures, typed so a bad client certificate can be told apart from
+/// an unreachable gateway.
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientCertificateError {
+    /// The configured chain or key could not be loaded.
+    Invalid { reason: String },
+    /// The gateway refused the handshake with a certificate alert.
+    Rejected { alert: String },
+}
+
+impl fmt::Display for ClientCertificateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid { reason } => write!(f, "Invalid client certificate: {reason}"),
+            Self::Rejected { alert } => {
+                write!(f, "Gateway rejected the client certificate ({alert})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientCertificateError {}
+
+/// The event to report when `error` is a certificate failure that retrying
+/// cannot fix.
+pub(crate) fn certificate_failure_event(error: &anyhow::Error) -> Option<GatewayEvent> {
+    if let Some(rejection) = error.downcast_ref::<CertificateRejection>() {
+        return Some(rejection.to_event());
+    }
+    match error.downcast_ref::<ClientCertificateError>()? {
+        ClientCertificateError::Rejected { alert } => {
+            Some(GatewayEvent::ClientCertificateRejected {
+                alert: alert.clone(),
+            })
+        }
+        ClientCertificateError::Invalid { .. } => Some(GatewayEvent::Error {
+            message: error.to_string(),
+        }),
+    }
+}
+
+/// Client configuration built from `TlsOptions`, plus the verdict of the last
+/// rejected handshake so connect errors can be reported precisely.
+#[derive(Debug)]
+pub(crate) struct TlsClient {
+    config: Arc<ClientConfig>,
+    verifier: Arc<GatewayCertVerifier>,
+}
+
+impl TlsClient {
+    pub(crate) fn new(options: &TlsOptions) -> Result<Self> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let mut roots = RootCertStore::empty();
+        roots.add_parsable_certificates(rustls_native_certs::load_native_certs().certs);
+        for pem in &options.extra_ca_pem {
+            let certs = CertificateDer::pem_slice_iter(pem.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| anyhow!("Invalid CA certificate: {e:?}"))?;
+            if certs.is_empty() {
+                return Err(anyhow!("No certificate found in CA PEM"));
+            }
+            for cert in certs {
+                roots.add(cert)?;
+            }
+        }
+        let webpki = if roots.is_empty() {
+            None
+        } else {
+            Some(
+                WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+                    .build()?,
+            )
+        };
+        let pins = options
+            .spki_pins
+            .iter()
+            .map(|pin| normalize_pin(pin))
+            .collect::<Result<Vec<_>>>()?;
+        let known_hosts = match (options.trust_on_first_use, &options.known_hosts_dir) {
+            (false, _) => None,
+            (true, Some(dir)) => Some(KnownHosts {
+                path: Path::new(dir).join(KNOWN_HOSTS_FILE_NAME),
+            }),
+            (true, None) => {
+                return Err(anyhow!(
+                    "Trust on first use needs known_hosts_dir to remember gateway keys"
+                ))
+            }
+        };
+        if options.allow_self_signed && pins.is_empty() && known_hosts.is_none() {
+            return Err(anyhow!(
+                "allow_self_signed needs spki_pins or trust_on_first_use"
+            ));
+        }
+        let verifier = Arc::new(GatewayCertVerifier {
+            webpki,
+            pins,
+            known_hosts,
+            allow_self_signed: options.allow_self_signed,
+            provider: provider.clone(),
+            rejection: Mutex::new(None),
+        });
+        let builder = ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(PortVerifier {
+                verifier: verifier.clone(),
+                port: DEFAULT_WSS_PORT,
+            }));
+        let config = match client_identity(options)? {
+            Some((chain, key)) => builder.with_client_auth_cert(chain, key).map_err(|e| {
+                ClientCertificateError::Invalid {
+                    reason: e.to_string(),
+                }
+            })?,
+            None => builder.with_no_client_auth(),
+        };
+        Ok(Self {
+            config: Arc::new(config),
+            verifier,
+        })
+    }
+
+    /// Client configuration for a gateway listening on `port`, which first-use
+    /// records are keyed by together with the host.
+    pub(crate) fn config(&self, port: u16) -> Arc<ClientConfig> {
+        let mut config = (*self.config).clone();
+        config
+            .dangerous()
+            .set_certificate_verifier(Arc::new(PortVerifier {
+                verifier: self.verifier.clone(),
+                port,
+            }));
+        Arc::new(config)
+    }
+
+    fn take_rejection(&self) -> Option<CertificateRejection> {
+        self.verifier
+            .rejection
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+    }
+
+    /// Replaces a failed connect's error with the typed certificate failure behind it.
+    pub(crate) fn explain(&self, error: tungstenite::Error) -> anyhow::Error {
+        if let Some(rejection) = self.take_rejection() {
+            return rejection.into();
+        }
+        match client_certificate_alert(&error) {
+            Some(alert) => ClientCertificateError::Rejected { alert }.into(),
+            None => error.into(),
+        }
+    }
+}
+
+fn client_identity(
+    options: &TlsOptions,
+) -> Result<Option<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)>, ClientCertificateError>
+{
+    let invalid = |reason: String| ClientCertificateError::Invalid { reason };
+    let key = match (&options.client_key_pem, &options.client_key_pkcs8) {
+        (Some(pem), _) => Some(
+            PrivateKeyDer::from_pem_slice(pem.as_bytes())
+                .map_err(|e| invalid(format!("unreadable private key ({e:?})")))?,
+        ),
+        (None, Some(der)) => Some(PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.clone()))),
+        (None, None) => None,
+    };
+    let (chain_pem, key) = match (&options.client_cert_chain_pem, key) {
+        (None, None) => return Ok(None),
+        (Some(chain_pem), Some(key)) => (chain_pem, key),
+        (Some(_), None) => return Err(invalid("certificate has no private key".to_string())),
+        (None, Some(_)) => return Err(invalid("private key has no certificate".to_string())),
+    };
+    let chain = CertificateDer::pem_slice_iter(chain_pem.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| invalid(format!("unreadable certificate chain ({e:?})")))?;
+    if chain.is_empty() {
+        return Err(invalid("no certificate found in chain PEM".to_string()));
+    }
+    Ok(Some((chain, key)))
+}
+
+fn client_certificate_alert(error: &tungstenite::Error) -> Option<String> {
+    let tungstenite::Error::Io(io_error) = error else {
+        return None;
+    };
+    let rustls::Error::AlertReceived(alert) = io_error.get_ref()?.downcast_ref()? else {
+        return None;
+    };
+    matches!(
+        alert,
+        AlertDescription::BadCertificate
+            | AlertDescription::CertificateRequired
+            | AlertDescription::UnsupportedCertificate
+            | AlertDescription::CertificateRevoked
+            | AlertDescription::CertificateExpired
+            | AlertDescription::CertificateUnknown
+            | AlertDescription::UnknownCA
+            | AlertDescription::AccessDenied
+    )
+    .then(|| format!("{alert:?}"))
+}
+
+#[derive(Debug)]
+struct GatewayCertVerifier {
+    webpki: Option<Arc<WebPkiServerVerifier>>,
+    pins: Vec<String>,
+    known_hosts: Option<KnownHosts>,
+    allow_self_signed: bool,
+    provider: Arc<CryptoProvider>,
+    rejection: Mutex<Option<CertificateRejection>>,
+}
+
+impl GatewayCertVerifier {
+    fn reject(&self, rejection: CertificateRejection) -> rustls::Error {
+        *self
+            .rejection
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(rejection);
+        rustls::Error::InvalidCertificate(CertificateError::ApplicationVerificationFailure)
+    }
+
+    /// Validates the chain, expiry and hostname, treating a self-signed
+    /// certificate as its own root when that is allowed, then requires the key to
+    /// match the pins and the first-use record for `host:port`.
+    fn verify(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        port: u16,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let chain = match &self.webpki {
+            Some(webpki) => webpki.verify_server_cert(
+                end_entity,
+                intermediates,
+                server_name,
+                ocsp_response,
+                now,
+            ),
+            None => Err(rustls::Error::InvalidCertificate(
+                CertificateError::UnknownIssuer,
+            )),
+        };
+        if let Err(error) = chain {
+            if !self.allow_self_signed
+                || self
+                    .verify_self_signed(end_entity, server_name, ocsp_response, now)
+                    .is_err()
+            {
+                return Err(error);
+            }
+        }
+
+        let host = server_name.to_str().into_owned();
+        let received = spki_fingerprint(end_entity)?;
+        if !self.pins.is_empty() && !self.pins.contains(&received) {
+            return Err(self.reject(CertificateRejection::NotPinned { host, received }));
+        }
+        if let Some(known_hosts) = &self.known_hosts {
+            let key = known_host_key(&host, port);
+            match known_hosts.get(&key) {
+                Some(expected) if expected == received => {}
+                Some(expected) => {
+                    return Err(self.reject(CertificateRejection::Changed {
+                        host,
+                        expected,
+                        received,
+                    }))
+                }
+                None => known_hosts
+                    .remember(&key, &received)
+                    .map_err(|e| rustls::Error::General(e.to_string()))?,
+            }
+        }
+        Ok(ServerCertVerified::assertion())
+    }
+
+    /// Checks a certificate that signs itself with the certificate as its own
+    /// trust anchor, so expiry and hostname validation still apply.
+    fn verify_self_signed(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let mut roots = RootCertStore::empty();
+        roots.add(end_entity.clone().into_owned())?;
+        WebPkiServerVerifier::builder_with_provider(Arc::new(roots), self.provider.clone())
+            .build()
+            .map_err(|e| rustls::Error::General(e.to_string()))?
+            .verify_server_cert(end_entity, &[], server_name, ocsp_response, now)
+    }
+}
+
+/// Verifies connections to one gateway port, so first-use records for
+/// different ports on the same host stay apart.
+#[derive(Debug)]
+struct PortVerifier {
+    verifier: Arc<GatewayCertVerifier>,
+    port: u16,
+}
+
+impl ServerCertVerifier for PortVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        self.verifier.verify(
+            end_entity,
+            intermediates,
+            server_name,
+            self.port,
+            ocsp_response,
+            now,
+        )
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.verifier.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.verifier.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.verifier
+            .provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+/// `sha256/<base64>` of the certificate's DER encoded SubjectPublicKeyInfo.
+fn spki_fingerprint(cert: &CertificateDer<'_>) -> Result<String, rustls::Error> {
+    let anchor = webpki::anchor_from_trusted_cert(cert)
+        .map_err(|_| rustls::Error::InvalidCertificate(CertificateError::BadEncoding))?;
+    let spki = der_sequence(anchor.subject_public_key_info.as_ref());
+    Ok(format!(
+        "{PIN_PREFIX}{}",
+        STANDARD.encode(digest(&SHA256, &spki))
+    ))
+}
+
+/// Restores the SEQUENCE header that webpki strips from the SubjectPublicKeyInfo.
+fn der_sequence(contents: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![0x30];
+    let len = contents.len();
+    if len < 0x80 {
+        encoded.push(len as u8);
+    } else {
+        let len_bytes: Vec<u8> = len
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|byte| *byte == 0)
+            .collect();
+        encoded.push(0x80 | len_bytes.len() as u8);
+        encoded.extend(len_bytes);
+    }
+    encoded.extend_from_slice(contents);
+    encoded
+}
+
+fn normalize_pin(pin: &str) -> Result<String> {
+    let encoded = pin.trim();
+    let encoded = encoded.strip_prefix(PIN_PREFIX).unwrap_or(encoded);
+    let hash = STANDARD
+        .decode(encoded)
+        .map_err(|e| anyhow!("Invalid SPKI pin {pin}: {e}"))?;
+    if hash.len() != SHA256.output_len() {
+        return Err(anyhow!("SPKI pin {pin} is not a SHA-256 hash"));
+    }
+    Ok(format!("{PIN_PREFIX}{encoded}"))
+}
+
+/// `host:port`, with IPv6 addresses in brackets.
+fn known_host_key(host: &str, port: u16) -> String {
+    if host.contains(':') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    }
+}
+
+#[derive(Debug)]
+struct KnownHosts {
+    path: PathBuf,
+}
+
+impl KnownHosts {
+    fn get(&self, key: &str) -> Option<String> {
+        read_known_hosts(&self.path).ok()?.remove(key)
+    }
+
+    fn remember(&self, key: &str, fingerprint: &str) -> Result<()> {
+        let mut hosts = read_known_hosts(&self.path)?;
+        hosts.insert(key.to_string(), fingerprint.to_string());
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec_pretty(&hosts)?)?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+fn read_known_hosts(path: &Path) -> Result<BTreeMap<String, String>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    Ok(serde_json::from_slice(&fs::read(path)?)?)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) const CA_PEM: &str = include_str!("../../testdata/tls/ca.pem");
+    pub(crate) const LEAF_PEM: &str = include_str!("../../testdata/tls/leaf.pem");
+    pub(crate) const LEAF_KEY_PEM: &str = include_str!("../../testdata/tls/leaf-key.pem");
+    pub(crate) const OTHER_PEM: &str = include_str!("../../testdata/tls/other.pem");
+    pub(crate) const OTHER_KEY_PEM: &str = include_str!("../../testdata/tls/other-key.pem");
+    pub(crate) const CLIENT_PEM: &str = include_str!("../../testdata/tls/client.pem");
+    pub(crate) const CLIENT_KEY_PEM: &str = include_str!("../../testdata/tls/client-key.pem");
+    const CLIENT_KEY_DER: &[u8] = include_bytes!("../../testdata/tls/client-key.der");
+
+    fn cert(pem: &str) -> CertificateDer<'static> {
+        CertificateDer::from_pem_slice(pem.as_bytes()).expect("certificate")
+    }
+
+    fn verify(client: &TlsClient, pem: &str) -> Result<ServerCertVerified, rustls::Error> {
+        verify_host(client, pem, "localhost", 443)
+    }
+
+    fn verify_host(
+        client: &TlsClient,
+        pem: &str,
+        host: &str,
+        port: u16,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        client.verifier.verify(
+            &cert(pem),
+            &[],
+            &ServerName::try_from(host).expect("name"),
+            port,
+            &[],
+            UnixTime::now(),
+        )
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("openclaw-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fingerprint_matches_openssl_spki_hash() {
+        assert_eq!(
+            spki_fingerprint(&cert(LEAF_PEM)).expect("fingerprint"),
+            "sha256/rz1jRxCAigYJ0F2TB7AtLqUvI+4b1qacuI91IKszRKw="
+        );
+    }
+
+    #[test]
+    fn extra_ca_and_pins_decide_trust() {
+        let trusted = TlsClient::new(&TlsOptions {
+            extra_ca_pem: vec![CA_PEM.to_string()],
+            ..TlsOptions::default()
+        })
+        .expect("client");
+        assert!(verify(&trusted, LEAF_PEM).is_ok());
+        assert!(verify(&trusted, OTHER_PEM).is_err());
+        assert!(trusted.take_rejection().is_none());
+
+        let pinned = TlsClient::new(&TlsOptions {
+            extra_ca_pem: vec![CA_PEM.to_string()],
+            spki_pins: vec!["rz1jRxCAigYJ0F2TB7AtLqUvI+4b1qacuI91IKszRKw=".to_string()],
+            ..TlsOptions::default()
+        })
+        .expect("client");
+        assert!(verify(&pinned, LEAF_PEM).is_ok());
+        assert!(verify_host(&pinned, LEAF_PEM, "example.com", 443).is_err());
+        assert!(pinned.take_rejection().is_none());
+
+        let other_pin = spki_fingerprint(&cert(OTHER_PEM)).expect("other");
+        let pinned_other = TlsClient::new(&TlsOptions {
+            extra_ca_pem: vec![CA_PEM.to_string()],
+            spki_pins: vec![other_pin.clone()],
+            ..TlsOptions::default()
+        })
+        .expect("client");
+        assert!(verify(&pinned_other, LEAF_PEM).is_err());
+        assert!(matches!(
+            pinned_other.take_rejection(),
+            Some(CertificateRejection::NotPinned { host, .. }) if host == "localhost"
+        ));
+        assert!(verify(&pinned_other, OTHER_PEM).is_err());
+        assert!(pinned_other.take_rejection().is_none());
+
+        assert!(TlsClient::new(&TlsOptions {
+            spki_pins: vec!["sha256/short".to_string()],
+            ..TlsOptions::default()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn self_signed_needs_opt_in_and_still_checks_the_hostname() {
+        let pin = spki_fingerprint(&cert(OTHER_PEM)).expect("other");
+        let strict = TlsClient::new(&TlsOptions {
+            spki_pins: vec![pin.clone()],
+            ..TlsOptions::default()
+        })
+        .expect("client");
+        assert!(verify(&strict, OTHER_PEM).is_err());
+
+        let self_signed = TlsClient::new(&TlsOptions {
+            spki_pins: vec![pin],
+            allow_self_signed: true,
+            ..TlsOptions::default()
+        })
+        .expect("client");
+        assert!(verify(&self_signed, OTHER_PEM).is_ok());
+        assert!(verify_host(&self_signed, OTHER_PEM, "example.com", 443).is_err());
+        assert!(verify(&self_signed, LEAF_PEM).is_err());
+
+        assert!(TlsClient::new(&TlsOptions {
+            allow_self_signed: true,
+            ..TlsOptions::default()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn trust_on_first_use_requires_a_known_hosts_dir() {
+        let error = TlsClient::new(&TlsOptions {
+            trust_on_first_use: true,
+            ..TlsOptions::default()
+        })
+        .expect_err("memory-only first use");
+        assert!(error.to_string().contains("known_hosts_dir"), "{error}");
+    }
+
+    #[test]
+    fn trust_on_first_use_records_and_detects_key_changes() {
+        let dir = temp_dir("tofu");
+        let options = TlsOptions {
+            extra_ca_pem: vec![CA_PEM.to_string()],
+            trust_on_first_use: true,
+            known_hosts_dir: Some(dir.to_string_lossy().into_owned()),
+            allow_self_signed: true,
+            ..TlsOptions::default()
+        };
+
+        let client = TlsClient::new(&options).expect("client");
+        assert!(verify(&client, OTHER_PEM).is_ok());
+        let reopened = TlsClient::new(&options).expect("client");
+        assert!(verify(&reopened, OTHER_PEM).is_ok());
+        assert!(verify_host(&reopened, LEAF_PEM, "localhost", 8443).is_ok());
+        assert!(verify(&reopened, LEAF_PEM).is_err());
+        match reopened.take_rejection() {
+            Some(CertificateRejection::Changed {
+                expected, received, ..
+            }) => {
+                assert_eq!(expected, spki_fingerprint(&cert(OTHER_PEM)).expect("other"));
+                assert_eq!(received, spki_fingerprint(&cert(LEAF_PEM)).expect("leaf"));
+            }
+            other => panic!("expected a changed key, got {other:?}"),
+        }
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn client_identity_accepts_pem_or_pkcs8_and_types_bad_input() {
+        for options in [
+            TlsOptions {
+                client_cert_chain_pem: Some(CLIENT_PEM.to_string()),
+                client_key_pem: Some(CLIENT_KEY_PEM.to_string()),
+                ..TlsOptions::default()
+            },
+            TlsOptions {
+                client_cert_chain_pem: Some(CLIENT_PEM.to_string()),
+                client_key_pkcs8: Some(CLIENT_KEY_DER.to_vec()),
+                ..TlsOptions::default()
+            },
+        ] {
+            let client = TlsClient::new(&options).expect("client");
+            assert!(client.config.client_auth_cert_resolver.has_certs());
+        }
+
+        for options in [
+            TlsOptions {
+                client_cert_chain_pem: Some(CLIENT_PEM.to_string()),
+                ..TlsOptions::default()
+            },
+            TlsOptions {
+                client_cert_chain_pem: Some(CLIENT_PEM.to_string()),
+                client_key_pem: Some("not a key".to_string()),
+                ..TlsOptions::default()
+            },
+        ] {
+            let error = TlsClient::new(&options).expect_err("invalid");
+            assert!(
+                matches!(
+                    error.downcast_ref::<ClientCertificateError>(),
+                    Some(ClientCertificateError::Invalid { .. })
+                ),
+                "{error}"
+            );
+        }
+    }
+}
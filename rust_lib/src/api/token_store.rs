@@ -0,0 +1,195 @@
+use crate::api::device_identity::write_private_file;
+use anyhow::Result;
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures_util::future::BoxFuture;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+const TOKEN_FILE_NAME: &str = "device-tokens.json";
+
+#[frb(ignore)]
+pub trait DeviceTokenStore: Send + Sync {
+    #[frb(ignore)]
+    fn load(&self, key: String) -> BoxFuture<'static, Result<Option<String>>>;
+    #[frb(ignore)]
+    fn save(&self, key: String, token: String) -> BoxFuture<'static, Result<()>>;
+    #[frb(ignore)]
+    fn clear(&self, key: String) -> BoxFuture<'static, Result<()>>;
+}
+
+type SharedTokenStore = Arc<dyn DeviceTokenStore>;
+
+static DEVICE_TOKEN_STORE: OnceLock<Mutex<Option<SharedTokenStore>>> = OnceLock::new();
+
+fn device_token_store_slot() -> &'static Mutex<Option<SharedTokenStore>> {
+    DEVICE_TOKEN_STORE.get_or_init(|| Mutex::new(None))
+}
+
+pub(crate) fn registered_device_token_store() -> Option<SharedTokenStore> {
+    device_token_store_slot()
+        .lock()
+        .ok()
+        .and_then(|guard| guard.clone())
+}
+
+pub fn register_device_token_store(store: CallbackTokenStore) {
+    if let Ok(mut guard) = device_token_store_slot().lock() {
+        *guard = Some(Arc::new(DartTokenStore(store)));
+    }
+}
+
+pub fn clear_device_token_store() {
+    if let Ok(mut guard) = device_token_store_slot().lock() {
+        *guard = None;
+    }
+}
+
+pub(crate) fn device_token_key(url: &str, role: &str) -> String {
+    format!("{}|{role}", url.trim_end_matches('/'))
+}
+
+#[frb(ignore)]
+pub(crate) struct FileTokenStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileTokenStore {
+    pub(crate) fn in_directory(directory: &Path) -> Self {
+        Self {
+            path: directory.join(TOKEN_FILE_NAME),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn read_tokens(&self) -> Result<BTreeMap<String, String>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        Ok(serde_json::from_slice(&fs::read(&self.path)?)?)
+    }
+
+    fn update_tokens(&self, update: impl FnOnce(&mut BTreeMap<String, String>)) -> Result<()> {
+        let _guard = self
+            .lock
+            .lock()
+            .map_err(|_| anyhow::anyhow!("token store poisoned"))?;
+        let mut tokens = self.read_tokens()?;
+        update(&mut tokens);
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_private_file(&self.path, &serde_json::to_vec_pretty(&tokens)?)
+    }
+}
+
+impl DeviceTokenStore for FileTokenStore {
+    fn load(&self, key: String) -> BoxFuture<'static, Result<Option<String>>> {
+        let result = self.read_tokens().map(|mut tokens| tokens.remove(&key));
+        Box::pin(async move { result })
+    }
+
+    fn save(&self, key: String, token: String) -> BoxFuture<'static, Result<()>> {
+        let result = self.update_tokens(|tokens| {
+            tokens.insert(key, token);
+        });
+        Box::pin(async move { result })
+    }
+
+    fn clear(&self, key: String) -> BoxFuture<'static, Result<()>> {
+        let result = self.update_tokens(|tokens| {
+            tokens.remove(&key);
+        });
+        Box::pin(async move { result })
+    }
+}
+
+type LoadTokenFn = dyn Fn(String) -> DartFnFuture<Option<String>> + Send + Sync;
+type SaveTokenFn = dyn Fn(String, String) -> DartFnFuture<()> + Send + Sync;
+type ClearTokenFn = dyn Fn(String) -> DartFnFuture<()> + Send + Sync;
+
+pub struct CallbackTokenStore {
+    load: Arc<LoadTokenFn>,
+    save: Arc<SaveTokenFn>,
+    clear: Arc<ClearTokenFn>,
+}
+
+impl CallbackTokenStore {
+    pub fn new(
+        load: impl Fn(String) -> DartFnFuture<Option<String>> + Send + Sync + 'static,
+        save: impl Fn(String, String) -> DartFnFuture<()> + Send + Sync + 'static,
+        clear: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            load: Arc::new(load),
+            save: Arc::new(save),
+            clear: Arc::new(clear),
+        }
+    }
+}
+
+/// Runs the callbacks of a registered `CallbackTokenStore`. A separate type so the
+/// bridge does not export `DeviceTokenStore` through it.
+#[frb(ignore)]
+struct DartTokenStore(CallbackTokenStore);
+
+impl DeviceTokenStore for DartTokenStore {
+    fn load(&self, key: String) -> BoxFuture<'static, Result<Option<String>>> {
+        let future = (self.0.load)(key);
+        Box::pin(async move { Ok(future.await) })
+    }
+
+    fn save(&self, key: String, token: String) -> BoxFuture<'static, Result<()>> {
+        let future = (self.0.save)(key, token);
+        Box::pin(async move {
+            future.await;
+            Ok(())
+        })
+    }
+
+    fn clear(&self, key: String) -> BoxFuture<'static, Result<()>> {
+        let future = (self.0.clear)(key);
+        Box::pin(async move {
+            future.await;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn file_store_round_trips_tokens() {
+        let dir = std::env::temp_dir().join(format!("openclaw-tokens-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let store = FileTokenStore::in_directory(&dir);
+        let key = device_token_key("wss://gateway.local/", "operator");
+
+        assert_eq!(store.load(key.clone()).await.expect("load"), None);
+        store
+            .save(key.clone(), "token-1".to_string())
+            .await
+            .expect("save");
+        let reopened = FileTokenStore::in_directory(&dir);
+        assert_eq!(
+            reopened.load(key.clone()).await.expect("load"),
+            Some("token-1".to_string())
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(dir.join(TOKEN_FILE_NAME))
+                .expect("token file")
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        reopened.clear(key.clone()).await.expect("clear");
+        assert_eq!(store.load(key).await.expect("load"), None);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
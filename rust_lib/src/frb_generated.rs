@@ -25,23 +25,27 @@
 
 // Section: imports
 
+use crate::api::device_identity::*;
 use crate::api::events::*;
+use crate::api::terminal_emulator::*;
+use crate::api::token_store::*;
 use crate::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
-use serde_json::Value;
-use std::collections::BTreeMap;
 
 // Section: boilerplate
 
+use serde_json::Value;
+use std::collections::BTreeMap;
+
 flutter_rust_bridge::frb_generated_boilerplate!(
     default_stream_sink_codec = SseCodec,
     default_rust_opaque = RustOpaqueMoi,
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 109217522;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 92992336;
 
 // Section: executor
 
@@ -534,6 +538,50 @@ fn wire__crate__api__events__AgentTurn_auto_accessor_set_timeout_seconds_impl(
         },
     )
 }
+fn wire__crate__api__token_store__CallbackTokenStore_new_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CallbackTokenStore_new",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_load = decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            let api_save = decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            let api_clear = decode_DartFn_Inputs_String_Output_unit_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::token_store::CallbackTokenStore::new(
+                            api_load, api_save, api_clear,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__events__CameraSnapshot_auto_accessor_get_compression_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1601,6 +1649,39 @@ fn wire__crate__api__events__ConnectAuth_auto_accessor_set_token_impl(
         },
     )
 }
+fn wire__crate__api__events__ConnectAuth_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ConnectAuth_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::events::ConnectAuth::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__events__ConnectChallenge_auto_accessor_get_extra_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2765,6 +2846,39 @@ fn wire__crate__api__events__ConnectClient_auto_accessor_set_version_impl(
         },
     )
 }
+fn wire__crate__api__events__ConnectClient_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ConnectClient_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::events::ConnectClient::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__events__ConnectDevice_auto_accessor_get_extra_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3347,6 +3461,39 @@ fn wire__crate__api__events__ConnectDevice_auto_accessor_set_signed_at_impl(
         },
     )
 }
+fn wire__crate__api__events__ConnectDevice_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ConnectDevice_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::events::ConnectDevice::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__events__ConnectParams_auto_accessor_get_auth_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3623,6 +3770,52 @@ fn wire__crate__api__events__ConnectParams_auto_accessor_get_extra_impl(
         },
     )
 }
+fn wire__crate__api__events__ConnectParams_auto_accessor_get_last_seq_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ConnectParams_auto_accessor_get_last_seq",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConnectParams>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(api_that_guard.last_seq.clone())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__events__ConnectParams_auto_accessor_get_locale_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4251,6 +4444,57 @@ fn wire__crate__api__events__ConnectParams_auto_accessor_set_extra_impl(
         },
     )
 }
+fn wire__crate__api__events__ConnectParams_auto_accessor_set_last_seq_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ConnectParams_auto_accessor_set_last_seq",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConnectParams>,
+            >>::sse_decode(&mut deserializer);
+            let api_last_seq = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    {
+                        api_that_guard.last_seq = api_last_seq;
+                    };
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__events__ConnectParams_auto_accessor_set_locale_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4608,14 +4852,47 @@ fn wire__crate__api__events__ConnectParams_auto_accessor_set_user_agent_impl(
         },
     )
 }
-fn wire__crate__api__events__ExecOutput_auto_accessor_get_data_impl(
+fn wire__crate__api__events__ConnectParams_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ConnectParams_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::events::ConnectParams::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__device_identity__DeviceIdentity_device_id_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ExecOutput_auto_accessor_get_data",
+            debug_name: "DeviceIdentity_device_id",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4630,7 +4907,7 @@ fn wire__crate__api__events__ExecOutput_auto_accessor_get_data_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ExecOutput>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -4648,20 +4925,22 @@ fn wire__crate__api__events__ExecOutput_auto_accessor_get_data_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.data.clone())?;
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::device_identity::DeviceIdentity::device_id(&*api_that_guard),
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__events__ExecOutput_auto_accessor_get_encoding_impl(
+fn wire__crate__api__device_identity__DeviceIdentity_public_key_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ExecOutput_auto_accessor_get_encoding",
+            debug_name: "DeviceIdentity_public_key",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4676,7 +4955,7 @@ fn wire__crate__api__events__ExecOutput_auto_accessor_get_encoding_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ExecOutput>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -4694,8 +4973,102 @@ fn wire__crate__api__events__ExecOutput_auto_accessor_get_encoding_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(api_that_guard.encoding.clone())?;
-                Ok(output_ok)
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::device_identity::DeviceIdentity::public_key(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__events__ExecOutput_auto_accessor_get_data_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ExecOutput_auto_accessor_get_data",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ExecOutput>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(api_that_guard.data.clone())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__events__ExecOutput_auto_accessor_get_encoding_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ExecOutput_auto_accessor_get_encoding",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ExecOutput>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(api_that_guard.encoding.clone())?;
+                Ok(output_ok)
             })())
         },
     )
@@ -6969,6 +7342,100 @@ fn wire__crate__api__events__GatewayError_default_impl(
         },
     )
 }
+fn wire__crate__api__events__GatewayEvent_kind_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "GatewayEvent_kind",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayEvent>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::events::GatewayEvent::kind(&*api_that_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__events__GatewayEvent_text_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "GatewayEvent_text",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayEvent>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::events::GatewayEvent::text(&*api_that_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_frame_type_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7506,6 +7973,58 @@ fn wire__crate__api__events__GatewayRequestFrame_to_json_impl(
         },
     )
 }
+fn wire__crate__api__events__GatewayResponsePayload_to_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "GatewayResponsePayload_to_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::events::GatewayResponsePayload::to_json(&*api_that_guard)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__events__HelloAuth_auto_accessor_get_device_token_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7894,16 +8413,17 @@ fn wire__crate__api__events__HelloAuth_auto_accessor_set_scopes_impl(
         },
     )
 }
-fn wire__crate__api__events__HelloOk_auto_accessor_get_auth_impl(
+fn wire__crate__api__events__HelloAuth_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "HelloOk_auto_accessor_get_auth",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "HelloAuth_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -7915,23 +8435,54 @@ fn wire__crate__api__events__HelloOk_auto_accessor_get_auth_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HelloOk>,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::events::HelloAuth::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__events__HelloOk_auto_accessor_get_auth_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "HelloOk_auto_accessor_get_auth",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HelloOk>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok(api_that_guard.auth.clone())?;
@@ -8471,6 +9022,52 @@ fn wire__crate__api__events__HelloPolicy_auto_accessor_get_tick_interval_ms_impl
         },
     )
 }
+fn wire__crate__api__events__HelloPolicy_auto_accessor_get_tick_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "HelloPolicy_auto_accessor_get_tick_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HelloPolicy>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(api_that_guard.tick_mode.clone())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__events__HelloPolicy_auto_accessor_set_extra_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -8573,6 +9170,90 @@ fn wire__crate__api__events__HelloPolicy_auto_accessor_set_tick_interval_ms_impl
         },
     )
 }
+fn wire__crate__api__events__HelloPolicy_auto_accessor_set_tick_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "HelloPolicy_auto_accessor_set_tick_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HelloPolicy>,
+            >>::sse_decode(&mut deserializer);
+            let api_tick_mode = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    {
+                        api_that_guard.tick_mode = api_tick_mode;
+                    };
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__events__HelloPolicy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "HelloPolicy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::events::HelloPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__events__LogEntry_auto_accessor_get_extra_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -18960,33 +19641,16 @@ fn wire__crate__api__events__SystemProbeResult_default_impl(
         },
     )
 }
-fn wire__crate__api__connection__connect_to_gateway_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "connect_to_gateway", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
-let api_sink = <StreamSink<GatewayEvent,flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
-                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
-                         let output_ok = crate::api::connection::connect_to_gateway(api_url, api_sink).await?;   Ok(output_ok)
-                    })().await)
-                } })
-}
-fn wire__crate__api__terminal__exec_command_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__terminal_emulator__TerminalEmulator_feed_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "exec_command",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "TerminalEmulator_feed",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -18998,47 +19662,46 @@ fn wire__crate__api__terminal__exec_command_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_command = <String>::sse_decode(&mut deserializer);
-            let api_cwd = <Option<String>>::sse_decode(&mut deserializer);
-            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::terminal::TerminalChunk,
-                flutter_rust_bridge::for_generated::SseCodec,
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>,
             >>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::terminal::exec_command(
-                            api_url,
-                            api_command,
-                            api_cwd,
-                            api_timeout_ms,
-                            api_session_key,
-                            api_sink,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::terminal_emulator::TerminalEmulator::feed(
+                        &mut *api_that_guard,
+                        api_bytes,
+                    ))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_agent_turn_request_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__terminal_emulator__TerminalEmulator_new_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_agent_turn_request",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "TerminalEmulator_new",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -19050,24 +19713,776 @@ fn wire__crate__api__connection__gateway_client_agent_turn_request_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_model = <Option<String>>::sse_decode(&mut deserializer);
-            let api_thinking = <Option<String>>::sse_decode(&mut deserializer);
-            let api_timeout_seconds = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_cols = <u32>::sse_decode(&mut deserializer);
+            let api_rows = <u32>::sse_decode(&mut deserializer);
+            let api_scrollback_limit = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::terminal_emulator::TerminalEmulator::new(
+                        api_cols,
+                        api_rows,
+                        api_scrollback_limit,
+                    ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__terminal_emulator__TerminalEmulator_resize_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "TerminalEmulator_resize",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>,
+            >>::sse_decode(&mut deserializer);
+            let api_cols = <u32>::sse_decode(&mut deserializer);
+            let api_rows = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::terminal_emulator::TerminalEmulator::resize(
+                        &mut *api_that_guard,
+                        api_cols,
+                        api_rows,
+                    ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__terminal_emulator__TerminalEmulator_resize_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "TerminalEmulator_resize_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>,
+            >>::sse_decode(&mut deserializer);
+            let api_client = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_cols = <u32>::sse_decode(&mut deserializer);
+            let api_rows = <u32>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::terminal_emulator::TerminalEmulator::resize_request(
+                                &mut *api_that_guard,
+                                &api_client,
+                                api_request_id,
+                                api_stream_id,
+                                api_cols,
+                                api_rows,
+                                api_session_key,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__terminal_emulator__TerminalEmulator_scrollback_lines_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "TerminalEmulator_scrollback_lines",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>,
+            >>::sse_decode(&mut deserializer);
+            let api_start = <u32>::sse_decode(&mut deserializer);
+            let api_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::terminal_emulator::TerminalEmulator::scrollback_lines(
+                        &*api_that_guard,
+                        api_start,
+                        api_count,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__terminal_emulator__TerminalEmulator_snapshot_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "TerminalEmulator_snapshot",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::terminal_emulator::TerminalEmulator::snapshot(&mut *api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__backpressure__backpressure_stats_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "backpressure_stats_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::backpressure::BackpressureStats::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__token_store__clear_device_token_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_device_token_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::token_store::clear_device_token_store();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__deflate__compression_stats_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "compression_stats_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::deflate::CompressionStats::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__connect_to_gateway_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "connect_to_gateway", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+let api_url = <String>::sse_decode(&mut deserializer);
+let api_options = <crate::api::connection::HandshakeOptions>::sse_decode(&mut deserializer);
+let api_config = <Option<crate::api::connection::ConnectionConfig>>::sse_decode(&mut deserializer);
+let api_sink = <StreamSink<GatewayEvent,flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::connection::connect_to_gateway(api_handle, api_url, api_options, api_config, api_sink).await?;   Ok(output_ok)
+                    })().await)
+                } })
+}
+fn wire__crate__api__connection__connection_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "connection_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::connection::ConnectionConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__deflate__deflate_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "deflate_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::deflate::DeflateConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__disconnect_gateway_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "disconnect_gateway",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_reason = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::connection::disconnect_gateway(api_handle, api_reason)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__terminal__exec_command_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "exec_command",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_command = <String>::sse_decode(&mut deserializer);
+            let api_cwd = <Option<String>>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_raw_output = <bool>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::terminal::TerminalChunk,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::terminal::exec_command(
+                            api_handle,
+                            api_command,
+                            api_cwd,
+                            api_timeout_ms,
+                            api_session_key,
+                            api_raw_output,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_backpressure_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_backpressure_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::connection::gateway_backpressure_stats(api_handle)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_agent_turn_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_agent_turn_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_model = <Option<String>>::sse_decode(&mut deserializer);
+            let api_thinking = <Option<String>>::sse_decode(&mut deserializer);
+            let api_timeout_seconds = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
                         let output_ok = crate::api::connection::GatewayClient::agent_turn_request(
                             &api_that,
                             api_request_id,
-                            api_message,
-                            api_model,
-                            api_thinking,
-                            api_timeout_seconds,
+                            api_message,
+                            api_model,
+                            api_thinking,
+                            api_timeout_seconds,
+                            api_session_key,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_camera_snap_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_camera_snap_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_frame_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_format = <Option<String>>::sse_decode(&mut deserializer);
+            let api_width = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_height = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_timestamp_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_compress = <bool>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::connection::GatewayClient::camera_snap_request(
+                            &api_that,
+                            api_request_id,
+                            api_frame_bytes,
+                            api_format,
+                            api_width,
+                            api_height,
+                            api_timestamp_ms,
+                            api_compress,
+                            api_session_key,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_exec_command_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_exec_command_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_command = <String>::sse_decode(&mut deserializer);
+            let api_args = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_stdin_bytes = <Option<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_cwd = <Option<String>>::sse_decode(&mut deserializer);
+            let api_env_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_exec_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_kill_on_drop = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_pty = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_cols = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_rows = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_term = <Option<String>>::sse_decode(&mut deserializer);
+            let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::connection::GatewayClient::exec_command_request(
+                                &api_that,
+                                api_request_id,
+                                api_command,
+                                api_args,
+                                api_stdin_bytes,
+                                api_cwd,
+                                api_env_json,
+                                api_timeout_ms,
+                                api_exec_id,
+                                api_kill_on_drop,
+                                api_pty,
+                                api_cols,
+                                api_rows,
+                                api_term,
+                                api_permissions_json,
+                                api_session_key,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_exec_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_exec_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_command = <String>::sse_decode(&mut deserializer);
+            let api_args = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_cwd = <Option<String>>::sse_decode(&mut deserializer);
+            let api_env_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_stdin = <Option<String>>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_stream = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_exec_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_kill_on_drop = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::connection::GatewayClient::exec_request(
+                            &api_that,
+                            api_request_id,
+                            api_command,
+                            api_args,
+                            api_cwd,
+                            api_env_json,
+                            api_stdin,
+                            api_timeout_ms,
+                            api_stream,
+                            api_exec_id,
+                            api_kill_on_drop,
                             api_session_key,
                         )?;
                         Ok(output_ok)
@@ -19077,7 +20492,159 @@ fn wire__crate__api__connection__gateway_client_agent_turn_request_impl(
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_camera_snap_request_impl(
+fn wire__crate__api__connection__gateway_client_get_url_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_get_url",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::connection::GatewayClient::get_url(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_interactive_shell_close_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_interactive_shell_close_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::connection::GatewayClient::interactive_shell_close_request(
+                                &api_that,
+                                api_request_id,
+                                api_stream_id,
+                                api_reason,
+                                api_permissions_json,
+                                api_session_key,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_interactive_shell_open_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_interactive_shell_open_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_cols = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_rows = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_term = <Option<String>>::sse_decode(&mut deserializer);
+            let api_metadata_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::connection::GatewayClient::interactive_shell_open_request(
+                                &api_that,
+                                api_request_id,
+                                api_cols,
+                                api_rows,
+                                api_term,
+                                api_metadata_json,
+                                api_permissions_json,
+                                api_session_key,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_interactive_shell_resize_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "gateway_client_interactive_shell_resize_request", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+let api_request_id = <String>::sse_decode(&mut deserializer);
+let api_stream_id = <Option<String>>::sse_decode(&mut deserializer);
+let api_cols = <u32>::sse_decode(&mut deserializer);
+let api_rows = <u32>::sse_decode(&mut deserializer);
+let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
+let api_session_key = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::connection::GatewayClient::interactive_shell_resize_request(&api_that, api_request_id, api_stream_id, api_cols, api_rows, api_permissions_json, api_session_key)?;   Ok(output_ok)
+                    })())
+                } })
+}
+fn wire__crate__api__connection__gateway_client_interactive_shell_send_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19085,7 +20652,7 @@ fn wire__crate__api__connection__gateway_client_camera_snap_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_camera_snap_request",
+            debug_name: "gateway_client_interactive_shell_send_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19101,28 +20668,121 @@ fn wire__crate__api__connection__gateway_client_camera_snap_request_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
             let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_frame_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_format = <Option<String>>::sse_decode(&mut deserializer);
-            let api_width = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_height = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_timestamp_ms = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_compress = <bool>::sse_decode(&mut deserializer);
+            let api_stream_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_channel = <Option<String>>::sse_decode(&mut deserializer);
+            let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
             let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::connection::GatewayClient::camera_snap_request(
-                            &api_that,
-                            api_request_id,
-                            api_frame_bytes,
-                            api_format,
-                            api_width,
-                            api_height,
-                            api_timestamp_ms,
-                            api_compress,
-                            api_session_key,
-                        )?;
+                        let output_ok =
+                            crate::api::connection::GatewayClient::interactive_shell_send_request(
+                                &api_that,
+                                api_request_id,
+                                api_stream_id,
+                                api_input_bytes,
+                                api_channel,
+                                api_permissions_json,
+                                api_session_key,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_logs_subscribe_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_logs_subscribe_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_level = <Option<String>>::sse_decode(&mut deserializer);
+            let api_tail = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_since = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_include_internal = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_filters_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::connection::GatewayClient::logs_subscribe_request(
+                                &api_that,
+                                api_request_id,
+                                api_level,
+                                api_tail,
+                                api_since,
+                                api_include_internal,
+                                api_filters_json,
+                                api_session_key,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_logs_unsubscribe_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_logs_unsubscribe_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_subscription_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::connection::GatewayClient::logs_unsubscribe_request(
+                                &api_that,
+                                api_request_id,
+                                api_subscription_id,
+                                api_session_key,
+                            )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -19130,7 +20790,73 @@ fn wire__crate__api__connection__gateway_client_camera_snap_request_impl(
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_exec_command_request_impl(
+fn wire__crate__api__connection__gateway_client_new_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_new",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::connection::GatewayClient::new(api_url))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_next_request_id_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gateway_client_next_request_id",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::connection::GatewayClient::next_request_id(&api_that),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__connection__gateway_client_sessions_close_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19138,7 +20864,7 @@ fn wire__crate__api__connection__gateway_client_exec_command_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_exec_command_request",
+            debug_name: "gateway_client_sessions_close_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19154,42 +20880,20 @@ fn wire__crate__api__connection__gateway_client_exec_command_request_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
             let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_command = <String>::sse_decode(&mut deserializer);
-            let api_args = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_stdin_bytes = <Option<Vec<u8>>>::sse_decode(&mut deserializer);
-            let api_cwd = <Option<String>>::sse_decode(&mut deserializer);
-            let api_env_json = <Option<String>>::sse_decode(&mut deserializer);
-            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_exec_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_kill_on_drop = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_pty = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_cols = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_rows = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_term = <Option<String>>::sse_decode(&mut deserializer);
-            let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_key = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            let api_metadata_json = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
                         let output_ok =
-                            crate::api::connection::GatewayClient::exec_command_request(
+                            crate::api::connection::GatewayClient::sessions_close_request(
                                 &api_that,
                                 api_request_id,
-                                api_command,
-                                api_args,
-                                api_stdin_bytes,
-                                api_cwd,
-                                api_env_json,
-                                api_timeout_ms,
-                                api_exec_id,
-                                api_kill_on_drop,
-                                api_pty,
-                                api_cols,
-                                api_rows,
-                                api_term,
-                                api_permissions_json,
                                 api_session_key,
+                                api_reason,
+                                api_metadata_json,
                             )?;
                         Ok(output_ok)
                     })(),
@@ -19198,7 +20902,7 @@ fn wire__crate__api__connection__gateway_client_exec_command_request_impl(
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_exec_request_impl(
+fn wire__crate__api__connection__gateway_client_sessions_list_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19206,7 +20910,7 @@ fn wire__crate__api__connection__gateway_client_exec_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_exec_request",
+            debug_name: "gateway_client_sessions_list_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19222,34 +20926,21 @@ fn wire__crate__api__connection__gateway_client_exec_request_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
             let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_command = <String>::sse_decode(&mut deserializer);
-            let api_args = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_cwd = <Option<String>>::sse_decode(&mut deserializer);
-            let api_env_json = <Option<String>>::sse_decode(&mut deserializer);
-            let api_stdin = <Option<String>>::sse_decode(&mut deserializer);
-            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_stream = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_exec_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_kill_on_drop = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_cursor = <Option<String>>::sse_decode(&mut deserializer);
             let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::connection::GatewayClient::exec_request(
-                            &api_that,
-                            api_request_id,
-                            api_command,
-                            api_args,
-                            api_cwd,
-                            api_env_json,
-                            api_stdin,
-                            api_timeout_ms,
-                            api_stream,
-                            api_exec_id,
-                            api_kill_on_drop,
-                            api_session_key,
-                        )?;
+                        let output_ok =
+                            crate::api::connection::GatewayClient::sessions_list_request(
+                                &api_that,
+                                api_request_id,
+                                api_limit,
+                                api_cursor,
+                                api_session_key,
+                            )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -19257,16 +20948,17 @@ fn wire__crate__api__connection__gateway_client_exec_request_impl(
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_get_url_impl(
+fn wire__crate__api__connection__gateway_client_sessions_spawn_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_get_url",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "gateway_client_sessions_spawn_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -19279,16 +20971,32 @@ fn wire__crate__api__connection__gateway_client_get_url_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
+            let api_request_id = <String>::sse_decode(&mut deserializer);
+            let api_parent_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_label = <Option<String>>::sse_decode(&mut deserializer);
+            let api_metadata_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::connection::GatewayClient::get_url(&api_that))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::connection::GatewayClient::sessions_spawn_request(
+                                &api_that,
+                                api_request_id,
+                                api_parent_session_key,
+                                api_label,
+                                api_metadata_json,
+                                api_session_key,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_interactive_shell_close_request_impl(
+fn wire__crate__api__connection__gateway_client_system_event_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19296,7 +21004,7 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_close_request_
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_interactive_shell_close_request",
+            debug_name: "gateway_client_system_event_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19312,21 +21020,19 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_close_request_
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
             let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_stream_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
-            let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_text = <Option<String>>::sse_decode(&mut deserializer);
+            let api_mode = <Option<String>>::sse_decode(&mut deserializer);
             let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
                         let output_ok =
-                            crate::api::connection::GatewayClient::interactive_shell_close_request(
+                            crate::api::connection::GatewayClient::system_event_request(
                                 &api_that,
                                 api_request_id,
-                                api_stream_id,
-                                api_reason,
-                                api_permissions_json,
+                                api_text,
+                                api_mode,
                                 api_session_key,
                             )?;
                         Ok(output_ok)
@@ -19336,7 +21042,7 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_close_request_
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_interactive_shell_open_request_impl(
+fn wire__crate__api__connection__gateway_client_system_probe_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19344,7 +21050,7 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_open_request_i
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_interactive_shell_open_request",
+            debug_name: "gateway_client_system_probe_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19360,25 +21066,21 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_open_request_i
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
             let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_cols = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_rows = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_term = <Option<String>>::sse_decode(&mut deserializer);
-            let api_metadata_json = <Option<String>>::sse_decode(&mut deserializer);
-            let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_network = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_disk = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_gateway = <Option<bool>>::sse_decode(&mut deserializer);
             let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
                         let output_ok =
-                            crate::api::connection::GatewayClient::interactive_shell_open_request(
+                            crate::api::connection::GatewayClient::system_probe_request(
                                 &api_that,
                                 api_request_id,
-                                api_cols,
-                                api_rows,
-                                api_term,
-                                api_metadata_json,
-                                api_permissions_json,
+                                api_network,
+                                api_disk,
+                                api_gateway,
                                 api_session_key,
                             )?;
                         Ok(output_ok)
@@ -19388,28 +21090,7 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_open_request_i
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_interactive_shell_resize_request_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "gateway_client_interactive_shell_resize_request", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-let api_request_id = <String>::sse_decode(&mut deserializer);
-let api_stream_id = <Option<String>>::sse_decode(&mut deserializer);
-let api_cols = <u32>::sse_decode(&mut deserializer);
-let api_rows = <u32>::sse_decode(&mut deserializer);
-let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
-let api_session_key = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
-                         let output_ok = crate::api::connection::GatewayClient::interactive_shell_resize_request(&api_that, api_request_id, api_stream_id, api_cols, api_rows, api_permissions_json, api_session_key)?;   Ok(output_ok)
-                    })())
-                } })
-}
-fn wire__crate__api__connection__gateway_client_interactive_shell_send_request_impl(
+fn wire__crate__api__connection__gateway_compression_stats_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19417,7 +21098,7 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_send_request_i
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_interactive_shell_send_request",
+            debug_name: "gateway_compression_stats",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19431,27 +21112,13 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_send_request_i
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_stream_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_channel = <Option<String>>::sse_decode(&mut deserializer);
-            let api_permissions_json = <Option<String>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_handle = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
                         let output_ok =
-                            crate::api::connection::GatewayClient::interactive_shell_send_request(
-                                &api_that,
-                                api_request_id,
-                                api_stream_id,
-                                api_input_bytes,
-                                api_channel,
-                                api_permissions_json,
-                                api_session_key,
-                            )?;
+                            crate::api::connection::gateway_compression_stats(api_handle)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -19459,15 +21126,15 @@ fn wire__crate__api__connection__gateway_client_interactive_shell_send_request_i
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_logs_subscribe_request_impl(
+fn wire__crate__api__connection__gateway_request_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_logs_subscribe_request",
+            debug_name: "gateway_request",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19481,37 +21148,61 @@ fn wire__crate__api__connection__gateway_client_logs_subscribe_request_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_level = <Option<String>>::sse_decode(&mut deserializer);
-            let api_tail = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_since = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_include_internal = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_filters_json = <Option<String>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_frame_json = <String>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::connection::GatewayClient::logs_subscribe_request(
-                                &api_that,
-                                api_request_id,
-                                api_level,
-                                api_tail,
-                                api_since,
-                                api_include_internal,
-                                api_filters_json,
-                                api_session_key,
-                            )?;
+                    (move || async move {
+                        let output_ok = crate::api::connection::gateway_request(
+                            api_handle,
+                            api_frame_json,
+                            api_timeout_ms,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_logs_unsubscribe_request_impl(
+fn wire__crate__api__connection__handshake_options_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19519,7 +21210,7 @@ fn wire__crate__api__connection__gateway_client_logs_unsubscribe_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_logs_unsubscribe_request",
+            debug_name: "handshake_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19533,29 +21224,18 @@ fn wire__crate__api__connection__gateway_client_logs_unsubscribe_request_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_subscription_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::connection::GatewayClient::logs_unsubscribe_request(
-                                &api_that,
-                                api_request_id,
-                                api_subscription_id,
-                                api_session_key,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::connection::HandshakeOptions::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_new_impl(
+fn wire__crate__api__device_identity__load_device_identity_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19563,7 +21243,7 @@ fn wire__crate__api__connection__gateway_client_new_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_new",
+            debug_name: "load_device_identity",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19577,28 +21257,31 @@ fn wire__crate__api__connection__gateway_client_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_directory = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::connection::GatewayClient::new(api_url))?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::device_identity::load_device_identity(api_directory)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_next_request_id_impl(
+fn wire__crate__api__outbox__outbox_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_next_request_id",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "outbox_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -19610,18 +21293,18 @@ fn wire__crate__api__connection__gateway_client_next_request_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::connection::GatewayClient::next_request_id(&api_that),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::outbox::OutboxConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_sessions_close_request_impl(
+fn wire__crate__api__events__parse_gateway_frame_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19629,7 +21312,7 @@ fn wire__crate__api__connection__gateway_client_sessions_close_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_sessions_close_request",
+            debug_name: "parse_gateway_frame",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19643,31 +21326,19 @@ fn wire__crate__api__connection__gateway_client_sessions_close_request_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_session_key = <String>::sse_decode(&mut deserializer);
-            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
-            let api_metadata_json = <Option<String>>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::connection::GatewayClient::sessions_close_request(
-                                &api_that,
-                                api_request_id,
-                                api_session_key,
-                                api_reason,
-                                api_metadata_json,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::events::parse_gateway_frame(&api_text))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_sessions_list_request_impl(
+fn wire__crate__api__token_store__register_device_token_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19675,7 +21346,7 @@ fn wire__crate__api__connection__gateway_client_sessions_list_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_sessions_list_request",
+            debug_name: "register_device_token_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19689,31 +21360,20 @@ fn wire__crate__api__connection__gateway_client_sessions_list_request_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_cursor = <Option<String>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_store = <CallbackTokenStore>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::connection::GatewayClient::sessions_list_request(
-                                &api_that,
-                                api_request_id,
-                                api_limit,
-                                api_cursor,
-                                api_session_key,
-                            )?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::token_store::register_device_token_store(api_store);
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_sessions_spawn_request_impl(
+fn wire__crate__api__connection__send_gateway_request_frame_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19721,7 +21381,7 @@ fn wire__crate__api__connection__gateway_client_sessions_spawn_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_sessions_spawn_request",
+            debug_name: "send_gateway_request_frame",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19735,25 +21395,16 @@ fn wire__crate__api__connection__gateway_client_sessions_spawn_request_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_parent_session_key = <Option<String>>::sse_decode(&mut deserializer);
-            let api_label = <Option<String>>::sse_decode(&mut deserializer);
-            let api_metadata_json = <Option<String>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_frame_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::connection::GatewayClient::sessions_spawn_request(
-                                &api_that,
-                                api_request_id,
-                                api_parent_session_key,
-                                api_label,
-                                api_metadata_json,
-                                api_session_key,
-                            )?;
+                        let output_ok = crate::api::connection::send_gateway_request_frame(
+                            api_handle,
+                            api_frame_json,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -19761,7 +21412,7 @@ fn wire__crate__api__connection__gateway_client_sessions_spawn_request_impl(
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_system_event_request_impl(
+fn wire__crate__api__connection__set_gateway_events_paused_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19769,7 +21420,7 @@ fn wire__crate__api__connection__gateway_client_system_event_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_system_event_request",
+            debug_name: "set_gateway_events_paused",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19783,23 +21434,15 @@ fn wire__crate__api__connection__gateway_client_system_event_request_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_text = <Option<String>>::sse_decode(&mut deserializer);
-            let api_mode = <Option<String>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_paused = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::connection::GatewayClient::system_event_request(
-                                &api_that,
-                                api_request_id,
-                                api_text,
-                                api_mode,
-                                api_session_key,
-                            )?;
+                        let output_ok = crate::api::connection::set_gateway_events_paused(
+                            api_handle, api_paused,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -19807,15 +21450,15 @@ fn wire__crate__api__connection__gateway_client_system_event_request_impl(
         },
     )
 }
-fn wire__crate__api__connection__gateway_client_system_probe_request_impl(
+fn wire__crate__api__terminal__terminal_stream_attach_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gateway_client_system_probe_request",
+            debug_name: "terminal_stream_attach",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19829,41 +21472,41 @@ fn wire__crate__api__connection__gateway_client_system_probe_request_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::connection::GatewayClient>::sse_decode(&mut deserializer);
-            let api_request_id = <String>::sse_decode(&mut deserializer);
-            let api_network = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_disk = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_gateway = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <String>::sse_decode(&mut deserializer);
+            let api_raw_output = <bool>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::terminal::TerminalChunk,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::connection::GatewayClient::system_probe_request(
-                                &api_that,
-                                api_request_id,
-                                api_network,
-                                api_disk,
-                                api_gateway,
-                                api_session_key,
-                            )?;
+                    (move || async move {
+                        let output_ok = crate::api::terminal::terminal_stream_attach(
+                            api_handle,
+                            api_stream_id,
+                            api_raw_output,
+                            api_sink,
+                        )
+                        .await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__terminal__terminal_stream_open_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
+            debug_name: "terminal_stream_open",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19877,26 +21520,40 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_cols = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_rows = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_term = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::terminal::terminal_stream_open(
+                            api_handle,
+                            api_cols,
+                            api_rows,
+                            api_term,
+                            api_session_key,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__events__parse_gateway_frame_impl(
+fn wire__crate__api__terminal__terminal_stream_send_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_gateway_frame",
+            debug_name: "terminal_stream_send",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19910,19 +21567,30 @@ fn wire__crate__api__events__parse_gateway_frame_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_stream_id = <String>::sse_decode(&mut deserializer);
+            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::events::parse_gateway_frame(&api_text))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::terminal::terminal_stream_send(
+                            api_handle,
+                            api_stream_id,
+                            api_input_bytes,
+                            api_session_key,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__connection__send_gateway_request_frame_impl(
+fn wire__crate__api__terminal_emulator__terminal_style_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -19930,7 +21598,7 @@ fn wire__crate__api__connection__send_gateway_request_frame_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_gateway_request_frame",
+            debug_name: "terminal_style_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19944,29 +21612,27 @@ fn wire__crate__api__connection__send_gateway_request_frame_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_frame_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::connection::send_gateway_request_frame(api_frame_json)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::terminal_emulator::TerminalStyle::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__terminal__terminal_stream_open_impl(
+fn wire__crate__api__tls__tls_options_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "terminal_stream_open",
+            debug_name: "tls_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -19980,32 +21646,17 @@ fn wire__crate__api__terminal__terminal_stream_open_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_cols = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_rows = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_term = <Option<String>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::terminal::terminal_stream_open(
-                            api_url,
-                            api_cols,
-                            api_rows,
-                            api_term,
-                            api_session_key,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::tls::TlsOptions::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__terminal__terminal_stream_send_impl(
+fn wire__crate__api__connection__watch_connection_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -20013,7 +21664,7 @@ fn wire__crate__api__terminal__terminal_stream_send_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "terminal_stream_send",
+            debug_name: "watch_connection_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -20027,21 +21678,18 @@ fn wire__crate__api__terminal__terminal_stream_send_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_stream_id = <String>::sse_decode(&mut deserializer);
-            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_session_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_handle = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::connection::ConnectionState,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::terminal::terminal_stream_send(
-                            api_url,
-                            api_stream_id,
-                            api_input_bytes,
-                            api_session_key,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::connection::watch_connection_state(api_handle, api_sink)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -20053,6 +21701,106 @@ fn wire__crate__api__terminal__terminal_stream_send_impl(
 
 // Section: related_funcs
 
+fn decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String) -> flutter_rust_bridge::DartFnFuture<Option<String>> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: String) -> Option<String> {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<Option<String>>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: String) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String, String) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: String, arg1: String) -> () {
+        let args = vec![
+            arg0.into_into_dart().into_dart(),
+            arg1.into_into_dart().into_dart(),
+        ];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String, arg1: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+            arg1,
+        ))
+    }
+}
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AgentTurn>
 );
@@ -20062,6 +21810,9 @@ flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BTreeMap<String, bool>>
 );
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>
+);
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CameraSnapshot>
 );
@@ -20080,6 +21831,9 @@ flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConnectParams>
 );
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>
+);
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ExecOutput>
 );
@@ -20101,6 +21855,9 @@ flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayRequestParams>
 );
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>
+);
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HelloAuth>
 );
@@ -20170,6 +21927,9 @@ flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SystemProbeResult>
 );
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>
+);
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Value>
 );
@@ -20214,6 +21974,16 @@ impl SseDecode for BTreeMap<String, bool> {
     }
 }
 
+impl SseDecode for CallbackTokenStore {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
 impl SseDecode for CameraSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -20274,6 +22044,16 @@ impl SseDecode for ConnectParams {
     }
 }
 
+impl SseDecode for DeviceIdentity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
 impl SseDecode for ExecOutput {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -20344,6 +22124,16 @@ impl SseDecode for GatewayRequestParams {
     }
 }
 
+impl SseDecode for GatewayResponsePayload {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
 impl SseDecode for HelloAuth {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -20574,6 +22364,16 @@ impl SseDecode for SystemProbeResult {
     }
 }
 
+impl SseDecode for TerminalEmulator {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
 impl SseDecode for Value {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -20584,6 +22384,22 @@ impl SseDecode for Value {
     }
 }
 
+impl SseDecode for chrono::Duration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i64>::sse_decode(deserializer);
+        return chrono::Duration::microseconds(inner);
+    }
+}
+
+impl SseDecode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };
+    }
+}
+
 impl SseDecode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AgentTurn>>
 {
@@ -20618,6 +22434,16 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
 impl SseDecode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CameraSnapshot>>
 {
@@ -20678,6 +22504,16 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
 impl SseDecode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ExecOutput>>
 {
@@ -20748,6 +22584,18 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
 impl SseDecode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HelloAuth>>
 {
@@ -20978,6 +22826,16 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
 impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Value>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -20994,6 +22852,19 @@ impl SseDecode for StreamSink<GatewayEvent, flutter_rust_bridge::for_generated::
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        crate::api::connection::ConnectionState,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         crate::api::terminal::TerminalChunk,
@@ -21015,6 +22886,41 @@ impl SseDecode for String {
     }
 }
 
+impl SseDecode for crate::api::backpressure::BackpressurePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::backpressure::BackpressurePolicy::Block,
+            1 => crate::api::backpressure::BackpressurePolicy::DropOldest,
+            2 => crate::api::backpressure::BackpressurePolicy::Coalesce,
+            _ => unreachable!("Invalid variant for BackpressurePolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::backpressure::BackpressureStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_queued = <u32>::sse_decode(deserializer);
+        let mut var_paused = <bool>::sse_decode(deserializer);
+        let mut var_droppedLogs = <u64>::sse_decode(deserializer);
+        let mut var_droppedOutput = <u64>::sse_decode(deserializer);
+        let mut var_droppedEvents = <u64>::sse_decode(deserializer);
+        let mut var_coalesced = <u64>::sse_decode(deserializer);
+        let mut var_lagged = <u64>::sse_decode(deserializer);
+        return crate::api::backpressure::BackpressureStats {
+            queued: var_queued,
+            paused: var_paused,
+            dropped_logs: var_droppedLogs,
+            dropped_output: var_droppedOutput,
+            dropped_events: var_droppedEvents,
+            coalesced: var_coalesced,
+            lagged: var_lagged,
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -21022,6 +22928,158 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::api::deflate::CompressionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_negotiated = <bool>::sse_decode(deserializer);
+        let mut var_outgoingCompressionDisabled = <bool>::sse_decode(deserializer);
+        let mut var_sentBytes = <u64>::sse_decode(deserializer);
+        let mut var_sentWireBytes = <u64>::sse_decode(deserializer);
+        let mut var_receivedBytes = <u64>::sse_decode(deserializer);
+        let mut var_receivedWireBytes = <u64>::sse_decode(deserializer);
+        return crate::api::deflate::CompressionStats {
+            negotiated: var_negotiated,
+            outgoing_compression_disabled: var_outgoingCompressionDisabled,
+            sent_bytes: var_sentBytes,
+            sent_wire_bytes: var_sentWireBytes,
+            received_bytes: var_receivedBytes,
+            received_wire_bytes: var_receivedWireBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::connection::ConnectionConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backoffBase = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_maxBackoff = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_jitter = <bool>::sse_decode(deserializer);
+        let mut var_maxAttempts = <Option<u32>>::sse_decode(deserializer);
+        let mut var_connectTimeout = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_heartbeatInterval = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_heartbeatTimeout = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_handshakeTimeout = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_maxSessions = <Option<u32>>::sse_decode(deserializer);
+        let mut var_restartProbeInterval = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_restartProbeWindow = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_maxRestartWait = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_outbox = <Option<crate::api::outbox::OutboxConfig>>::sse_decode(deserializer);
+        let mut var_inboundCapacity = <u32>::sse_decode(deserializer);
+        let mut var_outboundCapacity = <u32>::sse_decode(deserializer);
+        let mut var_logsPolicy =
+            <crate::api::backpressure::BackpressurePolicy>::sse_decode(deserializer);
+        let mut var_outputPolicy =
+            <crate::api::backpressure::BackpressurePolicy>::sse_decode(deserializer);
+        let mut var_eventPolicy =
+            <crate::api::backpressure::BackpressurePolicy>::sse_decode(deserializer);
+        let mut var_outputBatchWindow = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_outputBatchBytes = <u32>::sse_decode(deserializer);
+        let mut var_tls = <Option<crate::api::tls::TlsOptions>>::sse_decode(deserializer);
+        let mut var_proxy = <Option<crate::api::proxy::ProxyConfig>>::sse_decode(deserializer);
+        let mut var_upgradeHeaders =
+            <Vec<crate::api::events::UpgradeHeader>>::sse_decode(deserializer);
+        let mut var_subprotocols = <Vec<String>>::sse_decode(deserializer);
+        let mut var_deflate =
+            <Option<crate::api::deflate::DeflateConfig>>::sse_decode(deserializer);
+        return crate::api::connection::ConnectionConfig {
+            backoff_base: var_backoffBase,
+            max_backoff: var_maxBackoff,
+            jitter: var_jitter,
+            max_attempts: var_maxAttempts,
+            connect_timeout: var_connectTimeout,
+            heartbeat_interval: var_heartbeatInterval,
+            heartbeat_timeout: var_heartbeatTimeout,
+            handshake_timeout: var_handshakeTimeout,
+            max_sessions: var_maxSessions,
+            restart_probe_interval: var_restartProbeInterval,
+            restart_probe_window: var_restartProbeWindow,
+            max_restart_wait: var_maxRestartWait,
+            outbox: var_outbox,
+            inbound_capacity: var_inboundCapacity,
+            outbound_capacity: var_outboundCapacity,
+            logs_policy: var_logsPolicy,
+            output_policy: var_outputPolicy,
+            event_policy: var_eventPolicy,
+            output_batch_window: var_outputBatchWindow,
+            output_batch_bytes: var_outputBatchBytes,
+            tls: var_tls,
+            proxy: var_proxy,
+            upgrade_headers: var_upgradeHeaders,
+            subprotocols: var_subprotocols,
+            deflate: var_deflate,
+        };
+    }
+}
+
+impl SseDecode for crate::api::connection::ConnectionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::connection::ConnectionState::Idle;
+            }
+            1 => {
+                let mut var_attempt = <u32>::sse_decode(deserializer);
+                return crate::api::connection::ConnectionState::Connecting {
+                    attempt: var_attempt,
+                };
+            }
+            2 => {
+                return crate::api::connection::ConnectionState::Handshaking;
+            }
+            3 => {
+                let mut var_protocol = <u32>::sse_decode(deserializer);
+                let mut var_role = <String>::sse_decode(deserializer);
+                let mut var_subprotocol = <Option<String>>::sse_decode(deserializer);
+                return crate::api::connection::ConnectionState::Ready {
+                    protocol: var_protocol,
+                    role: var_role,
+                    subprotocol: var_subprotocol,
+                };
+            }
+            4 => {
+                let mut var_nextRetryAtMs = <i64>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::connection::ConnectionState::Backoff {
+                    next_retry_at_ms: var_nextRetryAtMs,
+                    reason: var_reason,
+                };
+            }
+            5 => {
+                return crate::api::connection::ConnectionState::Restarting;
+            }
+            6 => {
+                let mut var_fatal = <bool>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::api::connection::ConnectionState::Failed {
+                    fatal: var_fatal,
+                    reason: var_reason,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::deflate::DeflateConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_clientMaxWindowBits = <u8>::sse_decode(deserializer);
+        let mut var_serverMaxWindowBits = <u8>::sse_decode(deserializer);
+        let mut var_clientNoContextTakeover = <bool>::sse_decode(deserializer);
+        let mut var_serverNoContextTakeover = <bool>::sse_decode(deserializer);
+        return crate::api::deflate::DeflateConfig {
+            client_max_window_bits: var_clientMaxWindowBits,
+            server_max_window_bits: var_serverMaxWindowBits,
+            client_no_context_takeover: var_clientNoContextTakeover,
+            server_no_context_takeover: var_serverNoContextTakeover,
+        };
+    }
+}
+
 impl SseDecode for crate::api::connection::GatewayClient {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -21030,6 +23088,53 @@ impl SseDecode for crate::api::connection::GatewayClient {
     }
 }
 
+impl SseDecode for crate::api::events::GatewayEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::events::GatewayEventKind::Connected,
+            1 => crate::api::events::GatewayEventKind::Disconnected,
+            2 => crate::api::events::GatewayEventKind::Message,
+            3 => crate::api::events::GatewayEventKind::Error,
+            4 => crate::api::events::GatewayEventKind::Other,
+            _ => unreachable!("Invalid variant for GatewayEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::connection::HandshakeOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_clientId = <Option<String>>::sse_decode(deserializer);
+        let mut var_clientVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_platform = <Option<String>>::sse_decode(deserializer);
+        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_instanceId = <Option<String>>::sse_decode(deserializer);
+        let mut var_identityDir = <Option<String>>::sse_decode(deserializer);
+        let mut var_role = <Option<String>>::sse_decode(deserializer);
+        let mut var_scopes = <Vec<String>>::sse_decode(deserializer);
+        let mut var_token = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        let mut var_locale = <Option<String>>::sse_decode(deserializer);
+        let mut var_userAgent = <Option<String>>::sse_decode(deserializer);
+        return crate::api::connection::HandshakeOptions {
+            client_id: var_clientId,
+            client_version: var_clientVersion,
+            platform: var_platform,
+            display_name: var_displayName,
+            instance_id: var_instanceId,
+            identity_dir: var_identityDir,
+            role: var_role,
+            scopes: var_scopes,
+            token: var_token,
+            password: var_password,
+            locale: var_locale,
+            user_agent: var_userAgent,
+        };
+    }
+}
+
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -21044,6 +23149,13 @@ impl SseDecode for i64 {
     }
 }
 
+impl SseDecode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap() as _
+    }
+}
+
 impl SseDecode for Vec<LogEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -21056,49 +23168,91 @@ impl SseDecode for Vec<LogEntry> {
     }
 }
 
-impl SseDecode for Vec<PresenceEntry> {
+impl SseDecode for Vec<PresenceEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<PresenceEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Value> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Value>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<PresenceEntry>::sse_decode(deserializer));
+            ans_.push(<u8>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<Value> {
+impl SseDecode for Vec<crate::api::terminal_emulator::TerminalLine> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<Value>::sse_decode(deserializer));
+            ans_.push(<crate::api::terminal_emulator::TerminalLine>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for Vec<crate::api::terminal_emulator::TerminalRun> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+            ans_.push(<crate::api::terminal_emulator::TerminalRun>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<crate::api::events::UpgradeHeader> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<crate::api::events::UpgradeHeader>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
@@ -21236,6 +23390,32 @@ impl SseDecode for Option<bool> {
     }
 }
 
+impl SseDecode for Option<crate::api::connection::ConnectionConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::connection::ConnectionConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::deflate::DeflateConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::deflate::DeflateConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -21258,6 +23438,39 @@ impl SseDecode for Option<i64> {
     }
 }
 
+impl SseDecode for Option<crate::api::outbox::OutboxConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::outbox::OutboxConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::proxy::ProxyConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::proxy::ProxyConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::tls::TlsOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::tls::TlsOptions>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -21291,18 +23504,230 @@ impl SseDecode for Option<Vec<u8>> {
     }
 }
 
+impl SseDecode for crate::api::outbox::OutboxConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxItems = <u32>::sse_decode(deserializer);
+        let mut var_ttl = <chrono::Duration>::sse_decode(deserializer);
+        let mut var_persistDir = <Option<String>>::sse_decode(deserializer);
+        return crate::api::outbox::OutboxConfig {
+            max_items: var_maxItems,
+            ttl: var_ttl,
+            persist_dir: var_persistDir,
+        };
+    }
+}
+
+impl SseDecode for crate::api::proxy::ProxyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::proxy::ProxyKind>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        return crate::api::proxy::ProxyConfig {
+            kind: var_kind,
+            host: var_host,
+            port: var_port,
+            username: var_username,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for crate::api::proxy::ProxyKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::proxy::ProxyKind::Http,
+            1 => crate::api::proxy::ProxyKind::Socks5,
+            _ => unreachable!("Invalid variant for ProxyKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::connection::RequestDelivery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::connection::RequestDelivery::Sent,
+            1 => crate::api::connection::RequestDelivery::Queued,
+            _ => unreachable!("Invalid variant for RequestDelivery: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::terminal::TerminalChunk {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_text = <String>::sse_decode(deserializer);
         let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_channel = <Option<String>>::sse_decode(deserializer);
+        let mut var_data = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_spans =
+            <Vec<crate::api::terminal_emulator::TerminalRun>>::sse_decode(deserializer);
         return crate::api::terminal::TerminalChunk {
             text: var_text,
             kind: var_kind,
+            channel: var_channel,
+            data: var_data,
+            spans: var_spans,
+        };
+    }
+}
+
+impl SseDecode for crate::api::terminal_emulator::TerminalColor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::terminal_emulator::TerminalColor::Default;
+            }
+            1 => {
+                let mut var_index = <u8>::sse_decode(deserializer);
+                return crate::api::terminal_emulator::TerminalColor::Indexed { index: var_index };
+            }
+            2 => {
+                let mut var_red = <u8>::sse_decode(deserializer);
+                let mut var_green = <u8>::sse_decode(deserializer);
+                let mut var_blue = <u8>::sse_decode(deserializer);
+                return crate::api::terminal_emulator::TerminalColor::Rgb {
+                    red: var_red,
+                    green: var_green,
+                    blue: var_blue,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::terminal_emulator::TerminalLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_row = <u32>::sse_decode(deserializer);
+        let mut var_runs =
+            <Vec<crate::api::terminal_emulator::TerminalRun>>::sse_decode(deserializer);
+        let mut var_wrapped = <bool>::sse_decode(deserializer);
+        return crate::api::terminal_emulator::TerminalLine {
+            row: var_row,
+            runs: var_runs,
+            wrapped: var_wrapped,
+        };
+    }
+}
+
+impl SseDecode for crate::api::terminal_emulator::TerminalRun {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_style =
+            <crate::api::terminal_emulator::TerminalStyle>::sse_decode(deserializer);
+        return crate::api::terminal_emulator::TerminalRun {
+            text: var_text,
+            style: var_style,
+        };
+    }
+}
+
+impl SseDecode for crate::api::terminal_emulator::TerminalScreenUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cols = <u32>::sse_decode(deserializer);
+        let mut var_rows = <u32>::sse_decode(deserializer);
+        let mut var_cursorRow = <u32>::sse_decode(deserializer);
+        let mut var_cursorCol = <u32>::sse_decode(deserializer);
+        let mut var_cursorVisible = <bool>::sse_decode(deserializer);
+        let mut var_alternateScreen = <bool>::sse_decode(deserializer);
+        let mut var_applicationCursorKeys = <bool>::sse_decode(deserializer);
+        let mut var_bracketedPaste = <bool>::sse_decode(deserializer);
+        let mut var_full = <bool>::sse_decode(deserializer);
+        let mut var_lines =
+            <Vec<crate::api::terminal_emulator::TerminalLine>>::sse_decode(deserializer);
+        let mut var_scrollbackLen = <u32>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        return crate::api::terminal_emulator::TerminalScreenUpdate {
+            cols: var_cols,
+            rows: var_rows,
+            cursor_row: var_cursorRow,
+            cursor_col: var_cursorCol,
+            cursor_visible: var_cursorVisible,
+            alternate_screen: var_alternateScreen,
+            application_cursor_keys: var_applicationCursorKeys,
+            bracketed_paste: var_bracketedPaste,
+            full: var_full,
+            lines: var_lines,
+            scrollback_len: var_scrollbackLen,
+            title: var_title,
+        };
+    }
+}
+
+impl SseDecode for crate::api::terminal_emulator::TerminalStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_foreground =
+            <crate::api::terminal_emulator::TerminalColor>::sse_decode(deserializer);
+        let mut var_background =
+            <crate::api::terminal_emulator::TerminalColor>::sse_decode(deserializer);
+        let mut var_bold = <bool>::sse_decode(deserializer);
+        let mut var_dim = <bool>::sse_decode(deserializer);
+        let mut var_italic = <bool>::sse_decode(deserializer);
+        let mut var_underline = <bool>::sse_decode(deserializer);
+        let mut var_inverse = <bool>::sse_decode(deserializer);
+        let mut var_hidden = <bool>::sse_decode(deserializer);
+        let mut var_strikethrough = <bool>::sse_decode(deserializer);
+        return crate::api::terminal_emulator::TerminalStyle {
+            foreground: var_foreground,
+            background: var_background,
+            bold: var_bold,
+            dim: var_dim,
+            italic: var_italic,
+            underline: var_underline,
+            inverse: var_inverse,
+            hidden: var_hidden,
+            strikethrough: var_strikethrough,
+        };
+    }
+}
+
+impl SseDecode for crate::api::tls::TlsOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_extraCaPem = <Vec<String>>::sse_decode(deserializer);
+        let mut var_spkiPins = <Vec<String>>::sse_decode(deserializer);
+        let mut var_trustOnFirstUse = <bool>::sse_decode(deserializer);
+        let mut var_knownHostsDir = <Option<String>>::sse_decode(deserializer);
+        let mut var_allowSelfSigned = <bool>::sse_decode(deserializer);
+        let mut var_clientCertChainPem = <Option<String>>::sse_decode(deserializer);
+        let mut var_clientKeyPem = <Option<String>>::sse_decode(deserializer);
+        let mut var_clientKeyPkcs8 = <Option<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::tls::TlsOptions {
+            extra_ca_pem: var_extraCaPem,
+            spki_pins: var_spkiPins,
+            trust_on_first_use: var_trustOnFirstUse,
+            known_hosts_dir: var_knownHostsDir,
+            allow_self_signed: var_allowSelfSigned,
+            client_cert_chain_pem: var_clientCertChainPem,
+            client_key_pem: var_clientKeyPem,
+            client_key_pkcs8: var_clientKeyPkcs8,
         };
     }
 }
 
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -21329,6 +23754,18 @@ impl SseDecode for () {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
+impl SseDecode for crate::api::events::UpgradeHeader {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::events::UpgradeHeader {
+            name: var_name,
+            value: var_value,
+        };
+    }
+}
+
 impl SseDecode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -21345,208 +23782,334 @@ fn pde_ffi_dispatcher_primary_impl(
 ) {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        143 => {
+        11 => wire__crate__api__token_store__CallbackTokenStore_new_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__events__ConnectAuth_default_impl(port, ptr, rust_vec_len, data_len),
+        59 => {
+            wire__crate__api__events__ConnectClient_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        72 => {
+            wire__crate__api__events__ConnectDevice_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        101 => {
+            wire__crate__api__events__ConnectParams_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        152 => {
             wire__crate__api__events__GatewayError_default_impl(port, ptr, rust_vec_len, data_len)
         }
-        154 => wire__crate__api__events__GatewayRequestFrame_to_json_impl(
+        165 => wire__crate__api__events__GatewayRequestFrame_to_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        166 => wire__crate__api__events__GatewayResponsePayload_to_json_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        175 => wire__crate__api__events__HelloAuth_default_impl(port, ptr, rust_vec_len, data_len),
+        192 => {
+            wire__crate__api__events__HelloPolicy_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        215 => wire__crate__api__events__LogEntry_default_impl(port, ptr, rust_vec_len, data_len),
+        224 => wire__crate__api__events__LogsEvent_default_impl(port, ptr, rust_vec_len, data_len),
+        237 => wire__crate__api__events__LogsSubscribeParams_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        242 => wire__crate__api__events__LogsUnsubscribeParams_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        281 => wire__crate__api__events__SessionsCloseParams_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        288 => wire__crate__api__events__SessionsListParams_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        199 => wire__crate__api__events__LogEntry_default_impl(port, ptr, rust_vec_len, data_len),
-        208 => wire__crate__api__events__LogsEvent_default_impl(port, ptr, rust_vec_len, data_len),
-        221 => wire__crate__api__events__LogsSubscribeParams_default_impl(
+        297 => wire__crate__api__events__SessionsSpawnParams_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        226 => wire__crate__api__events__LogsUnsubscribeParams_default_impl(
+        312 => wire__crate__api__events__StreamCloseParams_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        265 => wire__crate__api__events__SessionsCloseParams_default_impl(
+        323 => wire__crate__api__events__StreamClosedEvent_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        272 => wire__crate__api__events__SessionsListParams_default_impl(
+        342 => wire__crate__api__events__StreamDataEvent_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        281 => wire__crate__api__events__SessionsSpawnParams_default_impl(
+        355 => wire__crate__api__events__StreamOpenParams_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        296 => wire__crate__api__events__StreamCloseParams_default_impl(
+        374 => wire__crate__api__events__StreamSendParams_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        307 => wire__crate__api__events__StreamClosedEvent_default_impl(
+        393 => wire__crate__api__events__SystemProbeCheck_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        326 => wire__crate__api__events__StreamDataEvent_default_impl(
+        402 => wire__crate__api__events__SystemProbeParams_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        339 => wire__crate__api__events__StreamOpenParams_default_impl(
+        411 => wire__crate__api__events__SystemProbeResult_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        358 => wire__crate__api__events__StreamSendParams_default_impl(
+        415 => wire__crate__api__terminal_emulator__TerminalEmulator_resize_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        377 => wire__crate__api__events__SystemProbeCheck_default_impl(
+        418 => wire__crate__api__backpressure__backpressure_stats_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        386 => wire__crate__api__events__SystemProbeParams_default_impl(
+        419 => wire__crate__api__token_store__clear_device_token_store_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        395 => wire__crate__api__events__SystemProbeResult_default_impl(
+        420 => wire__crate__api__deflate__compression_stats_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        397 => {
+        421 => {
             wire__crate__api__connection__connect_to_gateway_impl(port, ptr, rust_vec_len, data_len)
         }
-        398 => wire__crate__api__terminal__exec_command_impl(port, ptr, rust_vec_len, data_len),
-        399 => wire__crate__api__connection__gateway_client_agent_turn_request_impl(
+        422 => wire__crate__api__connection__connection_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        423 => wire__crate__api__deflate__deflate_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        424 => {
+            wire__crate__api__connection__disconnect_gateway_impl(port, ptr, rust_vec_len, data_len)
+        }
+        425 => wire__crate__api__terminal__exec_command_impl(port, ptr, rust_vec_len, data_len),
+        426 => wire__crate__api__connection__gateway_backpressure_stats_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        427 => wire__crate__api__connection__gateway_client_agent_turn_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        400 => wire__crate__api__connection__gateway_client_camera_snap_request_impl(
+        428 => wire__crate__api__connection__gateway_client_camera_snap_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        401 => wire__crate__api__connection__gateway_client_exec_command_request_impl(
+        429 => wire__crate__api__connection__gateway_client_exec_command_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        402 => wire__crate__api__connection__gateway_client_exec_request_impl(
+        430 => wire__crate__api__connection__gateway_client_exec_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        404 => wire__crate__api__connection__gateway_client_interactive_shell_close_request_impl(
+        432 => wire__crate__api__connection__gateway_client_interactive_shell_close_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        405 => wire__crate__api__connection__gateway_client_interactive_shell_open_request_impl(
+        433 => wire__crate__api__connection__gateway_client_interactive_shell_open_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        406 => wire__crate__api__connection__gateway_client_interactive_shell_resize_request_impl(
+        434 => wire__crate__api__connection__gateway_client_interactive_shell_resize_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        407 => wire__crate__api__connection__gateway_client_interactive_shell_send_request_impl(
+        435 => wire__crate__api__connection__gateway_client_interactive_shell_send_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        408 => wire__crate__api__connection__gateway_client_logs_subscribe_request_impl(
+        436 => wire__crate__api__connection__gateway_client_logs_subscribe_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        409 => wire__crate__api__connection__gateway_client_logs_unsubscribe_request_impl(
+        437 => wire__crate__api__connection__gateway_client_logs_unsubscribe_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        410 => {
+        438 => {
             wire__crate__api__connection__gateway_client_new_impl(port, ptr, rust_vec_len, data_len)
         }
-        412 => wire__crate__api__connection__gateway_client_sessions_close_request_impl(
+        440 => wire__crate__api__connection__gateway_client_sessions_close_request_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        441 => wire__crate__api__connection__gateway_client_sessions_list_request_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        442 => wire__crate__api__connection__gateway_client_sessions_spawn_request_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        443 => wire__crate__api__connection__gateway_client_system_event_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        413 => wire__crate__api__connection__gateway_client_sessions_list_request_impl(
+        444 => wire__crate__api__connection__gateway_client_system_probe_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        414 => wire__crate__api__connection__gateway_client_sessions_spawn_request_impl(
+        445 => wire__crate__api__connection__gateway_compression_stats_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        415 => wire__crate__api__connection__gateway_client_system_event_request_impl(
+        446 => {
+            wire__crate__api__connection__gateway_request_impl(port, ptr, rust_vec_len, data_len)
+        }
+        447 => wire__crate__api__simple__greet_impl(port, ptr, rust_vec_len, data_len),
+        448 => wire__crate__api__connection__handshake_options_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        416 => wire__crate__api__connection__gateway_client_system_probe_request_impl(
+        449 => wire__crate__api__device_identity__load_device_identity_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        417 => wire__crate__api__simple__greet_impl(port, ptr, rust_vec_len, data_len),
-        418 => {
+        450 => {
+            wire__crate__api__outbox__outbox_config_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        451 => {
             wire__crate__api__events__parse_gateway_frame_impl(port, ptr, rust_vec_len, data_len)
         }
-        419 => wire__crate__api__connection__send_gateway_request_frame_impl(
+        452 => wire__crate__api__token_store__register_device_token_store_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        453 => wire__crate__api__connection__send_gateway_request_frame_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        454 => wire__crate__api__connection__set_gateway_events_paused_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        455 => wire__crate__api__terminal__terminal_stream_attach_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        420 => {
+        456 => {
             wire__crate__api__terminal__terminal_stream_open_impl(port, ptr, rust_vec_len, data_len)
         }
-        421 => {
+        457 => {
             wire__crate__api__terminal__terminal_stream_send_impl(port, ptr, rust_vec_len, data_len)
         }
+        458 => wire__crate__api__terminal_emulator__terminal_style_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        459 => wire__crate__api__tls__tls_options_default_impl(port, ptr, rust_vec_len, data_len),
+        460 => wire__crate__api__connection__watch_connection_state_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
         _ => unreachable!(),
     }
 }
@@ -21589,1882 +24152,1939 @@ fn pde_ffi_dispatcher_sync_impl(
             rust_vec_len,
             data_len,
         ),
-        7 => wire__crate__api__events__AgentTurn_auto_accessor_set_message_impl(
+        7 => wire__crate__api__events__AgentTurn_auto_accessor_set_message_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__events__AgentTurn_auto_accessor_set_model_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__events__AgentTurn_auto_accessor_set_thinking_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__events__AgentTurn_auto_accessor_set_timeout_seconds_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_compression_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_data_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_encoding_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        8 => wire__crate__api__events__AgentTurn_auto_accessor_set_model_impl(
+        15 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        9 => wire__crate__api__events__AgentTurn_auto_accessor_set_thinking_impl(
+        16 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_format_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        10 => wire__crate__api__events__AgentTurn_auto_accessor_set_timeout_seconds_impl(
+        17 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_height_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        11 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_compression_impl(
+        18 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_timestamp_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        12 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_data_impl(
+        19 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_width_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        13 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_encoding_impl(
+        20 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_compression_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        14 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_extra_impl(
+        21 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_data_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        15 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_format_impl(
+        22 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_encoding_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        16 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_height_impl(
+        23 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        17 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_timestamp_ms_impl(
+        24 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_format_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        18 => wire__crate__api__events__CameraSnapshot_auto_accessor_get_width_impl(
+        25 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_height_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        19 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_compression_impl(
+        26 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_timestamp_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        20 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_data_impl(
+        27 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_width_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        21 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_encoding_impl(
+        28 => wire__crate__api__events__ConnectAuth_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        22 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_extra_impl(
+        29 => wire__crate__api__events__ConnectAuth_auto_accessor_get_password_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        23 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_format_impl(
+        30 => wire__crate__api__events__ConnectAuth_auto_accessor_get_token_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        24 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_height_impl(
+        31 => wire__crate__api__events__ConnectAuth_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        25 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_timestamp_ms_impl(
+        32 => wire__crate__api__events__ConnectAuth_auto_accessor_set_password_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        26 => wire__crate__api__events__CameraSnapshot_auto_accessor_set_width_impl(
+        33 => wire__crate__api__events__ConnectAuth_auto_accessor_set_token_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        27 => wire__crate__api__events__ConnectAuth_auto_accessor_get_extra_impl(
+        35 => wire__crate__api__events__ConnectChallenge_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        28 => wire__crate__api__events__ConnectAuth_auto_accessor_get_password_impl(
+        36 => wire__crate__api__events__ConnectChallenge_auto_accessor_get_nonce_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        29 => wire__crate__api__events__ConnectAuth_auto_accessor_get_token_impl(
+        37 => wire__crate__api__events__ConnectChallenge_auto_accessor_get_ts_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        30 => wire__crate__api__events__ConnectAuth_auto_accessor_set_extra_impl(
+        38 => wire__crate__api__events__ConnectChallenge_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        31 => wire__crate__api__events__ConnectAuth_auto_accessor_set_password_impl(
+        39 => wire__crate__api__events__ConnectChallenge_auto_accessor_set_nonce_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        32 => wire__crate__api__events__ConnectAuth_auto_accessor_set_token_impl(
+        40 => wire__crate__api__events__ConnectChallenge_auto_accessor_set_ts_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        33 => wire__crate__api__events__ConnectChallenge_auto_accessor_get_extra_impl(
+        41 => wire__crate__api__events__ConnectClient_auto_accessor_get_device_family_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        34 => wire__crate__api__events__ConnectChallenge_auto_accessor_get_nonce_impl(
+        42 => wire__crate__api__events__ConnectClient_auto_accessor_get_display_name_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        35 => wire__crate__api__events__ConnectChallenge_auto_accessor_get_ts_impl(
+        43 => wire__crate__api__events__ConnectClient_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        36 => wire__crate__api__events__ConnectChallenge_auto_accessor_set_extra_impl(
+        44 => wire__crate__api__events__ConnectClient_auto_accessor_get_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__api__events__ConnectChallenge_auto_accessor_set_nonce_impl(
+        45 => wire__crate__api__events__ConnectClient_auto_accessor_get_instance_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        38 => wire__crate__api__events__ConnectChallenge_auto_accessor_set_ts_impl(
+        46 => wire__crate__api__events__ConnectClient_auto_accessor_get_mode_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        39 => wire__crate__api__events__ConnectClient_auto_accessor_get_device_family_impl(
+        47 => wire__crate__api__events__ConnectClient_auto_accessor_get_model_identifier_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        40 => wire__crate__api__events__ConnectClient_auto_accessor_get_display_name_impl(
+        48 => wire__crate__api__events__ConnectClient_auto_accessor_get_platform_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        41 => wire__crate__api__events__ConnectClient_auto_accessor_get_extra_impl(
+        49 => wire__crate__api__events__ConnectClient_auto_accessor_get_version_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        42 => wire__crate__api__events__ConnectClient_auto_accessor_get_id_impl(
+        50 => wire__crate__api__events__ConnectClient_auto_accessor_set_device_family_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        43 => wire__crate__api__events__ConnectClient_auto_accessor_get_instance_id_impl(
+        51 => wire__crate__api__events__ConnectClient_auto_accessor_set_display_name_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        44 => wire__crate__api__events__ConnectClient_auto_accessor_get_mode_impl(
+        52 => wire__crate__api__events__ConnectClient_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        45 => wire__crate__api__events__ConnectClient_auto_accessor_get_model_identifier_impl(
+        53 => wire__crate__api__events__ConnectClient_auto_accessor_set_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        46 => wire__crate__api__events__ConnectClient_auto_accessor_get_platform_impl(
+        54 => wire__crate__api__events__ConnectClient_auto_accessor_set_instance_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        47 => wire__crate__api__events__ConnectClient_auto_accessor_get_version_impl(
+        55 => wire__crate__api__events__ConnectClient_auto_accessor_set_mode_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => wire__crate__api__events__ConnectClient_auto_accessor_set_device_family_impl(
+        56 => wire__crate__api__events__ConnectClient_auto_accessor_set_model_identifier_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        49 => wire__crate__api__events__ConnectClient_auto_accessor_set_display_name_impl(
+        57 => wire__crate__api__events__ConnectClient_auto_accessor_set_platform_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        50 => wire__crate__api__events__ConnectClient_auto_accessor_set_extra_impl(
+        58 => wire__crate__api__events__ConnectClient_auto_accessor_set_version_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        51 => wire__crate__api__events__ConnectClient_auto_accessor_set_id_impl(
+        60 => wire__crate__api__events__ConnectDevice_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        52 => wire__crate__api__events__ConnectClient_auto_accessor_set_instance_id_impl(
+        61 => wire__crate__api__events__ConnectDevice_auto_accessor_get_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        53 => wire__crate__api__events__ConnectClient_auto_accessor_set_mode_impl(
+        62 => wire__crate__api__events__ConnectDevice_auto_accessor_get_nonce_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__crate__api__events__ConnectClient_auto_accessor_set_model_identifier_impl(
+        63 => wire__crate__api__events__ConnectDevice_auto_accessor_get_public_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        55 => wire__crate__api__events__ConnectClient_auto_accessor_set_platform_impl(
+        64 => wire__crate__api__events__ConnectDevice_auto_accessor_get_signature_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        56 => wire__crate__api__events__ConnectClient_auto_accessor_set_version_impl(
+        65 => wire__crate__api__events__ConnectDevice_auto_accessor_get_signed_at_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        57 => wire__crate__api__events__ConnectDevice_auto_accessor_get_extra_impl(
+        66 => wire__crate__api__events__ConnectDevice_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        58 => wire__crate__api__events__ConnectDevice_auto_accessor_get_id_impl(
+        67 => wire__crate__api__events__ConnectDevice_auto_accessor_set_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        59 => wire__crate__api__events__ConnectDevice_auto_accessor_get_nonce_impl(
+        68 => wire__crate__api__events__ConnectDevice_auto_accessor_set_nonce_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        60 => wire__crate__api__events__ConnectDevice_auto_accessor_get_public_key_impl(
+        69 => wire__crate__api__events__ConnectDevice_auto_accessor_set_public_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        61 => wire__crate__api__events__ConnectDevice_auto_accessor_get_signature_impl(
+        70 => wire__crate__api__events__ConnectDevice_auto_accessor_set_signature_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        62 => wire__crate__api__events__ConnectDevice_auto_accessor_get_signed_at_impl(
+        71 => wire__crate__api__events__ConnectDevice_auto_accessor_set_signed_at_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        63 => wire__crate__api__events__ConnectDevice_auto_accessor_set_extra_impl(
+        73 => wire__crate__api__events__ConnectParams_auto_accessor_get_auth_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        64 => wire__crate__api__events__ConnectDevice_auto_accessor_set_id_impl(
+        74 => wire__crate__api__events__ConnectParams_auto_accessor_get_caps_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        65 => wire__crate__api__events__ConnectDevice_auto_accessor_set_nonce_impl(
+        75 => wire__crate__api__events__ConnectParams_auto_accessor_get_client_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        66 => wire__crate__api__events__ConnectDevice_auto_accessor_set_public_key_impl(
+        76 => wire__crate__api__events__ConnectParams_auto_accessor_get_commands_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        67 => wire__crate__api__events__ConnectDevice_auto_accessor_set_signature_impl(
+        77 => wire__crate__api__events__ConnectParams_auto_accessor_get_device_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        68 => wire__crate__api__events__ConnectDevice_auto_accessor_set_signed_at_impl(
+        78 => wire__crate__api__events__ConnectParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__crate__api__events__ConnectParams_auto_accessor_get_auth_impl(
+        79 => wire__crate__api__events__ConnectParams_auto_accessor_get_last_seq_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => wire__crate__api__events__ConnectParams_auto_accessor_get_caps_impl(
+        80 => wire__crate__api__events__ConnectParams_auto_accessor_get_locale_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        71 => wire__crate__api__events__ConnectParams_auto_accessor_get_client_impl(
+        81 => wire__crate__api__events__ConnectParams_auto_accessor_get_max_protocol_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        72 => wire__crate__api__events__ConnectParams_auto_accessor_get_commands_impl(
+        82 => wire__crate__api__events__ConnectParams_auto_accessor_get_min_protocol_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        73 => wire__crate__api__events__ConnectParams_auto_accessor_get_device_impl(
+        83 => wire__crate__api__events__ConnectParams_auto_accessor_get_permissions_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        74 => wire__crate__api__events__ConnectParams_auto_accessor_get_extra_impl(
+        84 => wire__crate__api__events__ConnectParams_auto_accessor_get_role_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        75 => wire__crate__api__events__ConnectParams_auto_accessor_get_locale_impl(
+        85 => wire__crate__api__events__ConnectParams_auto_accessor_get_scopes_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        76 => wire__crate__api__events__ConnectParams_auto_accessor_get_max_protocol_impl(
+        86 => wire__crate__api__events__ConnectParams_auto_accessor_get_user_agent_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        77 => wire__crate__api__events__ConnectParams_auto_accessor_get_min_protocol_impl(
+        87 => wire__crate__api__events__ConnectParams_auto_accessor_set_auth_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        78 => wire__crate__api__events__ConnectParams_auto_accessor_get_permissions_impl(
+        88 => wire__crate__api__events__ConnectParams_auto_accessor_set_caps_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        79 => wire__crate__api__events__ConnectParams_auto_accessor_get_role_impl(
+        89 => wire__crate__api__events__ConnectParams_auto_accessor_set_client_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        80 => wire__crate__api__events__ConnectParams_auto_accessor_get_scopes_impl(
+        90 => wire__crate__api__events__ConnectParams_auto_accessor_set_commands_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        81 => wire__crate__api__events__ConnectParams_auto_accessor_get_user_agent_impl(
+        91 => wire__crate__api__events__ConnectParams_auto_accessor_set_device_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        82 => wire__crate__api__events__ConnectParams_auto_accessor_set_auth_impl(
+        92 => wire__crate__api__events__ConnectParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        83 => wire__crate__api__events__ConnectParams_auto_accessor_set_caps_impl(
+        93 => wire__crate__api__events__ConnectParams_auto_accessor_set_last_seq_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        84 => wire__crate__api__events__ConnectParams_auto_accessor_set_client_impl(
+        94 => wire__crate__api__events__ConnectParams_auto_accessor_set_locale_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        85 => wire__crate__api__events__ConnectParams_auto_accessor_set_commands_impl(
+        95 => wire__crate__api__events__ConnectParams_auto_accessor_set_max_protocol_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        86 => wire__crate__api__events__ConnectParams_auto_accessor_set_device_impl(
+        96 => wire__crate__api__events__ConnectParams_auto_accessor_set_min_protocol_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        87 => wire__crate__api__events__ConnectParams_auto_accessor_set_extra_impl(
+        97 => wire__crate__api__events__ConnectParams_auto_accessor_set_permissions_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        88 => wire__crate__api__events__ConnectParams_auto_accessor_set_locale_impl(
+        98 => wire__crate__api__events__ConnectParams_auto_accessor_set_role_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        89 => wire__crate__api__events__ConnectParams_auto_accessor_set_max_protocol_impl(
+        99 => wire__crate__api__events__ConnectParams_auto_accessor_set_scopes_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        90 => wire__crate__api__events__ConnectParams_auto_accessor_set_min_protocol_impl(
+        100 => wire__crate__api__events__ConnectParams_auto_accessor_set_user_agent_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        91 => wire__crate__api__events__ConnectParams_auto_accessor_set_permissions_impl(
+        102 => wire__crate__api__device_identity__DeviceIdentity_device_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        92 => wire__crate__api__events__ConnectParams_auto_accessor_set_role_impl(
+        103 => wire__crate__api__device_identity__DeviceIdentity_public_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        93 => wire__crate__api__events__ConnectParams_auto_accessor_set_scopes_impl(
+        104 => wire__crate__api__events__ExecOutput_auto_accessor_get_data_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        94 => wire__crate__api__events__ConnectParams_auto_accessor_set_user_agent_impl(
+        105 => wire__crate__api__events__ExecOutput_auto_accessor_get_encoding_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        95 => wire__crate__api__events__ExecOutput_auto_accessor_get_data_impl(
+        106 => wire__crate__api__events__ExecOutput_auto_accessor_get_eof_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        96 => wire__crate__api__events__ExecOutput_auto_accessor_get_encoding_impl(
+        107 => wire__crate__api__events__ExecOutput_auto_accessor_get_exec_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        97 => wire__crate__api__events__ExecOutput_auto_accessor_get_eof_impl(
+        108 => wire__crate__api__events__ExecOutput_auto_accessor_get_exit_code_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        98 => wire__crate__api__events__ExecOutput_auto_accessor_get_exec_id_impl(
+        109 => wire__crate__api__events__ExecOutput_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        99 => wire__crate__api__events__ExecOutput_auto_accessor_get_exit_code_impl(
+        110 => wire__crate__api__events__ExecOutput_auto_accessor_get_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        100 => wire__crate__api__events__ExecOutput_auto_accessor_get_extra_impl(
+        111 => wire__crate__api__events__ExecOutput_auto_accessor_get_timestamp_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        101 => wire__crate__api__events__ExecOutput_auto_accessor_get_stream_impl(
+        112 => wire__crate__api__events__ExecOutput_auto_accessor_set_data_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        102 => wire__crate__api__events__ExecOutput_auto_accessor_get_timestamp_ms_impl(
+        113 => wire__crate__api__events__ExecOutput_auto_accessor_set_encoding_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        103 => wire__crate__api__events__ExecOutput_auto_accessor_set_data_impl(
+        114 => wire__crate__api__events__ExecOutput_auto_accessor_set_eof_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        104 => wire__crate__api__events__ExecOutput_auto_accessor_set_encoding_impl(
+        115 => wire__crate__api__events__ExecOutput_auto_accessor_set_exec_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        105 => wire__crate__api__events__ExecOutput_auto_accessor_set_eof_impl(
+        116 => wire__crate__api__events__ExecOutput_auto_accessor_set_exit_code_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        106 => wire__crate__api__events__ExecOutput_auto_accessor_set_exec_id_impl(
+        117 => wire__crate__api__events__ExecOutput_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        107 => wire__crate__api__events__ExecOutput_auto_accessor_set_exit_code_impl(
+        118 => wire__crate__api__events__ExecOutput_auto_accessor_set_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        108 => wire__crate__api__events__ExecOutput_auto_accessor_set_extra_impl(
+        119 => wire__crate__api__events__ExecOutput_auto_accessor_set_timestamp_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        109 => wire__crate__api__events__ExecOutput_auto_accessor_set_stream_impl(
+        120 => wire__crate__api__events__ExecParams_auto_accessor_get_args_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        110 => wire__crate__api__events__ExecOutput_auto_accessor_set_timestamp_ms_impl(
+        121 => wire__crate__api__events__ExecParams_auto_accessor_get_command_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        111 => wire__crate__api__events__ExecParams_auto_accessor_get_args_impl(
+        122 => wire__crate__api__events__ExecParams_auto_accessor_get_cwd_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        112 => wire__crate__api__events__ExecParams_auto_accessor_get_command_impl(
+        123 => wire__crate__api__events__ExecParams_auto_accessor_get_env_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        113 => wire__crate__api__events__ExecParams_auto_accessor_get_cwd_impl(
+        124 => wire__crate__api__events__ExecParams_auto_accessor_get_exec_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        114 => wire__crate__api__events__ExecParams_auto_accessor_get_env_impl(
+        125 => wire__crate__api__events__ExecParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        115 => wire__crate__api__events__ExecParams_auto_accessor_get_exec_id_impl(
+        126 => wire__crate__api__events__ExecParams_auto_accessor_get_kill_on_drop_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        116 => wire__crate__api__events__ExecParams_auto_accessor_get_extra_impl(
+        127 => wire__crate__api__events__ExecParams_auto_accessor_get_stdin_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        117 => wire__crate__api__events__ExecParams_auto_accessor_get_kill_on_drop_impl(
+        128 => wire__crate__api__events__ExecParams_auto_accessor_get_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        118 => wire__crate__api__events__ExecParams_auto_accessor_get_stdin_impl(
+        129 => wire__crate__api__events__ExecParams_auto_accessor_get_timeout_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        119 => wire__crate__api__events__ExecParams_auto_accessor_get_stream_impl(
+        130 => wire__crate__api__events__ExecParams_auto_accessor_set_args_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        120 => wire__crate__api__events__ExecParams_auto_accessor_get_timeout_ms_impl(
+        131 => wire__crate__api__events__ExecParams_auto_accessor_set_command_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        121 => wire__crate__api__events__ExecParams_auto_accessor_set_args_impl(
+        132 => wire__crate__api__events__ExecParams_auto_accessor_set_cwd_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        122 => wire__crate__api__events__ExecParams_auto_accessor_set_command_impl(
+        133 => wire__crate__api__events__ExecParams_auto_accessor_set_env_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        123 => wire__crate__api__events__ExecParams_auto_accessor_set_cwd_impl(
+        134 => wire__crate__api__events__ExecParams_auto_accessor_set_exec_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        124 => wire__crate__api__events__ExecParams_auto_accessor_set_env_impl(
+        135 => wire__crate__api__events__ExecParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        125 => wire__crate__api__events__ExecParams_auto_accessor_set_exec_id_impl(
+        136 => wire__crate__api__events__ExecParams_auto_accessor_set_kill_on_drop_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        126 => wire__crate__api__events__ExecParams_auto_accessor_set_extra_impl(
+        137 => wire__crate__api__events__ExecParams_auto_accessor_set_stdin_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        127 => wire__crate__api__events__ExecParams_auto_accessor_set_kill_on_drop_impl(
+        138 => wire__crate__api__events__ExecParams_auto_accessor_set_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        128 => wire__crate__api__events__ExecParams_auto_accessor_set_stdin_impl(
+        139 => wire__crate__api__events__ExecParams_auto_accessor_set_timeout_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        129 => wire__crate__api__events__ExecParams_auto_accessor_set_stream_impl(
+        140 => wire__crate__api__events__GatewayError_auto_accessor_get_code_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        130 => wire__crate__api__events__ExecParams_auto_accessor_set_timeout_ms_impl(
+        141 => wire__crate__api__events__GatewayError_auto_accessor_get_details_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        131 => wire__crate__api__events__GatewayError_auto_accessor_get_code_impl(
+        142 => wire__crate__api__events__GatewayError_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        132 => wire__crate__api__events__GatewayError_auto_accessor_get_details_impl(
+        143 => wire__crate__api__events__GatewayError_auto_accessor_get_message_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        133 => wire__crate__api__events__GatewayError_auto_accessor_get_extra_impl(
+        144 => wire__crate__api__events__GatewayError_auto_accessor_get_retry_after_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        134 => wire__crate__api__events__GatewayError_auto_accessor_get_message_impl(
+        145 => wire__crate__api__events__GatewayError_auto_accessor_get_retryable_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        135 => wire__crate__api__events__GatewayError_auto_accessor_get_retry_after_ms_impl(
+        146 => wire__crate__api__events__GatewayError_auto_accessor_set_code_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        136 => wire__crate__api__events__GatewayError_auto_accessor_get_retryable_impl(
+        147 => wire__crate__api__events__GatewayError_auto_accessor_set_details_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        137 => wire__crate__api__events__GatewayError_auto_accessor_set_code_impl(
+        148 => wire__crate__api__events__GatewayError_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        138 => wire__crate__api__events__GatewayError_auto_accessor_set_details_impl(
+        149 => wire__crate__api__events__GatewayError_auto_accessor_set_message_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        139 => wire__crate__api__events__GatewayError_auto_accessor_set_extra_impl(
+        150 => wire__crate__api__events__GatewayError_auto_accessor_set_retry_after_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        140 => wire__crate__api__events__GatewayError_auto_accessor_set_message_impl(
+        151 => wire__crate__api__events__GatewayError_auto_accessor_set_retryable_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        141 => wire__crate__api__events__GatewayError_auto_accessor_set_retry_after_ms_impl(
+        153 => wire__crate__api__events__GatewayEvent_kind_impl(ptr, rust_vec_len, data_len),
+        154 => wire__crate__api__events__GatewayEvent_text_impl(ptr, rust_vec_len, data_len),
+        155 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_frame_type_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        142 => wire__crate__api__events__GatewayError_auto_accessor_set_retryable_impl(
+        156 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        144 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_frame_type_impl(
+        157 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_method_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        145 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_id_impl(
+        158 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_params_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        146 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_method_impl(
+        159 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_session_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        147 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_params_impl(
+        160 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_frame_type_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        148 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_get_session_key_impl(
+        161 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        149 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_frame_type_impl(
+        162 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_method_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        150 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_id_impl(
+        163 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_params_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        151 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_method_impl(
+        164 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_session_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        152 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_params_impl(
+        167 => wire__crate__api__events__HelloAuth_auto_accessor_get_device_token_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        153 => wire__crate__api__events__GatewayRequestFrame_auto_accessor_set_session_key_impl(
+        168 => wire__crate__api__events__HelloAuth_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        155 => wire__crate__api__events__HelloAuth_auto_accessor_get_device_token_impl(
+        169 => wire__crate__api__events__HelloAuth_auto_accessor_get_role_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        156 => wire__crate__api__events__HelloAuth_auto_accessor_get_extra_impl(
+        170 => wire__crate__api__events__HelloAuth_auto_accessor_get_scopes_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        157 => wire__crate__api__events__HelloAuth_auto_accessor_get_role_impl(
+        171 => wire__crate__api__events__HelloAuth_auto_accessor_set_device_token_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        158 => wire__crate__api__events__HelloAuth_auto_accessor_get_scopes_impl(
+        172 => wire__crate__api__events__HelloAuth_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        159 => wire__crate__api__events__HelloAuth_auto_accessor_set_device_token_impl(
+        173 => wire__crate__api__events__HelloAuth_auto_accessor_set_role_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        160 => wire__crate__api__events__HelloAuth_auto_accessor_set_extra_impl(
+        174 => wire__crate__api__events__HelloAuth_auto_accessor_set_scopes_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        161 => wire__crate__api__events__HelloAuth_auto_accessor_set_role_impl(
+        176 => wire__crate__api__events__HelloOk_auto_accessor_get_auth_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        162 => wire__crate__api__events__HelloAuth_auto_accessor_set_scopes_impl(
+        177 => wire__crate__api__events__HelloOk_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        163 => wire__crate__api__events__HelloOk_auto_accessor_get_auth_impl(
+        178 => wire__crate__api__events__HelloOk_auto_accessor_get_payload_type_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        164 => wire__crate__api__events__HelloOk_auto_accessor_get_extra_impl(
+        179 => wire__crate__api__events__HelloOk_auto_accessor_get_policy_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        165 => wire__crate__api__events__HelloOk_auto_accessor_get_payload_type_impl(
+        180 => wire__crate__api__events__HelloOk_auto_accessor_get_protocol_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        166 => wire__crate__api__events__HelloOk_auto_accessor_get_policy_impl(
+        181 => wire__crate__api__events__HelloOk_auto_accessor_set_auth_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        167 => wire__crate__api__events__HelloOk_auto_accessor_get_protocol_impl(
+        182 => wire__crate__api__events__HelloOk_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        168 => wire__crate__api__events__HelloOk_auto_accessor_set_auth_impl(
+        183 => wire__crate__api__events__HelloOk_auto_accessor_set_payload_type_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        169 => wire__crate__api__events__HelloOk_auto_accessor_set_extra_impl(
+        184 => wire__crate__api__events__HelloOk_auto_accessor_set_policy_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        170 => wire__crate__api__events__HelloOk_auto_accessor_set_payload_type_impl(
+        185 => wire__crate__api__events__HelloOk_auto_accessor_set_protocol_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        171 => wire__crate__api__events__HelloOk_auto_accessor_set_policy_impl(
+        186 => wire__crate__api__events__HelloPolicy_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        172 => wire__crate__api__events__HelloOk_auto_accessor_set_protocol_impl(
+        187 => wire__crate__api__events__HelloPolicy_auto_accessor_get_tick_interval_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        173 => wire__crate__api__events__HelloPolicy_auto_accessor_get_extra_impl(
+        188 => wire__crate__api__events__HelloPolicy_auto_accessor_get_tick_mode_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        174 => wire__crate__api__events__HelloPolicy_auto_accessor_get_tick_interval_ms_impl(
+        189 => wire__crate__api__events__HelloPolicy_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        175 => wire__crate__api__events__HelloPolicy_auto_accessor_set_extra_impl(
+        190 => wire__crate__api__events__HelloPolicy_auto_accessor_set_tick_interval_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        176 => wire__crate__api__events__HelloPolicy_auto_accessor_set_tick_interval_ms_impl(
+        191 => wire__crate__api__events__HelloPolicy_auto_accessor_set_tick_mode_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        177 => wire__crate__api__events__LogEntry_auto_accessor_get_extra_impl(
+        193 => wire__crate__api__events__LogEntry_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        178 => wire__crate__api__events__LogEntry_auto_accessor_get_fields_impl(
+        194 => wire__crate__api__events__LogEntry_auto_accessor_get_fields_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        179 => wire__crate__api__events__LogEntry_auto_accessor_get_level_impl(
+        195 => wire__crate__api__events__LogEntry_auto_accessor_get_level_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        180 => wire__crate__api__events__LogEntry_auto_accessor_get_message_impl(
+        196 => wire__crate__api__events__LogEntry_auto_accessor_get_message_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        181 => wire__crate__api__events__LogEntry_auto_accessor_get_span_impl(
+        197 => wire__crate__api__events__LogEntry_auto_accessor_get_span_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        182 => wire__crate__api__events__LogEntry_auto_accessor_get_spans_impl(
+        198 => wire__crate__api__events__LogEntry_auto_accessor_get_spans_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        183 => wire__crate__api__events__LogEntry_auto_accessor_get_target_impl(
+        199 => wire__crate__api__events__LogEntry_auto_accessor_get_target_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        184 => wire__crate__api__events__LogEntry_auto_accessor_get_thread_id_impl(
+        200 => wire__crate__api__events__LogEntry_auto_accessor_get_thread_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        185 => wire__crate__api__events__LogEntry_auto_accessor_get_thread_name_impl(
+        201 => wire__crate__api__events__LogEntry_auto_accessor_get_thread_name_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        186 => wire__crate__api__events__LogEntry_auto_accessor_get_timestamp_impl(
+        202 => wire__crate__api__events__LogEntry_auto_accessor_get_timestamp_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        187 => wire__crate__api__events__LogEntry_auto_accessor_get_ts_impl(
+        203 => wire__crate__api__events__LogEntry_auto_accessor_get_ts_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        188 => wire__crate__api__events__LogEntry_auto_accessor_set_extra_impl(
+        204 => wire__crate__api__events__LogEntry_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        189 => wire__crate__api__events__LogEntry_auto_accessor_set_fields_impl(
+        205 => wire__crate__api__events__LogEntry_auto_accessor_set_fields_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        190 => wire__crate__api__events__LogEntry_auto_accessor_set_level_impl(
+        206 => wire__crate__api__events__LogEntry_auto_accessor_set_level_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        191 => wire__crate__api__events__LogEntry_auto_accessor_set_message_impl(
+        207 => wire__crate__api__events__LogEntry_auto_accessor_set_message_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        192 => wire__crate__api__events__LogEntry_auto_accessor_set_span_impl(
+        208 => wire__crate__api__events__LogEntry_auto_accessor_set_span_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        193 => wire__crate__api__events__LogEntry_auto_accessor_set_spans_impl(
+        209 => wire__crate__api__events__LogEntry_auto_accessor_set_spans_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        194 => wire__crate__api__events__LogEntry_auto_accessor_set_target_impl(
+        210 => wire__crate__api__events__LogEntry_auto_accessor_set_target_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        195 => wire__crate__api__events__LogEntry_auto_accessor_set_thread_id_impl(
+        211 => wire__crate__api__events__LogEntry_auto_accessor_set_thread_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        196 => wire__crate__api__events__LogEntry_auto_accessor_set_thread_name_impl(
+        212 => wire__crate__api__events__LogEntry_auto_accessor_set_thread_name_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        197 => wire__crate__api__events__LogEntry_auto_accessor_set_timestamp_impl(
+        213 => wire__crate__api__events__LogEntry_auto_accessor_set_timestamp_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        198 => wire__crate__api__events__LogEntry_auto_accessor_set_ts_impl(
+        214 => wire__crate__api__events__LogEntry_auto_accessor_set_ts_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        200 => wire__crate__api__events__LogsEvent_auto_accessor_get_entries_impl(
+        216 => wire__crate__api__events__LogsEvent_auto_accessor_get_entries_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        201 => wire__crate__api__events__LogsEvent_auto_accessor_get_entry_impl(
+        217 => wire__crate__api__events__LogsEvent_auto_accessor_get_entry_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        202 => wire__crate__api__events__LogsEvent_auto_accessor_get_extra_impl(
+        218 => wire__crate__api__events__LogsEvent_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        203 => wire__crate__api__events__LogsEvent_auto_accessor_get_subscription_id_impl(
+        219 => wire__crate__api__events__LogsEvent_auto_accessor_get_subscription_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        204 => wire__crate__api__events__LogsEvent_auto_accessor_set_entries_impl(
+        220 => wire__crate__api__events__LogsEvent_auto_accessor_set_entries_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        205 => wire__crate__api__events__LogsEvent_auto_accessor_set_entry_impl(
+        221 => wire__crate__api__events__LogsEvent_auto_accessor_set_entry_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        206 => wire__crate__api__events__LogsEvent_auto_accessor_set_extra_impl(
+        222 => wire__crate__api__events__LogsEvent_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        207 => wire__crate__api__events__LogsEvent_auto_accessor_set_subscription_id_impl(
+        223 => wire__crate__api__events__LogsEvent_auto_accessor_set_subscription_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        209 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_extra_impl(
+        225 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        210 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_filters_impl(
+        226 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_filters_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        211 => {
+        227 => {
             wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_include_internal_impl(
                 ptr,
                 rust_vec_len,
                 data_len,
             )
         }
-        212 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_level_impl(
+        228 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_level_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        213 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_since_impl(
+        229 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_since_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        214 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_tail_impl(
+        230 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_get_tail_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        215 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_extra_impl(
+        231 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        216 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_filters_impl(
+        232 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_filters_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        217 => {
+        233 => {
             wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_include_internal_impl(
                 ptr,
                 rust_vec_len,
                 data_len,
             )
         }
-        218 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_level_impl(
+        234 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_level_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        219 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_since_impl(
+        235 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_since_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        220 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_tail_impl(
+        236 => wire__crate__api__events__LogsSubscribeParams_auto_accessor_set_tail_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        222 => wire__crate__api__events__LogsUnsubscribeParams_auto_accessor_get_extra_impl(
+        238 => wire__crate__api__events__LogsUnsubscribeParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        223 => {
+        239 => {
             wire__crate__api__events__LogsUnsubscribeParams_auto_accessor_get_subscription_id_impl(
                 ptr,
                 rust_vec_len,
                 data_len,
             )
         }
-        224 => wire__crate__api__events__LogsUnsubscribeParams_auto_accessor_set_extra_impl(
+        240 => wire__crate__api__events__LogsUnsubscribeParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        225 => {
+        241 => {
             wire__crate__api__events__LogsUnsubscribeParams_auto_accessor_set_subscription_id_impl(
                 ptr,
                 rust_vec_len,
                 data_len,
             )
         }
-        227 => wire__crate__api__events__PresenceEntry_auto_accessor_get_device_family_impl(
+        243 => wire__crate__api__events__PresenceEntry_auto_accessor_get_device_family_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        228 => wire__crate__api__events__PresenceEntry_auto_accessor_get_extra_impl(
+        244 => wire__crate__api__events__PresenceEntry_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        229 => wire__crate__api__events__PresenceEntry_auto_accessor_get_host_impl(
+        245 => wire__crate__api__events__PresenceEntry_auto_accessor_get_host_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        230 => wire__crate__api__events__PresenceEntry_auto_accessor_get_instance_id_impl(
+        246 => wire__crate__api__events__PresenceEntry_auto_accessor_get_instance_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        231 => wire__crate__api__events__PresenceEntry_auto_accessor_get_ip_impl(
+        247 => wire__crate__api__events__PresenceEntry_auto_accessor_get_ip_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        232 => wire__crate__api__events__PresenceEntry_auto_accessor_get_last_input_seconds_impl(
+        248 => wire__crate__api__events__PresenceEntry_auto_accessor_get_last_input_seconds_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        233 => wire__crate__api__events__PresenceEntry_auto_accessor_get_mode_impl(
+        249 => wire__crate__api__events__PresenceEntry_auto_accessor_get_mode_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        234 => wire__crate__api__events__PresenceEntry_auto_accessor_get_model_identifier_impl(
+        250 => wire__crate__api__events__PresenceEntry_auto_accessor_get_model_identifier_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        235 => wire__crate__api__events__PresenceEntry_auto_accessor_get_reason_impl(
+        251 => wire__crate__api__events__PresenceEntry_auto_accessor_get_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        236 => wire__crate__api__events__PresenceEntry_auto_accessor_get_tags_impl(
+        252 => wire__crate__api__events__PresenceEntry_auto_accessor_get_tags_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        237 => wire__crate__api__events__PresenceEntry_auto_accessor_get_ts_impl(
+        253 => wire__crate__api__events__PresenceEntry_auto_accessor_get_ts_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        238 => wire__crate__api__events__PresenceEntry_auto_accessor_get_version_impl(
+        254 => wire__crate__api__events__PresenceEntry_auto_accessor_get_version_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        239 => wire__crate__api__events__PresenceEntry_auto_accessor_set_device_family_impl(
+        255 => wire__crate__api__events__PresenceEntry_auto_accessor_set_device_family_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        240 => wire__crate__api__events__PresenceEntry_auto_accessor_set_extra_impl(
+        256 => wire__crate__api__events__PresenceEntry_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        241 => wire__crate__api__events__PresenceEntry_auto_accessor_set_host_impl(
+        257 => wire__crate__api__events__PresenceEntry_auto_accessor_set_host_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        242 => wire__crate__api__events__PresenceEntry_auto_accessor_set_instance_id_impl(
+        258 => wire__crate__api__events__PresenceEntry_auto_accessor_set_instance_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        243 => wire__crate__api__events__PresenceEntry_auto_accessor_set_ip_impl(
+        259 => wire__crate__api__events__PresenceEntry_auto_accessor_set_ip_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        244 => wire__crate__api__events__PresenceEntry_auto_accessor_set_last_input_seconds_impl(
+        260 => wire__crate__api__events__PresenceEntry_auto_accessor_set_last_input_seconds_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        245 => wire__crate__api__events__PresenceEntry_auto_accessor_set_mode_impl(
+        261 => wire__crate__api__events__PresenceEntry_auto_accessor_set_mode_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        246 => wire__crate__api__events__PresenceEntry_auto_accessor_set_model_identifier_impl(
+        262 => wire__crate__api__events__PresenceEntry_auto_accessor_set_model_identifier_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        247 => wire__crate__api__events__PresenceEntry_auto_accessor_set_reason_impl(
+        263 => wire__crate__api__events__PresenceEntry_auto_accessor_set_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        248 => wire__crate__api__events__PresenceEntry_auto_accessor_set_tags_impl(
+        264 => wire__crate__api__events__PresenceEntry_auto_accessor_set_tags_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        249 => wire__crate__api__events__PresenceEntry_auto_accessor_set_ts_impl(
+        265 => wire__crate__api__events__PresenceEntry_auto_accessor_set_ts_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        250 => wire__crate__api__events__PresenceEntry_auto_accessor_set_version_impl(
+        266 => wire__crate__api__events__PresenceEntry_auto_accessor_set_version_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        251 => wire__crate__api__events__PresenceEvent_auto_accessor_get_entries_impl(
+        267 => wire__crate__api__events__PresenceEvent_auto_accessor_get_entries_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        252 => wire__crate__api__events__PresenceEvent_auto_accessor_get_extra_impl(
+        268 => wire__crate__api__events__PresenceEvent_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        253 => wire__crate__api__events__PresenceEvent_auto_accessor_set_entries_impl(
+        269 => wire__crate__api__events__PresenceEvent_auto_accessor_set_entries_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        254 => wire__crate__api__events__PresenceEvent_auto_accessor_set_extra_impl(
+        270 => wire__crate__api__events__PresenceEvent_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        255 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_archive_impl(
+        271 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_archive_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        256 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_extra_impl(
+        272 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        257 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_metadata_impl(
+        273 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_metadata_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        258 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_reason_impl(
+        274 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        259 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_session_key_impl(
+        275 => wire__crate__api__events__SessionsCloseParams_auto_accessor_get_session_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        260 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_archive_impl(
+        276 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_archive_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        261 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_extra_impl(
+        277 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        262 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_metadata_impl(
+        278 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_metadata_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        263 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_reason_impl(
+        279 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        264 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_session_key_impl(
+        280 => wire__crate__api__events__SessionsCloseParams_auto_accessor_set_session_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        266 => wire__crate__api__events__SessionsListParams_auto_accessor_get_cursor_impl(
+        282 => wire__crate__api__events__SessionsListParams_auto_accessor_get_cursor_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        267 => wire__crate__api__events__SessionsListParams_auto_accessor_get_extra_impl(
+        283 => wire__crate__api__events__SessionsListParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        268 => wire__crate__api__events__SessionsListParams_auto_accessor_get_limit_impl(
+        284 => wire__crate__api__events__SessionsListParams_auto_accessor_get_limit_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        269 => wire__crate__api__events__SessionsListParams_auto_accessor_set_cursor_impl(
+        285 => wire__crate__api__events__SessionsListParams_auto_accessor_set_cursor_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        270 => wire__crate__api__events__SessionsListParams_auto_accessor_set_extra_impl(
+        286 => wire__crate__api__events__SessionsListParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        271 => wire__crate__api__events__SessionsListParams_auto_accessor_set_limit_impl(
+        287 => wire__crate__api__events__SessionsListParams_auto_accessor_set_limit_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        273 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_get_extra_impl(
+        289 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        274 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_get_label_impl(
+        290 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_get_label_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        275 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_get_metadata_impl(
+        291 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_get_metadata_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        276 => {
+        292 => {
             wire__crate__api__events__SessionsSpawnParams_auto_accessor_get_parent_session_key_impl(
                 ptr,
                 rust_vec_len,
                 data_len,
             )
         }
-        277 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_set_extra_impl(
+        293 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        278 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_set_label_impl(
+        294 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_set_label_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        279 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_set_metadata_impl(
+        295 => wire__crate__api__events__SessionsSpawnParams_auto_accessor_set_metadata_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        280 => {
+        296 => {
             wire__crate__api__events__SessionsSpawnParams_auto_accessor_set_parent_session_key_impl(
                 ptr,
                 rust_vec_len,
                 data_len,
             )
         }
-        282 => wire__crate__api__events__ShutdownEvent_auto_accessor_get_extra_impl(
+        298 => wire__crate__api__events__ShutdownEvent_auto_accessor_get_extra_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        299 => wire__crate__api__events__ShutdownEvent_auto_accessor_get_reason_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        300 => wire__crate__api__events__ShutdownEvent_auto_accessor_get_restart_expected_ms_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        301 => wire__crate__api__events__ShutdownEvent_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        283 => wire__crate__api__events__ShutdownEvent_auto_accessor_get_reason_impl(
+        302 => wire__crate__api__events__ShutdownEvent_auto_accessor_set_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        284 => wire__crate__api__events__ShutdownEvent_auto_accessor_get_restart_expected_ms_impl(
+        303 => wire__crate__api__events__ShutdownEvent_auto_accessor_set_restart_expected_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        285 => wire__crate__api__events__ShutdownEvent_auto_accessor_set_extra_impl(
+        304 => wire__crate__api__events__StreamCloseParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        286 => wire__crate__api__events__ShutdownEvent_auto_accessor_set_reason_impl(
+        305 => wire__crate__api__events__StreamCloseParams_auto_accessor_get_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        287 => wire__crate__api__events__ShutdownEvent_auto_accessor_set_restart_expected_ms_impl(
+        306 => wire__crate__api__events__StreamCloseParams_auto_accessor_get_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        288 => wire__crate__api__events__StreamCloseParams_auto_accessor_get_extra_impl(
+        307 => wire__crate__api__events__StreamCloseParams_auto_accessor_get_stream_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        289 => wire__crate__api__events__StreamCloseParams_auto_accessor_get_reason_impl(
+        308 => wire__crate__api__events__StreamCloseParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        290 => wire__crate__api__events__StreamCloseParams_auto_accessor_get_stream_impl(
+        309 => wire__crate__api__events__StreamCloseParams_auto_accessor_set_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        291 => wire__crate__api__events__StreamCloseParams_auto_accessor_get_stream_id_impl(
+        310 => wire__crate__api__events__StreamCloseParams_auto_accessor_set_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        292 => wire__crate__api__events__StreamCloseParams_auto_accessor_set_extra_impl(
+        311 => wire__crate__api__events__StreamCloseParams_auto_accessor_set_stream_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        293 => wire__crate__api__events__StreamCloseParams_auto_accessor_set_reason_impl(
+        313 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_exit_code_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        294 => wire__crate__api__events__StreamCloseParams_auto_accessor_set_stream_impl(
+        314 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        295 => wire__crate__api__events__StreamCloseParams_auto_accessor_set_stream_id_impl(
+        315 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        297 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_exit_code_impl(
+        316 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        298 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_extra_impl(
+        317 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_stream_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        299 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_reason_impl(
+        318 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_exit_code_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        300 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_stream_impl(
+        319 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        301 => wire__crate__api__events__StreamClosedEvent_auto_accessor_get_stream_id_impl(
+        320 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_reason_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        302 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_exit_code_impl(
+        321 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        303 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_extra_impl(
+        322 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_stream_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        304 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_reason_impl(
+        324 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_channel_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        305 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_stream_impl(
+        325 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_cols_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        306 => wire__crate__api__events__StreamClosedEvent_auto_accessor_set_stream_id_impl(
+        326 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_data_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        308 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_channel_impl(
+        327 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_encoding_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        309 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_cols_impl(
+        328 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        310 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_data_impl(
+        329 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_rows_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        311 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_encoding_impl(
+        330 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        312 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_extra_impl(
+        331 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_stream_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        313 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_rows_impl(
+        332 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_timestamp_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        314 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_stream_impl(
+        333 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_channel_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        315 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_stream_id_impl(
+        334 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_cols_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        316 => wire__crate__api__events__StreamDataEvent_auto_accessor_get_timestamp_ms_impl(
+        335 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_data_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        317 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_channel_impl(
+        336 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_encoding_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        318 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_cols_impl(
+        337 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        319 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_data_impl(
+        338 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_rows_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        320 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_encoding_impl(
+        339 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        321 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_extra_impl(
+        340 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_stream_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        322 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_rows_impl(
+        341 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_timestamp_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        323 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_stream_impl(
+        343 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_cols_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        324 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_stream_id_impl(
+        344 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        325 => wire__crate__api__events__StreamDataEvent_auto_accessor_set_timestamp_ms_impl(
+        345 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_metadata_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        327 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_cols_impl(
+        346 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_rows_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        328 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_extra_impl(
+        347 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        329 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_metadata_impl(
+        348 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_term_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        330 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_rows_impl(
+        349 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_cols_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        331 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_stream_impl(
+        350 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        332 => wire__crate__api__events__StreamOpenParams_auto_accessor_get_term_impl(
+        351 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_metadata_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        333 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_cols_impl(
+        352 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_rows_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        334 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_extra_impl(
+        353 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        335 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_metadata_impl(
+        354 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_term_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        336 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_rows_impl(
+        356 => wire__crate__api__events__StreamSendParams_auto_accessor_get_channel_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        337 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_stream_impl(
+        357 => wire__crate__api__events__StreamSendParams_auto_accessor_get_cols_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        338 => wire__crate__api__events__StreamOpenParams_auto_accessor_set_term_impl(
+        358 => wire__crate__api__events__StreamSendParams_auto_accessor_get_data_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        340 => wire__crate__api__events__StreamSendParams_auto_accessor_get_channel_impl(
+        359 => wire__crate__api__events__StreamSendParams_auto_accessor_get_encoding_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        341 => wire__crate__api__events__StreamSendParams_auto_accessor_get_cols_impl(
+        360 => wire__crate__api__events__StreamSendParams_auto_accessor_get_event_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        342 => wire__crate__api__events__StreamSendParams_auto_accessor_get_data_impl(
+        361 => wire__crate__api__events__StreamSendParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        343 => wire__crate__api__events__StreamSendParams_auto_accessor_get_encoding_impl(
+        362 => wire__crate__api__events__StreamSendParams_auto_accessor_get_rows_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        344 => wire__crate__api__events__StreamSendParams_auto_accessor_get_event_impl(
+        363 => wire__crate__api__events__StreamSendParams_auto_accessor_get_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        345 => wire__crate__api__events__StreamSendParams_auto_accessor_get_extra_impl(
+        364 => wire__crate__api__events__StreamSendParams_auto_accessor_get_stream_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        346 => wire__crate__api__events__StreamSendParams_auto_accessor_get_rows_impl(
+        365 => wire__crate__api__events__StreamSendParams_auto_accessor_set_channel_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        347 => wire__crate__api__events__StreamSendParams_auto_accessor_get_stream_impl(
+        366 => wire__crate__api__events__StreamSendParams_auto_accessor_set_cols_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        348 => wire__crate__api__events__StreamSendParams_auto_accessor_get_stream_id_impl(
+        367 => wire__crate__api__events__StreamSendParams_auto_accessor_set_data_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        349 => wire__crate__api__events__StreamSendParams_auto_accessor_set_channel_impl(
+        368 => wire__crate__api__events__StreamSendParams_auto_accessor_set_encoding_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        350 => wire__crate__api__events__StreamSendParams_auto_accessor_set_cols_impl(
+        369 => wire__crate__api__events__StreamSendParams_auto_accessor_set_event_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        351 => wire__crate__api__events__StreamSendParams_auto_accessor_set_data_impl(
+        370 => wire__crate__api__events__StreamSendParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        352 => wire__crate__api__events__StreamSendParams_auto_accessor_set_encoding_impl(
+        371 => wire__crate__api__events__StreamSendParams_auto_accessor_set_rows_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        353 => wire__crate__api__events__StreamSendParams_auto_accessor_set_event_impl(
+        372 => wire__crate__api__events__StreamSendParams_auto_accessor_set_stream_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        354 => wire__crate__api__events__StreamSendParams_auto_accessor_set_extra_impl(
+        373 => wire__crate__api__events__StreamSendParams_auto_accessor_set_stream_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        355 => wire__crate__api__events__StreamSendParams_auto_accessor_set_rows_impl(
+        375 => wire__crate__api__events__SystemEvent_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        356 => wire__crate__api__events__StreamSendParams_auto_accessor_set_stream_impl(
+        376 => wire__crate__api__events__SystemEvent_auto_accessor_get_mode_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        357 => wire__crate__api__events__StreamSendParams_auto_accessor_set_stream_id_impl(
+        377 => wire__crate__api__events__SystemEvent_auto_accessor_get_session_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        359 => wire__crate__api__events__SystemEvent_auto_accessor_get_extra_impl(
+        378 => wire__crate__api__events__SystemEvent_auto_accessor_get_text_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        360 => wire__crate__api__events__SystemEvent_auto_accessor_get_mode_impl(
+        379 => wire__crate__api__events__SystemEvent_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        361 => wire__crate__api__events__SystemEvent_auto_accessor_get_session_key_impl(
+        380 => wire__crate__api__events__SystemEvent_auto_accessor_set_mode_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        362 => wire__crate__api__events__SystemEvent_auto_accessor_get_text_impl(
+        381 => wire__crate__api__events__SystemEvent_auto_accessor_set_session_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        363 => wire__crate__api__events__SystemEvent_auto_accessor_set_extra_impl(
+        382 => wire__crate__api__events__SystemEvent_auto_accessor_set_text_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        364 => wire__crate__api__events__SystemEvent_auto_accessor_set_mode_impl(
+        383 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_details_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        365 => wire__crate__api__events__SystemEvent_auto_accessor_set_session_key_impl(
+        384 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        366 => wire__crate__api__events__SystemEvent_auto_accessor_set_text_impl(
+        385 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_latency_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        367 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_details_impl(
+        386 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_message_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        368 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_extra_impl(
+        387 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_ok_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        369 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_latency_ms_impl(
+        388 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_details_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        370 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_message_impl(
+        389 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        371 => wire__crate__api__events__SystemProbeCheck_auto_accessor_get_ok_impl(
+        390 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_latency_ms_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        372 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_details_impl(
+        391 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_message_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        373 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_extra_impl(
+        392 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_ok_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        374 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_latency_ms_impl(
+        394 => wire__crate__api__events__SystemProbeParams_auto_accessor_get_disk_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        375 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_message_impl(
+        395 => wire__crate__api__events__SystemProbeParams_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        376 => wire__crate__api__events__SystemProbeCheck_auto_accessor_set_ok_impl(
+        396 => wire__crate__api__events__SystemProbeParams_auto_accessor_get_gateway_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        378 => wire__crate__api__events__SystemProbeParams_auto_accessor_get_disk_impl(
+        397 => wire__crate__api__events__SystemProbeParams_auto_accessor_get_network_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        379 => wire__crate__api__events__SystemProbeParams_auto_accessor_get_extra_impl(
+        398 => wire__crate__api__events__SystemProbeParams_auto_accessor_set_disk_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        380 => wire__crate__api__events__SystemProbeParams_auto_accessor_get_gateway_impl(
+        399 => wire__crate__api__events__SystemProbeParams_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        381 => wire__crate__api__events__SystemProbeParams_auto_accessor_get_network_impl(
+        400 => wire__crate__api__events__SystemProbeParams_auto_accessor_set_gateway_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        382 => wire__crate__api__events__SystemProbeParams_auto_accessor_set_disk_impl(
+        401 => wire__crate__api__events__SystemProbeParams_auto_accessor_set_network_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        383 => wire__crate__api__events__SystemProbeParams_auto_accessor_set_extra_impl(
+        403 => wire__crate__api__events__SystemProbeResult_auto_accessor_get_disk_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        384 => wire__crate__api__events__SystemProbeParams_auto_accessor_set_gateway_impl(
+        404 => wire__crate__api__events__SystemProbeResult_auto_accessor_get_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        385 => wire__crate__api__events__SystemProbeParams_auto_accessor_set_network_impl(
+        405 => wire__crate__api__events__SystemProbeResult_auto_accessor_get_gateway_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        387 => wire__crate__api__events__SystemProbeResult_auto_accessor_get_disk_impl(
+        406 => wire__crate__api__events__SystemProbeResult_auto_accessor_get_network_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        388 => wire__crate__api__events__SystemProbeResult_auto_accessor_get_extra_impl(
+        407 => wire__crate__api__events__SystemProbeResult_auto_accessor_set_disk_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        389 => wire__crate__api__events__SystemProbeResult_auto_accessor_get_gateway_impl(
+        408 => wire__crate__api__events__SystemProbeResult_auto_accessor_set_extra_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        390 => wire__crate__api__events__SystemProbeResult_auto_accessor_get_network_impl(
+        409 => wire__crate__api__events__SystemProbeResult_auto_accessor_set_gateway_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        391 => wire__crate__api__events__SystemProbeResult_auto_accessor_set_disk_impl(
+        410 => wire__crate__api__events__SystemProbeResult_auto_accessor_set_network_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        392 => wire__crate__api__events__SystemProbeResult_auto_accessor_set_extra_impl(
+        412 => wire__crate__api__terminal_emulator__TerminalEmulator_feed_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        393 => wire__crate__api__events__SystemProbeResult_auto_accessor_set_gateway_impl(
+        413 => wire__crate__api__terminal_emulator__TerminalEmulator_new_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        394 => wire__crate__api__events__SystemProbeResult_auto_accessor_set_network_impl(
+        414 => wire__crate__api__terminal_emulator__TerminalEmulator_resize_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        403 => {
+        416 => wire__crate__api__terminal_emulator__TerminalEmulator_scrollback_lines_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        417 => wire__crate__api__terminal_emulator__TerminalEmulator_snapshot_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        431 => {
             wire__crate__api__connection__gateway_client_get_url_impl(ptr, rust_vec_len, data_len)
         }
-        411 => wire__crate__api__connection__gateway_client_next_request_id_impl(
+        439 => wire__crate__api__connection__gateway_client_next_request_id_impl(
             ptr,
             rust_vec_len,
             data_len,
@@ -23473,679 +26093,1264 @@ fn pde_ffi_dispatcher_sync_impl(
     }
 }
 
-// Section: rust2dart
-
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<AgentTurn> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AgentTurn> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AgentTurn>> for AgentTurn {
+    fn into_into_dart(self) -> FrbWrapper<AgentTurn> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<BTreeMap<String, Value>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<BTreeMap<String, Value>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<BTreeMap<String, Value>>>
+    for BTreeMap<String, Value>
+{
+    fn into_into_dart(self) -> FrbWrapper<BTreeMap<String, Value>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<BTreeMap<String, bool>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<BTreeMap<String, bool>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<BTreeMap<String, bool>>>
+    for BTreeMap<String, bool>
+{
+    fn into_into_dart(self) -> FrbWrapper<BTreeMap<String, bool>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<CallbackTokenStore> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<CallbackTokenStore>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<CallbackTokenStore>> for CallbackTokenStore {
+    fn into_into_dart(self) -> FrbWrapper<CallbackTokenStore> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<CameraSnapshot> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<CameraSnapshot> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<CameraSnapshot>> for CameraSnapshot {
+    fn into_into_dart(self) -> FrbWrapper<CameraSnapshot> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectAuth> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectAuth> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectAuth>> for ConnectAuth {
+    fn into_into_dart(self) -> FrbWrapper<ConnectAuth> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectChallenge> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectChallenge> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectChallenge>> for ConnectChallenge {
+    fn into_into_dart(self) -> FrbWrapper<ConnectChallenge> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectClient> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectClient> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectClient>> for ConnectClient {
+    fn into_into_dart(self) -> FrbWrapper<ConnectClient> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectDevice> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectDevice> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectDevice>> for ConnectDevice {
+    fn into_into_dart(self) -> FrbWrapper<ConnectDevice> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectParams> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectParams> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectParams>> for ConnectParams {
+    fn into_into_dart(self) -> FrbWrapper<ConnectParams> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<DeviceIdentity> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<DeviceIdentity> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<DeviceIdentity>> for DeviceIdentity {
+    fn into_into_dart(self) -> FrbWrapper<DeviceIdentity> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ExecOutput> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ExecOutput> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ExecOutput>> for ExecOutput {
+    fn into_into_dart(self) -> FrbWrapper<ExecOutput> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ExecParams> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ExecParams> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ExecParams>> for ExecParams {
+    fn into_into_dart(self) -> FrbWrapper<ExecParams> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayError> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<GatewayError> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayError>> for GatewayError {
+    fn into_into_dart(self) -> FrbWrapper<GatewayError> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayEvent> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<GatewayEvent> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayEvent>> for GatewayEvent {
+    fn into_into_dart(self) -> FrbWrapper<GatewayEvent> {
+        self.into()
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<AgentTurn> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayEventPayload> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AgentTurn> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<GatewayEventPayload>
+{
+}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AgentTurn>> for AgentTurn {
-    fn into_into_dart(self) -> FrbWrapper<AgentTurn> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayEventPayload>> for GatewayEventPayload {
+    fn into_into_dart(self) -> FrbWrapper<GatewayEventPayload> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<BTreeMap<String, Value>> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayRequestFrame> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<BTreeMap<String, Value>>
+    for FrbWrapper<GatewayRequestFrame>
 {
 }
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<BTreeMap<String, Value>>>
-    for BTreeMap<String, Value>
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayRequestFrame>> for GatewayRequestFrame {
+    fn into_into_dart(self) -> FrbWrapper<GatewayRequestFrame> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayRequestParams> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<GatewayRequestParams>
 {
-    fn into_into_dart(self) -> FrbWrapper<BTreeMap<String, Value>> {
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayRequestParams>> for GatewayRequestParams {
+    fn into_into_dart(self) -> FrbWrapper<GatewayRequestParams> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<BTreeMap<String, bool>> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayResponsePayload> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<BTreeMap<String, bool>>
+    for FrbWrapper<GatewayResponsePayload>
 {
 }
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<BTreeMap<String, bool>>>
-    for BTreeMap<String, bool>
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayResponsePayload>>
+    for GatewayResponsePayload
 {
-    fn into_into_dart(self) -> FrbWrapper<BTreeMap<String, bool>> {
+    fn into_into_dart(self) -> FrbWrapper<GatewayResponsePayload> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<CameraSnapshot> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<HelloAuth> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<CameraSnapshot> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<HelloAuth> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<CameraSnapshot>> for CameraSnapshot {
-    fn into_into_dart(self) -> FrbWrapper<CameraSnapshot> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<HelloAuth>> for HelloAuth {
+    fn into_into_dart(self) -> FrbWrapper<HelloAuth> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectAuth> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<HelloOk> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectAuth> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<HelloOk> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectAuth>> for ConnectAuth {
-    fn into_into_dart(self) -> FrbWrapper<ConnectAuth> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<HelloOk>> for HelloOk {
+    fn into_into_dart(self) -> FrbWrapper<HelloOk> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectChallenge> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<HelloPolicy> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectChallenge> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<HelloPolicy> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectChallenge>> for ConnectChallenge {
-    fn into_into_dart(self) -> FrbWrapper<ConnectChallenge> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<HelloPolicy>> for HelloPolicy {
+    fn into_into_dart(self) -> FrbWrapper<HelloPolicy> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectClient> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<LogEntry> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectClient> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<LogEntry> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectClient>> for ConnectClient {
-    fn into_into_dart(self) -> FrbWrapper<ConnectClient> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LogEntry>> for LogEntry {
+    fn into_into_dart(self) -> FrbWrapper<LogEntry> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectDevice> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<LogsEvent> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectDevice> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<LogsEvent> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectDevice>> for ConnectDevice {
-    fn into_into_dart(self) -> FrbWrapper<ConnectDevice> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LogsEvent>> for LogsEvent {
+    fn into_into_dart(self) -> FrbWrapper<LogsEvent> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ConnectParams> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<LogsSubscribeParams> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ConnectParams> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<LogsSubscribeParams>
+{
+}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ConnectParams>> for ConnectParams {
-    fn into_into_dart(self) -> FrbWrapper<ConnectParams> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LogsSubscribeParams>> for LogsSubscribeParams {
+    fn into_into_dart(self) -> FrbWrapper<LogsSubscribeParams> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ExecOutput> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<LogsUnsubscribeParams> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ExecOutput> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<LogsUnsubscribeParams>
+{
+}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ExecOutput>> for ExecOutput {
-    fn into_into_dart(self) -> FrbWrapper<ExecOutput> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LogsUnsubscribeParams>>
+    for LogsUnsubscribeParams
+{
+    fn into_into_dart(self) -> FrbWrapper<LogsUnsubscribeParams> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ExecParams> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<MediaEvent> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ExecParams> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<MediaEvent> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ExecParams>> for ExecParams {
-    fn into_into_dart(self) -> FrbWrapper<ExecParams> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MediaEvent>> for MediaEvent {
+    fn into_into_dart(self) -> FrbWrapper<MediaEvent> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayError> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<PresenceEntry> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<GatewayError> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PresenceEntry> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayError>> for GatewayError {
-    fn into_into_dart(self) -> FrbWrapper<GatewayError> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PresenceEntry>> for PresenceEntry {
+    fn into_into_dart(self) -> FrbWrapper<PresenceEntry> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayEvent> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<PresenceEvent> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<GatewayEvent> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PresenceEvent> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayEvent>> for GatewayEvent {
-    fn into_into_dart(self) -> FrbWrapper<GatewayEvent> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PresenceEvent>> for PresenceEvent {
+    fn into_into_dart(self) -> FrbWrapper<PresenceEvent> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayEventPayload> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SessionsCloseParams> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<GatewayEventPayload>
+    for FrbWrapper<SessionsCloseParams>
 {
 }
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayEventPayload>> for GatewayEventPayload {
-    fn into_into_dart(self) -> FrbWrapper<GatewayEventPayload> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SessionsCloseParams>> for SessionsCloseParams {
+    fn into_into_dart(self) -> FrbWrapper<SessionsCloseParams> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayRequestFrame> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SessionsListParams> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<GatewayRequestFrame>
+    for FrbWrapper<SessionsListParams>
 {
 }
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayRequestFrame>> for GatewayRequestFrame {
-    fn into_into_dart(self) -> FrbWrapper<GatewayRequestFrame> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SessionsListParams>> for SessionsListParams {
+    fn into_into_dart(self) -> FrbWrapper<SessionsListParams> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<GatewayRequestParams> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SessionsSpawnParams> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<GatewayRequestParams>
+    for FrbWrapper<SessionsSpawnParams>
 {
 }
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<GatewayRequestParams>> for GatewayRequestParams {
-    fn into_into_dart(self) -> FrbWrapper<GatewayRequestParams> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SessionsSpawnParams>> for SessionsSpawnParams {
+    fn into_into_dart(self) -> FrbWrapper<SessionsSpawnParams> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ShutdownEvent> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ShutdownEvent> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ShutdownEvent>> for ShutdownEvent {
+    fn into_into_dart(self) -> FrbWrapper<ShutdownEvent> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamCloseParams> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamCloseParams> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamCloseParams>> for StreamCloseParams {
+    fn into_into_dart(self) -> FrbWrapper<StreamCloseParams> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamClosedEvent> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamClosedEvent> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamClosedEvent>> for StreamClosedEvent {
+    fn into_into_dart(self) -> FrbWrapper<StreamClosedEvent> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamDataEvent> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamDataEvent> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamDataEvent>> for StreamDataEvent {
+    fn into_into_dart(self) -> FrbWrapper<StreamDataEvent> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamOpenParams> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamOpenParams> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamOpenParams>> for StreamOpenParams {
+    fn into_into_dart(self) -> FrbWrapper<StreamOpenParams> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamSendParams> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamSendParams> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamSendParams>> for StreamSendParams {
+    fn into_into_dart(self) -> FrbWrapper<StreamSendParams> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SystemEvent> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SystemEvent> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SystemEvent>> for SystemEvent {
+    fn into_into_dart(self) -> FrbWrapper<SystemEvent> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<HelloAuth> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SystemProbeCheck> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<HelloAuth> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SystemProbeCheck> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<HelloAuth>> for HelloAuth {
-    fn into_into_dart(self) -> FrbWrapper<HelloAuth> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SystemProbeCheck>> for SystemProbeCheck {
+    fn into_into_dart(self) -> FrbWrapper<SystemProbeCheck> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<HelloOk> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SystemProbeParams> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<HelloOk> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SystemProbeParams> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<HelloOk>> for HelloOk {
-    fn into_into_dart(self) -> FrbWrapper<HelloOk> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SystemProbeParams>> for SystemProbeParams {
+    fn into_into_dart(self) -> FrbWrapper<SystemProbeParams> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<HelloPolicy> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SystemProbeResult> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<HelloPolicy> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SystemProbeResult> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<HelloPolicy>> for HelloPolicy {
-    fn into_into_dart(self) -> FrbWrapper<HelloPolicy> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SystemProbeResult>> for SystemProbeResult {
+    fn into_into_dart(self) -> FrbWrapper<SystemProbeResult> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<LogEntry> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<TerminalEmulator> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<LogEntry> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<TerminalEmulator> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LogEntry>> for LogEntry {
-    fn into_into_dart(self) -> FrbWrapper<LogEntry> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<TerminalEmulator>> for TerminalEmulator {
+    fn into_into_dart(self) -> FrbWrapper<TerminalEmulator> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<LogsEvent> {
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Value> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
             .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<LogsEvent> {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Value> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LogsEvent>> for LogsEvent {
-    fn into_into_dart(self) -> FrbWrapper<LogsEvent> {
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Value>> for Value {
+    fn into_into_dart(self) -> FrbWrapper<Value> {
         self.into()
     }
 }
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<LogsSubscribeParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::backpressure::BackpressurePolicy {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            Self::Block => 0.into_dart(),
+            Self::DropOldest => 1.into_dart(),
+            Self::Coalesce => 2.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<LogsSubscribeParams>
+    for crate::api::backpressure::BackpressurePolicy
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LogsSubscribeParams>> for LogsSubscribeParams {
-    fn into_into_dart(self) -> FrbWrapper<LogsSubscribeParams> {
-        self.into()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::backpressure::BackpressurePolicy>
+    for crate::api::backpressure::BackpressurePolicy
+{
+    fn into_into_dart(self) -> crate::api::backpressure::BackpressurePolicy {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<LogsUnsubscribeParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::backpressure::BackpressureStats {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.queued.into_into_dart().into_dart(),
+            self.paused.into_into_dart().into_dart(),
+            self.dropped_logs.into_into_dart().into_dart(),
+            self.dropped_output.into_into_dart().into_dart(),
+            self.dropped_events.into_into_dart().into_dart(),
+            self.coalesced.into_into_dart().into_dart(),
+            self.lagged.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<LogsUnsubscribeParams>
+    for crate::api::backpressure::BackpressureStats
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<LogsUnsubscribeParams>>
-    for LogsUnsubscribeParams
+impl flutter_rust_bridge::IntoIntoDart<crate::api::backpressure::BackpressureStats>
+    for crate::api::backpressure::BackpressureStats
 {
-    fn into_into_dart(self) -> FrbWrapper<LogsUnsubscribeParams> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::backpressure::BackpressureStats {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<MediaEvent> {
+impl flutter_rust_bridge::IntoDart for crate::api::deflate::CompressionStats {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.negotiated.into_into_dart().into_dart(),
+            self.outgoing_compression_disabled
+                .into_into_dart()
+                .into_dart(),
+            self.sent_bytes.into_into_dart().into_dart(),
+            self.sent_wire_bytes.into_into_dart().into_dart(),
+            self.received_bytes.into_into_dart().into_dart(),
+            self.received_wire_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<MediaEvent> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MediaEvent>> for MediaEvent {
-    fn into_into_dart(self) -> FrbWrapper<MediaEvent> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::deflate::CompressionStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::deflate::CompressionStats>
+    for crate::api::deflate::CompressionStats
+{
+    fn into_into_dart(self) -> crate::api::deflate::CompressionStats {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<PresenceEntry> {
+impl flutter_rust_bridge::IntoDart for crate::api::connection::ConnectionConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.backoff_base.into_into_dart().into_dart(),
+            self.max_backoff.into_into_dart().into_dart(),
+            self.jitter.into_into_dart().into_dart(),
+            self.max_attempts.into_into_dart().into_dart(),
+            self.connect_timeout.into_into_dart().into_dart(),
+            self.heartbeat_interval.into_into_dart().into_dart(),
+            self.heartbeat_timeout.into_into_dart().into_dart(),
+            self.handshake_timeout.into_into_dart().into_dart(),
+            self.max_sessions.into_into_dart().into_dart(),
+            self.restart_probe_interval.into_into_dart().into_dart(),
+            self.restart_probe_window.into_into_dart().into_dart(),
+            self.max_restart_wait.into_into_dart().into_dart(),
+            self.outbox.into_into_dart().into_dart(),
+            self.inbound_capacity.into_into_dart().into_dart(),
+            self.outbound_capacity.into_into_dart().into_dart(),
+            self.logs_policy.into_into_dart().into_dart(),
+            self.output_policy.into_into_dart().into_dart(),
+            self.event_policy.into_into_dart().into_dart(),
+            self.output_batch_window.into_into_dart().into_dart(),
+            self.output_batch_bytes.into_into_dart().into_dart(),
+            self.tls.into_into_dart().into_dart(),
+            self.proxy.into_into_dart().into_dart(),
+            self.upgrade_headers.into_into_dart().into_dart(),
+            self.subprotocols.into_into_dart().into_dart(),
+            self.deflate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PresenceEntry> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PresenceEntry>> for PresenceEntry {
-    fn into_into_dart(self) -> FrbWrapper<PresenceEntry> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::connection::ConnectionConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::connection::ConnectionConfig>
+    for crate::api::connection::ConnectionConfig
+{
+    fn into_into_dart(self) -> crate::api::connection::ConnectionConfig {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<PresenceEvent> {
+impl flutter_rust_bridge::IntoDart for crate::api::connection::ConnectionState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::connection::ConnectionState::Idle => [0.into_dart()].into_dart(),
+            crate::api::connection::ConnectionState::Connecting { attempt } => {
+                [1.into_dart(), attempt.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::connection::ConnectionState::Handshaking => [2.into_dart()].into_dart(),
+            crate::api::connection::ConnectionState::Ready {
+                protocol,
+                role,
+                subprotocol,
+            } => [
+                3.into_dart(),
+                protocol.into_into_dart().into_dart(),
+                role.into_into_dart().into_dart(),
+                subprotocol.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::connection::ConnectionState::Backoff {
+                next_retry_at_ms,
+                reason,
+            } => [
+                4.into_dart(),
+                next_retry_at_ms.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::connection::ConnectionState::Restarting => [5.into_dart()].into_dart(),
+            crate::api::connection::ConnectionState::Failed { fatal, reason } => [
+                6.into_dart(),
+                fatal.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<PresenceEvent> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<PresenceEvent>> for PresenceEvent {
-    fn into_into_dart(self) -> FrbWrapper<PresenceEvent> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::connection::ConnectionState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::connection::ConnectionState>
+    for crate::api::connection::ConnectionState
+{
+    fn into_into_dart(self) -> crate::api::connection::ConnectionState {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SessionsCloseParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::deflate::DeflateConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.client_max_window_bits.into_into_dart().into_dart(),
+            self.server_max_window_bits.into_into_dart().into_dart(),
+            self.client_no_context_takeover.into_into_dart().into_dart(),
+            self.server_no_context_takeover.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<SessionsCloseParams>
+    for crate::api::deflate::DeflateConfig
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SessionsCloseParams>> for SessionsCloseParams {
-    fn into_into_dart(self) -> FrbWrapper<SessionsCloseParams> {
-        self.into()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::deflate::DeflateConfig>
+    for crate::api::deflate::DeflateConfig
+{
+    fn into_into_dart(self) -> crate::api::deflate::DeflateConfig {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SessionsListParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::connection::GatewayClient {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [self.url.into_into_dart().into_dart()].into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<SessionsListParams>
+    for crate::api::connection::GatewayClient
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SessionsListParams>> for SessionsListParams {
-    fn into_into_dart(self) -> FrbWrapper<SessionsListParams> {
-        self.into()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::connection::GatewayClient>
+    for crate::api::connection::GatewayClient
+{
+    fn into_into_dart(self) -> crate::api::connection::GatewayClient {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SessionsSpawnParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::events::GatewayEventKind {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            Self::Connected => 0.into_dart(),
+            Self::Disconnected => 1.into_dart(),
+            Self::Message => 2.into_dart(),
+            Self::Error => 3.into_dart(),
+            Self::Other => 4.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<SessionsSpawnParams>
+    for crate::api::events::GatewayEventKind
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SessionsSpawnParams>> for SessionsSpawnParams {
-    fn into_into_dart(self) -> FrbWrapper<SessionsSpawnParams> {
-        self.into()
+impl flutter_rust_bridge::IntoIntoDart<crate::api::events::GatewayEventKind>
+    for crate::api::events::GatewayEventKind
+{
+    fn into_into_dart(self) -> crate::api::events::GatewayEventKind {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<ShutdownEvent> {
+impl flutter_rust_bridge::IntoDart for crate::api::connection::HandshakeOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.client_id.into_into_dart().into_dart(),
+            self.client_version.into_into_dart().into_dart(),
+            self.platform.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.instance_id.into_into_dart().into_dart(),
+            self.identity_dir.into_into_dart().into_dart(),
+            self.role.into_into_dart().into_dart(),
+            self.scopes.into_into_dart().into_dart(),
+            self.token.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.locale.into_into_dart().into_dart(),
+            self.user_agent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ShutdownEvent> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ShutdownEvent>> for ShutdownEvent {
-    fn into_into_dart(self) -> FrbWrapper<ShutdownEvent> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::connection::HandshakeOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::connection::HandshakeOptions>
+    for crate::api::connection::HandshakeOptions
+{
+    fn into_into_dart(self) -> crate::api::connection::HandshakeOptions {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamCloseParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::outbox::OutboxConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.max_items.into_into_dart().into_dart(),
+            self.ttl.into_into_dart().into_dart(),
+            self.persist_dir.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamCloseParams> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamCloseParams>> for StreamCloseParams {
-    fn into_into_dart(self) -> FrbWrapper<StreamCloseParams> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::outbox::OutboxConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::outbox::OutboxConfig>
+    for crate::api::outbox::OutboxConfig
+{
+    fn into_into_dart(self) -> crate::api::outbox::OutboxConfig {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamClosedEvent> {
+impl flutter_rust_bridge::IntoDart for crate::api::proxy::ProxyConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.host.into_into_dart().into_dart(),
+            self.port.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamClosedEvent> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamClosedEvent>> for StreamClosedEvent {
-    fn into_into_dart(self) -> FrbWrapper<StreamClosedEvent> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::proxy::ProxyConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy::ProxyConfig>
+    for crate::api::proxy::ProxyConfig
+{
+    fn into_into_dart(self) -> crate::api::proxy::ProxyConfig {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamDataEvent> {
+impl flutter_rust_bridge::IntoDart for crate::api::proxy::ProxyKind {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            Self::Http => 0.into_dart(),
+            Self::Socks5 => 1.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamDataEvent> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamDataEvent>> for StreamDataEvent {
-    fn into_into_dart(self) -> FrbWrapper<StreamDataEvent> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::proxy::ProxyKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::proxy::ProxyKind>
+    for crate::api::proxy::ProxyKind
+{
+    fn into_into_dart(self) -> crate::api::proxy::ProxyKind {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamOpenParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::connection::RequestDelivery {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            Self::Sent => 0.into_dart(),
+            Self::Queued => 1.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamOpenParams> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamOpenParams>> for StreamOpenParams {
-    fn into_into_dart(self) -> FrbWrapper<StreamOpenParams> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::connection::RequestDelivery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::connection::RequestDelivery>
+    for crate::api::connection::RequestDelivery
+{
+    fn into_into_dart(self) -> crate::api::connection::RequestDelivery {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<StreamSendParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::terminal::TerminalChunk {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.text.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.channel.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+            self.spans.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<StreamSendParams> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<StreamSendParams>> for StreamSendParams {
-    fn into_into_dart(self) -> FrbWrapper<StreamSendParams> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::terminal::TerminalChunk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::terminal::TerminalChunk>
+    for crate::api::terminal::TerminalChunk
+{
+    fn into_into_dart(self) -> crate::api::terminal::TerminalChunk {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SystemEvent> {
+impl flutter_rust_bridge::IntoDart for crate::api::terminal_emulator::TerminalColor {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            crate::api::terminal_emulator::TerminalColor::Default => [0.into_dart()].into_dart(),
+            crate::api::terminal_emulator::TerminalColor::Indexed { index } => {
+                [1.into_dart(), index.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::terminal_emulator::TerminalColor::Rgb { red, green, blue } => [
+                2.into_dart(),
+                red.into_into_dart().into_dart(),
+                green.into_into_dart().into_dart(),
+                blue.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SystemEvent> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SystemEvent>> for SystemEvent {
-    fn into_into_dart(self) -> FrbWrapper<SystemEvent> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::terminal_emulator::TerminalColor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::terminal_emulator::TerminalColor>
+    for crate::api::terminal_emulator::TerminalColor
+{
+    fn into_into_dart(self) -> crate::api::terminal_emulator::TerminalColor {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SystemProbeCheck> {
+impl flutter_rust_bridge::IntoDart for crate::api::terminal_emulator::TerminalLine {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.row.into_into_dart().into_dart(),
+            self.runs.into_into_dart().into_dart(),
+            self.wrapped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SystemProbeCheck> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SystemProbeCheck>> for SystemProbeCheck {
-    fn into_into_dart(self) -> FrbWrapper<SystemProbeCheck> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::terminal_emulator::TerminalLine
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::terminal_emulator::TerminalLine>
+    for crate::api::terminal_emulator::TerminalLine
+{
+    fn into_into_dart(self) -> crate::api::terminal_emulator::TerminalLine {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SystemProbeParams> {
+impl flutter_rust_bridge::IntoDart for crate::api::terminal_emulator::TerminalRun {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.text.into_into_dart().into_dart(),
+            self.style.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SystemProbeParams> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SystemProbeParams>> for SystemProbeParams {
-    fn into_into_dart(self) -> FrbWrapper<SystemProbeParams> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::terminal_emulator::TerminalRun
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::terminal_emulator::TerminalRun>
+    for crate::api::terminal_emulator::TerminalRun
+{
+    fn into_into_dart(self) -> crate::api::terminal_emulator::TerminalRun {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SystemProbeResult> {
+impl flutter_rust_bridge::IntoDart for crate::api::terminal_emulator::TerminalScreenUpdate {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.cols.into_into_dart().into_dart(),
+            self.rows.into_into_dart().into_dart(),
+            self.cursor_row.into_into_dart().into_dart(),
+            self.cursor_col.into_into_dart().into_dart(),
+            self.cursor_visible.into_into_dart().into_dart(),
+            self.alternate_screen.into_into_dart().into_dart(),
+            self.application_cursor_keys.into_into_dart().into_dart(),
+            self.bracketed_paste.into_into_dart().into_dart(),
+            self.full.into_into_dart().into_dart(),
+            self.lines.into_into_dart().into_dart(),
+            self.scrollback_len.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SystemProbeResult> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SystemProbeResult>> for SystemProbeResult {
-    fn into_into_dart(self) -> FrbWrapper<SystemProbeResult> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::terminal_emulator::TerminalScreenUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::terminal_emulator::TerminalScreenUpdate>
+    for crate::api::terminal_emulator::TerminalScreenUpdate
+{
+    fn into_into_dart(self) -> crate::api::terminal_emulator::TerminalScreenUpdate {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Value> {
+impl flutter_rust_bridge::IntoDart for crate::api::terminal_emulator::TerminalStyle {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.foreground.into_into_dart().into_dart(),
+            self.background.into_into_dart().into_dart(),
+            self.bold.into_into_dart().into_dart(),
+            self.dim.into_into_dart().into_dart(),
+            self.italic.into_into_dart().into_dart(),
+            self.underline.into_into_dart().into_dart(),
+            self.inverse.into_into_dart().into_dart(),
+            self.hidden.into_into_dart().into_dart(),
+            self.strikethrough.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Value> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Value>> for Value {
-    fn into_into_dart(self) -> FrbWrapper<Value> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::terminal_emulator::TerminalStyle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::terminal_emulator::TerminalStyle>
+    for crate::api::terminal_emulator::TerminalStyle
+{
+    fn into_into_dart(self) -> crate::api::terminal_emulator::TerminalStyle {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::connection::GatewayClient {
+impl flutter_rust_bridge::IntoDart for crate::api::tls::TlsOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.url.into_into_dart().into_dart()].into_dart()
+        [
+            self.extra_ca_pem.into_into_dart().into_dart(),
+            self.spki_pins.into_into_dart().into_dart(),
+            self.trust_on_first_use.into_into_dart().into_dart(),
+            self.known_hosts_dir.into_into_dart().into_dart(),
+            self.allow_self_signed.into_into_dart().into_dart(),
+            self.client_cert_chain_pem.into_into_dart().into_dart(),
+            self.client_key_pem.into_into_dart().into_dart(),
+            self.client_key_pkcs8.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::connection::GatewayClient
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::connection::GatewayClient>
-    for crate::api::connection::GatewayClient
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::tls::TlsOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::tls::TlsOptions>
+    for crate::api::tls::TlsOptions
 {
-    fn into_into_dart(self) -> crate::api::connection::GatewayClient {
+    fn into_into_dart(self) -> crate::api::tls::TlsOptions {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::terminal::TerminalChunk {
+impl flutter_rust_bridge::IntoDart for crate::api::events::UpgradeHeader {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.text.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::terminal::TerminalChunk
+    for crate::api::events::UpgradeHeader
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::terminal::TerminalChunk>
-    for crate::api::terminal::TerminalChunk
+impl flutter_rust_bridge::IntoIntoDart<crate::api::events::UpgradeHeader>
+    for crate::api::events::UpgradeHeader
 {
-    fn into_into_dart(self) -> crate::api::terminal::TerminalChunk {
+    fn into_into_dart(self) -> crate::api::events::UpgradeHeader {
         self
     }
 }
@@ -24188,6 +27393,13 @@ impl SseEncode for BTreeMap<String, bool> {
     }
 }
 
+impl SseEncode for CallbackTokenStore {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
 impl SseEncode for CameraSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -24230,6 +27442,13 @@ impl SseEncode for ConnectParams {
     }
 }
 
+impl SseEncode for DeviceIdentity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
 impl SseEncode for ExecOutput {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -24284,6 +27503,18 @@ impl SseEncode for GatewayRequestParams {
     }
 }
 
+impl SseEncode for GatewayResponsePayload {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for HelloAuth {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -24450,6 +27681,13 @@ impl SseEncode for SystemProbeResult {
     }
 }
 
+impl SseEncode for TerminalEmulator {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
 impl SseEncode for Value {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -24460,6 +27698,24 @@ impl SseEncode for Value {
     }
 }
 
+impl SseEncode for chrono::Duration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(
+            self.num_microseconds()
+                .expect("cannot get microseconds from time"),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.encode(), serializer);
+    }
+}
+
 impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AgentTurn>>
 {
@@ -24497,6 +27753,17 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
 impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CameraSnapshot>>
 {
@@ -24563,6 +27830,17 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
 impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ExecOutput>>
 {
@@ -24640,6 +27918,19 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
 impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HelloAuth>>
 {
@@ -24893,52 +28184,242 @@ impl SseEncode
     }
 }
 
-impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Value>> {
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Value>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode for StreamSink<GatewayEvent, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::connection::ConnectionState,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::terminal::TerminalChunk,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::backpressure::BackpressurePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::backpressure::BackpressurePolicy::Block => 0,
+                crate::api::backpressure::BackpressurePolicy::DropOldest => 1,
+                crate::api::backpressure::BackpressurePolicy::Coalesce => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::backpressure::BackpressureStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.queued, serializer);
+        <bool>::sse_encode(self.paused, serializer);
+        <u64>::sse_encode(self.dropped_logs, serializer);
+        <u64>::sse_encode(self.dropped_output, serializer);
+        <u64>::sse_encode(self.dropped_events, serializer);
+        <u64>::sse_encode(self.coalesced, serializer);
+        <u64>::sse_encode(self.lagged, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::deflate::CompressionStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.negotiated, serializer);
+        <bool>::sse_encode(self.outgoing_compression_disabled, serializer);
+        <u64>::sse_encode(self.sent_bytes, serializer);
+        <u64>::sse_encode(self.sent_wire_bytes, serializer);
+        <u64>::sse_encode(self.received_bytes, serializer);
+        <u64>::sse_encode(self.received_wire_bytes, serializer);
     }
 }
 
-impl SseEncode for StreamSink<GatewayEvent, flutter_rust_bridge::for_generated::SseCodec> {
+impl SseEncode for crate::api::connection::ConnectionConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <chrono::Duration>::sse_encode(self.backoff_base, serializer);
+        <chrono::Duration>::sse_encode(self.max_backoff, serializer);
+        <bool>::sse_encode(self.jitter, serializer);
+        <Option<u32>>::sse_encode(self.max_attempts, serializer);
+        <chrono::Duration>::sse_encode(self.connect_timeout, serializer);
+        <chrono::Duration>::sse_encode(self.heartbeat_interval, serializer);
+        <chrono::Duration>::sse_encode(self.heartbeat_timeout, serializer);
+        <chrono::Duration>::sse_encode(self.handshake_timeout, serializer);
+        <Option<u32>>::sse_encode(self.max_sessions, serializer);
+        <chrono::Duration>::sse_encode(self.restart_probe_interval, serializer);
+        <chrono::Duration>::sse_encode(self.restart_probe_window, serializer);
+        <chrono::Duration>::sse_encode(self.max_restart_wait, serializer);
+        <Option<crate::api::outbox::OutboxConfig>>::sse_encode(self.outbox, serializer);
+        <u32>::sse_encode(self.inbound_capacity, serializer);
+        <u32>::sse_encode(self.outbound_capacity, serializer);
+        <crate::api::backpressure::BackpressurePolicy>::sse_encode(self.logs_policy, serializer);
+        <crate::api::backpressure::BackpressurePolicy>::sse_encode(self.output_policy, serializer);
+        <crate::api::backpressure::BackpressurePolicy>::sse_encode(self.event_policy, serializer);
+        <chrono::Duration>::sse_encode(self.output_batch_window, serializer);
+        <u32>::sse_encode(self.output_batch_bytes, serializer);
+        <Option<crate::api::tls::TlsOptions>>::sse_encode(self.tls, serializer);
+        <Option<crate::api::proxy::ProxyConfig>>::sse_encode(self.proxy, serializer);
+        <Vec<crate::api::events::UpgradeHeader>>::sse_encode(self.upgrade_headers, serializer);
+        <Vec<String>>::sse_encode(self.subprotocols, serializer);
+        <Option<crate::api::deflate::DeflateConfig>>::sse_encode(self.deflate, serializer);
+    }
+}
+
+impl SseEncode for crate::api::connection::ConnectionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::connection::ConnectionState::Idle => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::connection::ConnectionState::Connecting { attempt } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(attempt, serializer);
+            }
+            crate::api::connection::ConnectionState::Handshaking => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::connection::ConnectionState::Ready {
+                protocol,
+                role,
+                subprotocol,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <u32>::sse_encode(protocol, serializer);
+                <String>::sse_encode(role, serializer);
+                <Option<String>>::sse_encode(subprotocol, serializer);
+            }
+            crate::api::connection::ConnectionState::Backoff {
+                next_retry_at_ms,
+                reason,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <i64>::sse_encode(next_retry_at_ms, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::api::connection::ConnectionState::Restarting => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::api::connection::ConnectionState::Failed { fatal, reason } => {
+                <i32>::sse_encode(6, serializer);
+                <bool>::sse_encode(fatal, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::api::terminal::TerminalChunk,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for crate::api::deflate::DeflateConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <u8>::sse_encode(self.client_max_window_bits, serializer);
+        <u8>::sse_encode(self.server_max_window_bits, serializer);
+        <bool>::sse_encode(self.client_no_context_takeover, serializer);
+        <bool>::sse_encode(self.server_no_context_takeover, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::connection::GatewayClient {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <String>::sse_encode(self.url, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::events::GatewayEventKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::events::GatewayEventKind::Connected => 0,
+                crate::api::events::GatewayEventKind::Disconnected => 1,
+                crate::api::events::GatewayEventKind::Message => 2,
+                crate::api::events::GatewayEventKind::Error => 3,
+                crate::api::events::GatewayEventKind::Other => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::connection::GatewayClient {
+impl SseEncode for crate::api::connection::HandshakeOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.client_id, serializer);
+        <Option<String>>::sse_encode(self.client_version, serializer);
+        <Option<String>>::sse_encode(self.platform, serializer);
+        <Option<String>>::sse_encode(self.display_name, serializer);
+        <Option<String>>::sse_encode(self.instance_id, serializer);
+        <Option<String>>::sse_encode(self.identity_dir, serializer);
+        <Option<String>>::sse_encode(self.role, serializer);
+        <Vec<String>>::sse_encode(self.scopes, serializer);
+        <Option<String>>::sse_encode(self.token, serializer);
+        <Option<String>>::sse_encode(self.password, serializer);
+        <Option<String>>::sse_encode(self.locale, serializer);
+        <Option<String>>::sse_encode(self.user_agent, serializer);
     }
 }
 
@@ -24956,6 +28437,16 @@ impl SseEncode for i64 {
     }
 }
 
+impl SseEncode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_i64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
 impl SseEncode for Vec<LogEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -25006,6 +28497,36 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<crate::api::terminal_emulator::TerminalLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::terminal_emulator::TerminalLine>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::terminal_emulator::TerminalRun> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::terminal_emulator::TerminalRun>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::events::UpgradeHeader> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::events::UpgradeHeader>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -25126,6 +28647,26 @@ impl SseEncode for Option<bool> {
     }
 }
 
+impl SseEncode for Option<crate::api::connection::ConnectionConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::connection::ConnectionConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::deflate::DeflateConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::deflate::DeflateConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -25146,6 +28687,36 @@ impl SseEncode for Option<i64> {
     }
 }
 
+impl SseEncode for Option<crate::api::outbox::OutboxConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::outbox::OutboxConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::proxy::ProxyConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::proxy::ProxyConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::tls::TlsOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::tls::TlsOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -25176,11 +28747,161 @@ impl SseEncode for Option<Vec<u8>> {
     }
 }
 
+impl SseEncode for crate::api::outbox::OutboxConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_items, serializer);
+        <chrono::Duration>::sse_encode(self.ttl, serializer);
+        <Option<String>>::sse_encode(self.persist_dir, serializer);
+    }
+}
+
+impl SseEncode for crate::api::proxy::ProxyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::proxy::ProxyKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.password, serializer);
+    }
+}
+
+impl SseEncode for crate::api::proxy::ProxyKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::proxy::ProxyKind::Http => 0,
+                crate::api::proxy::ProxyKind::Socks5 => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::connection::RequestDelivery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::connection::RequestDelivery::Sent => 0,
+                crate::api::connection::RequestDelivery::Queued => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::terminal::TerminalChunk {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.text, serializer);
         <String>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.channel, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.data, serializer);
+        <Vec<crate::api::terminal_emulator::TerminalRun>>::sse_encode(self.spans, serializer);
+    }
+}
+
+impl SseEncode for crate::api::terminal_emulator::TerminalColor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::terminal_emulator::TerminalColor::Default => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::terminal_emulator::TerminalColor::Indexed { index } => {
+                <i32>::sse_encode(1, serializer);
+                <u8>::sse_encode(index, serializer);
+            }
+            crate::api::terminal_emulator::TerminalColor::Rgb { red, green, blue } => {
+                <i32>::sse_encode(2, serializer);
+                <u8>::sse_encode(red, serializer);
+                <u8>::sse_encode(green, serializer);
+                <u8>::sse_encode(blue, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::terminal_emulator::TerminalLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.row, serializer);
+        <Vec<crate::api::terminal_emulator::TerminalRun>>::sse_encode(self.runs, serializer);
+        <bool>::sse_encode(self.wrapped, serializer);
+    }
+}
+
+impl SseEncode for crate::api::terminal_emulator::TerminalRun {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <crate::api::terminal_emulator::TerminalStyle>::sse_encode(self.style, serializer);
+    }
+}
+
+impl SseEncode for crate::api::terminal_emulator::TerminalScreenUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.cols, serializer);
+        <u32>::sse_encode(self.rows, serializer);
+        <u32>::sse_encode(self.cursor_row, serializer);
+        <u32>::sse_encode(self.cursor_col, serializer);
+        <bool>::sse_encode(self.cursor_visible, serializer);
+        <bool>::sse_encode(self.alternate_screen, serializer);
+        <bool>::sse_encode(self.application_cursor_keys, serializer);
+        <bool>::sse_encode(self.bracketed_paste, serializer);
+        <bool>::sse_encode(self.full, serializer);
+        <Vec<crate::api::terminal_emulator::TerminalLine>>::sse_encode(self.lines, serializer);
+        <u32>::sse_encode(self.scrollback_len, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+    }
+}
+
+impl SseEncode for crate::api::terminal_emulator::TerminalStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::terminal_emulator::TerminalColor>::sse_encode(self.foreground, serializer);
+        <crate::api::terminal_emulator::TerminalColor>::sse_encode(self.background, serializer);
+        <bool>::sse_encode(self.bold, serializer);
+        <bool>::sse_encode(self.dim, serializer);
+        <bool>::sse_encode(self.italic, serializer);
+        <bool>::sse_encode(self.underline, serializer);
+        <bool>::sse_encode(self.inverse, serializer);
+        <bool>::sse_encode(self.hidden, serializer);
+        <bool>::sse_encode(self.strikethrough, serializer);
+    }
+}
+
+impl SseEncode for crate::api::tls::TlsOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.extra_ca_pem, serializer);
+        <Vec<String>>::sse_encode(self.spki_pins, serializer);
+        <bool>::sse_encode(self.trust_on_first_use, serializer);
+        <Option<String>>::sse_encode(self.known_hosts_dir, serializer);
+        <bool>::sse_encode(self.allow_self_signed, serializer);
+        <Option<String>>::sse_encode(self.client_cert_chain_pem, serializer);
+        <Option<String>>::sse_encode(self.client_key_pem, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.client_key_pkcs8, serializer);
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -25210,6 +28931,14 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::events::UpgradeHeader {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -25228,7 +28957,10 @@ mod io {
     // Section: imports
 
     use super::*;
+    use crate::api::device_identity::*;
     use crate::api::events::*;
+    use crate::api::terminal_emulator::*;
+    use crate::api::token_store::*;
     use crate::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
@@ -25238,6 +28970,9 @@ mod io {
 
     // Section: boilerplate
 
+    use serde_json::Value;
+    use std::collections::BTreeMap;
+
     flutter_rust_bridge::frb_generated_boilerplate_io!();
 
     #[unsafe(no_mangle)]
@@ -25282,6 +29017,20 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BTreeMap < String , bool >>>::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(
         ptr: *const std::ffi::c_void,
@@ -25366,6 +29115,20 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConnectParams>>::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(
         ptr: *const std::ffi::c_void,
@@ -25464,6 +29227,20 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayRequestParams>>::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(
         ptr: *const std::ffi::c_void,
@@ -25786,6 +29563,20 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SystemProbeResult>>::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_openclaw_mobile_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_openclaw_mobile_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
         ptr: *const std::ffi::c_void,
@@ -25812,7 +29603,10 @@ mod web {
     // Section: imports
 
     use super::*;
+    use crate::api::device_identity::*;
     use crate::api::events::*;
+    use crate::api::terminal_emulator::*;
+    use crate::api::token_store::*;
     use crate::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
@@ -25824,6 +29618,9 @@ mod web {
 
     // Section: boilerplate
 
+    use serde_json::Value;
+    use std::collections::BTreeMap;
+
     flutter_rust_bridge::frb_generated_boilerplate_web!();
 
     #[wasm_bindgen]
@@ -25868,6 +29665,20 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BTreeMap < String , bool >>>::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackTokenStore(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackTokenStore>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraSnapshot(
         ptr: *const std::ffi::c_void,
@@ -25952,6 +29763,20 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConnectParams>>::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerExecOutput(
         ptr: *const std::ffi::c_void,
@@ -26050,6 +29875,20 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayRequestParams>>::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGatewayResponsePayload(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GatewayResponsePayload>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHelloAuth(
         ptr: *const std::ffi::c_void,
@@ -26372,6 +30211,20 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SystemProbeResult>>::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEmulator(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEmulator>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
         ptr: *const std::ffi::c_void,
@@ -29,6 +29,10 @@ impl Duration {
         }
     }
 
+    pub fn num_milliseconds(&self) -> i64 {
+        self.micros / 1_000
+    }
+
     pub fn num_microseconds(&self) -> Option<i64> {
         Some(self.micros)
     }
@@ -41,6 +45,18 @@ impl Duration {
     }
 }
 
+impl flutter_rust_bridge::IntoDart for Duration {
+    fn into_dart(self) -> flutter_rust_bridge::DartAbi {
+        flutter_rust_bridge::IntoDart::into_dart(self.micros)
+    }
+}
+
+impl flutter_rust_bridge::IntoIntoDart<Duration> for Duration {
+    fn into_into_dart(self) -> Duration {
+        self
+    }
+}
+
 #[flutter_rust_bridge::frb(init)]
 pub fn init_app() {
     // Default configuration for flutter_rust_bridge